@@ -0,0 +1,69 @@
+//! Compares bulk parsing with `parse_many` against a naive loop over `parse_from_spec`
+//! that interprets the format string for every input.
+//!
+//! Run with `cargo bench --bench parse_many`.
+
+use std::time::Instant;
+
+use eos::{
+    fmt::{format_spec, parse_many, parse_spec, ParseError},
+    DateTime, Utc,
+};
+
+const ITERATIONS: u32 = 100;
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+
+type Parsed = (Vec<DateTime<Utc>>, Vec<(usize, ParseError)>);
+
+fn bench<F: FnMut() -> Parsed>(name: &str, inputs: usize, mut f: F) {
+    // Warm up first
+    let mut last = f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        last = f();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<16} {:>8.1} ns/item ({} parsed, {} errors)",
+        name,
+        elapsed.as_nanos() as f64 / (ITERATIONS as f64 * inputs as f64),
+        last.0.len(),
+        last.1.len()
+    );
+}
+
+fn main() {
+    // Every tenth row is bad so the error path is exercised too
+    let inputs: Vec<String> = (0..10_000)
+        .map(|i| {
+            if i % 10 == 9 {
+                format!("2022-13-{:02} 00:00:00 +0000", i % 28 + 1)
+            } else {
+                format!(
+                    "2022-{:02}-{:02} {:02}:{:02}:00 -0500",
+                    i % 12 + 1,
+                    i % 28 + 1,
+                    i % 24,
+                    i % 60
+                )
+            }
+        })
+        .collect();
+    let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+
+    bench("parse_many", inputs.len(), || {
+        parse_many(inputs.iter().copied(), format_spec!("%Y-%m-%d %H:%M:%S %z"))
+    });
+    bench("naive loop", inputs.len(), || {
+        let mut parsed = Vec::new();
+        let mut errors = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let spec = parse_spec(FORMAT).unwrap();
+            match DateTime::parse_from_spec(input, spec) {
+                Ok(dt) => parsed.push(dt.in_timezone(Utc)),
+                Err(e) => errors.push((index, e)),
+            }
+        }
+        (parsed, errors)
+    });
+}
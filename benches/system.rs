@@ -0,0 +1,48 @@
+//! Compares querying the local timezone directly against the process-wide cache.
+//!
+//! Run with `cargo bench --bench system`.
+
+use std::time::Instant;
+
+use eos::{DateTime, System, TimeZone, Utc};
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench<F: FnMut() -> DateTime<Utc>>(name: &str, mut f: F) {
+    // Warm up so the cache is populated
+    let mut last = f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        last = f();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<24} {:>8.1} ns/iter ({})",
+        name,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+        last
+    );
+}
+
+fn main() -> Result<(), eos::Error> {
+    let system = System::new()?;
+    let cached = System::cached()?;
+    let utc = Utc::now();
+
+    bench("System::offset", || {
+        let offset = system.offset(utc.timestamp());
+        utc.with_timezone(offset).in_timezone(Utc)
+    });
+    bench("CachedSystem::offset", || {
+        let offset = cached.offset(utc.timestamp());
+        utc.with_timezone(offset).in_timezone(Utc)
+    });
+    bench("in_timezone(System)", || {
+        utc.in_timezone(system.clone()).in_timezone(Utc)
+    });
+    bench("in_timezone(Cached)", || {
+        utc.in_timezone(cached.clone()).in_timezone(Utc)
+    });
+    bench("System::now", || System::now().unwrap().in_timezone(Utc));
+    Ok(())
+}
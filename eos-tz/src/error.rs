@@ -12,8 +12,10 @@ pub enum ParseError {
     InvalidOffset,
     /// The abbreviation of a transition type was not UTF-8.
     InvalidAbbreviation,
-    /// The POSIX TZ string was invalid.
+    /// The POSIX TZ string footer was malformed.
     InvalidPosixTz,
+    /// A field of the POSIX TZ string was invalid.
+    Posix(eos::extra::PosixTzError),
 }
 
 /// The error type for most operations in the library.
@@ -39,6 +41,7 @@ impl std::fmt::Display for ParseError {
             ParseError::InvalidOffset => f.write_str("utcoffset is invalid"),
             ParseError::InvalidAbbreviation => f.write_str("abbreviation data was not UTF-8"),
             ParseError::InvalidPosixTz => f.write_str("POSIX TZ string is invalid"),
+            ParseError::Posix(e) => e.fmt(f),
         }
     }
 }
@@ -69,6 +72,12 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl From<eos::extra::PosixTzError> for ParseError {
+    fn from(e: eos::extra::PosixTzError) -> Self {
+        Self::Posix(e)
+    }
+}
+
 impl From<ParseError> for Error {
     fn from(e: ParseError) -> Self {
         Self::Parse(e)
@@ -84,6 +93,7 @@ impl std::error::Error for ParseError {
             ParseError::InvalidOffset => None,
             ParseError::InvalidAbbreviation => None,
             ParseError::InvalidPosixTz => None,
+            ParseError::Posix(e) => Some(e),
         }
     }
 }
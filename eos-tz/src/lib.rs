@@ -1,12 +1,11 @@
 #![allow(clippy::manual_range_contains)]
 
 pub(crate) mod error;
-mod posix;
 pub(crate) mod reader;
 pub(crate) mod timestamp;
 mod timezone;
 mod transitions;
 
+pub use eos::extra::{PosixTimeZone, PosixTzError};
 pub use error::{Error, ParseError};
-pub use posix::PosixTimeZone;
 pub use timezone::TimeZone;
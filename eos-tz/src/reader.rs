@@ -5,9 +5,10 @@ use std::{
     str::FromStr,
 };
 
+use eos::extra::PosixTimeZone;
+
 use crate::{
     error::ParseError,
-    posix::PosixTimeZone,
     transitions::{Transition, TransitionType},
 };
 
@@ -55,20 +55,10 @@ impl NaiveTimestamp {
         Self(secs)
     }
 
-    /// Returns the inner value. These are the number of seconds.
-    pub(crate) const fn into_inner(self) -> i64 {
-        self.0
-    }
-
     /// Converts the naive timestamp into a UTC [`DateTime`].
     pub(crate) fn to_utc(self) -> DateTime<Utc> {
         eos::Timestamp::from_seconds(self.0).to_utc()
     }
-
-    // Turns a NaiveTimestamp into a eos::Timestamp from a UtcOffset
-    pub(crate) fn to_regular(self, offset: &eos::UtcOffset) -> eos::Timestamp {
-        eos::Timestamp::from_seconds(self.0 - offset.total_seconds() as i64)
-    }
 }
 
 impl std::fmt::Debug for NaiveTimestamp {
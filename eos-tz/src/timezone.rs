@@ -3,9 +3,10 @@ use std::{
     sync::Arc,
 };
 
+use eos::extra::PosixTimeZone;
+
 use crate::{
     error::{Error, ParseError},
-    posix::PosixTimeZone,
     reader::parse_tzif,
     timestamp::NaiveTimestamp,
     transitions::{Transition, TransitionType},
@@ -27,7 +28,7 @@ struct TimeZoneData {
 pub struct TimeZone(Arc<TimeZoneData>);
 
 #[cfg(target_family = "unix")]
-const TZ_SEARCH_PATHS: [&'static str; 4] = [
+const TZ_SEARCH_PATHS: [&str; 4] = [
     "/usr/share/zoneinfo",
     "/usr/lib/zoneinfo",
     "/usr/share/lib/zoneinfo",
@@ -251,6 +252,20 @@ impl eos::TimeZone for TimeZone {
         }
     }
 
+    fn info_at(&self, ts: eos::Timestamp) -> eos::ZoneInfo {
+        match self.get_transition(ts.into()) {
+            None => match &self.0.posix {
+                // See `offset` for why this is using the last transition
+                None => eos::ZoneInfo::new(self.offset(ts), None, false),
+                Some(posix) => posix.info_at(ts),
+            },
+            Some(trans) => match self.0.ttypes.get(trans.name_idx) {
+                Some(ttype) => eos::ZoneInfo::new(trans.offset, Some(ttype.abbr.clone().into()), ttype.is_dst),
+                None => eos::ZoneInfo::new(trans.offset, None, false),
+            },
+        }
+    }
+
     fn convert_utc(self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
     where
         Self: Sized,
@@ -260,7 +275,7 @@ impl eos::TimeZone for TimeZone {
             None => match &self.0.posix {
                 None => utc.with_timezone(self.clone()),
                 Some(posix) => {
-                    posix.shift_utc(&mut utc);
+                    utc.shift(posix.offset(ts));
                     utc.with_timezone(self.clone())
                 }
             },
@@ -307,7 +322,7 @@ impl eos::TimeZone for TimeZone {
                 // If this transition is in the future then we fall back to the POSIX timezone
                 match &self.0.posix {
                     Some(posix) => {
-                        let (kind, earlier, later) = posix.partial_resolution(&date, &time);
+                        let (kind, earlier, later) = posix.resolve_offsets(&date, &time);
                         return match kind {
                             eos::DateTimeResolutionKind::Missing => {
                                 eos::DateTimeResolution::missing(date, time, earlier, later, self.clone())
@@ -369,6 +384,30 @@ impl eos::TimeZone for TimeZone {
         eos::DateTimeResolution::unambiguous(date, time, trans.offset, self.clone())
     }
 
+    fn next_transition(
+        &self,
+        after: &eos::DateTime<eos::Utc>,
+    ) -> Option<(eos::DateTime<eos::Utc>, eos::UtcOffset, eos::UtcOffset)> {
+        let ts = after.timestamp().as_seconds();
+        let transitions = &self.0.transitions;
+        // The first transition extends to the beginning of time so it has nothing before it
+        let idx = transitions.partition_point(|t| t.utc_start.0 <= ts).max(1);
+        for pair in transitions.get(idx - 1..).unwrap_or_default().windows(2) {
+            let (prev, trans) = (&pair[0], &pair[1]);
+            // Some transitions only change the name or DST status, those are skipped
+            if prev.offset != trans.offset {
+                return Some((trans.utc_start.to_utc(), prev.offset, trans.offset));
+            }
+        }
+
+        // Past the transition list the POSIX timezone takes over
+        let posix = self.0.posix.as_ref()?;
+        match transitions.last() {
+            Some(last) if last.utc_start.0 > ts => posix.next_transition(&last.utc_start.to_utc()),
+            _ => posix.next_transition(after),
+        }
+    }
+
     fn is_fixed(&self) -> bool {
         self.0.fixed
     }
@@ -376,14 +415,11 @@ impl eos::TimeZone for TimeZone {
 
 #[cfg(test)]
 mod tests {
-    use eos::datetime;
-
-    use super::*;
-
     #[test]
     #[cfg(feature = "bundled")]
     fn test_bundled_loading() {
-        use eos::TimeZone;
+        use super::*;
+        use eos::{datetime, TimeZone};
 
         let dt = datetime!(1911-12-30 00:00);
         let tz = zone!("Africa/Abidjan");
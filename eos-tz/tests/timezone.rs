@@ -465,6 +465,81 @@ fn test_unambiguous() {
     }
 }
 
+#[test]
+fn test_zone_info() {
+    for (key, transitions) in get_zonedump_data() {
+        let zone = get_zone(key);
+        for transition in transitions {
+            let before = transition.transition - 2.days();
+            let after = transition.transition + 2.days();
+            trace_variables!(key, before, after, transition, {
+                for ts in [before.timestamp(), after.timestamp()] {
+                    let info = zone.info_at(ts);
+                    assert_eq!(info.name(), zone.name(ts));
+                    assert_eq!(info.offset(), &zone.offset(ts));
+                }
+            });
+        }
+    }
+
+    // Both of these are past the transition data and use the POSIX string
+    let zone = zone!("America/Los_Angeles");
+    let summer = zone.info_at(datetime!(2100-07-01 12:00).timestamp());
+    assert_eq!(summer.name(), Some("PDT"));
+    assert_eq!(summer.offset(), &utc_offset!(-07:00));
+    assert!(summer.is_dst());
+    let winter = zone.info_at(datetime!(2100-01-01 12:00).timestamp());
+    assert_eq!(winter.name(), Some("PST"));
+    assert_eq!(winter.offset(), &utc_offset!(-08:00));
+    assert!(!winter.is_dst());
+
+    let summer = datetime!(2021-07-01 12:00).in_timezone(zone.clone()).zone_info();
+    assert_eq!(summer.name(), Some("PDT"));
+    assert!(summer.is_dst());
+    let winter = datetime!(2021-01-01 12:00).in_timezone(zone).zone_info();
+    assert_eq!(winter.name(), Some("PST"));
+    assert!(!winter.is_dst());
+
+    let info = zone!("UTC").info_at(datetime!(2021-07-01 12:00).timestamp());
+    assert_eq!(info.name(), Some("UTC"));
+    assert!(!info.is_dst());
+}
+
+#[test]
+fn test_next_transition() {
+    for (key, transitions) in get_zonedump_data() {
+        let zone = get_zone(key);
+        for transition in transitions {
+            // Transitions that don't change the offset are skipped
+            if transition.offset_before.offset == transition.offset_after.offset {
+                continue;
+            }
+            let mut instant = transition.transition;
+            instant.shift(-transition.offset_before.offset);
+            let expected = (instant, transition.offset_before.offset, transition.offset_after.offset);
+            trace_variables!(key, instant, transition, {
+                assert_eq!(zone.next_transition(&(instant - 1.seconds())), Some(expected));
+                assert_ne!(zone.next_transition(&instant), Some(expected));
+            });
+        }
+    }
+
+    // Past the transition data the POSIX string is used
+    let zone = zone!("America/Los_Angeles");
+    let found: Vec<_> =
+        eos::iter::transitions(zone, datetime!(2100-01-01 00:00), datetime!(2101-01-01 00:00)).collect();
+    assert_eq!(
+        found,
+        [
+            (datetime!(2100-03-14 10:00), utc_offset!(-08:00), utc_offset!(-07:00)),
+            (datetime!(2100-11-07 09:00), utc_offset!(-07:00), utc_offset!(-08:00)),
+        ]
+    );
+
+    assert_eq!(zone!("UTC").next_transition(&datetime!(2021-01-01 00:00)), None);
+    assert_eq!(zone!("Asia/Tokyo").next_transition(&datetime!(2021-01-01 00:00)), None);
+}
+
 #[test]
 fn test_ambiguous_times() {
     for (key, transitions) in get_zonedump_data() {
@@ -719,3 +794,21 @@ fn test_america_los_angeles_historical() {
     assert!(resolve.clone().later().is_err());
     assert_eq!(resolve.lenient(), datetime!(1991-04-07 03:30 am -07:00));
 }
+
+#[test]
+fn test_start_of_day_skipping_midnight() {
+    // On 2018-11-04 clocks in Sao Paulo went from 00:00 -03:00 to 01:00 -02:00
+    let zone = zone!("America/Sao_Paulo");
+    let dt = datetime!(2018-11-04 15:00).in_timezone(zone);
+    let start = dt.clone().start_of_day();
+    assert_eq!(start, datetime!(2018-11-04 01:00 -02:00));
+    assert_eq!(start.clone().start_of_day(), start);
+    assert_eq!(dt.clone().start_of_month(), datetime!(2018-11-01 00:00 -03:00));
+
+    let end = datetime!(2018-11-03 15:00)
+        .in_timezone(zone!("America/Sao_Paulo"))
+        .end_of_day();
+    assert_eq!(end.time(), &eos::Time::MAX);
+    assert_eq!(start.duration_since(&end), Ok(std::time::Duration::from_nanos(1)));
+    assert_eq!(end.offset(), &utc_offset!(-03:00));
+}
@@ -1,3 +1,5 @@
+// This needs to be a const to allow for compile-time lookups in `tzif!`
+#[allow(clippy::large_const_arrays)]
 pub mod data;
 
 /// An IANA tzdb zone entry.
@@ -0,0 +1,49 @@
+#![no_main]
+
+use eos::fmt::{FromIsoFormat, IsoFormatPrecision, Profile, ToIsoFormat};
+use eos::{Date, DateTime, IsoWeekDate, Time};
+use eos_fuzz::ArbitraryDateTime;
+use libfuzzer_sys::fuzz_target;
+
+const PRECISIONS: [IsoFormatPrecision; 6] = [
+    IsoFormatPrecision::Hour,
+    IsoFormatPrecision::Minute,
+    IsoFormatPrecision::Second,
+    IsoFormatPrecision::Millisecond,
+    IsoFormatPrecision::Microsecond,
+    IsoFormatPrecision::Nanosecond,
+];
+
+fuzz_target!(|input: ArbitraryDateTime| {
+    let date = input.date.get();
+    let time = input.time.get();
+    let dt = input.get();
+
+    // Every Display implementation must round trip through the ISO-8601 parser
+    assert_eq!(Date::from_iso_format(&date.to_string()), Ok(date));
+    let iso = date.iso_week();
+    assert_eq!(IsoWeekDate::from_iso_format(&iso.to_string()), Ok(iso));
+    assert_eq!(Time::from_iso_format(&time.to_string()), Ok(time));
+    assert_eq!(DateTime::from_iso_format(&dt.to_string()), Ok(dt));
+
+    // Lower precisions only truncate, so the prefix must be the same
+    let full = time.to_iso_format_with_precision(IsoFormatPrecision::Nanosecond);
+    for precision in PRECISIONS {
+        let s = time.to_iso_format_with_precision(precision);
+        if precision != IsoFormatPrecision::Hour {
+            assert!(full.starts_with(&s), "{} is not a prefix of {}", s, full);
+        }
+        let s = dt.to_iso_format_with_precision(precision);
+        assert!(s.starts_with(&date.to_string()));
+    }
+
+    let _ = dt.to_rfc3339().to_string();
+
+    // Profiles are allowed to reject values they can't represent, but not panic
+    for profile in [Profile::Rfc3339, Profile::Iso8601Extended, Profile::Iso8601Basic] {
+        if let Ok(formatter) = dt.format_with_profile(profile) {
+            let s = formatter.to_string();
+            assert!(DateTime::parse_with_profile(&s, profile).is_ok(), "{}", s);
+        }
+    }
+});
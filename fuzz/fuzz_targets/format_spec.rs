@@ -0,0 +1,40 @@
+#![no_main]
+
+use core::fmt::Write;
+
+use arbitrary::Arbitrary;
+use eos::fmt::format_spec;
+use eos::DateTime;
+use eos_fuzz::ArbitraryDateTime;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    dt: ArbitraryDateTime,
+    spec: &'a str,
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let dt = input.dt.get();
+
+    // Any valid format string must be able to format any valid value
+    if let Ok(spec) = eos::fmt::parse_spec(input.spec) {
+        let mut buffer = String::new();
+        write!(&mut buffer, "{}", dt.date().format(&spec)).unwrap();
+        write!(&mut buffer, "{}", dt.time().format(&spec)).unwrap();
+        write!(&mut buffer, "{}", dt.format(&spec)).unwrap();
+        let _ = DateTime::parse_from_spec(&buffer, &spec);
+    }
+
+    // These transmit every field so they must round trip
+    let specs = [
+        &format_spec!("%Y-%m-%d %H:%M:%S.%f%z")[..],
+        &format_spec!("%G-W%V-%u %H:%M:%S.%f%o")[..],
+        &format_spec!("%a %B %d %Y at %I:%M:%S.%f %p %o")[..],
+    ];
+
+    for spec in specs {
+        let s = dt.format(spec).to_string();
+        assert_eq!(DateTime::parse_from_spec(&s, spec), Ok(dt), "{}", s);
+    }
+});
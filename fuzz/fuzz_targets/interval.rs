@@ -0,0 +1,12 @@
+#![no_main]
+
+use eos::fmt::ToIsoFormat;
+use eos_fuzz::ArbitraryInterval;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: ArbitraryInterval| {
+    let interval = input.get();
+    let s = interval.to_string();
+    assert!(s.starts_with('P'), "{}", s);
+    assert_eq!(s, interval.to_iso_format());
+});
@@ -0,0 +1,89 @@
+//! Shared input types for the fuzz targets.
+//!
+//! The library types don't implement [`Arbitrary`] themselves, so these types generate
+//! the raw components and then clamp them into valid values using the public constructors.
+//! This way the fuzzer spends its time on the formatters rather than on rejected inputs.
+
+use arbitrary::Arbitrary;
+use eos::{gregorian::days_in_month, Date, DateTime, Interval, Time, UtcOffset};
+
+/// A valid [`Date`] spanning the entire supported range.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct ArbitraryDate {
+    year: i16,
+    month: u8,
+    day: u8,
+}
+
+impl ArbitraryDate {
+    pub fn get(&self) -> Date {
+        let month = self.month % 12 + 1;
+        let day = self.day % days_in_month(self.year, month) + 1;
+        Date::new(self.year, month, day).expect("date components should be in range")
+    }
+}
+
+/// A valid [`Time`], including leap seconds at the end of a minute.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct ArbitraryTime {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl ArbitraryTime {
+    pub fn get(&self) -> Time {
+        let second = self.second % 60;
+        let nanosecond = if second == 59 {
+            self.nanosecond % 2_000_000_000
+        } else {
+            self.nanosecond % 1_000_000_000
+        };
+        Time::new(self.hour % 24, self.minute % 60, second)
+            .and_then(|t| t.with_nanosecond(nanosecond))
+            .expect("time components should be in range")
+    }
+}
+
+/// A valid [`UtcOffset`] spanning the entire supported range.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct ArbitraryOffset {
+    seconds: i32,
+}
+
+impl ArbitraryOffset {
+    pub fn get(&self) -> UtcOffset {
+        UtcOffset::from_seconds(self.seconds % 86_401).expect("offset should be in range")
+    }
+}
+
+/// A valid [`DateTime`] with a fixed offset.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct ArbitraryDateTime {
+    pub date: ArbitraryDate,
+    pub time: ArbitraryTime,
+    pub offset: ArbitraryOffset,
+}
+
+impl ArbitraryDateTime {
+    pub fn get(&self) -> DateTime<UtcOffset> {
+        self.date.get().at(self.time.get()).with_timezone(self.offset.get())
+    }
+}
+
+/// An [`Interval`] made up of any combination of its components.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct ArbitraryInterval {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl ArbitraryInterval {
+    pub fn get(&self) -> Interval {
+        Interval::from_months(self.months)
+            + Interval::from_days(self.days)
+            + Interval::from_microseconds(self.microseconds)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Date, DateTime, Error, IsoWeekDate, Time, TimeZone, Weekday};
+use crate::{
+    gregorian::{days_in_year, iso_weeks_in_year},
+    Date, DateTime, DateTimeResolution, Error, IsoWeekDate, Time, TimeZone, Weekday,
+};
 
 /// A builder to construct a [`Date`], [`Time`], or [`DateTime`] instance.
 ///
@@ -12,6 +15,27 @@ use crate::{Date, DateTime, Error, IsoWeekDate, Time, TimeZone, Weekday};
 /// - Hour, minute, seconds, and nanoseconds default to `0`.
 /// - Month and day default to `1`.
 /// - Year defaults to `1970`.
+///
+/// A date can be given in one of three ways: a month and day, an ordinal day, or an
+/// ISO week. Giving more than one of these results in [`Error::ConflictingFields`].
+///
+/// The final step can also resolve the local date and time in a timezone set with
+/// [`Builder::timezone`], see [`Builder::build_in`].
+///
+/// ```
+/// use eos::{datetime, Builder};
+///
+/// let dt = Builder::new()
+///     .year(2021)
+///     .month(11)
+///     .day(7)
+///     .hour(1)
+///     .minute(30)
+///     .timezone(eos::utc_offset!(-05:00))
+///     .build_lenient_in()?;
+/// assert_eq!(dt, datetime!(2021-11-07 01:30 -05:00));
+/// # Ok::<_, eos::Error>(())
+/// ```
 #[derive(Debug, Clone)]
 #[must_use]
 pub struct Builder<Tz>
@@ -19,9 +43,10 @@ where
     Tz: TimeZone,
 {
     year: Option<i16>,
-    month: u8,
-    day: u8,
+    month: Option<u8>,
+    day: Option<u8>,
     ordinal: Option<u16>,
+    iso_year: Option<i16>,
     iso_week: Option<u8>,
     weekday: Option<Weekday>,
     meridiem: Option<AmPm>,
@@ -40,12 +65,14 @@ enum AmPm {
 
 impl Builder<crate::Utc> {
     /// Creates a new [`Builder`] with a UTC timezone.
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         Self {
             year: None,
-            month: 1,
-            day: 1,
+            month: None,
+            day: None,
             ordinal: None,
+            iso_year: None,
             iso_week: None,
             weekday: None,
             meridiem: None,
@@ -58,6 +85,76 @@ impl Builder<crate::Utc> {
     }
 }
 
+impl<Tz> Builder<Tz>
+where
+    Tz: TimeZone,
+{
+    fn with_parts(date: &Date, time: &Time, timezone: Tz) -> Self {
+        Self {
+            year: Some(date.year()),
+            month: Some(date.month()),
+            day: Some(date.day()),
+            ordinal: None,
+            iso_year: None,
+            iso_week: None,
+            weekday: None,
+            meridiem: None,
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            nanosecond: time.nanosecond(),
+            timezone,
+        }
+    }
+}
+
+/// Creates a [`Builder`] with every field and the timezone taken from the [`DateTime`].
+///
+/// Any field can then be overwritten before building. Since the date is given as a year,
+/// month, and day, setting an ordinal or ISO week afterwards results in
+/// [`Error::ConflictingFields`].
+///
+/// ```
+/// use eos::{datetime, Builder};
+///
+/// let dt = datetime!(2022-03-17 14:25:30 -05:00);
+/// let start_of_month = Builder::from(&dt).day(1).hour(0).minute(0).second(0).build()?;
+/// assert_eq!(start_of_month, datetime!(2022-03-01 00:00 -05:00));
+/// # Ok::<_, eos::Error>(())
+/// ```
+impl<Tz: TimeZone> From<DateTime<Tz>> for Builder<Tz> {
+    fn from(dt: DateTime<Tz>) -> Self {
+        Self::with_parts(&dt.date, &dt.time, dt.timezone)
+    }
+}
+
+impl<Tz: TimeZone> From<&DateTime<Tz>> for Builder<Tz> {
+    fn from(dt: &DateTime<Tz>) -> Self {
+        Self::with_parts(&dt.date, &dt.time, dt.timezone.clone())
+    }
+}
+
+/// Creates a UTC [`Builder`] with the year, month, and day taken from the [`Date`].
+impl From<Date> for Builder<crate::Utc> {
+    fn from(date: Date) -> Self {
+        Self::with_parts(&date, &Time::MIDNIGHT, crate::Utc)
+    }
+}
+
+/// Creates a UTC [`Builder`] with the time fields taken from the [`Time`].
+///
+/// The date fields are left unset and use their defaults.
+impl From<Time> for Builder<crate::Utc> {
+    fn from(time: Time) -> Self {
+        let mut builder = Self::new();
+        builder.hour = time.hour();
+        builder.minute = time.minute();
+        builder.second = time.second();
+        builder.nanosecond = time.nanosecond();
+        builder
+    }
+}
+
 impl<Tz> Builder<Tz>
 where
     Tz: TimeZone,
@@ -74,7 +171,7 @@ where
     ///
     /// This does *not* do any bound checking. The final build step does.
     pub fn month(&mut self, month: u8) -> &mut Self {
-        self.month = month;
+        self.month = Some(month);
         self
     }
 
@@ -82,7 +179,7 @@ where
     ///
     /// This does *not* do any bound checking. The final build step does.
     pub fn day(&mut self, day: u8) -> &mut Self {
-        self.day = day;
+        self.day = Some(day);
         self
     }
 
@@ -102,6 +199,33 @@ where
         self
     }
 
+    /// Sets the date to the given ISO week-numbering year.
+    ///
+    /// This can differ from the calendar year near the start and end of the year.
+    /// If this is not given then the [year](Self::year) is used as the ISO year when
+    /// building from an ISO week.
+    #[inline]
+    pub fn iso_year(&mut self, iso_year: i16) -> &mut Self {
+        self.iso_year = Some(iso_year);
+        self
+    }
+
+    /// Sets the date to the given ISO week date.
+    ///
+    /// This is a shorthand for setting the [ISO year], [ISO week], and [weekday] together.
+    ///
+    /// This does *not* do any bound checking. The final build step does.
+    ///
+    /// [ISO year]: Self::iso_year
+    /// [ISO week]: Self::iso_week
+    /// [weekday]: Self::weekday
+    pub fn iso_week_date(&mut self, year: i16, week: u8, weekday: Weekday) -> &mut Self {
+        self.iso_year = Some(year);
+        self.iso_week = Some(week);
+        self.weekday = Some(weekday);
+        self
+    }
+
     /// Sets the date to the given ordinal day.
     ///
     /// This does *not* do any bound checking. The final build step does.
@@ -188,9 +312,10 @@ where
 
     /// Sets the timezone.
     ///
-    /// This does *not* do any time modification.
+    /// This does *not* do any time modification. The local date and time are
+    /// resolved in this timezone when building.
     #[inline]
-    pub fn timezone<OtherTz>(self, timezone: OtherTz) -> Builder<OtherTz>
+    pub fn timezone<OtherTz>(&self, timezone: OtherTz) -> Builder<OtherTz>
     where
         OtherTz: TimeZone,
     {
@@ -199,6 +324,7 @@ where
             month: self.month,
             day: self.day,
             ordinal: self.ordinal,
+            iso_year: self.iso_year,
             iso_week: self.iso_week,
             weekday: self.weekday,
             meridiem: self.meridiem,
@@ -218,31 +344,70 @@ where
     /// To build a [`Date`], see [`Self::build_date`]. To build a [`Time`],
     /// see [`Self::build_time`].
     pub fn build(&self) -> Result<DateTime<Tz>, Error> {
+        self.build_in()?.exact()
+    }
+
+    /// Builds the final local date and time and resolves it in the builder's timezone.
+    ///
+    /// Unlike [`Self::build`], a local date time that is missing or ambiguous in the
+    /// timezone is not an error. Instead, the returned [`DateTimeResolution`] allows
+    /// choosing how to handle it. If the components represent an invalid date or time
+    /// then an [`Error`] is returned.
+    pub fn build_in(&self) -> Result<DateTimeResolution<Tz>, Error> {
         let date = self.build_date()?;
         let time = self.build_time()?;
-        self.timezone.clone().at_exactly(date, time)
+        Ok(self.timezone.clone().resolve(date, time))
+    }
+
+    /// Builds the final [`DateTime`] and resolves it leniently in the builder's timezone.
+    ///
+    /// This is equivalent to calling [`DateTimeResolution::lenient`] on the result of
+    /// [`Self::build_in`].
+    pub fn build_lenient_in(&self) -> Result<DateTime<Tz>, Error> {
+        self.build_in().map(DateTimeResolution::lenient)
     }
 
     /// Builds the final [`Date`] with the given components.
     ///
     /// A date is built with the following priority:
     ///
-    /// 1. If an ordinal and year is given, then calculate it using that.
-    /// 2. If an ISO week and year is given, then calculate using Monday as the weekday.
-    /// 3. If an ISO week, weekday, and year is given then calculate using that.
-    /// 4. Calculate the date using the provided values or with their defaults.
+    /// 1. If an ordinal is given, then calculate it using that and the year.
+    /// 2. If an ISO year or ISO week is given, then calculate it using those and the
+    ///    weekday. The week defaults to `1` and the weekday defaults to Monday.
+    /// 3. Calculate the date using the provided values or with their defaults.
     ///
-    /// If the components represent an invalid date then an [`Error`]
-    /// is returned.
+    /// If more than one of a month and day, an ordinal, or an ISO week is given, or if
+    /// both a year and an ISO year are given and the year is not the calendar year of the
+    /// resulting date, then [`Error::ConflictingFields`] is returned.
+    /// If a field is invalid, such as an ordinal or ISO week that does not exist in its
+    /// year, then [`Error::FieldOutOfRange`] is returned.
     pub fn build_date(&self) -> Result<Date, Error> {
-        if let Some((ordinal, year)) = self.ordinal.zip(self.year) {
+        let calendar = self.month.is_some() || self.day.is_some();
+        let iso = self.iso_week.is_some() || self.iso_year.is_some();
+        let systems = [calendar, self.ordinal.is_some(), iso];
+        if systems.iter().filter(|&&given| given).count() > 1 {
+            return Err(Error::ConflictingFields);
+        }
+
+        let year = self.year.unwrap_or(1970);
+        if let Some(ordinal) = self.ordinal {
+            Error::check_field("ordinal", ordinal as i64, 1, days_in_year(year) as i64)?;
             Date::from_ordinal(year, ordinal).ok_or(Error::OutOfRange)
-        } else if let Some((week, year)) = self.iso_week.zip(self.year) {
+        } else if iso {
+            let iso_year = self.iso_year.unwrap_or(year);
+            let week = self.iso_week.unwrap_or(1);
             let weekday = self.weekday.unwrap_or(Weekday::Monday);
-            let iso_week = IsoWeekDate::new(year, week, weekday).ok_or(Error::OutOfRange)?;
-            Ok(Date::from(iso_week))
+            Error::check_field("week", week as i64, 1, iso_weeks_in_year(iso_year) as i64)?;
+            let iso_week = IsoWeekDate::new(iso_year, week, weekday).ok_or(Error::OutOfRange)?;
+            let date = Date::from(iso_week);
+            // The calendar year can differ from the ISO year, e.g. 2014-12-29 is in 2015-W01
+            match (self.iso_year, self.year) {
+                (Some(_), Some(year)) if year != date.year() => Err(Error::ConflictingFields),
+                _ => Ok(date),
+            }
         } else {
-            Date::new(self.year.unwrap_or(1970), self.month, self.day).ok_or(Error::OutOfRange)
+            let (month, day) = (self.month.unwrap_or(1), self.day.unwrap_or(1));
+            Date::try_new(year, month, day)
         }
     }
 
@@ -252,9 +417,11 @@ where
     /// 12-hour clock with a range of `1..=12`. If they're not called then 24-hour time
     /// is assumed.
     ///
-    /// If the components represent an invalid time then an [`Error`]
-    /// is returned.
+    /// If a component is out of range then [`Error::FieldOutOfRange`] is returned.
     pub fn build_time(&self) -> Result<Time, Error> {
+        if self.meridiem.is_some() {
+            Error::check_field("hour", self.hour as i64, 1, 12)?;
+        }
         let hour = match self.meridiem {
             Some(AmPm::Am) => {
                 if self.hour == 12 {
@@ -273,9 +440,12 @@ where
             None => self.hour,
         };
 
-        Time::new(hour, self.minute, self.second)
-            .and_then(|t| t.with_nanosecond(self.nanosecond))
-            .ok_or(Error::OutOfRange)
+        Error::check_field("nanosecond", self.nanosecond as i64, 0, 1_999_999_999)?;
+        let time = Time::try_new(hour, self.minute, self.second)?;
+        Ok(Time {
+            nanosecond: self.nanosecond,
+            ..time
+        })
     }
 }
 
@@ -312,4 +482,177 @@ mod tests {
         assert_eq!(date.day(), 22);
         Ok(())
     }
+
+    #[test]
+    fn test_conflicting_fields() {
+        let mut builder = Builder::new();
+        builder.year(2020).ordinal(60).day(1);
+        assert_eq!(builder.build_date(), Err(Error::ConflictingFields));
+        let mut builder = Builder::new();
+        builder.year(2020).iso_week(30).month(7);
+        assert_eq!(builder.build(), Err(Error::ConflictingFields));
+        let mut builder = Builder::new();
+        builder.year(2020).iso_week(30).ordinal(60);
+        assert_eq!(builder.build_in().map(|_| ()), Err(Error::ConflictingFields));
+
+        // A weekday on its own doesn't conflict with a calendar date
+        let date = Builder::new()
+            .year(2020)
+            .month(7)
+            .day(22)
+            .weekday(Weekday::Friday)
+            .build_date();
+        assert_eq!(date, Ok(Date::new(2020, 7, 22).unwrap()));
+    }
+
+    #[test]
+    fn test_build_in_timezone() -> Result<(), Error> {
+        let offset = crate::UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let resolution = Builder::new()
+            .year(2022)
+            .month(3)
+            .day(1)
+            .hour(9)
+            .timezone(offset)
+            .build_in()?;
+        assert!(resolution.is_unambiguous());
+        let dt = resolution.exact()?;
+        assert_eq!(dt.offset(), &offset);
+        assert_eq!(dt.time(), &Time::new(9, 0, 0).unwrap());
+        assert_eq!(
+            Builder::new().hour(24).timezone(offset).build_lenient_in(),
+            Err(Error::FieldOutOfRange {
+                field: "hour",
+                value: 24,
+                min: 0,
+                max: 23
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_existing_values() -> Result<(), Error> {
+        let date = Date::new(2022, 3, 17).unwrap();
+        let time = Time::new(14, 25, 30).unwrap().with_nanosecond(500).unwrap();
+        let dt = date.at(time);
+
+        // Setters overwrite the fields that were taken from the value
+        let patched = Builder::from(&dt).day(1).hour(0).build()?;
+        assert_eq!(patched.date(), &Date::new(2022, 3, 1).unwrap());
+        assert_eq!(
+            patched.time(),
+            &Time::new(0, 25, 30).unwrap().with_nanosecond(500).unwrap()
+        );
+        assert_eq!(Builder::from(dt).build()?, dt);
+
+        assert_eq!(Builder::from(date).build()?, date.at(Time::MIDNIGHT));
+        assert_eq!(
+            Builder::from(time).year(2000).build()?,
+            Date::new(2000, 1, 1).unwrap().at(time)
+        );
+        assert_eq!(Builder::from(date).ordinal(10).build(), Err(Error::ConflictingFields));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iso_week_date() -> Result<(), Error> {
+        // 2021-01-01 is in the 53rd week of 2020
+        let date = Builder::new().iso_week_date(2020, 53, Weekday::Friday).build_date()?;
+        assert_eq!(date, Date::new(2021, 1, 1).unwrap());
+        let mut builder = Builder::new();
+        builder.iso_week_date(2020, 53, Weekday::Friday).day(1);
+        assert_eq!(builder.build_date(), Err(Error::ConflictingFields));
+        assert_eq!(
+            Builder::new().iso_week_date(2021, 53, Weekday::Friday).build_date(),
+            Err(Error::FieldOutOfRange {
+                field: "week",
+                value: 53,
+                min: 1,
+                max: 52
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_date_system_errors() {
+        let out_of_range = |field, value, max| Error::FieldOutOfRange {
+            field,
+            value,
+            min: 1,
+            max,
+        };
+        assert_eq!(
+            Builder::new().ordinal(366).build_date(),
+            Err(out_of_range("ordinal", 366, 365))
+        );
+        assert_eq!(
+            Builder::new().year(2020).ordinal(0).build_date(),
+            Err(out_of_range("ordinal", 0, 366))
+        );
+        assert_eq!(
+            Builder::new().iso_week(0).build_date(),
+            Err(out_of_range("week", 0, 53))
+        );
+        assert_eq!(
+            Builder::new().iso_year(2020).iso_week(54).build_date(),
+            Err(out_of_range("week", 54, 53))
+        );
+        assert_eq!(
+            Builder::new().iso_year(2020).ordinal(1).build_date(),
+            Err(Error::ConflictingFields)
+        );
+        assert_eq!(
+            Builder::new().iso_year(2020).month(1).build_date(),
+            Err(Error::ConflictingFields)
+        );
+        assert_eq!(
+            Builder::new().year(2021).iso_year(2020).iso_week(1).build_date(),
+            Err(Error::ConflictingFields)
+        );
+        assert_eq!(
+            Builder::new().year(2019).iso_year(2020).build_date(),
+            Ok(Date::new(2019, 12, 30).unwrap())
+        );
+        assert_eq!(
+            Builder::new().year(2020).iso_year(2020).build_date(),
+            Err(Error::ConflictingFields)
+        );
+        assert_eq!(
+            Builder::new()
+                .year(2014)
+                .iso_week_date(2015, 1, Weekday::Monday)
+                .build_date(),
+            Ok(Date::new(2014, 12, 29).unwrap())
+        );
+        assert_eq!(
+            Builder::new()
+                .year(2016)
+                .iso_week_date(2015, 53, Weekday::Friday)
+                .build_date(),
+            Ok(Date::new(2016, 1, 1).unwrap())
+        );
+        assert_eq!(
+            Builder::new().year(2020).ordinal(366).build_date(),
+            Ok(Date::new(2020, 12, 31).unwrap())
+        );
+        assert_eq!(
+            Builder::new().month(4).day(31).build_date(),
+            Err(out_of_range("day", 31, 30))
+        );
+        assert_eq!(
+            Builder::new().hour(13).pm().build_time(),
+            Err(out_of_range("hour", 13, 12))
+        );
+        assert_eq!(
+            Builder::new().minute(60).build_time(),
+            Err(Error::FieldOutOfRange {
+                field: "minute",
+                value: 60,
+                min: 0,
+                max: 59
+            })
+        );
+    }
 }
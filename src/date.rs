@@ -1,17 +1,20 @@
 use crate::{
+    extra::{Month, WeekdaySet},
     gregorian::{
-        date_from_epoch_days, date_to_epoch_days, date_to_ordinal, days_in_month, find_iso_week_start_epoch,
-        is_leap_year, iso_week_start_epoch_from_year, iso_weeks_in_year, weekday_from_days,
+        date_from_epoch_days, date_to_epoch_days, date_to_ordinal, days_in_month, days_in_year,
+        find_iso_week_start_epoch, is_leap_year, iso_week_date_to_epoch_days, iso_weeks_in_year, weekday_difference,
+        weekday_from_days, MAX_EPOCH_DAYS, MIN_EPOCH_DAYS, UNIX_EPOCH_JULIAN_DAY,
     },
+    iter::{DateRange, YearDays, YearWeekdays},
     step::Advance,
-    utils::{divrem, ensure_in_range},
-    DateTime, Interval, Time, TimeZone, Utc,
+    utils::{divmod, ensure_in_range},
+    DateTime, Error, Interval, Time, TimeZone, Utc,
 };
 
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 #[cfg(feature = "system")]
-use crate::{sys::systemtime::get_system_time_components, Error};
+use crate::sys::systemtime::get_system_time_components;
 
 #[cfg(feature = "formatting")]
 use crate::fmt::ToIsoFormat;
@@ -39,6 +42,24 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    /// Every weekday, starting from Monday.
+    ///
+    /// ```
+    /// use eos::Weekday;
+    ///
+    /// assert_eq!(Weekday::ALL[0], Weekday::Monday);
+    /// assert_eq!(Weekday::ALL[6], Weekday::Sunday);
+    /// ```
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
     /// Returns the next weekday.
     ///
     /// Current | `Monday`  | `Tuesday`   | `Wednesday` | `Thursday` | `Friday`   | `Saturday` | `Sunday`
@@ -150,6 +171,146 @@ impl Weekday {
             Self::Sunday => 0,
         }
     }
+
+    /// Creates a [`Weekday`] from its number starting from Monday.
+    ///
+    /// This is the inverse of [`Weekday::number_from_monday`]. If the number
+    /// is out of bounds (`1..=7`) then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Weekday;
+    /// assert_eq!(Weekday::from_monday_number(1), Some(Weekday::Monday));
+    /// assert_eq!(Weekday::from_monday_number(7), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_monday_number(0), None);
+    /// ```
+    #[inline]
+    pub const fn from_monday_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::Monday),
+            2 => Some(Self::Tuesday),
+            3 => Some(Self::Wednesday),
+            4 => Some(Self::Thursday),
+            5 => Some(Self::Friday),
+            6 => Some(Self::Saturday),
+            7 => Some(Self::Sunday),
+            _ => None,
+        }
+    }
+
+    /// Creates a [`Weekday`] from its number starting from Sunday.
+    ///
+    /// This is the inverse of [`Weekday::number_from_sunday`]. If the number
+    /// is out of bounds (`1..=7`) then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Weekday;
+    /// assert_eq!(Weekday::from_sunday_number(1), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_sunday_number(7), Some(Weekday::Saturday));
+    /// assert_eq!(Weekday::from_sunday_number(8), None);
+    /// ```
+    #[inline]
+    pub const fn from_sunday_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::Sunday),
+            2 => Some(Self::Monday),
+            3 => Some(Self::Tuesday),
+            4 => Some(Self::Wednesday),
+            5 => Some(Self::Thursday),
+            6 => Some(Self::Friday),
+            7 => Some(Self::Saturday),
+            _ => None,
+        }
+    }
+
+    /// Returns the weekday that is `n` days after this one.
+    ///
+    /// This wraps around the week, so `nth_next(7)` is the same weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Weekday;
+    /// assert_eq!(Weekday::Monday.nth_next(2), Weekday::Wednesday);
+    /// assert_eq!(Weekday::Saturday.nth_next(3), Weekday::Tuesday);
+    /// assert_eq!(Weekday::Friday.nth_next(7), Weekday::Friday);
+    /// assert_eq!(Weekday::Friday.nth_next(0), Weekday::Friday);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn nth_next(self, n: u8) -> Self {
+        match Self::from_monday_number((self.days_from_monday() + n % 7) % 7 + 1) {
+            Some(weekday) => weekday,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the number of days from this weekday until the next `other` weekday.
+    ///
+    /// The result is within `0..=6`, where 0 means both weekdays are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Weekday;
+    /// assert_eq!(Weekday::Monday.days_until(Weekday::Wednesday), 2);
+    /// assert_eq!(Weekday::Wednesday.days_until(Weekday::Monday), 5);
+    /// assert_eq!(Weekday::Sunday.days_until(Weekday::Sunday), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn days_until(self, other: Self) -> u8 {
+        weekday_difference(other.days_from_sunday(), self.days_from_sunday())
+    }
+}
+
+/// Parses a weekday from either its full English name or its three letter abbreviation.
+///
+/// The comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// # use eos::Weekday;
+/// assert_eq!("Monday".parse(), Ok(Weekday::Monday));
+/// assert_eq!("thu".parse(), Ok(Weekday::Thursday));
+/// assert_eq!("SUNDAY".parse(), Ok(Weekday::Sunday));
+/// assert!("Thurs".parse::<Weekday>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for Weekday {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: [(&str, Weekday); 7] = [
+            ("monday", Weekday::Monday),
+            ("tuesday", Weekday::Tuesday),
+            ("wednesday", Weekday::Wednesday),
+            ("thursday", Weekday::Thursday),
+            ("friday", Weekday::Friday),
+            ("saturday", Weekday::Saturday),
+            ("sunday", Weekday::Sunday),
+        ];
+
+        crate::fmt::parse_english_name(s, &NAMES)
+    }
+}
+
+/// The policy for placing the anniversary of February 29th in a year that is not a leap year.
+///
+/// This is used by [`Date::anniversary_in`] and [`Date::next_anniversary_after`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapDayPolicy {
+    /// The anniversary is on February 28th, the last day of February.
+    ///
+    /// This is the same behaviour as adding years with an [`Interval`].
+    #[default]
+    February28,
+    /// The anniversary is on March 1st, the day after February 28th.
+    March1,
 }
 
 /// A date in the [ISO 8601 week date system].
@@ -234,6 +395,11 @@ impl Ord for IsoWeekDate {
 
 /// A concrete date in the proleptic Gregorian calendar.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug), bytecheck(verify))
+)]
 pub struct Date {
     /// There is a possibility of using bit compression to represent dates.
     ///
@@ -280,6 +446,14 @@ impl Date {
         Self { year, month, day }
     }
 
+    #[doc(hidden)]
+    #[cfg(feature = "macros")]
+    #[inline]
+    pub const fn __from_epoch_days_unchecked_from_macro(days: i32) -> Self {
+        let (year, month, day) = date_from_epoch_days(days);
+        Self { year, month, day }
+    }
+
     /// Creates a new [`Date`] representing today's date in local time.
     #[cfg(feature = "system")]
     #[inline]
@@ -318,12 +492,111 @@ impl Date {
     /// ```
     #[inline]
     pub fn new(year: i16, month: u8, day: u8) -> Option<Self> {
-        ensure_in_range!(month, 1 => 12);
-        ensure_in_range!(day, 1 => days_in_month(year, month));
+        if !Self::is_valid(year, month, day) {
+            return None;
+        }
         Some(Self { year, month, day })
     }
 
+    /// Creates a new [`Date`] from a given year, month, and day, returning which field
+    /// was invalid on failure.
+    ///
+    /// This is the same as [`Date::new`] except it returns [`Error::FieldOutOfRange`]
+    /// naming the month or day if the date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, Error};
+    ///
+    /// assert_eq!(Date::try_new(2020, 2, 29), Ok(date!(2020-02-29)));
+    /// assert_eq!(
+    ///     Date::try_new(2021, 2, 29),
+    ///     Err(Error::FieldOutOfRange { field: "day", value: 29, min: 1, max: 28 })
+    /// );
+    /// assert_eq!(
+    ///     Date::try_new(2021, 13, 1),
+    ///     Err(Error::FieldOutOfRange { field: "month", value: 13, min: 1, max: 12 })
+    /// );
+    /// ```
+    pub fn try_new(year: i16, month: u8, day: u8) -> Result<Self, Error> {
+        Error::check_field("month", month as i64, 1, 12)?;
+        Error::check_field("day", day as i64, 1, days_in_month(year, month) as i64)?;
+        Ok(Self { year, month, day })
+    }
+
+    /// Creates a new [`Date`] from a given year, [`Month`], and day.
+    ///
+    /// This is the same as [`Date::new`] except the month can't be out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date};
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(Date::new_with_month(2022, Month::March, 4), Some(date!(2022-03-04)));
+    /// assert_eq!(Date::new_with_month(2022, Month::April, 31), None);
+    /// ```
+    #[inline]
+    pub fn new_with_month(year: i16, month: Month, day: u8) -> Option<Self> {
+        Self::new(year, month.number(), day)
+    }
+
+    /// Returns `true` if the given year, month, and day form a valid date.
+    ///
+    /// This is the exact check that [`Date::new`] performs, without creating the date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Date;
+    /// assert!(Date::is_valid(2020, 2, 29));
+    /// assert!(!Date::is_valid(2021, 2, 29));
+    /// assert!(!Date::is_valid(2021, 13, 1));
+    /// assert!(!Date::is_valid(2021, 1, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(year: i16, month: u8, day: u8) -> bool {
+        match Self::max_day(year, month) {
+            Some(max) => day >= 1 && day <= max,
+            None => false,
+        }
+    }
+
+    /// Returns the last valid day of the given month and year.
+    ///
+    /// If the month is out of bounds (`1..=12`) then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Date;
+    /// assert_eq!(Date::max_day(2020, 2), Some(29));
+    /// assert_eq!(Date::max_day(2021, 2), Some(28));
+    /// assert_eq!(Date::max_day(2021, 4), Some(30));
+    /// assert_eq!(Date::max_day(2021, 0), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn max_day(year: i16, month: u8) -> Option<u8> {
+        if month < 1 || month > 12 {
+            None
+        } else {
+            Some(days_in_month(year, month))
+        }
+    }
+
     /// Combines this [`Date`] with a [`Time`] to create a [`DateTime`] in [`Utc`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time};
+    ///
+    /// assert_eq!(date!(2021-03-17).at(time!(09:30)), datetime!(2021-03-17 09:30));
+    /// ```
     #[inline]
     #[must_use]
     pub fn at(&self, time: Time) -> DateTime<Utc> {
@@ -335,6 +608,61 @@ impl Date {
         }
     }
 
+    /// Combines this [`Date`] with a [`Time`] to create a [`DateTime`] in the given [`TimeZone`].
+    ///
+    /// The local date and time are resolved using [`DateTimeResolution::lenient`]. That is,
+    /// if the time is ambiguous then the earlier time is returned, and if the time is
+    /// skipped then it's shifted forward by the length of the gap. If a different policy
+    /// is needed then use [`TimeZone::resolve`] directly.
+    ///
+    /// [`DateTimeResolution::lenient`]: crate::DateTimeResolution::lenient
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time, utc_offset};
+    ///
+    /// let dt = date!(2021-03-17).at_in(time!(09:30), utc_offset!(-05:00));
+    /// assert_eq!(dt, datetime!(2021-03-17 09:30 -05:00));
+    /// ```
+    #[must_use]
+    pub fn at_in<Tz>(self, time: Time, tz: Tz) -> DateTime<Tz>
+    where
+        Tz: TimeZone,
+    {
+        tz.resolve(self, time).lenient()
+    }
+
+    /// Returns a [`DateTime`] in [`Utc`] at midnight of this date.
+    ///
+    /// This is a shorthand for `date.at(Time::MIDNIGHT)`.
+    #[inline]
+    #[must_use]
+    pub fn midnight(&self) -> DateTime<Utc> {
+        self.at(Time::MIDNIGHT)
+    }
+
+    /// Returns a [`DateTime`] in [`Utc`] at noon of this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime};
+    ///
+    /// assert_eq!(date!(2021-03-17).noon(), datetime!(2021-03-17 12:00));
+    /// assert_eq!(date!(2021-03-17).midnight(), datetime!(2021-03-17 00:00));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn noon(&self) -> DateTime<Utc> {
+        self.at(Time {
+            hour: 12,
+            minute: 0,
+            second: 0,
+            nanosecond: 0,
+        })
+    }
+
     /// Formats this date with a given slice of [`crate::fmt::FormatSpec`].
     ///
     /// Check the [`crate::fmt`] module for more documentation.
@@ -361,13 +689,225 @@ impl Date {
         tz.resolve(self, Time::MIDNIGHT).lenient()
     }
 
+    /// Returns the half-open range of UTC instants that cover this local date in the given [`TimeZone`].
+    ///
+    /// The first element is the start of this date and the second element is the start
+    /// of the next date, both as determined by [`Date::in_timezone`]. This means the range
+    /// is not necessarily 24 hours long if there is a transition within the day.
+    ///
+    /// This is mainly useful for range queries, e.g. `ts >= start AND ts < end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, utc_offset};
+    ///
+    /// let (start, end) = date!(2022-01-15).instant_range_in(utc_offset!(-05:00));
+    /// assert_eq!(start, datetime!(2022-01-15 05:00));
+    /// assert_eq!(end, datetime!(2022-01-16 05:00));
+    /// ```
+    #[must_use]
+    pub fn instant_range_in<Tz>(&self, tz: Tz) -> (DateTime<Utc>, DateTime<Utc>)
+    where
+        Tz: TimeZone,
+    {
+        let start = self.in_timezone(tz.clone()).into_utc();
+        let end = self.add_days(1).in_timezone(tz).into_utc();
+        (start, end)
+    }
+
+    /// Adds an [`Interval`] to the date, returning `None` if the result is out of range.
+    ///
+    /// Only the year, month, and day components of the interval are used, similar
+    /// to the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(date!(2012-02-29).checked_add(1.years()), Some(date!(2013-02-28)));
+    /// assert_eq!(Date::MAX.checked_add(1.days()), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add(self, interval: Interval) -> Option<Self> {
+        self.checked_add_months(interval.total_months())?
+            .checked_add_days(interval.days())
+    }
+
+    /// Subtracts an [`Interval`] from the date, returning `None` if the result is out of range.
+    ///
+    /// Only the year, month, and day components of the interval are used, similar
+    /// to the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(date!(2012-03-31).checked_sub(1.months()), Some(date!(2012-02-29)));
+    /// assert_eq!(Date::MIN.checked_sub(1.days()), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub(self, interval: Interval) -> Option<Self> {
+        self.checked_add_months(interval.total_months().checked_neg()?)?
+            .checked_add_days(interval.days().checked_neg()?)
+    }
+
+    /// Adds an [`Interval`] to the date, clamping to [`Date::MIN`] or [`Date::MAX`] if the
+    /// result is out of range.
+    ///
+    /// Only the year, month, and day components of the interval are used, similar
+    /// to the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(date!(2012-02-29).saturating_add(1.years()), date!(2013-02-28));
+    /// assert_eq!(Date::MAX.saturating_add(1.days()), Date::MAX);
+    /// assert_eq!(date!(32767-12-01).saturating_add(1.months()), Date::MAX);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_add(self, interval: Interval) -> Self {
+        self.saturating_add_parts(interval.total_months() as i64, interval.days() as i64)
+    }
+
+    /// Subtracts an [`Interval`] from the date, clamping to [`Date::MIN`] or [`Date::MAX`] if
+    /// the result is out of range.
+    ///
+    /// Only the year, month, and day components of the interval are used, similar
+    /// to the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(date!(2012-03-31).saturating_sub(1.months()), date!(2012-02-29));
+    /// assert_eq!(Date::MIN.saturating_sub(90.days()), Date::MIN);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_sub(self, interval: Interval) -> Self {
+        self.saturating_add_parts(-(interval.total_months() as i64), -(interval.days() as i64))
+    }
+
+    /// Returns the next calendar day, or [`None`] if this is [`Date::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date};
+    ///
+    /// assert_eq!(date!(2024-02-28).succ(), Some(date!(2024-02-29)));
+    /// assert_eq!(date!(2023-02-28).succ(), Some(date!(2023-03-01)));
+    /// assert_eq!(date!(2023-12-31).succ(), Some(date!(2024-01-01)));
+    /// assert_eq!(Date::MAX.succ(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn succ(self) -> Option<Self> {
+        let Self { year, month, day } = self;
+        if day < days_in_month(year, month) {
+            Some(Self {
+                year,
+                month,
+                day: day + 1,
+            })
+        } else if month < 12 {
+            Some(Self {
+                year,
+                month: month + 1,
+                day: 1,
+            })
+        } else if year < i16::MAX {
+            Some(Self {
+                year: year + 1,
+                month: 1,
+                day: 1,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the previous calendar day, or [`None`] if this is [`Date::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date};
+    ///
+    /// assert_eq!(date!(2024-03-01).pred(), Some(date!(2024-02-29)));
+    /// assert_eq!(date!(2023-03-01).pred(), Some(date!(2023-02-28)));
+    /// assert_eq!(date!(2024-01-01).pred(), Some(date!(2023-12-31)));
+    /// assert_eq!(Date::MIN.pred(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn pred(self) -> Option<Self> {
+        let Self { year, month, day } = self;
+        if day > 1 {
+            Some(Self {
+                year,
+                month,
+                day: day - 1,
+            })
+        } else if month > 1 {
+            Some(Self {
+                year,
+                month: month - 1,
+                day: days_in_month(year, month - 1),
+            })
+        } else if year > i16::MIN {
+            Some(Self {
+                year: year - 1,
+                month: 12,
+                day: 31,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn saturating_add_parts(self, months: i64, days: i64) -> Self {
+        let bound = |positive: bool| if positive { Self::MAX } else { Self::MIN };
+        let date = match i32::try_from(months).ok().and_then(|m| self.checked_add_months(m)) {
+            Some(date) => date,
+            None => return bound(months > 0),
+        };
+        match i32::try_from(days).ok().and_then(|d| date.checked_add_days(d)) {
+            Some(date) => date,
+            None => bound(days > 0),
+        }
+    }
+
+    pub(crate) fn checked_add_days(&self, days: i32) -> Option<Self> {
+        let days = self.days_since_epoch().checked_add(days)?;
+        ensure_in_range!(days, MIN_EPOCH_DAYS => MAX_EPOCH_DAYS);
+        let (year, month, day) = date_from_epoch_days(days);
+        Some(Self { year, month, day })
+    }
+
+    pub(crate) fn checked_add_months(&self, months: i32) -> Option<Self> {
+        if months == 0 {
+            return Some(*self);
+        }
+
+        let total = self.year as i64 * 12 + (self.month as i64 - 1) + months as i64;
+        let (year, month) = divmod!(total, 12);
+        let year = i16::try_from(year).ok()?;
+        let month = month as u8 + 1;
+        let day = days_in_month(year, month).min(self.day);
+        Some(Self { year, month, day })
+    }
+
     pub(crate) fn add_days(&self, days: i32) -> Self {
         if days == 0 {
             return *self;
         }
 
         let days = self.days_since_epoch().saturating_add(days);
-        let (year, month, day) = date_from_epoch_days(days);
+        let (year, month, day) = date_from_epoch_days(days.clamp(MIN_EPOCH_DAYS, MAX_EPOCH_DAYS));
         Self { year, month, day }
     }
 
@@ -376,27 +916,17 @@ impl Date {
             return *self;
         }
 
-        let m = (self.month as i32 - 1).saturating_add(months);
-        let (year, month) = if m >= 0 {
-            let (r, q) = divrem!(m, 12);
-            (r, q + 1)
-        } else {
-            let y = (m / 12) - 1;
-            let mut rem = m.abs() % 12;
-            if rem == 0 {
-                rem = 12;
-            }
-            let m = 12 - rem + 1;
-            if m == 1 {
-                (y + 1, m)
-            } else {
-                (y, m)
-            }
-        };
-        let month = month as u8;
-        let year = (self.year as i32 + year).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
-        let days = days_in_month(year, month);
-        let day = days.min(self.day);
+        // This is done in i64 since an i32 number of months can overflow when combined with the year
+        let total = self.year as i64 * 12 + (self.month as i64 - 1) + months as i64;
+        let (year, month) = divmod!(total, 12);
+        if year > i16::MAX as i64 {
+            return Self::MAX;
+        } else if year < i16::MIN as i64 {
+            return Self::MIN;
+        }
+        let year = year as i16;
+        let month = month as u8 + 1;
+        let day = days_in_month(year, month).min(self.day);
         Self { year, month, day }
     }
 
@@ -460,24 +990,61 @@ impl Date {
         self.month
     }
 
-    /// Returns the day.
-    ///
-    /// This value will always be within `1..=31`.
+    /// Returns the month as a [`Month`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use eos::date;
-    /// let date = date!(2012-01-15);
-    /// // or:
-    /// // let date = Date::new(2012, 1, 15)?;
-    /// assert_eq!(date.day(), 15);
-    /// # Ok::<_, eos::Error>(())
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(date!(2012-01-15).month_enum(), Month::January);
     /// ```
     #[inline]
     #[must_use]
-    pub const fn day(&self) -> u8 {
-        self.day
+    pub const fn month_enum(&self) -> Month {
+        match Month::from_number(self.month) {
+            Some(month) => month,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the quarter of the year.
+    ///
+    /// This value will always be within `1..=4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-01-15).quarter(), 1);
+    /// assert_eq!(date!(2012-06-30).quarter(), 2);
+    /// assert_eq!(date!(2012-10-01).quarter(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn quarter(&self) -> u8 {
+        (self.month - 1) / 3 + 1
+    }
+
+    /// Returns the day.
+    ///
+    /// This value will always be within `1..=31`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// let date = date!(2012-01-15);
+    /// // or:
+    /// // let date = Date::new(2012, 1, 15)?;
+    /// assert_eq!(date.day(), 15);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
     }
 
     /// Returns the ISO ordinal date.
@@ -501,13 +1068,107 @@ impl Date {
         date_to_ordinal(self.year, self.month, self.day)
     }
 
+    /// Returns the number of days in the month of this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(1900-02-10).days_in_month(), 28);
+    /// assert_eq!(date!(2000-02-10).days_in_month(), 29);
+    /// assert_eq!(date!(2100-02-10).days_in_month(), 28);
+    /// assert_eq!(date!(2100-04-10).days_in_month(), 30);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn days_in_month(&self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the number of days in the year of this date, either 365 or 366.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(1900-02-10).days_in_year(), 365);
+    /// assert_eq!(date!(2000-02-10).days_in_year(), 366);
+    /// assert_eq!(date!(2100-02-10).days_in_year(), 365);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn days_in_year(&self) -> u16 {
+        days_in_year(self.year)
+    }
+
     /// Returns the number of days since the UNIX Epoch (1970-01-01).
+    ///
+    /// This is the same as [`Date::to_epoch_days`].
     #[inline]
     #[must_use]
     pub const fn days_since_epoch(&self) -> i32 {
         date_to_epoch_days(self.year, self.month, self.day)
     }
 
+    /// Returns the number of days since the UNIX Epoch, where day 0 is 1970-01-01.
+    ///
+    /// Dates before the epoch return a negative number. Every representable
+    /// date fits in the range of [`Date::MIN`] through [`Date::MAX`], which
+    /// is `-12_687_794..=11_248_737`. This is the inverse of [`Date::from_epoch_days`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(1970-01-01).to_epoch_days(), 0);
+    /// assert_eq!(date!(1969-12-31).to_epoch_days(), -1);
+    /// assert_eq!(date!(2000-03-01).to_epoch_days(), 11017);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_epoch_days(&self) -> i32 {
+        self.days_since_epoch()
+    }
+
+    /// Returns the number of whole days from `other` to this date.
+    ///
+    /// This is positive if `other` is earlier than this date and negative if it is later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2024-03-01).days_since(&date!(2024-02-01)), 29);
+    /// assert_eq!(date!(2024-02-01).days_since(&date!(2024-03-01)), -29);
+    /// assert_eq!(date!(2024-03-01).days_since(&date!(2024-03-01)), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn days_since(&self, other: &Self) -> i32 {
+        self.days_since_epoch() - other.days_since_epoch()
+    }
+
+    /// Returns the Julian day number of this date.
+    ///
+    /// The Julian day number is the number of days since noon on January 1st, 4713 BC
+    /// in the proleptic Julian calendar, which is November 24th, -4713 in the proleptic
+    /// Gregorian calendar. Every representable date has a Julian day number that fits
+    /// in an `i32`, including dates before the start of the count, which are negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2000-01-01).julian_day_number(), 2_451_545);
+    /// assert_eq!(date!(1970-01-01).julian_day_number(), 2_440_588);
+    /// assert_eq!(date!(-4713-11-24).julian_day_number(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn julian_day_number(&self) -> i32 {
+        self.days_since_epoch() + UNIX_EPOCH_JULIAN_DAY
+    }
+
     /// Returns the weekday.
     ///
     /// # Examples
@@ -652,11 +1313,528 @@ impl Date {
             _ => unreachable!(),
         };
 
-        IsoWeekDate {
-            year,
-            week: week as _,
-            weekday,
-        }
+        IsoWeekDate {
+            year,
+            week: week as _,
+            weekday,
+        }
+    }
+
+    /// Returns `true` if the weekday of this date is in the given [`WeekdaySet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::date;
+    /// use eos::extra::WeekdaySet;
+    ///
+    /// // March 20th 2021 is a Saturday
+    /// assert!(date!(2021-03-20).weekday_in(&WeekdaySet::WEEKEND));
+    /// assert!(!date!(2021-03-20).weekday_in(&WeekdaySet::WEEKDAYS));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn weekday_in(&self, set: &WeekdaySet) -> bool {
+        set.contains(self.weekday())
+    }
+
+    /// Returns the first date after this one whose weekday is in the given [`WeekdaySet`].
+    ///
+    /// This date itself is never returned, even if its weekday is in the set. If the set is
+    /// empty or the next date would be past [`Date::MAX`] then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::date;
+    /// use eos::extra::WeekdaySet;
+    ///
+    /// // March 19th 2021 is a Friday
+    /// assert_eq!(date!(2021-03-19).next_date_in_set(&WeekdaySet::WEEKDAYS), Some(date!(2021-03-22)));
+    /// assert_eq!(date!(2021-03-19).next_date_in_set(&WeekdaySet::WEEKEND), Some(date!(2021-03-20)));
+    /// assert_eq!(date!(2021-03-19).next_date_in_set(&WeekdaySet::EMPTY), None);
+    /// ```
+    #[must_use]
+    pub fn next_date_in_set(&self, set: &WeekdaySet) -> Option<Self> {
+        let days = set.days_until_next(self.weekday())?;
+        self.checked_add_days(days as i32)
+    }
+
+    /// Returns the anniversary of this date in the given year.
+    ///
+    /// If this date is February 29th and the given year is not a leap year then
+    /// the given [`LeapDayPolicy`] decides whether the anniversary is on February 28th
+    /// or March 1st. Every other date has its anniversary on the same month and day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, LeapDayPolicy};
+    ///
+    /// let birthday = date!(2000-02-29);
+    /// assert_eq!(birthday.anniversary_in(2004, LeapDayPolicy::March1), date!(2004-02-29));
+    /// assert_eq!(birthday.anniversary_in(2001, LeapDayPolicy::February28), date!(2001-02-28));
+    /// assert_eq!(birthday.anniversary_in(2001, LeapDayPolicy::March1), date!(2001-03-01));
+    /// assert_eq!(date!(2000-03-01).anniversary_in(2001, LeapDayPolicy::February28), date!(2001-03-01));
+    /// ```
+    #[must_use]
+    pub const fn anniversary_in(&self, year: i16, policy: LeapDayPolicy) -> Self {
+        if self.month == 2 && self.day == 29 && !is_leap_year(year) {
+            match policy {
+                LeapDayPolicy::February28 => Self {
+                    year,
+                    month: 2,
+                    day: 28,
+                },
+                LeapDayPolicy::March1 => Self { year, month: 3, day: 1 },
+            }
+        } else {
+            Self {
+                year,
+                month: self.month,
+                day: self.day,
+            }
+        }
+    }
+
+    /// Returns the first anniversary of this date that is strictly after `after`.
+    ///
+    /// The date itself is not considered an anniversary, so if `after` is before this
+    /// date then the first anniversary, one year later, is returned. February 29th
+    /// anniversaries in other years are placed according to the given [`LeapDayPolicy`].
+    ///
+    /// If the anniversary would be after [`Date::MAX`] then [`Date::MAX`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, LeapDayPolicy};
+    ///
+    /// let birthday = date!(2000-02-29);
+    /// let policy = LeapDayPolicy::March1;
+    /// assert_eq!(birthday.next_anniversary_after(&date!(2001-02-28), policy), date!(2001-03-01));
+    /// assert_eq!(birthday.next_anniversary_after(&date!(2001-03-01), policy), date!(2002-03-01));
+    /// assert_eq!(birthday.next_anniversary_after(&date!(2003-12-31), policy), date!(2004-02-29));
+    /// assert_eq!(birthday.next_anniversary_after(&date!(1990-01-01), policy), date!(2001-03-01));
+    /// ```
+    #[must_use]
+    pub fn next_anniversary_after(&self, after: &Self, policy: LeapDayPolicy) -> Self {
+        // The anniversary in the year of `after` may be on or before it, in which
+        // case the one in the following year is the answer.
+        let start = (after.year as i32).max(self.year as i32 + 1);
+        (start..start + 2)
+            .map_while(|year| i16::try_from(year).ok())
+            .map(|year| self.anniversary_in(year, policy))
+            .find(|anniversary| anniversary > after)
+            .unwrap_or(Self::MAX)
+    }
+
+    /// Returns the number of full years from `earlier` to this date.
+    ///
+    /// This is the age of someone born on `earlier` as of this date. A February 29th
+    /// date reaches its anniversary on February 28th in years that are not leap years,
+    /// which is the same behaviour as adding years with an [`Interval`]. Use
+    /// [`Date::next_anniversary_after`] with [`LeapDayPolicy::March1`] if a
+    /// different policy is needed.
+    ///
+    /// If `earlier` is after this date then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::date;
+    ///
+    /// let birthday = date!(2000-02-29);
+    /// assert_eq!(date!(2001-02-27).years_since(&birthday), Some(0));
+    /// assert_eq!(date!(2001-02-28).years_since(&birthday), Some(1));
+    /// assert_eq!(date!(2001-03-01).years_since(&birthday), Some(1));
+    /// assert_eq!(date!(2024-02-29).years_since(&birthday), Some(24));
+    /// assert_eq!(date!(1999-12-31).years_since(&birthday), None);
+    /// ```
+    #[must_use]
+    pub fn years_since(&self, earlier: &Self) -> Option<u16> {
+        if earlier > self {
+            return None;
+        }
+        let years = self.year as i32 - earlier.year as i32;
+        if earlier.anniversary_in(self.year, LeapDayPolicy::February28) > *self {
+            Some((years - 1) as u16)
+        } else {
+            Some(years as u16)
+        }
+    }
+
+    /// Returns the week of the year, where weeks begin on the given weekday.
+    ///
+    /// Unlike [`Date::iso_week`], week 1 is always the week containing January 1st,
+    /// even if that week is partial. The second week begins on the first occurrence
+    /// of `first_weekday` after January 1st, and every week after that is a full
+    /// seven days. If January 1st falls on `first_weekday` then the first week is
+    /// also a full seven days. The last week of the year might also be partial, and
+    /// the days after December 31st belong to week 1 of the next year.
+    ///
+    /// This is the US-style numbering used by e.g. spreadsheet `WEEKNUM` functions when
+    /// `first_weekday` is [`Weekday::Sunday`]. The result is always within `1..=54`, where
+    /// 54 only happens in a leap year that starts on the last day of the week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Weekday};
+    ///
+    /// // January 1st 2022 is a Saturday, so it's the only day in the first week
+    /// assert_eq!(date!(2022-01-01).week_of_year(Weekday::Sunday), 1);
+    /// assert_eq!(date!(2022-01-02).week_of_year(Weekday::Sunday), 2);
+    /// assert_eq!(date!(2022-12-31).week_of_year(Weekday::Sunday), 53);
+    ///
+    /// // With weeks starting on Monday, the first week is 2 days long
+    /// assert_eq!(date!(2022-01-02).week_of_year(Weekday::Monday), 1);
+    /// assert_eq!(date!(2022-01-03).week_of_year(Weekday::Monday), 2);
+    ///
+    /// // The ISO week is different
+    /// assert_eq!(date!(2022-01-03).iso_week().week(), 1);
+    /// ```
+    #[must_use]
+    pub const fn week_of_year(&self, first_weekday: Weekday) -> u8 {
+        let first = weekday_from_days(date_to_epoch_days(self.year, 1, 1));
+        let lead = weekday_difference(first, first_weekday.days_from_sunday()) as u16;
+        ((self.ordinal() - 1 + lead) / 7 + 1) as u8
+    }
+
+    /// Returns the week of the month, where weeks begin on the given weekday.
+    ///
+    /// This follows the same rules as [`Date::week_of_year`] but within the month. That is,
+    /// week 1 is always the week containing the first day of the month, even if it's partial,
+    /// and the following weeks begin on `first_weekday`. The result is always within `1..=6`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Weekday};
+    ///
+    /// // May 1st 2021 is a Saturday
+    /// assert_eq!(date!(2021-05-01).week_of_month(Weekday::Sunday), 1);
+    /// assert_eq!(date!(2021-05-02).week_of_month(Weekday::Sunday), 2);
+    /// assert_eq!(date!(2021-05-31).week_of_month(Weekday::Sunday), 6);
+    /// assert_eq!(date!(2021-05-31).week_of_month(Weekday::Monday), 6);
+    /// assert_eq!(date!(2021-05-30).week_of_month(Weekday::Monday), 5);
+    /// ```
+    #[must_use]
+    pub const fn week_of_month(&self, first_weekday: Weekday) -> u8 {
+        let first = weekday_from_days(date_to_epoch_days(self.year, self.month, 1));
+        let lead = weekday_difference(first, first_weekday.days_from_sunday());
+        (self.day - 1 + lead) / 7 + 1
+    }
+
+    /// Returns an iterator over every date in the given year, in order.
+    ///
+    /// The iterator yields either 365 or 366 dates depending on whether the year
+    /// is a leap year. It can also be iterated in reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date};
+    ///
+    /// let mut dates = Date::iter_year(2024);
+    /// assert_eq!(dates.len(), 366);
+    /// assert_eq!(dates.next(), Some(date!(2024-01-01)));
+    /// assert_eq!(dates.next_back(), Some(date!(2024-12-31)));
+    /// assert_eq!(Date::iter_year(2023).nth(59), Some(date!(2023-03-01)));
+    /// ```
+    pub fn iter_year(year: i16) -> YearDays {
+        YearDays::new(year)
+    }
+
+    /// Returns an iterator over every date in the given year that falls on the given weekday, in order.
+    ///
+    /// The iterator yields either 52 or 53 dates. It can also be iterated in reverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, Weekday};
+    ///
+    /// let mut fridays = Date::iter_year_weekday(2024, Weekday::Friday);
+    /// assert_eq!(fridays.len(), 52);
+    /// assert_eq!(fridays.next(), Some(date!(2024-01-05)));
+    /// assert_eq!(fridays.next_back(), Some(date!(2024-12-27)));
+    ///
+    /// // 2024 starts on a Monday and is a leap year, so it ends on a Tuesday
+    /// assert_eq!(Date::iter_year_weekday(2024, Weekday::Monday).len(), 53);
+    /// assert_eq!(Date::iter_year_weekday(2024, Weekday::Tuesday).len(), 53);
+    /// ```
+    pub fn iter_year_weekday(year: i16, weekday: Weekday) -> YearWeekdays {
+        YearWeekdays::new(year, weekday)
+    }
+
+    /// Returns an iterator over the dates from this date up to, but not including, `end`.
+    ///
+    /// By default the iterator steps one day at a time. This can be changed using
+    /// [`DateRange::step`] and [`DateRange::step_days`], and the end can be included
+    /// using [`DateRange::inclusive`]. The iterator can also be iterated in reverse.
+    ///
+    /// Every date is computed from the start rather than the previous date, so month
+    /// steps clamp to the end of the month without drifting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, ext::IntervalLiteral};
+    ///
+    /// let dates: Vec<_> = date!(2024-01-30).iter_to(date!(2024-02-02)).collect();
+    /// assert_eq!(dates, [date!(2024-01-30), date!(2024-01-31), date!(2024-02-01)]);
+    ///
+    /// let mut weeks = date!(2024-01-01).iter_to(date!(2024-01-29)).step_days(7).inclusive();
+    /// assert_eq!(weeks.len(), 5);
+    /// assert_eq!(weeks.next_back(), Some(date!(2024-01-29)));
+    ///
+    /// let months: Vec<_> = date!(2023-01-31).iter_to(date!(2023-05-01)).step(1.months()).collect();
+    /// assert_eq!(months, [date!(2023-01-31), date!(2023-02-28), date!(2023-03-31), date!(2023-04-30)]);
+    ///
+    /// let backwards: Vec<_> = date!(2024-01-03).iter_to(date!(2024-01-01)).step_days(-1).collect();
+    /// assert_eq!(backwards, [date!(2024-01-03), date!(2024-01-02)]);
+    /// ```
+    pub fn iter_to(self, end: Date) -> DateRange {
+        DateRange::new(self, end)
+    }
+
+    /// Returns an iterator over the dates from `start` to `end`, including both.
+    ///
+    /// This is the stable equivalent of `start..=end`. If `start` is after `end` then
+    /// the iterator is empty. The iterator knows its exact length and can be iterated
+    /// in reverse. See [`Date::iter_to`] for ranges with other steps or an excluded end.
+    ///
+    /// With the `nightly` feature enabled, [`Date`] also implements [`core::iter::Step`]
+    /// so that the standard range syntax can be iterated directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date};
+    ///
+    /// let dates = Date::range_inclusive(date!(2022-01-01), date!(2022-01-31));
+    /// assert_eq!(dates.len(), 31);
+    /// assert_eq!(dates.rev().next(), Some(date!(2022-01-31)));
+    /// assert_eq!(Date::range_inclusive(date!(2022-01-02), date!(2022-01-01)).len(), 0);
+    /// ```
+    pub fn range_inclusive(start: Date, end: Date) -> DateRange {
+        start.iter_to(end).inclusive()
+    }
+
+    /// Returns the date of the nth occurrence of a weekday in the given month.
+    ///
+    /// The occurrence `n` starts at 1, so `n = 2` with [`Weekday::Tuesday`] is the second
+    /// Tuesday of the month. Returns [`Error::FieldOutOfRange`] if the month is out of bounds
+    /// (`1..=12`) or `n` is out of bounds (`1..=5`), and [`Error::OutOfRange`] if the month
+    /// does not have that many occurrences of the weekday. Every month has at least 4
+    /// occurrences of each weekday, but only some have a 5th.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, Weekday};
+    ///
+    /// assert_eq!(Date::nth_weekday_of_month(2024, 3, Weekday::Tuesday, 2)?, date!(2024-03-12));
+    /// assert_eq!(Date::nth_weekday_of_month(2024, 3, Weekday::Friday, 1)?, date!(2024-03-01));
+    /// assert_eq!(Date::nth_weekday_of_month(2024, 3, Weekday::Friday, 5)?, date!(2024-03-29));
+    /// assert!(Date::nth_weekday_of_month(2024, 3, Weekday::Monday, 5).is_err());
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn nth_weekday_of_month(year: i16, month: u8, weekday: Weekday, n: u8) -> Result<Self, Error> {
+        Error::check_field("month", month as i64, 1, 12)?;
+        Error::check_field("n", n as i64, 1, 5)?;
+        let first = weekday_from_days(date_to_epoch_days(year, month, 1));
+        let day = 1 + weekday_difference(weekday.days_from_sunday(), first) + (n - 1) * 7;
+        if day > days_in_month(year, month) {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns the date of the last occurrence of a weekday in the given month.
+    ///
+    /// Returns [`Error::FieldOutOfRange`] if the month is out of bounds (`1..=12`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, Date, Weekday};
+    ///
+    /// assert_eq!(Date::last_weekday_of_month(2024, 2, Weekday::Thursday)?, date!(2024-02-29));
+    /// assert_eq!(Date::last_weekday_of_month(2024, 2, Weekday::Friday)?, date!(2024-02-23));
+    /// assert!(Date::last_weekday_of_month(2024, 13, Weekday::Friday).is_err());
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn last_weekday_of_month(year: i16, month: u8, weekday: Weekday) -> Result<Self, Error> {
+        Error::check_field("month", month as i64, 1, 12)?;
+        let last = days_in_month(year, month);
+        let behind = weekday_difference(
+            weekday_from_days(date_to_epoch_days(year, month, last)),
+            weekday.days_from_sunday(),
+        );
+        Ok(Self {
+            year,
+            month,
+            day: last - behind,
+        })
+    }
+
+    /// Returns the first day of the quarter this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).first_day_of_quarter(), date!(2012-04-01));
+    /// assert_eq!(date!(2012-01-01).first_day_of_quarter(), date!(2012-01-01));
+    /// ```
+    #[must_use]
+    pub const fn first_day_of_quarter(&self) -> Self {
+        Self {
+            year: self.year,
+            month: (self.month - 1) / 3 * 3 + 1,
+            day: 1,
+        }
+    }
+
+    /// Returns the last day of the quarter this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).last_day_of_quarter(), date!(2012-06-30));
+    /// assert_eq!(date!(2012-12-31).last_day_of_quarter(), date!(2012-12-31));
+    /// ```
+    #[must_use]
+    pub const fn last_day_of_quarter(&self) -> Self {
+        let month = (self.month - 1) / 3 * 3 + 3;
+        Self {
+            year: self.year,
+            month,
+            day: days_in_month(self.year, month),
+        }
+    }
+
+    /// Returns the first day of the month this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).first_of_month(), date!(2012-05-01));
+    /// ```
+    #[must_use]
+    pub const fn first_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        }
+    }
+
+    /// Returns the last day of the month this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).last_of_month(), date!(2012-05-31));
+    /// assert_eq!(date!(2012-02-15).last_of_month(), date!(2012-02-29));
+    /// assert_eq!(date!(2013-02-15).last_of_month(), date!(2013-02-28));
+    /// ```
+    #[must_use]
+    pub const fn last_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: days_in_month(self.year, self.month),
+        }
+    }
+
+    /// Returns the first day of the year this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).first_of_year(), date!(2012-01-01));
+    /// ```
+    #[must_use]
+    pub const fn first_of_year(&self) -> Self {
+        Self {
+            year: self.year,
+            month: 1,
+            day: 1,
+        }
+    }
+
+    /// Returns the last day of the year this date is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-05-15).last_of_year(), date!(2012-12-31));
+    /// ```
+    #[must_use]
+    pub const fn last_of_year(&self) -> Self {
+        Self {
+            year: self.year,
+            month: 12,
+            day: 31,
+        }
+    }
+
+    /// Returns `true` if this date is the first day of its month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert!(date!(2012-05-01).is_first_of_month());
+    /// assert!(!date!(2012-05-02).is_first_of_month());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_first_of_month(&self) -> bool {
+        self.day == 1
+    }
+
+    /// Returns `true` if this date is the last day of its month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert!(date!(2012-02-29).is_last_of_month());
+    /// assert!(!date!(2012-02-28).is_last_of_month());
+    /// assert!(date!(2013-02-28).is_last_of_month());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_last_of_month(&self) -> bool {
+        self.day == days_in_month(self.year, self.month)
+    }
+
+    /// Returns a new [`Date`] moved by the given number of quarters.
+    ///
+    /// This is the same as adding three times as many months. If the day is out of bounds
+    /// for the resulting month then it's clamped to the last day of that month. If the result
+    /// is out of range then it's clamped to [`Date::MIN`] or [`Date::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-01-31).add_quarters(1), date!(2012-04-30));
+    /// assert_eq!(date!(2012-05-31).add_quarters(-1), date!(2012-02-29));
+    /// assert_eq!(date!(2012-05-31).add_quarters(4), date!(2013-05-31));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_quarters(self, quarters: i32) -> Self {
+        self.saturating_add_parts(quarters as i64 * 3, 0)
     }
 
     /// Returns a new [`Date] that points to the given year.
@@ -706,6 +1884,73 @@ impl Date {
         Some(self)
     }
 
+    /// Returns a new [`Date`] that points to the given ordinal day of the current year.
+    ///
+    /// If the ordinal is out of bounds (`1..=366`) then [`Error::FieldOutOfRange`] is returned.
+    /// Note that 366 is also invalid if the year is not a leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// assert_eq!(date!(2012-01-15).with_ordinal(60), Ok(date!(2012-02-29)));
+    /// assert_eq!(date!(2012-01-15).with_ordinal(366), Ok(date!(2012-12-31)));
+    /// assert!(date!(2013-01-15).with_ordinal(366).is_err());
+    /// assert!(date!(2013-01-15).with_ordinal(0).is_err());
+    /// ```
+    pub fn with_ordinal(self, ordinal: u16) -> Result<Self, Error> {
+        Error::check_field("ordinal", ordinal as i64, 1, days_in_year(self.year) as i64)?;
+        Self::from_ordinal(self.year, ordinal).ok_or(Error::OutOfRange)
+    }
+
+    /// Returns a new [`Date`] that points to the given ISO week of the current ISO year.
+    ///
+    /// The ISO year and weekday are kept the same. Note that the ISO year might be
+    /// different from the Gregorian year around the start and end of a year. If the
+    /// week is out of bounds for the ISO year (`1..=52` or `1..=53` depending on the
+    /// year) then [`Error::FieldOutOfRange`] is returned. If the resulting date is out of
+    /// range then [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::date;
+    /// // 2020-01-15 is the Wednesday of the third week of 2020
+    /// assert_eq!(date!(2020-01-15).with_iso_week(1), Ok(date!(2020-01-01)));
+    /// assert_eq!(date!(2020-01-15).with_iso_week(53), Ok(date!(2020-12-30)));
+    /// // 2021 only has 52 ISO weeks
+    /// assert!(date!(2021-01-15).with_iso_week(53).is_err());
+    /// // 2021-01-01 is in the last ISO week of 2020
+    /// assert_eq!(date!(2021-01-01).with_iso_week(1), Ok(date!(2020-01-03)));
+    /// ```
+    pub fn with_iso_week(self, week: u8) -> Result<Self, Error> {
+        let iso = self.iso_week();
+        Error::check_field("week", week as i64, 1, iso_weeks_in_year(iso.year) as i64)?;
+        let iso = IsoWeekDate::new(iso.year, week, iso.weekday).ok_or(Error::OutOfRange)?;
+        let days = iso_week_date_to_epoch_days(iso.year, week, iso.weekday.number_from_monday());
+        Self::from_epoch_days(days)
+    }
+
+    /// Returns a new [`Date`] that points to the given weekday within the current ISO week.
+    ///
+    /// ISO weeks start on Monday and end on Sunday, so e.g. moving a Wednesday to a
+    /// Monday goes back two days while moving it to a Sunday goes forward four days.
+    /// If the resulting date is out of range then [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{date, Weekday};
+    /// // 2021-03-17 is a Wednesday
+    /// assert_eq!(date!(2021-03-17).with_weekday(Weekday::Monday), Ok(date!(2021-03-15)));
+    /// assert_eq!(date!(2021-03-17).with_weekday(Weekday::Sunday), Ok(date!(2021-03-21)));
+    /// assert_eq!(date!(2021-03-17).with_weekday(Weekday::Wednesday), Ok(date!(2021-03-17)));
+    /// ```
+    pub fn with_weekday(self, weekday: Weekday) -> Result<Self, Error> {
+        let days = weekday.days_from_monday() as i32 - self.weekday().days_from_monday() as i32;
+        self.checked_add_days(days).ok_or(Error::OutOfRange)
+    }
+
     /// Creates a date from the given year and ordinal date.
     ///
     /// If the ordinal is out of bounds (`1..=366`) then [`None`] is returned.
@@ -733,6 +1978,66 @@ impl Date {
         let (year, month, day) = date_from_epoch_days(epoch);
         Some(Self { year, month, day })
     }
+
+    /// Creates a [`Date`] from a Julian day number.
+    ///
+    /// This is the inverse of [`Date::julian_day_number`]. If the resulting date
+    /// is outside of the range of [`Date::MIN`] and [`Date::MAX`] then
+    /// [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{Date, date};
+    /// assert_eq!(Date::from_julian_day_number(2_451_545), Ok(date!(2000-01-01)));
+    /// assert_eq!(Date::from_julian_day_number(0), Ok(date!(-4713-11-24)));
+    /// assert!(Date::from_julian_day_number(i32::MAX).is_err());
+    /// ```
+    pub fn from_julian_day_number(jdn: i32) -> Result<Self, Error> {
+        let days = jdn.checked_sub(UNIX_EPOCH_JULIAN_DAY).ok_or(Error::OutOfRange)?;
+        Self::from_epoch_days(days)
+    }
+
+    /// Creates a [`Date`] from the number of days since the UNIX Epoch, where day 0 is 1970-01-01.
+    ///
+    /// This is the inverse of [`Date::to_epoch_days`]. If the resulting date
+    /// is outside of the range of [`Date::MIN`] and [`Date::MAX`] then
+    /// [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{Date, date};
+    /// assert_eq!(Date::from_epoch_days(0), Ok(date!(1970-01-01)));
+    /// assert_eq!(Date::from_epoch_days(-1), Ok(date!(1969-12-31)));
+    /// assert_eq!(Date::from_epoch_days(11017), Ok(date!(2000-03-01)));
+    /// assert!(Date::from_epoch_days(i32::MAX).is_err());
+    /// ```
+    pub const fn from_epoch_days(days: i32) -> Result<Self, Error> {
+        if days < MIN_EPOCH_DAYS || days > MAX_EPOCH_DAYS {
+            return Err(Error::OutOfRange);
+        }
+        let (year, month, day) = date_from_epoch_days(days);
+        Ok(Self { year, month, day })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl core::iter::Step for Date {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        match usize::try_from(end.days_since(start)) {
+            Ok(steps) => (steps, Some(steps)),
+            Err(_) => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add_days(i32::try_from(count).ok()?)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add_days(i32::try_from(count).ok()?.checked_neg()?)
+    }
 }
 
 impl Add<Interval> for Date {
@@ -774,9 +2079,7 @@ impl Sub for Date {
 
 impl From<IsoWeekDate> for Date {
     fn from(iso: IsoWeekDate) -> Self {
-        let epoch = iso_week_start_epoch_from_year(iso.year)
-            + (iso.week as i32 - 1) * 7
-            + iso.weekday.days_from_monday() as i32;
+        let epoch = iso_week_date_to_epoch_days(iso.year, iso.week, iso.weekday.number_from_monday());
         let (year, month, day) = date_from_epoch_days(epoch);
         Self { year, month, day }
     }
@@ -822,13 +2125,12 @@ impl FromIsoFormat for Date {
 
 impl core::fmt::Display for IsoWeekDate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{:04}-W{:02}-{}",
-            self.year,
-            self.week,
-            self.weekday.number_from_monday()
-        )
+        let weekday = self.weekday.number_from_monday();
+        if self.year >= 0 && self.year <= 9999 {
+            write!(f, "{:04}-W{:02}-{}", self.year, self.week, weekday)
+        } else {
+            write!(f, "{:+05}-W{:02}-{}", self.year, self.week, weekday)
+        }
     }
 }
 
@@ -884,6 +2186,39 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_is_valid_matches_new() {
+        let years = (i16::MIN..=i16::MAX)
+            .step_by(97)
+            .chain([i16::MIN, -1, 0, 1, 1900, 2000, 2020, 2021, i16::MAX]);
+        for year in years {
+            for month in 0..=13 {
+                for day in 0..=32 {
+                    assert_eq!(
+                        Date::is_valid(year, month, day),
+                        Date::new(year, month, day).is_some(),
+                        "{}-{}-{}",
+                        year,
+                        month,
+                        day
+                    );
+                }
+                let last_valid = (1..=31).rev().find(|&day| Date::is_valid(year, month, day));
+                assert_eq!(Date::max_day(year, month), last_valid);
+            }
+        }
+        for hour in 0..=25 {
+            for minute in 0..=61 {
+                for second in 0..=61 {
+                    assert_eq!(
+                        Time::is_valid_hms(hour, minute, second),
+                        Time::new(hour, minute, second).is_some()
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_iso_week() {
         assert_eq!(
@@ -903,4 +2238,707 @@ mod tests {
             date!(2008 - 12 - 29)
         );
     }
+
+    #[test]
+    fn test_macro_ordinal_and_iso_week_dates() {
+        const ORDINAL: Date = date!(2022 - 048);
+        const WEEK: Date = date!(2022 - W07 - 3);
+        assert_eq!(ORDINAL, date!(2022 - 02 - 17));
+        assert_eq!(WEEK, date!(2022 - 02 - 16));
+
+        assert_eq!(date!(2021 - 001), date!(2021 - 01 - 01));
+        assert_eq!(date!(2021 - 365), date!(2021 - 12 - 31));
+        assert_eq!(date!(2020 - 060), date!(2020 - 02 - 29));
+        assert_eq!(date!(2020 - 366), date!(2020 - 12 - 31));
+        assert_eq!(date!(-32768 - 001), Date::MIN);
+        assert_eq!(date!(32767 - 365), Date::MAX);
+
+        assert_eq!(date!(2009 - W01 - 1), date!(2008 - 12 - 29));
+        assert_eq!(date!(2020 - W53 - 7), date!(2021 - 01 - 03));
+        assert_eq!(date!(2022 - W1 - 1), date!(2022 - 01 - 03));
+        assert_eq!(date!(2022 - W52 - 7), date!(2023 - 01 - 01));
+        assert_eq!(
+            date!(1994 - W52 - 7).iso_week(),
+            IsoWeekDate {
+                year: 1994,
+                week: 52,
+                weekday: Weekday::Sunday
+            }
+        );
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        use crate::ext::IntervalLiteral;
+
+        assert_eq!(Date::MAX.saturating_add(1.days()), Date::MAX);
+        assert_eq!(date!(32767 - 12 - 01).saturating_add(1.months()), Date::MAX);
+        assert_eq!(Date::MAX.saturating_sub(Interval::from_months(i32::MIN)), Date::MAX);
+        assert_eq!(Date::MIN.saturating_sub(90.days()), Date::MIN);
+        assert_eq!(Date::MIN.saturating_add(Interval::from_days(i32::MIN)), Date::MIN);
+        assert_eq!(date!(2020 - 01 - 31).saturating_add(1.months()), date!(2020 - 02 - 29));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        use crate::ext::IntervalLiteral;
+
+        assert_eq!(Date::MAX.checked_add(1.days()), None);
+        assert_eq!(Date::MAX.checked_add(1.months()), None);
+        assert_eq!(Date::MAX.checked_add(1.years()), None);
+        assert_eq!(Date::MAX.checked_add(Interval::ZERO), Some(Date::MAX));
+        assert_eq!(Date::MAX.checked_sub(1.days()), Some(date!(32767 - 12 - 30)));
+        assert_eq!(Date::MAX.checked_sub(Interval::from_days(i32::MIN)), None);
+        assert_eq!(Date::MIN.checked_sub(1.days()), None);
+        assert_eq!(Date::MIN.checked_add(Interval::from_months(i32::MAX)), None);
+        assert_eq!(
+            date!(2020 - 02 - 29).checked_add(1.years()),
+            Some(date!(2021 - 02 - 28))
+        );
+        assert_eq!(
+            date!(2020 - 01 - 31).checked_sub((-1).months()),
+            Some(date!(2020 - 02 - 29))
+        );
+    }
+
+    #[test]
+    fn test_iter_year() {
+        for year in 1990..2040 {
+            let expected = if is_leap_year(year) { 366 } else { 365 };
+            let dates = Date::iter_year(year);
+            assert_eq!(dates.len(), expected);
+            assert_eq!(dates.clone().count(), expected);
+            assert_eq!(dates.clone().next(), Date::new(year, 1, 1));
+            assert_eq!(dates.clone().next_back(), Date::new(year, 12, 31));
+
+            let mut previous = Date::new(year, 1, 1).unwrap().add_days(-1);
+            for date in dates.clone() {
+                assert_eq!(date, previous.add_days(1));
+                previous = date;
+            }
+
+            let first = Date::new(year, 1, 1).unwrap();
+            assert!(dates
+                .rev()
+                .eq((0..expected as i32).rev().map(|days| first.add_days(days))));
+        }
+
+        let mut dates = Date::iter_year(2022);
+        dates.next();
+        dates.next_back();
+        assert_eq!(dates.len(), 363);
+    }
+
+    #[test]
+    fn test_iter_to() {
+        use crate::ext::IntervalLiteral;
+
+        let start = date!(2022 - 01 - 01);
+        for (end, inclusive) in [(date!(2022 - 12 - 31), false), (date!(2023 - 01 - 01), true)] {
+            let mut range = start.iter_to(end);
+            if inclusive {
+                range = range.inclusive();
+            }
+            assert_eq!(range.len(), if inclusive { 366 } else { 364 });
+            assert!(range.clone().eq(Date::iter_year(2022).chain([end]).take(range.len())));
+            assert!(range
+                .clone()
+                .rev()
+                .eq(range.clone().collect::<Vec<_>>().into_iter().rev()));
+        }
+
+        // Empty ranges
+        assert_eq!(start.iter_to(start).len(), 0);
+        assert_eq!(start.iter_to(start).inclusive().collect::<Vec<_>>(), [start]);
+        assert_eq!(start.iter_to(date!(2021 - 12 - 01)).len(), 0);
+        assert_eq!(start.iter_to(date!(2022 - 02 - 01)).step_days(-1).len(), 0);
+
+        // Steps that don't land on the end
+        let range = start.iter_to(date!(2022 - 01 - 31)).step_days(7).inclusive();
+        assert_eq!(range.clone().next_back(), Some(date!(2022 - 01 - 29)));
+        assert_eq!(range.len(), 5);
+
+        // Month steps are anchored to the start and clamp
+        let months: Vec<_> = date!(2020 - 01 - 31)
+            .iter_to(date!(2021 - 01 - 31))
+            .step(1.months())
+            .inclusive()
+            .collect();
+        assert_eq!(months.len(), 13);
+        assert_eq!(months[1], date!(2020 - 02 - 29));
+        assert_eq!(months[2], date!(2020 - 03 - 31));
+        assert_eq!(months[3], date!(2020 - 04 - 30));
+        assert_eq!(months[12], date!(2021 - 01 - 31));
+
+        // Backwards iteration with a negative step
+        let back: Vec<_> = date!(2020 - 03 - 31)
+            .iter_to(date!(2019 - 12 - 31))
+            .step((-1).months())
+            .collect();
+        assert_eq!(
+            back,
+            [date!(2020 - 03 - 31), date!(2020 - 02 - 29), date!(2020 - 01 - 31)]
+        );
+
+        // Time components are ignored for dates
+        assert_eq!(
+            start.iter_to(date!(2022 - 01 - 05)).step(1.days() + 12.hours()).len(),
+            4
+        );
+
+        // Ranges near the limits don't overflow
+        assert_eq!(
+            Date::MIN.iter_to(Date::MAX).inclusive().len(),
+            Date::MAX.days_since(&Date::MIN) as usize + 1
+        );
+        assert_eq!(
+            Date::MAX.iter_to(Date::MIN).step_days(-1).nth(1),
+            Date::MAX.checked_add_days(-1)
+        );
+        assert_eq!(Date::MAX.iter_to(Date::MAX).step(1.years()).inclusive().count(), 1);
+
+        let mut range = start.iter_to(date!(2022 - 01 - 10));
+        assert_eq!(range.nth(3), Some(date!(2022 - 01 - 04)));
+        assert_eq!(range.next_back(), Some(date!(2022 - 01 - 09)));
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.nth(10), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        let dates = Date::range_inclusive(date!(2022 - 01 - 01), date!(2022 - 01 - 31));
+        assert_eq!(dates.len(), 31);
+        let collected: Vec<_> = dates.clone().collect();
+        assert_eq!(collected.capacity(), 31);
+        assert!(dates.rev().eq(collected.into_iter().rev()));
+
+        let single = Date::range_inclusive(date!(2022 - 01 - 01), date!(2022 - 01 - 01));
+        assert_eq!(single.collect::<Vec<_>>(), [date!(2022 - 01 - 01)]);
+        assert_eq!(Date::range_inclusive(Date::MAX, Date::MIN).len(), 0);
+        assert_eq!(Date::range_inclusive(Date::MAX, Date::MAX).count(), 1);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_step() {
+        let start = date!(2022 - 01 - 01);
+        let end = date!(2022 - 01 - 31);
+        assert!((start..=end).eq(Date::range_inclusive(start, end)));
+        assert_eq!((start..end).count(), 30);
+        assert_eq!((end..start).count(), 0);
+        assert_eq!((start..=end).rev().next(), Some(end));
+        assert_eq!((Date::MAX..=Date::MAX).count(), 1);
+        assert_eq!((start..).nth(365), Some(date!(2023 - 01 - 01)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_to_mixed_step() {
+        use crate::ext::IntervalLiteral;
+
+        let _ = date!(2022 - 01 - 01)
+            .iter_to(date!(2023 - 01 - 01))
+            .step(1.months() - 1.days());
+    }
+
+    #[test]
+    fn test_calendar_month() {
+        use crate::iter::calendar_month;
+
+        for year in 2000..2030 {
+            for month in 1..=12 {
+                for first_weekday in Weekday::ALL {
+                    let grid = calendar_month(year, month, first_weekday).unwrap();
+                    let dates: Vec<_> = grid.clone().map(Option::unwrap).collect();
+                    assert_eq!(dates.len(), grid.len());
+                    assert_eq!(dates.len() % 7, 0);
+                    assert!((4..=6).contains(&grid.weeks()));
+                    assert!(dates.chunks(7).all(|week| week[0].weekday() == first_weekday));
+                    assert!(dates.windows(2).all(|w| w[1] == w[0].add_days(1)));
+                    let in_month = dates.iter().filter(|d| grid.is_in_month(d)).count();
+                    assert_eq!(in_month, days_in_month(year, month) as usize);
+                    // The first and last weeks both have a day in the month
+                    assert!(grid.is_in_month(&dates[6]));
+                    assert!(grid.is_in_month(&dates[dates.len() - 7]));
+                    assert!(grid.clone().rev().eq(dates.iter().rev().copied().map(Some)));
+                }
+            }
+        }
+
+        // The edges of the supported range don't overflow
+        let grid = calendar_month(i16::MIN, 1, Weekday::Monday).unwrap();
+        assert_eq!(grid.len(), 35);
+        assert_eq!(grid.clone().take_while(Option::is_none).count(), 3);
+        assert_eq!(grid.clone().flatten().next(), Some(Date::MIN));
+        let grid = calendar_month(i16::MAX, 12, Weekday::Wednesday).unwrap();
+        assert_eq!(grid.clone().rev().take_while(Option::is_none).count(), 2);
+        assert_eq!(grid.clone().flatten().next_back(), Some(Date::MAX));
+        let grid = calendar_month(i16::MAX, 12, Weekday::Monday).unwrap();
+        assert_eq!(grid.clone().flatten().count(), grid.len());
+
+        assert!(calendar_month(2022, 0, Weekday::Monday).is_none());
+        assert!(calendar_month(2022, 13, Weekday::Monday).is_none());
+    }
+
+    #[test]
+    fn test_iter_year_weekday() {
+        for year in 1990..2040 {
+            let mut total = 0;
+            for weekday in Weekday::ALL {
+                let dates = Date::iter_year_weekday(year, weekday);
+                let expected = Date::iter_year(year).filter(|d| d.weekday() == weekday).count();
+                assert!(expected == 52 || expected == 53);
+                assert_eq!(dates.len(), expected);
+                assert_eq!(dates.clone().count(), expected);
+                assert!(dates.clone().all(|d| d.weekday() == weekday && d.year() == year));
+                assert_eq!(dates.clone().rev().count(), expected);
+                total += expected;
+            }
+            assert_eq!(total, Date::iter_year(year).len());
+        }
+
+        // 2021 starts and ends on a Friday
+        assert_eq!(Date::iter_year_weekday(2021, Weekday::Friday).len(), 53);
+        assert_eq!(Date::iter_year_weekday(2021, Weekday::Saturday).len(), 52);
+        let mut fridays = Date::iter_year_weekday(2021, Weekday::Friday);
+        assert_eq!(fridays.next_back(), Some(date!(2021 - 12 - 31)));
+        assert_eq!(fridays.len(), 52);
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        for year in [1900, 2000, 2023, 2024, -1, i16::MIN, i16::MAX] {
+            for month in 1..=12 {
+                for weekday in Weekday::ALL {
+                    let mut count = 0;
+                    let mut last = None;
+                    for day in 1..=days_in_month(year, month) {
+                        let date = Date::new(year, month, day).unwrap();
+                        if date.weekday() == weekday {
+                            count += 1;
+                            last = Some(date);
+                            assert_eq!(Date::nth_weekday_of_month(year, month, weekday, count), Ok(date));
+                        }
+                    }
+                    assert!(count == 4 || count == 5);
+                    assert!(Date::nth_weekday_of_month(year, month, weekday, count + 1).is_err());
+                    assert_eq!(Date::last_weekday_of_month(year, month, weekday), Ok(last.unwrap()));
+                }
+            }
+        }
+
+        assert!(Date::nth_weekday_of_month(2024, 1, Weekday::Monday, 0).is_err());
+        assert!(Date::nth_weekday_of_month(2024, 0, Weekday::Monday, 1).is_err());
+        assert!(Date::nth_weekday_of_month(2024, 13, Weekday::Monday, 1).is_err());
+        assert!(Date::last_weekday_of_month(2024, 0, Weekday::Monday).is_err());
+
+        // February only has a 5th occurrence on leap years
+        assert_eq!(
+            Date::nth_weekday_of_month(2024, 2, Weekday::Thursday, 5),
+            Ok(date!(2024 - 02 - 29))
+        );
+        assert!(Date::nth_weekday_of_month(2023, 2, Weekday::Wednesday, 5).is_err());
+    }
+
+    #[test]
+    fn test_quarters() {
+        for month in 1..=12 {
+            let date = Date::new(2023, month, 15).unwrap();
+            let quarter = date.quarter();
+            assert_eq!(quarter, (month - 1) / 3 + 1);
+            let first = date.first_day_of_quarter();
+            let last = date.last_day_of_quarter();
+            assert_eq!((first.quarter(), last.quarter()), (quarter, quarter));
+            assert_eq!(first.add_days(-1).quarter(), if quarter == 1 { 4 } else { quarter - 1 });
+            assert_eq!(last.add_days(1).quarter(), if quarter == 4 { 1 } else { quarter + 1 });
+            assert_eq!(date.add_quarters(1), date.add_months(3));
+            assert_eq!(date.add_quarters(-7), date.add_months(-21));
+        }
+
+        assert_eq!(date!(2023 - 01 - 31).add_quarters(1), date!(2023 - 04 - 30));
+        assert_eq!(date!(2024 - 11 - 30).add_quarters(1), date!(2025 - 02 - 28));
+        assert_eq!(date!(2023 - 11 - 30).add_quarters(1), date!(2024 - 02 - 29));
+        assert_eq!(date!(2024 - 03 - 31).last_day_of_quarter(), date!(2024 - 03 - 31));
+        assert_eq!(Date::MAX.add_quarters(1), Date::MAX);
+        assert_eq!(Date::MIN.add_quarters(i32::MIN), Date::MIN);
+        assert_eq!(Date::MAX.add_quarters(i32::MAX), Date::MAX);
+    }
+
+    #[test]
+    fn test_weekday_arithmetic() {
+        for (index, weekday) in Weekday::ALL.into_iter().enumerate() {
+            assert_eq!(Weekday::from_monday_number(weekday.number_from_monday()), Some(weekday));
+            assert_eq!(Weekday::from_sunday_number(weekday.number_from_sunday()), Some(weekday));
+            assert_eq!(weekday.nth_next(1), weekday.next());
+            assert_eq!(weekday.nth_next(6), weekday.prev());
+            assert_eq!(weekday.nth_next(u8::MAX), Weekday::ALL[(index + 255) % 7]);
+            for n in 0..30u8 {
+                let other = weekday.nth_next(n);
+                assert_eq!(other, Weekday::ALL[(index + n as usize) % 7]);
+                assert_eq!(weekday.days_until(other), n % 7);
+            }
+        }
+
+        assert_eq!(Weekday::from_monday_number(0), None);
+        assert_eq!(Weekday::from_monday_number(8), None);
+        assert_eq!(Weekday::from_sunday_number(0), None);
+        assert_eq!(Weekday::from_sunday_number(8), None);
+    }
+
+    #[test]
+    #[cfg(feature = "parsing")]
+    fn test_weekday_from_str() {
+        assert_eq!("Wednesday".parse(), Ok(Weekday::Wednesday));
+        assert_eq!("wEdNeSdAy".parse(), Ok(Weekday::Wednesday));
+        assert_eq!("WED".parse(), Ok(Weekday::Wednesday));
+        assert_eq!("sat".parse(), Ok(Weekday::Saturday));
+        assert_eq!("Tue".parse(), Ok(Weekday::Tuesday));
+        assert_eq!("Thu".parse(), Ok(Weekday::Thursday));
+
+        assert_eq!("".parse::<Weekday>(), Err(ParseError::UnexpectedEnd));
+        assert_eq!("Th".parse::<Weekday>(), Err(ParseError::UnexpectedEnd));
+        assert_eq!("Thurs".parse::<Weekday>(), Err(ParseError::UnexpectedEnd));
+        assert_eq!("Mondays".parse::<Weekday>(), Err(ParseError::UnexpectedChar('s')));
+        assert_eq!("Tux".parse::<Weekday>(), Err(ParseError::UnexpectedChar('x')));
+        assert_eq!(" Monday".parse::<Weekday>(), Err(ParseError::UnexpectedChar(' ')));
+        assert_eq!(
+            "Mon\u{e9}".parse::<Weekday>(),
+            Err(ParseError::UnexpectedChar('\u{e9}'))
+        );
+    }
+
+    #[test]
+    fn test_week_of_year_and_month() {
+        // These are the US calendars where weeks begin on Sunday
+        let cases = [
+            (date!(2021 - 01 - 01), 1, 1),
+            (date!(2021 - 01 - 02), 1, 1),
+            (date!(2021 - 01 - 03), 2, 2),
+            (date!(2021 - 03 - 17), 12, 3),
+            (date!(2021 - 12 - 25), 52, 4),
+            (date!(2021 - 12 - 26), 53, 5),
+            (date!(2021 - 12 - 31), 53, 5),
+            (date!(2022 - 01 - 01), 1, 1),
+            (date!(2022 - 01 - 02), 2, 2),
+            (date!(2022 - 07 - 04), 28, 2),
+            (date!(2022 - 10 - 31), 45, 6),
+            (date!(2022 - 12 - 31), 53, 5),
+            (date!(2023 - 01 - 01), 1, 1),
+            (date!(2023 - 01 - 07), 1, 1),
+            (date!(2023 - 01 - 08), 2, 2),
+            (date!(2023 - 12 - 31), 53, 6),
+            (date!(2024 - 01 - 01), 1, 1),
+            (date!(2024 - 01 - 07), 2, 2),
+            (date!(2024 - 02 - 29), 9, 5),
+            (date!(2024 - 12 - 31), 53, 5),
+        ];
+        for (date, week, week_of_month) in cases {
+            assert_eq!(date.week_of_year(Weekday::Sunday), week, "{}", date);
+            assert_eq!(date.week_of_month(Weekday::Sunday), week_of_month, "{}", date);
+        }
+
+        // With weeks beginning on Monday
+        assert_eq!(date!(2023 - 01 - 01).week_of_year(Weekday::Monday), 1);
+        assert_eq!(date!(2023 - 01 - 02).week_of_year(Weekday::Monday), 2);
+        assert_eq!(date!(2024 - 01 - 07).week_of_year(Weekday::Monday), 1);
+        assert_eq!(date!(2024 - 12 - 31).week_of_year(Weekday::Monday), 53);
+
+        // A leap year starting on the last day of the week has 54 weeks
+        assert_eq!(date!(2000 - 01 - 01).weekday(), Weekday::Saturday);
+        assert_eq!(date!(2000 - 12 - 31).week_of_year(Weekday::Sunday), 54);
+
+        // The week only ever advances on the first weekday or resets at the start of the year/month
+        for first in Weekday::ALL {
+            for year in 2021..=2024 {
+                let mut previous = Date::new(year, 1, 1).unwrap();
+                assert_eq!(previous.week_of_year(first), 1);
+                assert_eq!(previous.week_of_month(first), 1);
+                for date in Date::iter_year(year).skip(1) {
+                    let expected = previous.week_of_year(first) + (date.weekday() == first) as u8;
+                    assert_eq!(date.week_of_year(first), expected);
+                    let expected = if date.day() == 1 {
+                        1
+                    } else {
+                        previous.week_of_month(first) + (date.weekday() == first) as u8
+                    };
+                    assert_eq!(date.week_of_month(first), expected);
+                    assert!(date.week_of_month(first) <= 6);
+                    previous = date;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_and_last_of() {
+        for year in [1900, 2000, 2023, 2024, -1, i16::MIN, i16::MAX] {
+            for month in 1..=12 {
+                let date = Date::new(year, month, 10).unwrap();
+                let first = date.first_of_month();
+                let last = date.last_of_month();
+                assert!(first.is_first_of_month());
+                assert!(last.is_last_of_month());
+                assert!(!date.is_first_of_month() && !date.is_last_of_month());
+                assert_eq!(
+                    first.pred().map(|d| d.is_last_of_month()),
+                    (first != Date::MIN).then_some(true)
+                );
+                assert_eq!(
+                    last.succ().map(|d| d.is_first_of_month()),
+                    (last != Date::MAX).then_some(true)
+                );
+                assert_eq!(last.day(), date.days_in_month());
+                assert_eq!(date.first_of_year().ordinal(), 1);
+                assert_eq!(date.last_of_year().ordinal(), date.days_in_year());
+            }
+        }
+
+        assert_eq!(date!(2024 - 02 - 10).last_of_month(), date!(2024 - 02 - 29));
+        assert_eq!(date!(2100 - 02 - 10).last_of_month(), date!(2100 - 02 - 28));
+        assert_eq!(date!(2000 - 02 - 10).last_of_month(), date!(2000 - 02 - 29));
+        assert_eq!(Date::MAX.first_of_year().last_of_year(), Date::MAX);
+        assert_eq!(Date::MIN.last_of_month().first_of_year(), Date::MIN);
+
+        const LAST: Date = date!(2024 - 02 - 10).last_of_month();
+        assert!(LAST.is_last_of_month());
+    }
+
+    #[test]
+    fn test_with_week_date_components() {
+        let out_of_range = |field, value, max| Error::FieldOutOfRange {
+            field,
+            value,
+            min: 1,
+            max,
+        };
+        assert_eq!(date!(2013 - 06 - 01).with_ordinal(365), Ok(date!(2013 - 12 - 31)));
+        assert_eq!(
+            date!(2013 - 06 - 01).with_ordinal(366),
+            Err(out_of_range("ordinal", 366, 365))
+        );
+        assert_eq!(date!(2012 - 06 - 01).with_ordinal(366), Ok(date!(2012 - 12 - 31)));
+        assert_eq!(
+            date!(2012 - 06 - 01).with_ordinal(367),
+            Err(out_of_range("ordinal", 367, 366))
+        );
+        assert_eq!(
+            date!(2012 - 06 - 01).with_ordinal(0),
+            Err(out_of_range("ordinal", 0, 366))
+        );
+        assert_eq!(Date::MAX.with_ordinal(1), Ok(date!(32767 - 01 - 01)));
+
+        // 2015 has 53 ISO weeks while 2014 has 52
+        assert_eq!(date!(2015 - 06 - 01).with_iso_week(53), Ok(date!(2015 - 12 - 28)));
+        assert_eq!(
+            date!(2014 - 06 - 01).with_iso_week(53),
+            Err(out_of_range("week", 53, 52))
+        );
+        assert_eq!(date!(2014 - 06 - 01).with_iso_week(0), Err(out_of_range("week", 0, 52)));
+        assert_eq!(date!(2014 - 06 - 01).with_iso_week(52), Ok(date!(2014 - 12 - 28)));
+        // 2014-12-29 is in the first ISO week of 2015
+        assert_eq!(date!(2014 - 12 - 29).with_iso_week(2), Ok(date!(2015 - 01 - 05)));
+        assert_eq!(Date::MIN.with_iso_week(1), Ok(Date::MIN));
+        assert_eq!(Date::MAX.with_iso_week(1).map(|d| d.iso_week().week()), Ok(1));
+
+        for week in 1..=53 {
+            let date = date!(2020 - 01 - 15);
+            let moved = date.with_iso_week(week).unwrap();
+            assert_eq!(moved.weekday(), date.weekday());
+            assert_eq!(moved.iso_week().week(), week);
+            assert_eq!(moved.iso_week().year(), 2020);
+        }
+
+        // 2024-01-01 is a Monday
+        let monday = date!(2024 - 01 - 01);
+        for offset in 0..7 {
+            let date = monday.add_days(offset);
+            let weekday = date.weekday();
+            for day in 0..7 {
+                let target = monday.add_days(day);
+                assert_eq!(date.with_weekday(target.weekday()), Ok(target));
+            }
+            assert_eq!(date.with_weekday(weekday), Ok(date));
+        }
+
+        // 32767-12-31 is a Sunday and -32768-01-01 is a Thursday
+        assert_eq!(Date::MAX.weekday(), Weekday::Sunday);
+        assert_eq!(Date::MAX.with_weekday(Weekday::Monday), Ok(date!(32767 - 12 - 25)));
+        assert_eq!(Date::MIN.weekday(), Weekday::Thursday);
+        assert_eq!(
+            Date::MIN.with_weekday(Weekday::Monday).is_err(),
+            Date::MIN.weekday() != Weekday::Monday
+        );
+    }
+
+    #[test]
+    fn test_succ_and_pred() {
+        let cases = [
+            (date!(2023 - 01 - 31), date!(2023 - 02 - 01)),
+            (date!(2023 - 02 - 28), date!(2023 - 03 - 01)),
+            (date!(2024 - 02 - 28), date!(2024 - 02 - 29)),
+            (date!(2024 - 02 - 29), date!(2024 - 03 - 01)),
+            (date!(1900 - 02 - 28), date!(1900 - 03 - 01)),
+            (date!(2000 - 02 - 29), date!(2000 - 03 - 01)),
+            (date!(2023 - 04 - 30), date!(2023 - 05 - 01)),
+            (date!(2023 - 12 - 31), date!(2024 - 01 - 01)),
+            (date!(-1 - 12 - 31), date!(0 - 01 - 01)),
+            (date!(32767 - 12 - 30), Date::MAX),
+            (Date::MIN, date!(-32768 - 01 - 02)),
+        ];
+        for (date, next) in cases {
+            assert_eq!(date.succ(), Some(next));
+            assert_eq!(next.pred(), Some(date));
+        }
+
+        assert_eq!(Date::MAX.succ(), None);
+        assert_eq!(Date::MIN.pred(), None);
+
+        for days in (MIN_EPOCH_DAYS..MAX_EPOCH_DAYS).step_by(7919) {
+            let date = Date::from_epoch_days(days).unwrap();
+            assert_eq!(date.succ(), date.checked_add_days(1));
+            assert_eq!(date.pred(), date.checked_add_days(-1));
+        }
+    }
+
+    #[test]
+    fn test_age_on_leap_day_birthdays() {
+        let birthday = date!(2000 - 02 - 29);
+        assert_eq!(date!(2001 - 02 - 28).years_since(&birthday), Some(1));
+        assert_eq!(date!(2001 - 03 - 01).years_since(&birthday), Some(1));
+        assert_eq!(date!(2001 - 02 - 27).years_since(&birthday), Some(0));
+        assert_eq!(date!(2004 - 02 - 28).years_since(&birthday), Some(3));
+        assert_eq!(date!(2004 - 02 - 29).years_since(&birthday), Some(4));
+        assert_eq!(birthday.years_since(&birthday), Some(0));
+        assert_eq!(date!(2000 - 02 - 28).years_since(&birthday), None);
+
+        // The age is the number of anniversaries reached by adding years
+        for days in 0..2000 {
+            let date = birthday.add_days(days);
+            let years = (1..).find(|&n| birthday.add_years(n) > date).unwrap() - 1;
+            assert_eq!(date.years_since(&birthday), Some(years as u16), "{}", date);
+        }
+
+        // With the February 28th policy the birthday is on the 28th
+        let policy = LeapDayPolicy::February28;
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2001 - 02 - 27), policy),
+            date!(2001 - 02 - 28)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2001 - 02 - 28), policy),
+            date!(2002 - 02 - 28)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2003 - 03 - 01), policy),
+            date!(2004 - 02 - 29)
+        );
+
+        // With the March 1st policy the birthday is on the 1st
+        let policy = LeapDayPolicy::March1;
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2001 - 02 - 28), policy),
+            date!(2001 - 03 - 01)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2001 - 03 - 01), policy),
+            date!(2002 - 03 - 01)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2004 - 02 - 28), policy),
+            date!(2004 - 02 - 29)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&date!(2004 - 02 - 29), policy),
+            date!(2005 - 03 - 01)
+        );
+        assert_eq!(
+            birthday.next_anniversary_after(&birthday, policy),
+            date!(2001 - 03 - 01)
+        );
+
+        // Other dates are unaffected by the policy
+        let date = date!(2000 - 03 - 01);
+        for policy in [LeapDayPolicy::February28, LeapDayPolicy::March1] {
+            assert_eq!(date.anniversary_in(2001, policy), date!(2001 - 03 - 01));
+            assert_eq!(
+                date.next_anniversary_after(&date!(2001 - 02 - 28), policy),
+                date!(2001 - 03 - 01)
+            );
+        }
+
+        // The extremes
+        assert_eq!(Date::MAX.years_since(&Date::MIN), Some(u16::MAX));
+        assert_eq!(Date::MAX.add_days(-1).years_since(&Date::MIN), Some(u16::MAX));
+        assert_eq!(
+            date!(32767 - 12 - 30).years_since(&date!(-32768 - 12 - 31)),
+            Some(u16::MAX - 1)
+        );
+        assert_eq!(
+            Date::MIN.next_anniversary_after(&Date::MIN, LeapDayPolicy::February28),
+            date!(-32767 - 01 - 01)
+        );
+        assert_eq!(
+            Date::MIN.next_anniversary_after(&Date::MAX, LeapDayPolicy::February28),
+            Date::MAX
+        );
+        assert_eq!(
+            Date::MAX.next_anniversary_after(&Date::MIN, LeapDayPolicy::February28),
+            Date::MAX
+        );
+    }
+
+    #[test]
+    fn test_epoch_days() {
+        assert_eq!(Date::MIN.to_epoch_days(), MIN_EPOCH_DAYS);
+        assert_eq!(Date::MAX.to_epoch_days(), MAX_EPOCH_DAYS);
+        assert_eq!(Date::from_epoch_days(MIN_EPOCH_DAYS), Ok(Date::MIN));
+        assert_eq!(Date::from_epoch_days(MAX_EPOCH_DAYS), Ok(Date::MAX));
+        assert_eq!(Date::from_epoch_days(MIN_EPOCH_DAYS - 1), Err(Error::OutOfRange));
+        assert_eq!(Date::from_epoch_days(MAX_EPOCH_DAYS + 1), Err(Error::OutOfRange));
+        assert_eq!(Date::MAX.days_since(&Date::MIN), MAX_EPOCH_DAYS - MIN_EPOCH_DAYS);
+        assert_eq!(Date::MIN.days_since(&Date::MAX), MIN_EPOCH_DAYS - MAX_EPOCH_DAYS);
+
+        const DATE: Result<Date, Error> = Date::from_epoch_days(19_000);
+        assert_eq!(DATE, Ok(date!(2022 - 01 - 08)));
+
+        for days in (MIN_EPOCH_DAYS..=MAX_EPOCH_DAYS).step_by(997) {
+            let date = Date::from_epoch_days(days).unwrap();
+            assert_eq!(date.to_epoch_days(), days);
+            assert_eq!(date.add_days(400).days_since(&date), 400.min(MAX_EPOCH_DAYS - days));
+        }
+    }
+
+    #[test]
+    fn test_julian_day_number() {
+        // Fliegel and Van Flandern's algorithm as an independent reference.
+        // It only holds for positive Julian day numbers, so the year is shifted
+        // forward by 100 Gregorian cycles of 400 years (146,097 days each) first.
+        fn reference(date: Date) -> i32 {
+            let (y, m, d) = (date.year() as i32 + 40_000, date.month() as i32, date.day() as i32);
+            let a = (m - 14) / 12;
+            let jdn = (1461 * (y + 4800 + a)) / 4 + (367 * (m - 2 - 12 * a)) / 12 - (3 * ((y + 4900 + a) / 100)) / 4
+                + d
+                - 32075;
+            jdn - 100 * 146_097
+        }
+
+        let min = Date::MIN.julian_day_number();
+        let max = Date::MAX.julian_day_number();
+        assert_eq!(min, -10_247_206);
+        assert_eq!(max, 13_689_325);
+        assert_eq!(Date::from_julian_day_number(min - 1), Err(Error::OutOfRange));
+        assert_eq!(Date::from_julian_day_number(max + 1), Err(Error::OutOfRange));
+        assert_eq!(Date::from_julian_day_number(i32::MIN), Err(Error::OutOfRange));
+        assert_eq!(Date::from_julian_day_number(i32::MAX), Err(Error::OutOfRange));
+
+        let mut previous = None;
+        for jdn in min..=max {
+            let date = Date::from_julian_day_number(jdn).unwrap();
+            assert_eq!(date.julian_day_number(), jdn);
+            assert_eq!(reference(date), jdn);
+            assert!(previous < Some(date));
+            previous = Some(date);
+        }
+    }
 }
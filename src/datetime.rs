@@ -1,24 +1,29 @@
 use crate::{
-    step::Advance,
+    gregorian::{days_in_month, weekday_difference, MODIFIED_JULIAN_DAY_OFFSET, UNIX_EPOCH_JULIAN_DAY},
+    step::{round_local, round_to_duration, with_local, Advance, Round, Rounding},
     timestamp::Timestamp,
     timezone::{Utc, UtcOffset},
     utils::divmod,
-    Date, Interval, IsoWeekDate, Time, TimeZone, Weekday,
+    Date, DateTimeResolution, Error, Interval, IsoWeekDate, SubsecondPrecision, Time, TimeZone, Weekday,
 };
 
 #[cfg(feature = "system")]
-use crate::{sys::systemtime, Error, System};
+use crate::{sys::systemtime, System};
 
 use core::time::Duration;
 use core::{
     cmp::Ordering,
     fmt::Write,
-    ops::{Add, Sub},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Sub, SubAssign},
 };
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "alloc")]
+use crate::ZoneInfo;
+
 #[cfg(feature = "formatting")]
 use alloc::string::{String, ToString};
 
@@ -28,15 +33,27 @@ use crate::fmt::{IsoFormatPrecision, ToIsoFormat};
 #[cfg(feature = "parsing")]
 use crate::fmt::{FromIsoFormat, ParseError, Parser};
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use crate::fmt::Profile;
+
 /// An ISO 8601 combined date and time component.
 ///
 /// Unlike their individual components, [`DateTime`] have a timezone associated with them.
 /// For convenience, the methods of [`Time`] and [`Date`] are flattened and inherent methods
 /// of the struct. This means that methods such as [`second`] or [`month`] work as expected.
 ///
+/// Equality, ordering, and hashing are based on the instant in time the datetime
+/// represents, regardless of its timezone. For example, `2000-01-02 03:04:05 +03:00`
+/// and `2000-01-02 00:04:05 UTC` compare equal and have the same hash.
+///
 /// [`second`]: DateTime::second
 /// [`month`]: DateTime::month
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
 pub struct DateTime<Tz = Utc>
 where
     Tz: TimeZone,
@@ -68,11 +85,109 @@ impl DateTime<Utc> {
         timezone: Utc,
     };
 
+    /// The earliest [`DateTime`] in UTC, at [`Date::MIN`] and [`Time::MIN`].
+    ///
+    /// Every [`DateTime`] in UTC compares greater than or equal to this value. This is
+    /// not a bound for other timezones. Their local date and time has the same range as
+    /// UTC, so a local time of [`Date::MIN`] ahead of UTC is an earlier instant than this.
+    /// Operations that saturate in those timezones clamp the local date, not the instant.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime};
+    ///
+    /// assert!(datetime!(-32768-01-01 00:00 +01:00) < DateTime::MIN);
+    /// ```
+    pub const MIN: Self = Self {
+        date: Date::MIN,
+        time: Time::MIN,
+        offset: UtcOffset::UTC,
+        timezone: Utc,
+    };
+
+    /// The latest [`DateTime`] in UTC, at [`Date::MAX`] and [`Time::MAX`].
+    ///
+    /// Every [`DateTime`] in UTC that is not on a leap second compares less than or equal
+    /// to this value. Like [`DateTime::MIN`] this is not a bound for other timezones, since
+    /// a local time of [`Date::MAX`] behind UTC is a later instant than this.
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime};
+    ///
+    /// assert!(datetime!(32767-12-31 23:00 -01:00) > DateTime::MAX);
+    /// ```
+    pub const MAX: Self = Self {
+        date: Date::MAX,
+        time: Time::MAX,
+        offset: UtcOffset::UTC,
+        timezone: Utc,
+    };
+
+    /// Creates a [`DateTime`] from a Windows `FILETIME` value.
+    ///
+    /// See [`Timestamp::from_filetime`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime};
+    ///
+    /// assert_eq!(DateTime::from_filetime(116_444_736_000_000_000)?, DateTime::UNIX_EPOCH);
+    /// assert_eq!(DateTime::UNIX_EPOCH.to_filetime()?, 116_444_736_000_000_000);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn from_filetime(ticks: u64) -> Result<Self, Error> {
+        Timestamp::from_filetime(ticks).map(Timestamp::to_utc)
+    }
+
+    /// Creates a [`DateTime`] from an NTP timestamp.
+    ///
+    /// See [`Timestamp::from_ntp`] for more information.
+    pub fn from_ntp(seconds: u32, fraction: u32) -> Self {
+        Timestamp::from_ntp(seconds, fraction).to_utc()
+    }
+
+    /// Creates a [`DateTime`] from a GPS week number and the seconds into that week.
+    ///
+    /// The result is on the GPS time scale, which is ahead of UTC by the number of leap
+    /// seconds inserted since 1980. See [`Timestamp::from_gps_weeks_and_seconds`] for
+    /// more information.
+    pub fn from_gps_weeks_and_seconds(week: u16, seconds: f64) -> Result<Self, Error> {
+        Timestamp::from_gps_weeks_and_seconds(week, seconds).map(Timestamp::to_utc)
+    }
+
     /// Returns the current date and time in UTC.
+    ///
+    /// On `wasm32-unknown-unknown` this requires the `wasm-bindgen` feature, which
+    /// gets the current time from JavaScript's `Date.now()` at millisecond precision.
     #[inline]
     #[cfg(feature = "std")]
     pub fn utc_now() -> Self {
-        SystemTime::now().into()
+        crate::sys::utc_now()
+    }
+
+    /// Creates a [`DateTime`] from a [`SystemTime`], checking the range.
+    ///
+    /// This is the checked counterpart of the [`From`] implementation, which saturates.
+    /// If the [`SystemTime`] is outside of the range from [`DateTime::MIN`] to
+    /// [`DateTime::MAX`] then [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use eos::{datetime, DateTime};
+    ///
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1641173925);
+    /// assert_eq!(DateTime::try_from_system_time(time)?, datetime!(2022-01-03 1:38:45));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_system_time(time: SystemTime) -> Result<Self, Error> {
+        match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => Self::UNIX_EPOCH.checked_add_duration(duration),
+            Err(e) => Self::UNIX_EPOCH.checked_sub_duration(e.duration()),
+        }
+        .ok_or(Error::OutOfRange)
     }
 
     #[doc(hidden)]
@@ -99,6 +214,7 @@ impl DateTime<Utc> {
     /// by an arbitrary interval then check the [`Interval`] class and add the
     /// datetime to that value.
     pub fn shift(&mut self, offset: UtcOffset) {
+        // Both of these are bounded to around ±86,401 seconds so an i64 is plenty
         let offset_nanos = offset.total_seconds() as i64 * 1_000_000_000;
         let (days, time) = Time::adjust_from_nanos(self.time.total_nanos() as i64 + offset_nanos);
         self.date = self.date.add_days(days);
@@ -109,14 +225,50 @@ impl DateTime<Utc> {
 #[cfg(feature = "system")]
 impl DateTime<System> {
     /// Returns the current [`DateTime`] in local time.
+    ///
+    /// With the `std` feature enabled, the local timezone information comes from the
+    /// process-wide cache documented in [`crate::CachedSystem`].
     #[inline]
     pub fn now() -> Result<Self, Error> {
+        #[cfg(feature = "std")]
+        let (dt, local) = {
+            let mut utc = DateTime::utc_now();
+            let local = systemtime::cache::get(utc.timestamp().as_seconds())?;
+            utc.shift(local.offset());
+            (utc, local)
+        };
+        #[cfg(not(feature = "std"))]
         let (dt, local) = systemtime::get_system_time_components()?;
-        Ok(dt.with_timezone(System(local)))
+        // The offset is already known so there's no need to resolve the local time,
+        // which could otherwise pick the wrong side of a fold
+        let offset = local.offset();
+        Ok(crate::DateTimeResolution::unambiguous(dt.date, dt.time, offset, System(local)).lenient())
     }
 }
 
 impl DateTime<UtcOffset> {
+    /// Parses a [`DateTime`] with a [`UtcOffset`] from a string that conforms to the given [`Profile`].
+    ///
+    /// Unlike [`FromIsoFormat`], the accepted representations are exactly those allowed
+    /// by the profile. See [`Profile`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime};
+    /// use eos::fmt::Profile;
+    ///
+    /// let dt = DateTime::parse_with_profile("20210203T040506.5-0500", Profile::Iso8601Basic)?;
+    /// assert_eq!(dt, datetime!(2021-02-03 04:05:06 -05:00).with_millisecond(500).unwrap());
+    /// assert!(DateTime::parse_with_profile("2021-W05-3T04:05:06Z", Profile::Rfc3339).is_err());
+    /// assert!(DateTime::parse_with_profile("2021-W05-3T04:05:06Z", Profile::Iso8601Extended).is_ok());
+    /// # Ok::<_, eos::fmt::ParseError>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn parse_with_profile(s: &str, profile: Profile) -> Result<Self, ParseError> {
+        crate::fmt::profile::parse_datetime(s, profile)
+    }
+
     /// Parses a [`DateTime`] with a [`UtcOffset`] from an [RFC 3339] string.
     ///
     /// This differs from ISO-8601 parsing by only allowing the basic format:
@@ -198,14 +350,7 @@ impl DateTime<UtcOffset> {
         T: AsRef<str>,
         S: AsRef<[crate::fmt::FormatSpec<'a>]>,
     {
-        let spec = spec.as_ref();
-        let mut parser = Parser::new(s.as_ref());
-        let mut builder = crate::Builder::new().timezone(UtcOffset::UTC);
-        for s in spec {
-            s.parse_into(&mut builder, &mut parser)?;
-        }
-        builder.fix_leap_seconds();
-        builder.build().map_err(|_| ParseError::OutOfBounds)
+        crate::fmt::parse_with_spec(s.as_ref(), spec.as_ref())
     }
 }
 
@@ -249,6 +394,42 @@ impl<Tz> DateTime<Tz>
 where
     Tz: TimeZone,
 {
+    /// Resolves a local date and time in the given timezone without picking a result.
+    ///
+    /// This is useful when the local time comes from user input, since the
+    /// [`DateTimeResolution`] tells whether the time was skipped or is ambiguous in the
+    /// timezone. The caller can then choose to call [`DateTimeResolution::earlier`],
+    /// [`DateTimeResolution::later`], [`DateTimeResolution::lenient`], or
+    /// [`DateTimeResolution::exact`] to get the final [`DateTime`].
+    ///
+    /// This is the same as [`TimeZone::resolve`] except the date is validated first.
+    /// If the date is invalid then [`Error::FieldOutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{time, utc_offset, DateTime, DateTimeResolutionKind, Error};
+    ///
+    /// let resolution = DateTime::new_resolved(2022, 3, 1, time!(09:00), utc_offset!(-05:00))?;
+    /// assert_eq!(resolution.kind(), DateTimeResolutionKind::Unambiguous);
+    /// assert_eq!(resolution.exact()?.hour(), 9);
+    /// assert!(matches!(
+    ///     DateTime::new_resolved(2022, 2, 29, time!(09:00), utc_offset!(-05:00)),
+    ///     Err(Error::FieldOutOfRange { field: "day", .. })
+    /// ));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn new_resolved(
+        year: i16,
+        month: u8,
+        day: u8,
+        time: Time,
+        timezone: Tz,
+    ) -> Result<DateTimeResolution<Tz>, Error> {
+        let date = Date::try_new(year, month, day)?;
+        Ok(timezone.resolve(date, time))
+    }
+
     /// Creates a [`DateTime`] from the given year, ordinal date, and timezone. The time is set to
     /// midnight UTC.
     ///
@@ -274,6 +455,11 @@ where
 
     /// Creates a [`DateTime`] from a POSIX timestamp in seconds, a nanosecond component, and a timezone.
     ///
+    /// The supported range is from [`DateTime::MIN`] to [`DateTime::MAX`], which is
+    /// around ±1.1×10<sup>12</sup> seconds from the UNIX epoch. Timestamps outside of
+    /// that range saturate to the closest bound, see [`DateTime::try_from_timestamp`]
+    /// for a version that returns an error instead. This never panics.
+    ///
     /// ```
     /// use eos::{datetime, utc_offset, DateTime, Utc, Timestamp};
     /// assert_eq!(
@@ -294,6 +480,110 @@ where
         timezone.convert_utc(timestamp.to_utc())
     }
 
+    /// Creates a [`DateTime`] from the number of milliseconds since the UNIX epoch.
+    ///
+    /// Negative values are before the epoch. The sub-second component always counts
+    /// forward from the whole second, e.g. `-1` is `1969-12-31 23:59:59.999`.
+    ///
+    /// If the timestamp is outside of the range of [`DateTime`] then
+    /// [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, Utc};
+    ///
+    /// let dt = DateTime::from_timestamp_millis(1641173925123, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(2022-01-03 1:38:45)));
+    /// assert_eq!(dt.nanosecond(), 123_000_000);
+    ///
+    /// let dt = DateTime::from_timestamp_millis(-1, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(1969-12-31 23:59:59)));
+    /// assert_eq!(dt.nanosecond(), 999_000_000);
+    /// assert!(DateTime::from_timestamp_millis(i64::MAX, Utc).is_err());
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn from_timestamp_millis(milliseconds: i64, timezone: Tz) -> Result<Self, Error> {
+        Self::try_from_timestamp(Timestamp::from_milliseconds(milliseconds), timezone)
+    }
+
+    /// Creates a [`DateTime`] from the number of microseconds since the UNIX epoch.
+    ///
+    /// Negative values are before the epoch. The sub-second component always counts
+    /// forward from the whole second, e.g. `-1` is `1969-12-31 23:59:59.999999`.
+    ///
+    /// If the timestamp is outside of the range of [`DateTime`] then
+    /// [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, Utc};
+    ///
+    /// let dt = DateTime::from_timestamp_micros(1641173925123456, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(2022-01-03 1:38:45)));
+    /// assert_eq!(dt.nanosecond(), 123_456_000);
+    ///
+    /// let dt = DateTime::from_timestamp_micros(-1, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(1969-12-31 23:59:59)));
+    /// assert_eq!(dt.nanosecond(), 999_999_000);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn from_timestamp_micros(microseconds: i64, timezone: Tz) -> Result<Self, Error> {
+        Self::try_from_timestamp(Timestamp::from_microseconds(microseconds), timezone)
+    }
+
+    /// Creates a [`DateTime`] from the number of nanoseconds since the UNIX epoch.
+    ///
+    /// Negative values are before the epoch. The sub-second component always counts
+    /// forward from the whole second, e.g. `-1` is `1969-12-31 23:59:59.999999999`.
+    ///
+    /// This takes an `i128` so that every [`DateTime`] can be represented, an `i64`
+    /// of nanoseconds only covers the years 1677 to 2262. If the timestamp is outside
+    /// of the range of [`DateTime`] then [`Error::OutOfRange`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, Utc};
+    ///
+    /// let dt = DateTime::from_timestamp_nanos(1641173925123456789, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(2022-01-03 1:38:45)));
+    /// assert_eq!(dt.nanosecond(), 123_456_789);
+    ///
+    /// let dt = DateTime::from_timestamp_nanos(-1, Utc)?;
+    /// assert_eq!(dt.with_nanosecond(0), Some(datetime!(1969-12-31 23:59:59)));
+    /// assert_eq!(dt.nanosecond(), 999_999_999);
+    /// assert!(DateTime::from_timestamp_nanos(i128::MAX, Utc).is_err());
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn from_timestamp_nanos(nanoseconds: i128, timezone: Tz) -> Result<Self, Error> {
+        let (seconds, nanoseconds) = divmod!(nanoseconds, 1_000_000_000);
+        let seconds = i64::try_from(seconds).map_err(|_| Error::OutOfRange)?;
+        Self::try_from_timestamp(Timestamp::new(seconds, nanoseconds as u32), timezone)
+    }
+
+    /// Creates a [`DateTime`] from a POSIX timestamp and a timezone, checking the range.
+    ///
+    /// This is the same as [`DateTime::from_timestamp`] except that a timestamp outside
+    /// of the range from [`DateTime::MIN`] to [`DateTime::MAX`] returns
+    /// [`Error::OutOfRange`] instead of saturating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, DateTime, Error, Timestamp, Utc};
+    ///
+    /// let ts = Timestamp::from_seconds(1641173925);
+    /// assert_eq!(DateTime::try_from_timestamp(ts, Utc)?, datetime!(2022-01-03 1:38:45));
+    /// assert_eq!(DateTime::try_from_timestamp(DateTime::MAX.timestamp(), Utc)?, DateTime::MAX);
+    /// assert_eq!(DateTime::try_from_timestamp(Timestamp::from_seconds(i64::MAX), Utc), Err(Error::OutOfRange));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn try_from_timestamp(timestamp: Timestamp, timezone: Tz) -> Result<Self, Error> {
+        timestamp.checked_range().map(|ts| Self::from_timestamp(ts, timezone))
+    }
+
     /// Creates a [`DateTime`] representing the current day at midnight.
     #[cfg(feature = "std")]
     #[must_use]
@@ -317,8 +607,17 @@ where
     /// This is mostly the same as ISO-8601 except a space is used
     /// instead of a `T` for the separator between the date and time.
     /// Microsecond precision is used for the fractional component
-    /// rather than nanoseconds. Likewise, the seconds component of
-    /// the UTC offset is always ignored.
+    /// rather than nanoseconds. Likewise, since RFC 3339 cannot represent
+    /// the seconds component of the UTC offset, the offset is rounded to the
+    /// nearest minute and the local time is adjusted to represent the same instant.
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// // Africa/Abidjan before 1912 was at -00:16:08
+    /// let dt = datetime!(1900-01-01 00:00:00 -00:16:08);
+    /// assert_eq!(dt.to_rfc3339().to_string(), "1900-01-01 00:00:08-00:16");
+    /// ```
     ///
     /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
     #[cfg(feature = "formatting")]
@@ -326,6 +625,85 @@ where
         crate::fmt::Rfc3339Formatter { dt: self }
     }
 
+    /// Returns the local date, time, and UTC offset with the offset rounded to whole minutes.
+    ///
+    /// The local date and time are adjusted so they still represent the same instant.
+    #[cfg(feature = "formatting")]
+    pub(crate) fn whole_minute_parts(&self) -> (Date, Time, UtcOffset) {
+        if self.offset.is_whole_minutes() {
+            return (self.date, self.time, self.offset);
+        }
+        let offset = self.offset.round_to_minutes();
+        let mut local = self.date.at(self.time);
+        local.shift(offset.saturating_sub(self.offset));
+        (local.date, local.time, offset)
+    }
+
+    /// Formats this datetime according to the given [`Profile`].
+    ///
+    /// The canonical representation of the profile is used, e.g. `T` is always used
+    /// as the separator and the fewest fractional second digits the profile allows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FieldOutOfRange`] if the year is outside of `0000` to `9999`
+    /// and the profile does not allow expanded years, or if the UTC offset rounds to
+    /// 24 hours. Profiles do not allow seconds in the UTC offset, so the offset is
+    /// rounded to the nearest minute first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Error;
+    /// use eos::datetime;
+    /// use eos::fmt::Profile;
+    ///
+    /// let dt = datetime!(2021-02-03 04:05:06 -05:00).with_millisecond(500).unwrap();
+    /// assert_eq!(dt.format_with_profile(Profile::Rfc3339)?.to_string(), "2021-02-03T04:05:06.5-05:00");
+    /// assert_eq!(dt.format_with_profile(Profile::Iso8601Basic)?.to_string(), "20210203T040506.5-0500");
+    ///
+    /// let expanded = dt.with_year(10000).unwrap();
+    /// assert!(expanded.format_with_profile(Profile::Rfc3339).is_err());
+    /// assert_eq!(
+    ///     expanded.format_with_profile(Profile::Iso8601Extended)?.to_string(),
+    ///     "+10000-02-03T04:05:06.5-05:00"
+    /// );
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[cfg(feature = "formatting")]
+    pub fn format_with_profile(&self, profile: Profile) -> Result<crate::fmt::ProfileFormatter<'_, Tz>, Error> {
+        crate::fmt::ProfileFormatter::new(self, profile.options())
+    }
+
+    /// Formats this datetime as a filesystem safe string that sorts in chronological order.
+    ///
+    /// The datetime is converted to UTC and formatted using [`FILENAME`], e.g.
+    /// `2024-03-01_09-30-00.000000000Z`. Since every field has a fixed width, sorting
+    /// these strings lexically sorts them chronologically.
+    ///
+    /// Negative years and years past `9999` can't keep that guarantee, so [`None`]
+    /// is returned if the year in UTC is outside of `0000` to `9999`.
+    ///
+    /// [`FILENAME`]: crate::fmt::presets::FILENAME
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time};
+    ///
+    /// let dt = datetime!(2024-03-01 04:30:00 -05:00);
+    /// assert_eq!(dt.to_filename_string().as_deref(), Some("2024-03-01_09-30-00.000000000Z"));
+    /// assert_eq!(date!(-1 - 01 - 01).at(time!(00:00)).to_filename_string(), None);
+    /// ```
+    #[cfg(feature = "formatting")]
+    pub fn to_filename_string(&self) -> Option<String> {
+        let utc = self.clone().in_timezone(Utc);
+        if !(0..=9999).contains(&utc.year()) {
+            return None;
+        }
+        Some(utc.format(crate::fmt::presets::FILENAME).to_string())
+    }
+
     /// Returns a reference to the time component.
     #[must_use]
     pub fn time(&self) -> &Time {
@@ -352,7 +730,8 @@ where
 
     /// Returns a new [`DateTime`] with the newly specified [`Time`].
     ///
-    /// This does not do timezone conversion.
+    /// This does not do timezone conversion. To resolve the new time
+    /// through the timezone use [`Self::resolve_with`] instead.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     pub fn with_time(mut self, time: Time) -> Self {
         self.time = time;
@@ -360,12 +739,67 @@ where
     }
 
     /// Returns a new [`DateTime`] with the newly specified [`Date`].
+    ///
+    /// This does not do timezone conversion. To resolve the new date
+    /// through the timezone use [`Self::resolve_with`] instead.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     pub fn with_date(mut self, date: Date) -> Self {
         self.date = date;
         self
     }
 
+    /// Returns a new [`DateTime`] with the local date and time moved by the given amount.
+    ///
+    /// This is a pure wall clock adjustment, similar to [`DateTime::shift`] except it's
+    /// available for every timezone. The UTC offset and timezone are left untouched, so
+    /// unlike adding an [`Interval`] the resulting datetime represents a different instant.
+    /// Like [`Self::with_time`], this does not do timezone conversion. To resolve the new
+    /// local time through the timezone use [`Self::resolve_with`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset};
+    ///
+    /// let dt = datetime!(2022-01-31 23:30 -05:00);
+    /// assert_eq!(dt.add_wall_clock(utc_offset!(+01:00)), datetime!(2022-02-01 00:30 -05:00));
+    /// assert_eq!(dt.add_wall_clock(utc_offset!(-23:30)), datetime!(2022-01-31 00:00 -05:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_wall_clock(mut self, amount: UtcOffset) -> Self {
+        let mut local = self.date.at(self.time);
+        local.shift(amount);
+        self.date = local.date;
+        self.time = local.time;
+        self
+    }
+
+    /// Resolves a new local date and time in this datetime's timezone.
+    ///
+    /// Unlike [`Self::with_date`] and [`Self::with_time`], the UTC offset is recalculated
+    /// for the new wall clock time. Since the new wall clock time might be skipped or
+    /// repeated in the timezone, a [`DateTimeResolution`] is returned so the caller can
+    /// decide how to handle these cases. See its [`kind`] for which case occurred.
+    ///
+    /// This is equivalent to `self.timezone().clone().resolve(date, time)`.
+    ///
+    /// [`kind`]: DateTimeResolution::kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, time, DateTimeResolutionKind};
+    ///
+    /// let dt = datetime!(2022-03-01 09:00 -05:00);
+    /// let resolution = dt.resolve_with(*dt.date(), time!(17:30));
+    /// assert_eq!(resolution.kind(), DateTimeResolutionKind::Unambiguous);
+    /// assert_eq!(resolution.exact()?, datetime!(2022-03-01 17:30 -05:00));
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn resolve_with(self, date: Date, time: Time) -> DateTimeResolution<Tz> {
+        self.timezone.resolve(date, time)
+    }
+
     /// Returns a reference to the [`TimeZone`] associated with this datetime.
     #[must_use]
     pub fn timezone(&self) -> &Tz {
@@ -390,58 +824,57 @@ where
         self.timezone.name(self.timestamp())
     }
 
+    /// Returns the UTC offset, name, and DST status of the timezone at this datetime.
+    ///
+    /// This queries the timezone once through [`TimeZone::info_at`], so the values are
+    /// consistent with each other. Note that [`Self::offset`] is the offset stored in the
+    /// datetime and is not looked up again, which is what formatting uses for `%z` and `%o`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, Utc};
+    ///
+    /// let info = datetime!(2022-01-03 01:38:45).zone_info();
+    /// assert_eq!(info.offset(), &utc_offset!(00:00));
+    /// assert_eq!(info.name(), Some("UTC"));
+    /// assert!(!info.is_dst());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn zone_info(&self) -> ZoneInfo {
+        self.timezone.info_at(self.timestamp())
+    }
+
     /// Compares two datetime instances that do not share a timezone.
     ///
     /// Due to [a limitation][bad-ord] with the Rust [`Ord`] trait, this cannot be implemented
     /// using the standard trait. Therefore, `cmp` is only implemented on same-type
     /// [`TimeZone`] datetimes.
     ///
+    /// A leap second, i.e. a nanosecond value of a second or more, compares equal to the
+    /// same point in time of the next second, like it does when converted to a [`Timestamp`].
+    ///
     /// [bad-ord]: https://github.com/rust-lang/rfcs/issues/2511
     #[must_use]
     pub fn cmp_cross_timezone<OtherTz>(&self, other: &DateTime<OtherTz>) -> Ordering
     where
         OtherTz: TimeZone,
     {
-        if self.offset == other.offset {
-            return (self.date, self.time).cmp(&(other.date, other.time));
-        }
-
-        // Get how many days the two dates differ by and also whether
-        // they are the same "moment" after accounting for their UTC offsets
-        let (days, same) = {
-            // Initially this is just a difference in days via epoch
-            // If the UTC offsets at that date resolve to the same one, then they're on the same
-            // date and the calculation is correct
-            let days = self.date().days_since_epoch() - other.date().days_since_epoch();
-            if self.offset == other.offset {
-                (days, true)
-            } else {
-                // If they're not, then we need to take into consideration the time component.
-                // A time component is essentially constrained to 24 hours
-                // So the difference in seconds between two of them won't go too much over
-                // 24 hours, we can use this to our advantage because by the same virtue
-                // offsets are also only within 24 hour bounds, making this a rather simple
-                // second-wise addition
-                let delta_offsets = other.offset.total_seconds() - self.offset.total_seconds();
-                let seconds = self.time.total_seconds() - other.time.total_seconds() + delta_offsets;
-
-                let (d, s) = divmod!(seconds, 86_400);
-                (
-                    days + d,
-                    s == 0 && self.time().nanosecond() == other.time().nanosecond(),
-                )
-            }
-        };
-
-        // If the number of days we differ by is negative then lhs is less than rhs by virtue
+        // Normalize both sides to UTC and compare the day first and then the time of day.
+        // The local wall clock time can't be compared directly, e.g. 23:00 +10:00 is
+        // earlier than 20:00 UTC on the same day. This is done even if the offsets are the
+        // same so that leap seconds compare the same way regardless of the offset.
+        self.utc_epoch_day_and_nanos().cmp(&other.utc_epoch_day_and_nanos())
+    }
 
-        if days < 0 {
-            Ordering::Less
-        } else if days == 0 && same {
-            Ordering::Equal
-        } else {
-            Ordering::Greater
-        }
+    /// Returns the number of days since the UNIX epoch and the nanoseconds
+    /// since midnight of that day, both in UTC.
+    fn utc_epoch_day_and_nanos(&self) -> (i64, i64) {
+        // Offsets are within a day, so this only moves the date by at most a day
+        let nanos = self.time.total_nanos() as i64 - self.offset.total_seconds() as i64 * 1_000_000_000;
+        let (days, nanos) = divmod!(nanos, 86_400_000_000_000);
+        (self.days_since_epoch() as i64 + days, nanos)
     }
 
     /// Compares two datetime instances without caring about their timezone information.
@@ -459,12 +892,27 @@ where
         self.time.cmp(&other.time)
     }
 
+    /// Returns the exact number of nanoseconds from the other datetime to this one.
+    fn nanos_since<OtherTz>(&self, other: &DateTime<OtherTz>) -> i128
+    where
+        OtherTz: TimeZone,
+    {
+        fn utc_nanos<Tz: TimeZone>(dt: &DateTime<Tz>) -> i128 {
+            (dt.days_since_epoch() as i128 * 86_400 - dt.offset.total_seconds() as i128) * 1_000_000_000
+                + dt.time.total_nanos() as i128
+        }
+        utc_nanos(self) - utc_nanos(other)
+    }
+
     /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
     ///
-    /// # Panics
+    /// The difference is exact, including the nanoseconds, and accounts for the UTC
+    /// offsets of both datetimes.
+    ///
+    /// # Errors
     ///
-    /// If `earlier` is later than `self`. Use [`Self::checked_duration_since`] or
-    /// [`Self::saturating_duration_since`] for non-panicking behavior.
+    /// If `earlier` is later than `self` then the amount of time between the two is
+    /// returned as the error instead. This mirrors [`std::time::SystemTime::duration_since`].
     ///
     /// # Examples
     ///
@@ -474,413 +922,1751 @@ where
     ///
     /// let earlier = datetime!(2022-01-01 12:10);
     /// let later = datetime!(2022-01-01 13:10);
-    /// assert_eq!(later.duration_since(&earlier), Duration::from_secs(3600));
+    /// assert_eq!(later.duration_since(&earlier), Ok(Duration::from_secs(3600)));
+    /// assert_eq!(earlier.duration_since(&later), Err(Duration::from_secs(3600)));
     /// ```
-    #[must_use]
-    pub fn duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Duration
+    pub fn duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Result<Duration, Duration>
     where
         OtherTz: TimeZone,
     {
-        self.checked_duration_since(earlier)
-            .expect("supplied datetime is later than self")
+        // The largest possible difference is around 2^41 seconds so this cannot overflow
+        let to_duration = |nanos: i128| Duration::new((nanos / 1_000_000_000) as u64, (nanos % 1_000_000_000) as u32);
+        let nanos = self.nanos_since(earlier);
+        if nanos >= 0 {
+            Ok(to_duration(nanos))
+        } else {
+            Err(to_duration(-nanos))
+        }
     }
 
-    /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
-    /// If `earlier` is later than `self` then [`Duration::ZERO`] is returned.
+    /// Returns the number of whole units of `unit_nanos` from this datetime to `other`.
+    fn whole_units_until<OtherTz>(&self, other: &DateTime<OtherTz>, unit_nanos: i128) -> i64
+    where
+        OtherTz: TimeZone,
+    {
+        // The largest possible difference is around 2^41 seconds so this always fits
+        (other.nanos_since(self) / unit_nanos) as i64
+    }
+
+    /// Returns the number of whole days from this datetime until `other`.
+    ///
+    /// The difference is the exact amount of time elapsed between the two instants
+    /// truncated toward zero, where a day is 24 hours. Since the datetimes are normalized
+    /// to UTC first, a day that is shorter or longer in local time due to a DST transition
+    /// is not counted as a whole day. Use [`Date::days_since`] to count calendar days instead.
+    ///
+    /// The result is negative if `other` is earlier than this datetime.
     ///
     /// # Examples
     ///
     /// ```
     /// # use eos::datetime;
-    /// # use std::time::Duration;
-    ///
-    /// let earlier = datetime!(2022-01-01 12:10);
-    /// let later = datetime!(2022-01-01 13:10);
-    /// assert_eq!(later.saturating_duration_since(&earlier), Duration::from_secs(3600));
-    /// assert_eq!(earlier.saturating_duration_since(&later), Duration::ZERO);
+    /// let start = datetime!(2022-01-01 12:00);
+    /// assert_eq!(start.days_until(&datetime!(2022-01-03 11:59)), 1);
+    /// assert_eq!(start.days_until(&datetime!(2022-01-03 12:00)), 2);
+    /// assert_eq!(start.days_until(&datetime!(2021-12-30 12:01)), -1);
+    /// assert_eq!(start.days_until(&datetime!(2022-01-03 04:00 -08:00)), 2);
     /// ```
     #[must_use]
-    pub fn saturating_duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Duration
+    pub fn days_until<OtherTz>(&self, other: &DateTime<OtherTz>) -> i64
     where
         OtherTz: TimeZone,
     {
-        self.checked_duration_since(earlier).unwrap_or_default()
+        self.whole_units_until(other, 86_400_000_000_000)
     }
 
-    /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
-    /// If `earlier` is later than `self` then [`None`] is returned.
+    /// Returns the number of whole hours from this datetime until `other`.
+    ///
+    /// The difference is the exact amount of time elapsed between the two instants
+    /// truncated toward zero. Since the datetimes are normalized to UTC first, DST
+    /// transitions do not skew the count. The result is negative if `other` is earlier
+    /// than this datetime.
     ///
     /// # Examples
     ///
     /// ```
     /// # use eos::datetime;
-    /// # use std::time::Duration;
-    ///
-    /// let earlier = datetime!(2022-01-01 12:10);
-    /// let later = datetime!(2022-01-01 13:10);
-    /// assert_eq!(later.checked_duration_since(&earlier), Some(Duration::from_secs(3600)));
-    /// assert_eq!(earlier.checked_duration_since(&later), None);
+    /// let start = datetime!(2022-01-01 12:00);
+    /// assert_eq!(start.hours_until(&datetime!(2022-01-01 14:59)), 2);
+    /// assert_eq!(start.hours_until(&datetime!(2022-01-01 09:30)), -2);
+    /// assert_eq!(start.hours_until(&datetime!(2022-01-01 12:00 +02:00)), -2);
     /// ```
     #[must_use]
-    pub fn checked_duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Option<Duration>
+    pub fn hours_until<OtherTz>(&self, other: &DateTime<OtherTz>) -> i64
     where
         OtherTz: TimeZone,
     {
-        let days = self.days_since_epoch() - earlier.days_since_epoch();
-        let mut total_seconds = days * 86400 + self.time.total_seconds() - earlier.time.total_seconds()
-            + earlier.offset.total_seconds()
-            - self.offset.total_seconds();
-        let (secs, nanos) = divmod!(self.nanosecond() as i64 - earlier.nanosecond() as i64, 1_000_000_000);
-        total_seconds += secs as i32;
-        if total_seconds < 0 {
-            None
-        } else {
-            Some(Duration::new(total_seconds as u64, nanos as u32))
-        }
+        self.whole_units_until(other, 3_600_000_000_000)
     }
 
-    /// Returns an iterator builder to create a recurrent range over date times.
-    ///
-    /// At its most basic form, it allows you to iterate as if repeatedly adding
-    /// the given interval. However, it can also be chained to create certain
-    /// conditions to iterate over.
-    ///
-    /// It's important to note that this does not actually implement the entire
-    /// [`rrule`] specification since it differs from this function when it comes
-    /// to missing or ambiguous times and is more complicated than this method.
+    /// Returns the number of whole minutes from this datetime until `other`.
     ///
-    /// [`rrule`]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+    /// This works like [`Self::hours_until`] but counts minutes instead.
     ///
     /// # Examples
     ///
-    /// Getting every 3 days starting from now:
-    ///
-    /// ```ignore
-    /// let now = Utc::now();
-    /// for dt in now.every(3.days()) {
-    ///     println!("{}", dt);
-    /// }
     /// ```
+    /// # use eos::datetime;
+    /// let start = datetime!(2022-01-01 12:00);
+    /// assert_eq!(start.minutes_until(&datetime!(2022-01-01 13:30:59)), 90);
+    /// assert_eq!(start.minutes_until(&datetime!(2022-01-01 11:58:30)), -1);
+    /// ```
+    #[must_use]
+    pub fn minutes_until<OtherTz>(&self, other: &DateTime<OtherTz>) -> i64
+    where
+        OtherTz: TimeZone,
+    {
+        self.whole_units_until(other, 60_000_000_000)
+    }
+
+    /// Returns the number of whole seconds from this datetime until `other`.
     ///
-    /// Getting the first 5 of the previous iteration:
+    /// This works like [`Self::hours_until`] but counts seconds instead.
     ///
-    /// ```ignore
-    /// let now = Utc::now();
-    /// for dt in now.every(3.days()).into_iter().take(5) {
-    ///     println!("{}", dt);
-    /// }
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let start = datetime!(2022-01-01 12:00);
+    /// assert_eq!(start.seconds_until(&datetime!(2022-01-01 12:01:05)), 65);
+    /// assert_eq!(start.seconds_until(&datetime!(2022-01-01 11:59:00)), -60);
     /// ```
+    #[must_use]
+    pub fn seconds_until<OtherTz>(&self, other: &DateTime<OtherTz>) -> i64
+    where
+        OtherTz: TimeZone,
+    {
+        self.whole_units_until(other, 1_000_000_000)
+    }
+
+    /// Returns the number of whole units from this datetime until `other`.
     ///
-    /// Getting every week on Sunday:
+    /// Units with a fixed length, from nanoseconds up to weeks, are counted from the exact
+    /// amount of time elapsed between the two instants like [`Self::days_until`]. Months,
+    /// quarters, and years are counted from the calendar difference given by
+    /// [`Interval::between`]. The count is truncated toward zero and is negative if `other`
+    /// is earlier than this datetime.
     ///
-    /// ```ignore
-    /// let now = Utc::now();
-    /// for dt in now.every(1.weeks()).on(Weekday::Sunday).into_iter().take(5) {
-    ///     println!("{}", dt);
-    /// }
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{datetime, unit::Unit};
+    /// let start = datetime!(2022-01-31 12:00);
+    /// assert_eq!(start.units_until(&datetime!(2022-02-01 11:00), Unit::Hour), 23);
+    /// assert_eq!(start.units_until(&datetime!(2022-02-14 12:00), Unit::Week), 2);
+    /// assert_eq!(start.units_until(&datetime!(2022-04-30 11:00), Unit::Month), 2);
+    /// assert_eq!(start.units_until(&datetime!(2021-01-31 12:00), Unit::Year), -1);
     /// ```
+    #[must_use]
+    pub fn units_until<OtherTz>(&self, other: &DateTime<OtherTz>, unit: crate::unit::Unit) -> i64
+    where
+        OtherTz: TimeZone,
+    {
+        match unit.duration_estimate() {
+            Some(duration) => self.whole_units_until(other, duration.as_nanos() as i128),
+            None => {
+                let interval = Interval::between(self, other);
+                let months = interval.years() as i64 * 12 + interval.months() as i64;
+                match unit {
+                    crate::unit::Unit::Quarter => months / 3,
+                    crate::unit::Unit::Year => months / 12,
+                    _ => months,
+                }
+            }
+        }
+    }
+
+    /// Returns the amount of time elapsed since this datetime until now.
     ///
-    /// Getting every 5 days at 3:30 AM:
+    /// If this datetime is in the future then [`Duration::ZERO`] is returned.
+    /// See [`Self::try_elapsed`] to detect that case instead.
+    ///
+    /// # Examples
     ///
-    /// ```ignore
-    /// let now = Utc::now();
-    /// for dt in now.every(5.days()).at(time!(03:30)).into_iter().take(10) {
-    ///     println!("{}", dt);
-    /// }
     /// ```
+    /// use eos::{DateTime, ext::IntervalLiteral};
+    /// # use std::time::Duration;
     ///
-    /// Getting every 3 days until a limit date is reached:
+    /// let past = DateTime::utc_now() - 1.hours();
+    /// assert!(past.elapsed() >= Duration::from_secs(3600));
     ///
-    /// ```ignore
-    /// let now = Utc::now();
-    /// let until = datetime!(2023-04-10);
-    /// for dt in now.every(3.days()).at(time!(03:30)).until(until) {
-    ///     println!("{}", dt);
-    /// }
+    /// let future = DateTime::utc_now() + 1.hours();
+    /// assert_eq!(future.elapsed(), Duration::ZERO);
     /// ```
-    pub fn every(self, interval: Interval) -> crate::iter::Every<Tz> {
-        crate::iter::Every::new(self, interval)
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        DateTime::utc_now().saturating_duration_since(self)
     }
 
-    #[inline]
-    pub(crate) fn into_utc(self) -> DateTime<Utc> {
-        let offset = self.offset; // Copy value before moving
-        let mut utc = DateTime {
-            date: self.date,
-            time: self.time,
-            offset: UtcOffset::UTC,
-            timezone: Utc,
-        };
-        utc.shift(-offset);
-        utc
+    /// Returns the amount of time elapsed since this datetime until now.
+    ///
+    /// # Errors
+    ///
+    /// If this datetime is in the future then the amount of time until it is
+    /// reached is returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{DateTime, ext::IntervalLiteral};
+    /// # use std::time::Duration;
+    ///
+    /// let past = DateTime::utc_now() - 1.hours();
+    /// assert!(past.try_elapsed().unwrap() >= Duration::from_secs(3600));
+    ///
+    /// let future = DateTime::utc_now() + 1.hours();
+    /// assert!(future.try_elapsed().unwrap_err() <= Duration::from_secs(3600));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_elapsed(&self) -> Result<Duration, Duration> {
+        DateTime::utc_now().duration_since(self)
     }
 
-    /// Returns a new [`DateTime`] with the newly specified [`TimeZone`],
-    /// adjusting the date and time components to point to the same internal UTC
-    /// time but in the given timezone's local time.
+    /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
+    /// If `earlier` is later than `self` then [`Duration::ZERO`] is returned.
     ///
-    /// If you merely want to change the internal timezone without making adjustments
-    /// for the date and time, then [`DateTime::with_timezone`] should be used instead.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn in_timezone<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// # use std::time::Duration;
+    ///
+    /// let earlier = datetime!(2022-01-01 12:10);
+    /// let later = datetime!(2022-01-01 13:10);
+    /// assert_eq!(later.saturating_duration_since(&earlier), Duration::from_secs(3600));
+    /// assert_eq!(earlier.saturating_duration_since(&later), Duration::ZERO);
+    /// ```
+    #[must_use]
+    pub fn saturating_duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Duration
     where
         OtherTz: TimeZone,
     {
-        timezone.convert_utc(self.into_utc())
+        self.checked_duration_since(earlier).unwrap_or_default()
     }
 
-    /// Returns a new [`DateTime`] with the timezone component changed.
-    /// This attempts to retain the local date and time as much as possible.
-    /// The local date time is only changed if the date time cannot represented
-    /// in that timezone, such as when there's a gap due to a transition.
+    /// Returns the amount of time elapsed from another datetime to this one as a [`Duration`].
+    /// If `earlier` is later than `self` then [`None`] is returned.
     ///
-    /// If you want to change the local date time and the timezone then
-    /// [`DateTime::in_timezone`] should be used instead.
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn with_timezone<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// # use std::time::Duration;
+    ///
+    /// let earlier = datetime!(2022-01-01 12:10);
+    /// let later = datetime!(2022-01-01 13:10);
+    /// assert_eq!(later.checked_duration_since(&earlier), Some(Duration::from_secs(3600)));
+    /// assert_eq!(earlier.checked_duration_since(&later), None);
+    /// ```
+    #[must_use]
+    pub fn checked_duration_since<OtherTz>(&self, earlier: &DateTime<OtherTz>) -> Option<Duration>
     where
         OtherTz: TimeZone,
     {
-        timezone.resolve(self.date, self.time).lenient()
+        self.duration_since(earlier).ok()
     }
 
-    /// Returns a new [`DateTime`] with the newly specified [`TimeZone`],
-    /// adjusting the date and time components to point to the same internal UTC
-    /// time but in the given timezone's local time.
+    /// Adds an [`Interval`] to the datetime, returning `None` if the result is out of range.
     ///
-    /// This is an alias to [`Self::in_timezone`].
+    /// This has the same semantics as the `+` operator when it comes to handling
+    /// missing and ambiguous times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time, ext::IntervalLiteral};
+    ///
+    /// let dt = datetime!(2022-01-31 23:00);
+    /// assert_eq!(dt.checked_add(1.months()), Some(datetime!(2022-02-28 23:00)));
+    /// assert_eq!(dt.checked_add(2.hours()), Some(datetime!(2022-02-01 01:00)));
+    ///
+    /// let max = Date::MAX.at(Time::MIDNIGHT);
+    /// assert_eq!(max.checked_add(1.days()), None);
+    /// ```
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn at<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
-    where
-        OtherTz: TimeZone,
-    {
-        timezone.convert_utc(self.into_utc())
+    pub fn checked_add(self, interval: Interval) -> Option<Self> {
+        let (sub, duration) = interval.get_time_duration();
+        let (days, time) = if sub {
+            self.time.checked_sub_with_duration(duration)?
+        } else {
+            self.time.checked_add_with_duration(duration)?
+        };
+
+        let date = self
+            .date
+            .checked_add_months(interval.total_months())?
+            .checked_add_days(interval.days().checked_add(days)?)?;
+        Some(self.with_wall_clock(date, time, sub))
     }
 
-    /// Returns the UNIX timestamp.
-    #[must_use]
-    pub fn timestamp(&self) -> Timestamp {
-        let seconds = self.days_since_epoch() as i64 * 86400
-            + self.hour() as i64 * 3600
-            + self.minute() as i64 * 60
-            + self.second() as i64
-            // This is deliberately subtracted
-            // e.g. UTC-5 means we need to add +5 to get back to UTC.
-            - self.offset.total_seconds() as i64;
+    /// Subtracts an [`Interval`] from the datetime, returning `None` if the result is out of range.
+    ///
+    /// This has the same semantics as the `-` operator when it comes to handling
+    /// missing and ambiguous times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time, ext::IntervalLiteral};
+    ///
+    /// let dt = datetime!(2022-03-31 01:00);
+    /// assert_eq!(dt.checked_sub(1.months()), Some(datetime!(2022-02-28 01:00)));
+    /// assert_eq!(dt.checked_sub(2.hours()), Some(datetime!(2022-03-30 23:00)));
+    ///
+    /// let min = Date::MIN.at(Time::MIDNIGHT);
+    /// assert_eq!(min.checked_sub(1.seconds()), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub(self, interval: Interval) -> Option<Self> {
+        let (sub, duration) = interval.get_time_duration();
+        let (days, time) = if sub {
+            self.time.checked_add_with_duration(duration)?
+        } else {
+            self.time.checked_sub_with_duration(duration)?
+        };
+
+        let date = self
+            .date
+            .checked_add_months(interval.total_months().checked_neg()?)?
+            .checked_add_days(interval.days().checked_neg()?.checked_add(days)?)?;
+        Some(self.with_wall_clock(date, time, !sub))
+    }
+
+    /// Adds a [`Duration`] to the datetime, returning `None` if the result is out of range.
+    ///
+    /// Like the `+` operator, this advances the underlying instant by the elapsed time
+    /// rather than the local wall clock time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time};
+    /// use core::time::Duration;
+    ///
+    /// let dt = datetime!(2022-01-31 23:00);
+    /// assert_eq!(dt.checked_add_duration(Duration::from_secs(7200)), Some(datetime!(2022-02-01 01:00)));
+    ///
+    /// let max = Date::MAX.at(Time::MAX);
+    /// assert_eq!(max.checked_add_duration(Duration::from_nanos(1)), None);
+    /// assert_eq!(max.checked_add_duration(Duration::MAX), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+        if !self.timezone.is_fixed() {
+            let timezone = self.timezone.clone();
+            return Some(self.into_utc().checked_add_duration(duration)?.in_timezone(timezone));
+        }
 
-        Timestamp::new(seconds, self.nanosecond())
+        let (days, time) = self.time.checked_add_with_duration(duration)?;
+        let date = self.date.checked_add_days(days)?;
+        Some(self.with_wall_clock(date, time, false))
     }
 
-    pub(crate) fn add_months(mut self, months: i32) -> Self {
-        self.date = self.date.add_months(months);
+    /// Subtracts a [`Duration`] from the datetime, returning `None` if the result is out of range.
+    ///
+    /// Like the `-` operator, this moves the underlying instant back by the elapsed time
+    /// rather than the local wall clock time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time};
+    /// use core::time::Duration;
+    ///
+    /// let dt = datetime!(2022-02-01 01:00);
+    /// assert_eq!(dt.checked_sub_duration(Duration::from_secs(7200)), Some(datetime!(2022-01-31 23:00)));
+    ///
+    /// let min = Date::MIN.at(Time::MIN);
+    /// assert_eq!(min.checked_sub_duration(Duration::from_nanos(1)), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub_duration(self, duration: Duration) -> Option<Self> {
+        if !self.timezone.is_fixed() {
+            let timezone = self.timezone.clone();
+            return Some(self.into_utc().checked_sub_duration(duration)?.in_timezone(timezone));
+        }
+
+        let (days, time) = self.time.checked_sub_with_duration(duration)?;
+        let date = self.date.checked_add_days(days)?;
+        Some(self.with_wall_clock(date, time, true))
+    }
+
+    /// Adds an [`Interval`] to the datetime, clamping to the minimum or maximum local
+    /// date and time if the result is out of range.
+    ///
+    /// The bounds are [`Date::MIN`] at [`Time::MIN`] and [`Date::MAX`] at [`Time::MAX`]
+    /// in this datetime's timezone. Otherwise, this has the same semantics as the `+` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time, ext::IntervalLiteral};
+    ///
+    /// let dt = datetime!(2022-01-31 23:00);
+    /// assert_eq!(dt.saturating_add(1.months()), datetime!(2022-02-28 23:00));
+    ///
+    /// let max = Date::MAX.at(Time::MAX);
+    /// assert_eq!(datetime!(32767-12-31 00:00).saturating_add(2.days()), max);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_add(self, interval: Interval) -> Self {
+        let (sub, duration) = interval.get_time_duration();
+        self.saturating_add_parts(interval.total_months() as i64, interval.days() as i64, sub, duration)
+    }
+
+    /// Subtracts an [`Interval`] from the datetime, clamping to the minimum or maximum local
+    /// date and time if the result is out of range.
+    ///
+    /// See [`Self::saturating_add`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, DateTime, Time, ext::IntervalLiteral};
+    ///
+    /// let dt = datetime!(2022-03-31 01:00);
+    /// assert_eq!(dt.saturating_sub(1.months()), datetime!(2022-02-28 01:00));
+    ///
+    /// let min = Date::MIN.at(Time::MIN);
+    /// assert_eq!(min.saturating_sub(90.days()), min);
+    /// assert_eq!(min.saturating_add(1.days()).saturating_sub(90.days()), min);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_sub(self, interval: Interval) -> Self {
+        let (sub, duration) = interval.get_time_duration();
+        self.saturating_add_parts(
+            -(interval.total_months() as i64),
+            -(interval.days() as i64),
+            !sub,
+            duration,
+        )
+    }
+
+    /// Returns a new [`DateTime`] moved by the given number of quarters.
+    ///
+    /// This is the same as adding three times as many months using [`DateTime::saturating_add`],
+    /// so the day is clamped to the last day of the resulting month if needed and the time
+    /// is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2022-01-31 09:30).add_quarters(1), datetime!(2022-04-30 09:30));
+    /// assert_eq!(datetime!(2022-01-31 09:30).add_quarters(-1), datetime!(2021-10-31 09:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn add_quarters(self, quarters: i32) -> Self {
+        self.saturating_add_parts(quarters as i64 * 3, 0, false, Duration::ZERO)
+    }
+
+    fn saturating_add_parts(self, months: i64, days: i64, sub: bool, duration: Duration) -> Self {
+        let time = if sub {
+            self.time.checked_sub_with_duration(duration)
+        } else {
+            self.time.checked_add_with_duration(duration)
+        };
+        let (extra, time) = match time {
+            Some(pair) => pair,
+            None => return self.saturated(!sub),
+        };
+
+        let date = match i32::try_from(months).ok().and_then(|m| self.date.checked_add_months(m)) {
+            Some(date) => date,
+            None => return self.saturated(months > 0),
+        };
+
+        let days = days + extra as i64;
+        match i32::try_from(days).ok().and_then(|d| date.checked_add_days(d)) {
+            Some(date) => self.with_wall_clock(date, time, sub),
+            None => self.saturated(days > 0),
+        }
+    }
+
+    fn saturated(self, max: bool) -> Self {
+        if max {
+            self.with_wall_clock(Date::MAX, Time::MAX, false)
+        } else {
+            self.with_wall_clock(Date::MIN, Time::MIN, true)
+        }
+    }
+
+    /// Replaces the local date and time after an arithmetic operation.
+    ///
+    /// Fixed timezones keep their offset, otherwise the timezone is asked to resolve the new
+    /// date and time. If `backwards` is true then the result was found by moving back in time.
+    fn with_wall_clock(self, date: Date, time: Time, backwards: bool) -> Self {
+        if self.timezone.is_fixed() {
+            DateTime {
+                date,
+                time,
+                offset: self.offset,
+                timezone: self.timezone,
+            }
+        } else if backwards {
+            self.timezone.resolve(date, time).backwards()
+        } else {
+            self.timezone.resolve(date, time).lenient()
+        }
+    }
+
+    /// Returns the same wall clock time on the next calendar day.
+    ///
+    /// This is equivalent to adding one day with [`Interval::from_days`], so the
+    /// timezone is preserved and the offset is recalculated for the new date.
+    /// If the next day would be past [`Date::MAX`] then the date is left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2024-02-28 09:30 -05:00).tomorrow(), datetime!(2024-02-29 09:30 -05:00));
+    /// assert_eq!(datetime!(2023-12-31 23:00).tomorrow(), datetime!(2024-01-01 23:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn tomorrow(self) -> Self {
+        let date = self.date.succ().unwrap_or(self.date);
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns the same wall clock time on the previous calendar day.
+    ///
+    /// This is equivalent to subtracting one day with [`Interval::from_days`], so the
+    /// timezone is preserved and the offset is recalculated for the new date.
+    /// If the previous day would be before [`Date::MIN`] then the date is left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2024-03-01 09:30 +09:00).yesterday(), datetime!(2024-02-29 09:30 +09:00));
+    /// assert_eq!(datetime!(2024-01-01 00:00).yesterday(), datetime!(2023-12-31 00:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn yesterday(self) -> Self {
+        let date = self.date.pred().unwrap_or(self.date);
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns the same wall clock time on the next calendar day, or [`None`] if
+    /// the date is already [`Date::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, Time};
+    ///
+    /// assert_eq!(datetime!(2023-02-28 12:00).checked_tomorrow(), Some(datetime!(2023-03-01 12:00)));
+    /// assert_eq!(Date::MAX.at(Time::MIDNIGHT).checked_tomorrow(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_tomorrow(self) -> Option<Self> {
+        let date = self.date.succ()?;
+        let time = self.time;
+        Some(self.with_wall_clock(date, time, false))
+    }
+
+    /// Returns the same wall clock time on the previous calendar day, or [`None`] if
+    /// the date is already [`Date::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Date, Time};
+    ///
+    /// assert_eq!(datetime!(2023-03-01 12:00).checked_yesterday(), Some(datetime!(2023-02-28 12:00)));
+    /// assert_eq!(Date::MIN.at(Time::MIDNIGHT).checked_yesterday(), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_yesterday(self) -> Option<Self> {
+        let date = self.date.pred()?;
+        let time = self.time;
+        Some(self.with_wall_clock(date, time, false))
+    }
+
+    /// Returns an iterator builder to create a recurrent range over date times.
+    ///
+    /// At its most basic form, it allows you to iterate as if repeatedly adding
+    /// the given interval. However, it can also be chained to create certain
+    /// conditions to iterate over.
+    ///
+    /// It's important to note that this does not actually implement the entire
+    /// [`rrule`] specification since it differs from this function when it comes
+    /// to missing or ambiguous times and is more complicated than this method.
+    ///
+    /// [`rrule`]: https://datatracker.ietf.org/doc/html/rfc5545#section-3.3.10
+    ///
+    /// # Examples
+    ///
+    /// Getting every 3 days starting from now:
+    ///
+    /// ```ignore
+    /// let now = Utc::now();
+    /// for dt in now.every(3.days()) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
+    ///
+    /// Getting the first 5 of the previous iteration:
+    ///
+    /// ```ignore
+    /// let now = Utc::now();
+    /// for dt in now.every(3.days()).into_iter().take(5) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
+    ///
+    /// Getting every week on Sunday:
+    ///
+    /// ```ignore
+    /// let now = Utc::now();
+    /// for dt in now.every(1.weeks()).on(Weekday::Sunday).into_iter().take(5) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
+    ///
+    /// Getting every 5 days at 3:30 AM:
+    ///
+    /// ```ignore
+    /// let now = Utc::now();
+    /// for dt in now.every(5.days()).at(time!(03:30)).into_iter().take(10) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
+    ///
+    /// Getting every 3 days until a limit date is reached:
+    ///
+    /// ```ignore
+    /// let now = Utc::now();
+    /// let until = datetime!(2023-04-10);
+    /// for dt in now.every(3.days()).at(time!(03:30)).until(until) {
+    ///     println!("{}", dt);
+    /// }
+    /// ```
+    pub fn every(self, interval: Interval) -> crate::iter::Every<Tz> {
+        crate::iter::Every::new(self, interval)
+    }
+
+    /// Returns an iterator over the datetimes from this datetime up to, but not including, `end`.
+    ///
+    /// By default the iterator steps one day at a time. This can be changed using
+    /// [`DateTimeRange::step`] and the end can be included using [`DateTimeRange::inclusive`].
+    /// The iterator can also be iterated in reverse.
+    ///
+    /// Unlike [`Self::every`], every datetime is computed from the start rather than the
+    /// previous datetime, so month steps clamp to the end of the month without drifting.
+    ///
+    /// [`DateTimeRange::step`]: crate::iter::DateTimeRange::step
+    /// [`DateTimeRange::inclusive`]: crate::iter::DateTimeRange::inclusive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, ext::IntervalLiteral};
+    ///
+    /// let start = datetime!(2022-01-01 00:00);
+    /// let buckets: Vec<_> = start.iter_to(datetime!(2022-01-01 01:00)).step(15.minutes()).collect();
+    /// assert_eq!(buckets.len(), 4);
+    /// assert_eq!(buckets[3], datetime!(2022-01-01 00:45));
+    ///
+    /// let mut range = start.iter_to(datetime!(2022-01-01 01:00)).step(15.minutes()).inclusive();
+    /// assert_eq!(range.len(), 5);
+    /// assert_eq!(range.next_back(), Some(datetime!(2022-01-01 01:00)));
+    ///
+    /// let months: Vec<_> = datetime!(2022-01-31 12:00)
+    ///     .iter_to(datetime!(2022-04-01 00:00))
+    ///     .step(1.months())
+    ///     .collect();
+    /// assert_eq!(months, [datetime!(2022-01-31 12:00), datetime!(2022-02-28 12:00), datetime!(2022-03-31 12:00)]);
+    /// ```
+    pub fn iter_to(self, end: DateTime<Tz>) -> crate::iter::DateTimeRange<Tz> {
+        crate::iter::DateTimeRange::new(self, end)
+    }
+
+    #[inline]
+    pub(crate) fn into_utc(self) -> DateTime<Utc> {
+        let offset = self.offset; // Copy value before moving
+        let mut utc = DateTime {
+            date: self.date,
+            time: self.time,
+            offset: UtcOffset::UTC,
+            timezone: Utc,
+        };
+        utc.shift(-offset);
+        utc
+    }
+
+    /// Returns a new [`DateTime`] with the newly specified [`TimeZone`],
+    /// adjusting the date and time components to point to the same internal UTC
+    /// time but in the given timezone's local time.
+    ///
+    /// If you merely want to change the internal timezone without making adjustments
+    /// for the date and time, then [`DateTime::with_timezone`] should be used instead.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn in_timezone<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        timezone.convert_utc(self.into_utc())
+    }
+
+    /// Returns a new [`DateTime`] with the timezone component changed.
+    /// This attempts to retain the local date and time as much as possible.
+    /// The local date time is only changed if the date time cannot represented
+    /// in that timezone, such as when there's a gap due to a transition.
+    ///
+    /// If you want to change the local date time and the timezone then
+    /// [`DateTime::in_timezone`] should be used instead.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_timezone<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        timezone.resolve(self.date, self.time).lenient()
+    }
+
+    /// Returns a new [`DateTime`] with the newly specified [`TimeZone`],
+    /// adjusting the date and time components to point to the same internal UTC
+    /// time but in the given timezone's local time.
+    ///
+    /// This is an alias to [`Self::in_timezone`].
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn at<OtherTz>(self, timezone: OtherTz) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        timezone.convert_utc(self.into_utc())
+    }
+
+    /// Returns a new [`DateTime`] pointing to the same instant but in the timezone of
+    /// another [`DateTime`].
+    ///
+    /// This is equivalent to `self.in_timezone(other.timezone().clone())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, utc_offset};
+    ///
+    /// let tokyo = datetime!(2022-03-01 09:00 +09:00);
+    /// let utc = datetime!(2022-03-01 12:00);
+    /// let converted = utc.in_timezone_of(&tokyo);
+    /// assert_eq!(converted, datetime!(2022-03-01 21:00 +09:00));
+    /// assert_eq!(converted.timezone(), &utc_offset!(+09:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn in_timezone_of<OtherTz>(self, other: &DateTime<OtherTz>) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        self.in_timezone(other.timezone.clone())
+    }
+
+    /// Returns a new [`DateTime`] with the timezone component copied from another [`DateTime`].
+    ///
+    /// Like [`DateTime::with_timezone`], this attempts to retain the local date and time.
+    /// This is equivalent to `self.with_timezone(other.timezone().clone())`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::datetime;
+    ///
+    /// let tokyo = datetime!(2022-03-01 09:00 +09:00);
+    /// let utc = datetime!(2022-03-01 12:00);
+    /// assert_eq!(utc.with_timezone_of(&tokyo), datetime!(2022-03-01 12:00 +09:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_timezone_of<OtherTz>(self, other: &DateTime<OtherTz>) -> DateTime<OtherTz>
+    where
+        OtherTz: TimeZone,
+    {
+        self.with_timezone(other.timezone.clone())
+    }
+
+    /// Returns the next time this datetime's timezone changes its UTC offset, such as when
+    /// DST starts or ends.
+    ///
+    /// This returns the instant of the transition in this timezone along with the UTC
+    /// offsets before and after it. If the timezone has no transition data, such as fixed
+    /// offset timezones, then `None` is returned. See [`TimeZone::next_transition`].
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, NamedOffset};
+    ///
+    /// // Fixed offsets never transition
+    /// let dt = datetime!(2022-01-01 00:00).in_timezone(NamedOffset::new(utc_offset!(+01:00), "CET"));
+    /// assert_eq!(dt.next_dst_transition(), None);
+    /// ```
+    #[must_use]
+    pub fn next_dst_transition(&self) -> Option<(DateTime<Tz>, UtcOffset, UtcOffset)> {
+        let (at, before, after) = self.timezone.next_transition(&self.clone().into_utc())?;
+        Some((at.in_timezone(self.timezone.clone()), before, after))
+    }
+
+    /// Returns the UNIX timestamp.
+    ///
+    /// This is exact for every representable datetime. The number of seconds is
+    /// at most around 10<sup>12</sup> in either direction, well within an `i64`.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        // The fraction is removed so the whole seconds aren't truncated toward zero
+        let fraction = Interval::from_microseconds(self.microsecond() as i64);
+        let elapsed = Interval::days_between(&DateTime::UNIX_EPOCH, self) - fraction;
+        Timestamp::new(elapsed.as_seconds(), self.nanosecond())
+    }
+
+    /// Returns the number of milliseconds since the UNIX epoch.
+    ///
+    /// The sub-millisecond part is truncated towards the past, so that the result
+    /// round trips with [`DateTime::from_timestamp_millis`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let dt = datetime!(2022-01-03 1:38:45).with_nanosecond(123_456_789).unwrap();
+    /// assert_eq!(dt.timestamp_millis(), 1641173925123);
+    ///
+    /// let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(999_500_000).unwrap();
+    /// assert_eq!(dt.timestamp_millis(), -1);
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> i64 {
+        let elapsed = Interval::days_between(&DateTime::UNIX_EPOCH, self);
+        elapsed.as_nanoseconds().div_euclid(1_000_000) as i64
+    }
+
+    /// Returns the number of microseconds since the UNIX epoch.
+    ///
+    /// The sub-microsecond part is truncated towards the past, so that the result
+    /// round trips with [`DateTime::from_timestamp_micros`]. This can't overflow
+    /// since every [`DateTime`] is within around 10<sup>18</sup> microseconds of the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let dt = datetime!(2022-01-03 1:38:45).with_nanosecond(123_456_789).unwrap();
+    /// assert_eq!(dt.timestamp_micros(), 1641173925123456);
+    ///
+    /// let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(999_999_500).unwrap();
+    /// assert_eq!(dt.timestamp_micros(), -1);
+    /// ```
+    #[must_use]
+    pub fn timestamp_micros(&self) -> i64 {
+        let ts = self.timestamp();
+        ts.as_seconds() * 1_000_000 + (ts.nanoseconds / 1_000) as i64
+    }
+
+    /// Returns the number of nanoseconds since the UNIX epoch.
+    ///
+    /// This is an `i128` since an `i64` of nanoseconds overflows in the year 2262.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// let dt = datetime!(2022-01-03 1:38:45).with_nanosecond(123_456_789).unwrap();
+    /// assert_eq!(dt.timestamp_nanos(), 1641173925123456789);
+    ///
+    /// let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(999_999_999).unwrap();
+    /// assert_eq!(dt.timestamp_nanos(), -1);
+    /// ```
+    #[must_use]
+    pub fn timestamp_nanos(&self) -> i128 {
+        let ts = self.timestamp();
+        ts.as_seconds() as i128 * 1_000_000_000 + ts.nanoseconds as i128
+    }
+
+    /// Returns the Windows `FILETIME` value for this datetime.
+    ///
+    /// See [`Timestamp::to_filetime`] for more information.
+    pub fn to_filetime(&self) -> Result<u64, Error> {
+        self.timestamp().to_filetime()
+    }
+
+    /// Returns the Julian date of this datetime.
+    ///
+    /// The Julian date is the number of fractional days since noon UTC on November 24th,
+    /// -4713 in the proleptic Gregorian calendar. It is computed from the UTC instant,
+    /// so the offset of this datetime does not matter. Since this is a floating point
+    /// number, the precision is around 20 microseconds for contemporary dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2000-01-01 12:00).julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 07:00 -05:00).julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(1970-01-01 00:00).julian_date(), 2_440_587.5);
+    /// ```
+    #[must_use]
+    pub fn julian_date(&self) -> f64 {
+        let (days, fraction) = self.utc_day_fraction();
+        (days + UNIX_EPOCH_JULIAN_DAY as i64) as f64 + (fraction - 0.5)
+    }
+
+    /// Returns the Modified Julian Date of this datetime.
+    ///
+    /// The Modified Julian Date is the number of fractional days since midnight UTC
+    /// on November 17th, 1858. This is equal to the [Julian date](Self::julian_date)
+    /// minus 2,400,000.5, but is computed directly to retain more precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(1858-11-17 00:00).modified_julian_date(), 0.0);
+    /// assert_eq!(datetime!(2000-01-01 18:00).modified_julian_date(), 51_544.75);
+    /// assert_eq!(datetime!(1970-01-01 00:00 +01:00).modified_julian_date(), 40_586.958333333336);
+    /// ```
+    #[must_use]
+    pub fn modified_julian_date(&self) -> f64 {
+        let (days, fraction) = self.utc_day_fraction();
+        (days + (UNIX_EPOCH_JULIAN_DAY - MODIFIED_JULIAN_DAY_OFFSET) as i64) as f64 + fraction
+    }
+
+    /// Returns the number of days since the UNIX epoch and the fraction of the
+    /// day that has elapsed, both in UTC.
+    fn utc_day_fraction(&self) -> (i64, f64) {
+        let ts = self.timestamp();
+        let (days, seconds) = divmod!(ts.as_seconds(), 86400);
+        let fraction = (seconds as f64 + ts.nanoseconds as f64 / 1e9) / 86400.0;
+        (days, fraction)
+    }
+
+    pub(crate) fn add_months(mut self, months: i32) -> Self {
+        self.date = self.date.add_months(months);
+        self
+    }
+
+    // The "common" functions begin here.
+    // I want to "unroll" the trait and make them inherent methods since their discoverability
+    // is better in the documentation, and the trait usability is mostly subpar.
+    // This is done both in Time and Date.
+
+    /// Returns the year.
+    ///
+    /// Note that year 0 is equivalent to 1 BC (or BCE) and year 1 is equivalent
+    /// to 1 AD (or CE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let date = datetime!(2012-01-15 00:00);
+    /// assert_eq!(date.year(), 2012);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn year(&self) -> i16 {
+        self.date.year()
+    }
+
+    /// Returns the month.
+    ///
+    /// This value will always be within `1..=12`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let date = datetime!(2012-01-15 00:00);
+    /// assert_eq!(date.month(), 1);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.date.month()
+    }
+
+    /// Returns the month as a [`Month`](crate::extra::Month).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(datetime!(2012-01-15 00:00).month_enum(), Month::January);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn month_enum(&self) -> crate::extra::Month {
+        self.date.month_enum()
+    }
+
+    /// Returns the quarter of the year.
+    ///
+    /// This value will always be within `1..=4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// assert_eq!(datetime!(2012-01-15 00:00).quarter(), 1);
+    /// assert_eq!(datetime!(2012-11-15 00:00).quarter(), 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn quarter(&self) -> u8 {
+        self.date.quarter()
+    }
+
+    /// Returns the day.
+    ///
+    /// This value will always be within `1..=31`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::datetime;
+    /// let date = datetime!(2012-01-15 00:00);
+    /// assert_eq!(date.day(), 15);
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.date.day()
+    }
+
+    /// Returns the ISO ordinal date.
+    ///
+    /// January 1st is 1 and December 31st is either 365 or 366 depending on leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{DateTime, Utc};
+    /// # fn test() -> Option<()> {
+    /// let date = DateTime::<Utc>::new(2013, 3, 17)?;
+    /// let leap = DateTime::<Utc>::new(2012, 3, 17)?;
+    ///
+    /// assert_eq!(date.ordinal(), 76);
+    /// assert_eq!(leap.ordinal(), 77); // 2012 was a leap year
+    /// # Some(())
+    /// # }
+    /// # test();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ordinal(&self) -> u16 {
+        self.date.ordinal()
+    }
+
+    /// Returns the number of days since the UNIX Epoch (1970-01-01).
+    #[must_use]
+    pub fn days_since_epoch(&self) -> i32 {
+        self.date().days_since_epoch()
+    }
+
+    /// Returns the weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::{DateTime, Utc};
+    /// # use eos::Weekday;
+    /// # fn test() -> Option<()> {
+    /// assert_eq!(DateTime::<Utc>::new(2021, 12, 25)?.weekday(), Weekday::Saturday);
+    /// assert_eq!(DateTime::<Utc>::new(2012, 2, 29)?.weekday(), Weekday::Wednesday);
+    /// # Some(())
+    /// # }
+    /// # test();
+    /// ```
+    #[must_use]
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Returns a [`DateTime`] moved to the next step of the given value.
+    ///
+    /// # Examples
+    ///
+    /// Getting the next weekday:
+    ///
+    /// ```rust
+    /// use eos::{datetime, Weekday};
+    ///
+    /// // March 17th 2021 was a Wednesday
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Monday), datetime!(2021-3-22 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Tuesday), datetime!(2021-3-23 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Wednesday), datetime!(2021-3-24 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Thursday), datetime!(2021-3-18 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Friday), datetime!(2021-3-19 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Saturday), datetime!(2021-3-20 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Sunday), datetime!(2021-3-21 00:00));
+    /// ```
+    ///
+    /// Getting the next time:
+    ///
+    /// ```rust
+    /// use eos::{datetime, time};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(time!(03:00)), datetime!(2021-3-17 03:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(time!(02:00)), datetime!(2021-3-18 02:00));
+    /// ```
+    ///
+    /// Getting the next unit:
+    ///
+    /// ```rust
+    /// use eos::{datetime, unit};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Year), datetime!(2022-3-17 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Month), datetime!(2021-4-17 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Week), datetime!(2021-3-24 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Day), datetime!(2021-3-18 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Hour), datetime!(2021-3-17 03:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Minute), datetime!(2021-3-17 02:01));
+    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Second), datetime!(2021-3-17 02:00:01));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn next<A>(self, advance: A) -> Self
+    where
+        A: Advance<Self>,
+    {
+        advance.next_from(self)
+    }
+
+    /// Returns a [`DateTime`] moved to the previous step of the given value.
+    ///
+    /// # Examples
+    ///
+    /// Getting the previous weekday:
+    ///
+    /// ```rust
+    /// use eos::{datetime, Weekday};
+    ///
+    /// // March 17th 2021 was a Wednesday
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Monday), datetime!(2021-3-15 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Tuesday), datetime!(2021-3-16 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Wednesday), datetime!(2021-3-10 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Thursday), datetime!(2021-3-11 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Friday), datetime!(2021-3-12 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Saturday), datetime!(2021-3-13 00:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Sunday), datetime!(2021-3-14 00:00));
+    /// ```
+    ///
+    /// Getting the previous time:
+    ///
+    /// ```rust
+    /// use eos::{datetime, time};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 04:00).prev(time!(03:00)), datetime!(2021-3-17 03:00));
+    /// assert_eq!(datetime!(2021-3-17 03:00).prev(time!(03:00)), datetime!(2021-3-16 03:00));
+    /// ```
+    ///
+    /// Getting the previous unit:
+    ///
+    /// ```rust
+    /// use eos::{datetime, unit};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Year), datetime!(2020-3-17 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Month), datetime!(2021-2-17 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Week), datetime!(2021-3-10 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Day), datetime!(2021-3-16 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Hour), datetime!(2021-3-17 01:00));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Minute), datetime!(2021-3-17 01:59));
+    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Second), datetime!(2021-3-17 01:59:59));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn prev<A>(self, advance: A) -> Self
+    where
+        A: Advance<Self>,
+    {
+        advance.prev_from(self)
+    }
+
+    /// Returns the first [`DateTime`] strictly after this one where the local time is the given time.
+    ///
+    /// Unlike [`DateTime::next`], this compares actual instants rather than the local time,
+    /// so the result is always later than `self` even across UTC offset changes. Local times
+    /// that do not exist or are ambiguous are resolved using
+    /// [`DateTimeResolution::lenient`](crate::DateTimeResolution::lenient). This means that
+    /// a time that happens twice in a day only occurs once, at the earlier of the two.
+    ///
+    /// If there's no such date time before [`Date::MAX`] then the last candidate is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, time};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 04:00).next_occurrence(time!(09:30)), datetime!(2021-3-17 09:30));
+    /// assert_eq!(datetime!(2021-3-17 09:30).next_occurrence(time!(09:30)), datetime!(2021-3-18 09:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn next_occurrence(self, time: Time) -> Self {
+        // Start a day early in case a transition shifts the time into the next day
+        let mut date = self.date.checked_add_days(-1).unwrap_or(self.date);
+        loop {
+            let candidate = with_local(self.clone(), (date, time));
+            if candidate > self {
+                return candidate;
+            }
+            match date.checked_add_days(1) {
+                Some(next) => date = next,
+                None => return candidate,
+            }
+        }
+    }
+
+    /// Returns the last [`DateTime`] strictly before this one where the local time is the given time.
+    ///
+    /// This is the counterpart to [`DateTime::next_occurrence`] and resolves local times the
+    /// same way. If there's no such date time after [`Date::MIN`] then the last candidate is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, time};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 04:00).prev_occurrence(time!(09:30)), datetime!(2021-3-16 09:30));
+    /// assert_eq!(datetime!(2021-3-17 09:31).prev_occurrence(time!(09:30)), datetime!(2021-3-17 09:30));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn prev_occurrence(self, time: Time) -> Self {
+        let mut date = self.date.checked_add_days(1).unwrap_or(self.date);
+        loop {
+            let candidate = with_local(self.clone(), (date, time));
+            if candidate < self {
+                return candidate;
+            }
+            match date.checked_add_days(-1) {
+                Some(prev) => date = prev,
+                None => return candidate,
+            }
+        }
+    }
+
+    /// Returns a [`DateTime`] truncated to the start of the given unit.
+    ///
+    /// Truncating to a [`unit::Day`] returns midnight of the same local date and truncating
+    /// to a [`unit::Week`] returns midnight of the previous Monday, or the same day if it's
+    /// already a Monday.
+    ///
+    /// If the resulting local time does not exist or is ambiguous in this timezone, then it's
+    /// resolved using [`DateTimeResolution::lenient`](crate::DateTimeResolution::lenient).
+    /// The exception is a datetime in the second occurrence of a repeated hour, which keeps
+    /// its offset if the result is ambiguous, so it never moves back to the first occurrence.
+    ///
+    /// [`unit::Day`]: crate::unit::Day
+    /// [`unit::Week`]: crate::unit::Week
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, unit};
+    ///
+    /// // March 17th 2021 was a Wednesday
+    /// assert_eq!(datetime!(2021-3-17 02:30).floor(unit::Year), datetime!(2021-1-1 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).floor(unit::Month), datetime!(2021-3-1 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).floor(unit::Week), datetime!(2021-3-15 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).floor(unit::Day), datetime!(2021-3-17 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).floor(unit::Hour), datetime!(2021-3-17 02:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn floor<R>(self, unit: R) -> Self
+    where
+        R: Round<Self>,
+    {
+        unit.floor_from(self)
+    }
+
+    /// Returns a [`DateTime`] moved to the start of the next given unit.
+    ///
+    /// If the datetime is already at the start of the unit then it's returned unchanged.
+    /// Local times that do not exist or are ambiguous are resolved like [`DateTime::floor`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, unit};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:30).ceil(unit::Month), datetime!(2021-4-1 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).ceil(unit::Week), datetime!(2021-3-22 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).ceil(unit::Hour), datetime!(2021-3-17 03:00));
+    /// assert_eq!(datetime!(2021-3-17 00:00).ceil(unit::Day), datetime!(2021-3-17 00:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn ceil<R>(self, unit: R) -> Self
+    where
+        R: Round<Self>,
+    {
+        unit.ceil_from(self)
+    }
+
+    /// Returns a [`DateTime`] moved to the nearest start of the given unit.
+    ///
+    /// The distance is measured in local wall clock time. A datetime exactly halfway
+    /// between two units is rounded up. Local times that do not exist or are ambiguous are
+    /// resolved like [`DateTime::floor`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, unit};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:29:59).round(unit::Hour), datetime!(2021-3-17 02:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).round(unit::Hour), datetime!(2021-3-17 03:00));
+    /// assert_eq!(datetime!(2021-3-17 12:00).round(unit::Day), datetime!(2021-3-18 00:00));
+    /// assert_eq!(datetime!(2021-3-17 02:30).round(unit::Month), datetime!(2021-4-1 00:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn round<R>(self, unit: R) -> Self
+    where
+        R: Round<Self>,
+    {
+        unit.round_from(self)
+    }
+
+    /// Returns a [`DateTime`] with everything below the given precision set to zero.
+    ///
+    /// This never rounds up and keeps the same UTC offset, see [`Time::truncate_subsec`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, SubsecondPrecision};
+    ///
+    /// let dt = datetime!(2021-3-17 09:44:59).with_nanosecond(999_999_999).unwrap();
+    /// assert_eq!(dt.truncate_subsec(SubsecondPrecision::Microsecond).nanosecond(), 999_999_000);
+    /// assert_eq!(dt.truncate_subsec(SubsecondPrecision::Second), datetime!(2021-3-17 09:44:59));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn truncate_subsec(mut self, precision: SubsecondPrecision) -> Self {
+        self.time = self.time.truncate_subsec(precision);
         self
     }
 
-    // The "common" functions begin here.
-    // I want to "unroll" the trait and make them inherent methods since their discoverability
-    // is better in the documentation, and the trait usability is mostly subpar.
-    // This is done both in Time and Date.
-
-    /// Returns the year.
+    /// Returns a [`DateTime`] rounded to the given precision, with ties rounding up.
     ///
-    /// Note that year 0 is equivalent to 1 BC (or BCE) and year 1 is equivalent
-    /// to 1 AD (or CE).
+    /// Rounding up can carry into the next second and from there into the next day,
+    /// see [`Time::with_precision_rounded`]. If the carried over local time does not
+    /// exist or is ambiguous in this timezone then it's resolved like [`DateTime::floor`].
+    /// Carrying past [`Date::MAX`] saturates to [`Time::MAX`] on that date instead.
     ///
     /// # Examples
     ///
+    /// ```rust
+    /// use eos::{datetime, SubsecondPrecision};
+    ///
+    /// let dt = datetime!(2021-12-31 23:59:59).with_nanosecond(999_600_000).unwrap();
+    /// assert_eq!(dt.with_precision_rounded(SubsecondPrecision::Millisecond), datetime!(2022-01-01 00:00));
+    /// assert_eq!(dt.with_precision_rounded(SubsecondPrecision::Nanosecond), dt);
     /// ```
-    /// # use eos::datetime;
-    /// let date = datetime!(2012-01-15 00:00);
-    /// assert_eq!(date.year(), 2012);
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_precision_rounded(mut self, precision: SubsecondPrecision) -> Self {
+        let (days, time) = self.time.with_precision_rounded(precision);
+        if days == 0
+            && time.truncate_subsec(SubsecondPrecision::Second) == self.time.truncate_subsec(SubsecondPrecision::Second)
+        {
+            // Only the sub-second part changed so the offset is still the same
+            self.time = time;
+            self
+        } else if days > 0 && self.date == Date::MAX {
+            // There is no next day to carry into
+            self.time = Time::MAX;
+            self
+        } else {
+            let date = self.date.add_days(days);
+            round_local(self, (date, time))
+        }
+    }
+
+    /// Returns a [`DateTime`] truncated to a multiple of the given duration.
+    ///
+    /// If the duration evenly divides a day, such as 15 minutes, then the multiples
+    /// are counted from midnight of the local date. Otherwise they're counted from
+    /// the Unix epoch in local time. Local times that do not exist or are ambiguous are
+    /// resolved like [`DateTime::floor`].
+    ///
+    /// Returns [`Error::OutOfRange`] if the duration is zero or longer than a day,
+    /// or if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::datetime;
+    /// use core::time::Duration;
+    ///
+    /// let quarter = Duration::from_secs(15 * 60);
+    /// assert_eq!(datetime!(2021-3-17 09:44:59).floor_to(quarter)?, datetime!(2021-3-17 09:30));
+    /// assert!(datetime!(2021-3-17 09:44:59).floor_to(Duration::ZERO).is_err());
     /// # Ok::<_, eos::Error>(())
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn year(&self) -> i16 {
-        self.date.year()
+    pub fn floor_to(self, duration: Duration) -> Result<Self, Error> {
+        round_to_duration(self, duration, Rounding::Floor)
     }
 
-    /// Returns the month.
+    /// Returns a [`DateTime`] moved forward to a multiple of the given duration.
     ///
-    /// This value will always be within `1..=12`.
+    /// If the datetime is already on a multiple then it's returned unchanged. See
+    /// [`DateTime::floor_to`] for how the multiples are counted and the errors returned.
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use eos::datetime;
-    /// let date = datetime!(2012-01-15 00:00);
-    /// assert_eq!(date.month(), 1);
+    /// ```rust
+    /// use eos::datetime;
+    /// use core::time::Duration;
+    ///
+    /// let quarter = Duration::from_secs(15 * 60);
+    /// assert_eq!(datetime!(2021-3-17 09:30:01).ceil_to(quarter)?, datetime!(2021-3-17 09:45));
+    /// assert_eq!(datetime!(2021-3-17 09:30).ceil_to(quarter)?, datetime!(2021-3-17 09:30));
     /// # Ok::<_, eos::Error>(())
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn month(&self) -> u8 {
-        self.date.month()
+    pub fn ceil_to(self, duration: Duration) -> Result<Self, Error> {
+        round_to_duration(self, duration, Rounding::Ceil)
     }
 
-    /// Returns the day.
+    /// Returns a [`DateTime`] moved to the nearest multiple of the given duration.
     ///
-    /// This value will always be within `1..=31`.
+    /// A datetime exactly halfway between two multiples is rounded up. See
+    /// [`DateTime::floor_to`] for how the multiples are counted and the errors returned.
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use eos::datetime;
-    /// let date = datetime!(2012-01-15 00:00);
-    /// assert_eq!(date.day(), 15);
+    /// ```rust
+    /// use eos::datetime;
+    /// use core::time::Duration;
+    ///
+    /// let quarter = Duration::from_secs(15 * 60);
+    /// assert_eq!(datetime!(2021-3-17 09:37:29).round_to(quarter)?, datetime!(2021-3-17 09:30));
+    /// assert_eq!(datetime!(2021-3-17 09:37:30).round_to(quarter)?, datetime!(2021-3-17 09:45));
+    /// assert_eq!(datetime!(2021-3-17 23:52:30).round_to(quarter)?, datetime!(2021-3-18 00:00));
     /// # Ok::<_, eos::Error>(())
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn day(&self) -> u8 {
-        self.date.day()
+    pub fn round_to(self, duration: Duration) -> Result<Self, Error> {
+        round_to_duration(self, duration, Rounding::Nearest)
     }
 
-    /// Returns the ISO ordinal date.
+    fn start_on(self, date: Date) -> Self {
+        with_local(self, (date, Time::MIDNIGHT))
+    }
+
+    fn end_on(self, date: Date) -> Self {
+        // The end of a day is right before the start of the next one, which accounts
+        // for days that end early or late due to a transition.
+        match date.checked_add_days(1) {
+            Some(next) if !self.timezone.is_fixed() => self.start_on(next) - Duration::from_nanos(1),
+            _ => with_local(self, (date, Time::MAX)),
+        }
+    }
+
+    /// Returns a [`DateTime`] at the start of the current day.
     ///
-    /// January 1st is 1 and December 31st is either 365 or 366 depending on leap year.
+    /// If midnight does not exist in the timezone, such as when a transition skips
+    /// from 00:00 to 01:00, then the first valid time of the day is returned.
     ///
     /// # Examples
     ///
+    /// ```rust
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:30).start_of_day(), datetime!(2021-3-17 00:00));
     /// ```
-    /// # use eos::{DateTime, Utc};
-    /// # fn test() -> Option<()> {
-    /// let date = DateTime::<Utc>::new(2013, 3, 17)?;
-    /// let leap = DateTime::<Utc>::new(2012, 3, 17)?;
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn start_of_day(self) -> Self {
+        let date = self.date;
+        self.start_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the last nanosecond of the current day.
     ///
-    /// assert_eq!(date.ordinal(), 76);
-    /// assert_eq!(leap.ordinal(), 77); // 2012 was a leap year
-    /// # Some(())
-    /// # }
-    /// # test();
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, Time};
+    ///
+    /// let dt = datetime!(2021-3-17 02:30).end_of_day();
+    /// assert_eq!(dt, datetime!(2021-3-17 00:00).with_time(Time::MAX));
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn ordinal(&self) -> u16 {
-        self.date.ordinal()
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_day(self) -> Self {
+        let date = self.date;
+        self.end_on(date)
     }
 
-    /// Returns the number of days since the UNIX Epoch (1970-01-01).
-    #[must_use]
-    pub fn days_since_epoch(&self) -> i32 {
-        self.date().days_since_epoch()
+    /// Returns a [`DateTime`] at the start of the current week.
+    ///
+    /// The week is considered to start on the given weekday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{datetime, Weekday};
+    ///
+    /// // March 17th 2021 was a Wednesday
+    /// let dt = datetime!(2021-3-17 02:30);
+    /// assert_eq!(dt.start_of_week(Weekday::Monday), datetime!(2021-3-15 00:00));
+    /// assert_eq!(dt.start_of_week(Weekday::Sunday), datetime!(2021-3-14 00:00));
+    /// assert_eq!(dt.start_of_week(Weekday::Wednesday), datetime!(2021-3-17 00:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn start_of_week(self, first: Weekday) -> Self {
+        let behind = weekday_difference(self.weekday().days_from_sunday(), first.days_from_sunday());
+        let date = self.date.checked_add_days(-(behind as i32)).unwrap_or(Date::MIN);
+        self.start_on(date)
     }
 
-    /// Returns the weekday.
+    /// Returns a [`DateTime`] at the last nanosecond of the current week.
+    ///
+    /// The week is considered to start on the given weekday.
     ///
     /// # Examples
     ///
+    /// ```rust
+    /// use eos::{datetime, Time, Weekday};
+    ///
+    /// // March 17th 2021 was a Wednesday
+    /// let dt = datetime!(2021-3-17 02:30);
+    /// assert_eq!(dt.end_of_week(Weekday::Monday), datetime!(2021-3-21 00:00).with_time(Time::MAX));
+    /// assert_eq!(dt.end_of_week(Weekday::Thursday), datetime!(2021-3-17 00:00).with_time(Time::MAX));
     /// ```
-    /// # use eos::{DateTime, Utc};
-    /// # use eos::Weekday;
-    /// # fn test() -> Option<()> {
-    /// assert_eq!(DateTime::<Utc>::new(2021, 12, 25)?.weekday(), Weekday::Saturday);
-    /// assert_eq!(DateTime::<Utc>::new(2012, 2, 29)?.weekday(), Weekday::Wednesday);
-    /// # Some(())
-    /// # }
-    /// # test();
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_week(self, first: Weekday) -> Self {
+        let ahead = 6 - weekday_difference(self.weekday().days_from_sunday(), first.days_from_sunday());
+        let date = self.date.checked_add_days(ahead as i32).unwrap_or(Date::MAX);
+        self.end_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the start of the current month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::datetime;
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:30).start_of_month(), datetime!(2021-3-1 00:00));
     /// ```
-    #[must_use]
-    pub fn weekday(&self) -> Weekday {
-        self.date.weekday()
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn start_of_month(self) -> Self {
+        let date = Date {
+            year: self.date.year,
+            month: self.date.month,
+            day: 1,
+        };
+        self.start_on(date)
     }
 
-    /// Returns a [`DateTime`] moved to the next step of the given value.
+    /// Returns a [`DateTime`] at the last nanosecond of the current month.
     ///
     /// # Examples
     ///
-    /// Getting the next weekday:
+    /// ```rust
+    /// use eos::{datetime, Time};
+    ///
+    /// assert_eq!(datetime!(2021-3-17 02:30).end_of_month(), datetime!(2021-3-31 00:00).with_time(Time::MAX));
+    /// assert_eq!(datetime!(2024-2-10 02:30).end_of_month(), datetime!(2024-2-29 00:00).with_time(Time::MAX));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_month(self) -> Self {
+        let date = Date {
+            year: self.date.year,
+            month: self.date.month,
+            day: days_in_month(self.date.year, self.date.month),
+        };
+        self.end_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the start of the current quarter.
+    ///
+    /// Quarters start on January 1st, April 1st, July 1st, and October 1st.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, Weekday};
+    /// use eos::datetime;
     ///
-    /// // March 17th 2021 was a Wednesday
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Monday), datetime!(2021-3-22 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Tuesday), datetime!(2021-3-23 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Wednesday), datetime!(2021-3-24 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Thursday), datetime!(2021-3-18 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Friday), datetime!(2021-3-19 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Saturday), datetime!(2021-3-20 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).next(Weekday::Sunday), datetime!(2021-3-21 00:00));
+    /// assert_eq!(datetime!(2021-5-17 02:30).start_of_quarter(), datetime!(2021-4-1 00:00));
     /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn start_of_quarter(self) -> Self {
+        let date = self.date.first_day_of_quarter();
+        self.start_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the last nanosecond of the current quarter.
     ///
-    /// Getting the next time:
+    /// Quarters end on March 31st, June 30th, September 30th, and December 31st.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, time};
+    /// use eos::{datetime, Time};
     ///
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(time!(03:00)), datetime!(2021-3-17 03:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(time!(02:00)), datetime!(2021-3-18 02:00));
+    /// assert_eq!(datetime!(2021-5-17 02:30).end_of_quarter(), datetime!(2021-6-30 00:00).with_time(Time::MAX));
     /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_quarter(self) -> Self {
+        let date = self.date.last_day_of_quarter();
+        self.end_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the start of the current year.
     ///
-    /// Getting the next unit:
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, unit};
+    /// use eos::datetime;
     ///
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Year), datetime!(2022-3-17 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Month), datetime!(2021-4-17 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Week), datetime!(2021-3-24 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Day), datetime!(2021-3-18 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Hour), datetime!(2021-3-17 03:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Minute), datetime!(2021-3-17 02:01));
-    /// assert_eq!(datetime!(2021-3-17 02:00).next(unit::Second), datetime!(2021-3-17 02:00:01));
+    /// assert_eq!(datetime!(2021-5-17 02:30).start_of_year(), datetime!(2021-1-1 00:00));
     /// ```
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn next<A>(self, advance: A) -> Self
-    where
-        A: Advance<Self>,
-    {
-        advance.next_from(self)
+    pub fn start_of_year(self) -> Self {
+        let date = Date {
+            year: self.date.year,
+            month: 1,
+            day: 1,
+        };
+        self.start_on(date)
     }
 
-    /// Returns a [`DateTime`] moved to the previous step of the given value.
+    /// Returns a [`DateTime`] at the last nanosecond of the current year.
     ///
     /// # Examples
     ///
-    /// Getting the previous weekday:
+    /// ```rust
+    /// use eos::{datetime, Time};
+    ///
+    /// assert_eq!(datetime!(2021-5-17 02:30).end_of_year(), datetime!(2021-12-31 00:00).with_time(Time::MAX));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn end_of_year(self) -> Self {
+        let date = Date {
+            year: self.date.year,
+            month: 12,
+            day: 31,
+        };
+        self.end_on(date)
+    }
+
+    /// Returns a [`DateTime`] at the same time of day on the first day of the current month.
+    ///
+    /// Unlike [`DateTime::start_of_month`], the time of day is kept as-is. The offset
+    /// is recalculated for the new date in the same way as adding an [`Interval`].
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, Weekday};
+    /// use eos::datetime;
     ///
-    /// // March 17th 2021 was a Wednesday
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Monday), datetime!(2021-3-15 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Tuesday), datetime!(2021-3-16 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Wednesday), datetime!(2021-3-10 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Thursday), datetime!(2021-3-11 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Friday), datetime!(2021-3-12 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Saturday), datetime!(2021-3-13 00:00));
-    /// assert_eq!(datetime!(2021-3-17 00:00).prev(Weekday::Sunday), datetime!(2021-3-14 00:00));
+    /// let dt = datetime!(2024-02-10 09:30 -05:00);
+    /// assert_eq!(dt.first_of_month(), datetime!(2024-02-01 09:30 -05:00));
+    /// assert_eq!(dt.last_of_month(), datetime!(2024-02-29 09:30 -05:00));
+    /// assert_eq!(dt.first_of_year(), datetime!(2024-01-01 09:30 -05:00));
+    /// assert_eq!(dt.last_of_year(), datetime!(2024-12-31 09:30 -05:00));
     /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn first_of_month(self) -> Self {
+        let date = self.date.first_of_month();
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns a [`DateTime`] at the same time of day on the last day of the current month.
     ///
-    /// Getting the previous time:
+    /// Unlike [`DateTime::end_of_month`], the time of day is kept as-is.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn last_of_month(self) -> Self {
+        let date = self.date.last_of_month();
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns a [`DateTime`] at the same time of day on the first day of the current year.
+    ///
+    /// Unlike [`DateTime::start_of_year`], the time of day is kept as-is.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn first_of_year(self) -> Self {
+        let date = self.date.first_of_year();
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns a [`DateTime`] at the same time of day on the last day of the current year.
+    ///
+    /// Unlike [`DateTime::end_of_year`], the time of day is kept as-is.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn last_of_year(self) -> Self {
+        let date = self.date.last_of_year();
+        let time = self.time;
+        self.with_wall_clock(date, time, false)
+    }
+
+    /// Returns `true` if the date is the first day of its month.
+    #[inline]
+    #[must_use]
+    pub fn is_first_of_month(&self) -> bool {
+        self.date.is_first_of_month()
+    }
+
+    /// Returns `true` if the date is the last day of its month.
+    #[inline]
+    #[must_use]
+    pub fn is_last_of_month(&self) -> bool {
+        self.date.is_last_of_month()
+    }
+
+    /// Returns a [`DateTime`] moved to the nth occurrence of a weekday in the current month.
+    ///
+    /// The time is preserved. See [`Date::nth_weekday_of_month`] for the errors returned.
+    /// Local times that do not exist or are ambiguous on the new date are resolved using
+    /// [`DateTimeResolution::lenient`](crate::DateTimeResolution::lenient).
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, time};
+    /// use eos::{datetime, Weekday};
     ///
-    /// assert_eq!(datetime!(2021-3-17 04:00).prev(time!(03:00)), datetime!(2021-3-17 03:00));
-    /// assert_eq!(datetime!(2021-3-17 03:00).prev(time!(03:00)), datetime!(2021-3-16 03:00));
+    /// let dt = datetime!(2024-3-20 09:30);
+    /// assert_eq!(dt.nth_weekday_of_month(Weekday::Tuesday, 2)?, datetime!(2024-3-12 09:30));
+    /// assert!(dt.nth_weekday_of_month(Weekday::Monday, 5).is_err());
+    /// # Ok::<_, eos::Error>(())
     /// ```
+    pub fn nth_weekday_of_month(self, weekday: Weekday, n: u8) -> Result<Self, Error> {
+        let date = Date::nth_weekday_of_month(self.date.year, self.date.month, weekday, n)?;
+        let time = self.time;
+        Ok(with_local(self, (date, time)))
+    }
+
+    /// Returns a [`DateTime`] moved to the last occurrence of a weekday in the current month.
     ///
-    /// Getting the previous unit:
+    /// The time is preserved. Local times that do not exist or are ambiguous on the new date
+    /// are resolved using [`DateTimeResolution::lenient`](crate::DateTimeResolution::lenient).
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use eos::{datetime, unit};
+    /// use eos::{datetime, Weekday};
     ///
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Year), datetime!(2020-3-17 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Month), datetime!(2021-2-17 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Week), datetime!(2021-3-10 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Day), datetime!(2021-3-16 02:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Hour), datetime!(2021-3-17 01:00));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Minute), datetime!(2021-3-17 01:59));
-    /// assert_eq!(datetime!(2021-3-17 02:00).prev(unit::Second), datetime!(2021-3-17 01:59:59));
+    /// let dt = datetime!(2024-3-20 09:30);
+    /// assert_eq!(dt.last_weekday_of_month(Weekday::Friday), datetime!(2024-3-29 09:30));
+    /// assert_eq!(dt.last_weekday_of_month(Weekday::Sunday), datetime!(2024-3-31 09:30));
     /// ```
     #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn prev<A>(self, advance: A) -> Self
-    where
-        A: Advance<Self>,
-    {
-        advance.prev_from(self)
+    pub fn last_weekday_of_month(self, weekday: Weekday) -> Self {
+        // The month is always valid so this never fails
+        let date = Date::last_weekday_of_month(self.date.year, self.date.month, weekday).unwrap_or(self.date);
+        let time = self.time;
+        with_local(self, (date, time))
     }
 
     /// Returns the ISO week date for this datetime.
@@ -908,8 +2694,26 @@ where
     /// ```
     #[inline]
     #[must_use]
-    pub fn iso_week(&self) -> IsoWeekDate {
-        self.date.iso_week()
+    pub fn iso_week(&self) -> IsoWeekDate {
+        self.date.iso_week()
+    }
+
+    /// Returns the week of the year, where weeks begin on the given weekday.
+    ///
+    /// See [`Date::week_of_year`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn week_of_year(&self, first_weekday: Weekday) -> u8 {
+        self.date.week_of_year(first_weekday)
+    }
+
+    /// Returns the week of the month, where weeks begin on the given weekday.
+    ///
+    /// See [`Date::week_of_month`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn week_of_month(&self, first_weekday: Weekday) -> u8 {
+        self.date.week_of_month(first_weekday)
     }
 
     /// Returns a new [`DateTime`] with the date pointing to the given year.
@@ -958,6 +2762,42 @@ where
         Some(self)
     }
 
+    /// Returns a new [`DateTime`] that points to the given ordinal day of the current year.
+    ///
+    /// See [`Date::with_ordinal`] for more information.
+    pub fn with_ordinal(mut self, ordinal: u16) -> Result<Self, Error> {
+        self.date = self.date.with_ordinal(ordinal)?;
+        Ok(self)
+    }
+
+    /// Returns a new [`DateTime`] that points to the given ISO week of the current ISO year.
+    ///
+    /// See [`Date::with_iso_week`] for more information.
+    pub fn with_iso_week(mut self, week: u8) -> Result<Self, Error> {
+        self.date = self.date.with_iso_week(week)?;
+        Ok(self)
+    }
+
+    /// Returns a new [`DateTime`] that points to the given weekday within the current ISO week.
+    ///
+    /// See [`Date::with_weekday`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Weekday};
+    ///
+    /// // 2021-03-17 is a Wednesday
+    /// let dt = datetime!(2021-03-17 09:30 -05:00);
+    /// assert_eq!(dt.with_weekday(Weekday::Friday), Ok(datetime!(2021-03-19 09:30 -05:00)));
+    /// assert_eq!(dt.with_iso_week(1), Ok(datetime!(2021-01-06 09:30 -05:00)));
+    /// assert_eq!(dt.with_ordinal(1), Ok(datetime!(2021-01-01 09:30 -05:00)));
+    /// ```
+    pub fn with_weekday(mut self, weekday: Weekday) -> Result<Self, Error> {
+        self.date = self.date.with_weekday(weekday)?;
+        Ok(self)
+    }
+
     /// Returns the hour.
     ///
     /// This value will always be within `0..24`.
@@ -1133,7 +2973,7 @@ impl FromIsoFormat for DateTime<UtcOffset> {
         } else {
             let negative = parser.parse_required_sign()?;
             let hours = parser.parse_two_digits()? as i8;
-            if hours > 23 {
+            if hours > 24 {
                 return Err(ParseError::OutOfBounds);
             }
 
@@ -1157,6 +2997,11 @@ impl FromIsoFormat for DateTime<UtcOffset> {
                 None => (0, 0),
             };
 
+            // UtcOffset::MIN and UtcOffset::MAX are the only offsets with 24 hours
+            if hours == 24 && (minutes != 0 || seconds != 0) {
+                return Err(ParseError::OutOfBounds);
+            }
+
             if negative {
                 UtcOffset {
                     hours: -hours,
@@ -1181,52 +3026,128 @@ impl FromIsoFormat for DateTime<UtcOffset> {
     }
 }
 
-impl Add<Duration> for DateTime {
-    type Output = DateTime;
+// Durations represent elapsed time, so unlike intervals they advance the underlying instant
+// rather than the local wall clock time.
+impl<Tz> Add<Duration> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    type Output = Self;
 
     fn add(self, rhs: Duration) -> Self::Output {
+        if !self.timezone.is_fixed() {
+            let timezone = self.timezone.clone();
+            return (self.into_utc() + rhs).in_timezone(timezone);
+        }
+
         let (days, time) = self.time.add_with_duration(rhs);
-        let date = self.date.add_days(days);
-        if self.timezone.is_fixed() {
-            DateTime {
-                date,
-                time,
-                offset: self.offset,
-                timezone: self.timezone,
-            }
-        } else {
-            self.timezone.resolve(date, time).lenient()
+        DateTime {
+            date: self.date.add_days(days),
+            time,
+            offset: self.offset,
+            timezone: self.timezone,
         }
     }
 }
 
-impl Sub<Duration> for DateTime {
-    type Output = DateTime;
+impl<Tz> Sub<Duration> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    type Output = Self;
 
     fn sub(self, rhs: Duration) -> Self::Output {
+        if !self.timezone.is_fixed() {
+            let timezone = self.timezone.clone();
+            return (self.into_utc() - rhs).in_timezone(timezone);
+        }
+
         let (days, time) = self.time.sub_with_duration(rhs);
-        let date = self.date.add_days(days);
-        if self.timezone.is_fixed() {
-            DateTime {
-                date,
-                time,
-                offset: self.offset,
-                timezone: self.timezone,
-            }
-        } else {
-            self.timezone.resolve(date, time).backwards()
+        DateTime {
+            date: self.date.add_days(days),
+            time,
+            offset: self.offset,
+            timezone: self.timezone,
         }
     }
 }
 
+impl<Tz> AddAssign<Duration> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<Tz> SubAssign<Duration> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// Converts a [`SystemTime`] into a [`DateTime`].
+///
+/// A [`SystemTime`] can be far outside of the range of a [`DateTime`], in which case
+/// the result saturates to [`DateTime::MAX`] or [`DateTime::MIN`]. Use
+/// [`DateTime::try_from_system_time`] to get an error instead.
+///
+/// A [`SystemTime`] has at most nanosecond precision, so the conversion is exact and
+/// never rounds.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use eos::DateTime;
+///
+/// if let Some(far) = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(u64::MAX / 2)) {
+///     assert_eq!(DateTime::from(far), DateTime::MAX);
+/// }
+/// ```
 #[cfg(feature = "std")]
 impl From<SystemTime> for DateTime {
-    /// Creates
     fn from(time: SystemTime) -> Self {
         match time.duration_since(SystemTime::UNIX_EPOCH) {
-            Ok(duration) => Self::UNIX_EPOCH + duration,
-            Err(e) => Self::UNIX_EPOCH - e.duration(),
+            Ok(duration) => Self::UNIX_EPOCH.checked_add_duration(duration).unwrap_or(Self::MAX),
+            Err(e) => Self::UNIX_EPOCH.checked_sub_duration(e.duration()).unwrap_or(Self::MIN),
+        }
+    }
+}
+
+/// Converts a [`DateTime`] into a [`SystemTime`].
+///
+/// If the instant can't be represented by the platform's [`SystemTime`] then
+/// [`Error::OutOfRange`] is returned. For example, Windows can't represent
+/// times before the year 1601.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use eos::datetime;
+///
+/// let time = SystemTime::try_from(datetime!(2022-01-02 20:38:45 -05:00))?;
+/// assert_eq!(time, SystemTime::UNIX_EPOCH + Duration::from_secs(1641173925));
+/// # Ok::<_, eos::Error>(())
+/// ```
+#[cfg(feature = "std")]
+impl<Tz> TryFrom<DateTime<Tz>> for SystemTime
+where
+    Tz: TimeZone,
+{
+    type Error = Error;
+
+    fn try_from(dt: DateTime<Tz>) -> Result<Self, Self::Error> {
+        match dt.duration_since(&DateTime::UNIX_EPOCH) {
+            Ok(duration) => SystemTime::UNIX_EPOCH.checked_add(duration),
+            Err(duration) => SystemTime::UNIX_EPOCH.checked_sub(duration),
         }
+        .ok_or(Error::OutOfRange)
     }
 }
 
@@ -1253,6 +3174,16 @@ where
     }
 }
 
+// This must agree with PartialEq, so it hashes the same key that comparisons use
+impl<Tz> Hash for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.utc_epoch_day_and_nanos().hash(state);
+    }
+}
+
 // Rust does not allow Ord<Rhs> for some reason
 // see: https://github.com/rust-lang/rfcs/issues/2511
 impl<Tz> Ord for DateTime<Tz>
@@ -1329,6 +3260,24 @@ where
     }
 }
 
+impl<Tz> AddAssign<Interval> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<Tz> SubAssign<Interval> for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = self.clone() - rhs;
+    }
+}
+
 impl<Tz, OtherTz> Sub<DateTime<OtherTz>> for DateTime<Tz>
 where
     Tz: TimeZone,
@@ -1348,7 +3297,308 @@ where
 #[allow(clippy::eq_op)]
 mod tests {
     use super::*;
-    use crate::{datetime, utc_offset};
+    use crate::{date, datetime, time, utc_offset};
+
+    #[test]
+    fn test_add_duration_with_offset() {
+        let dt = datetime!(2022-01-31 23:00 -05:00);
+        let later = dt + Duration::from_secs(7200);
+        assert_eq!(later, datetime!(2022-02-01 01:00 -05:00));
+        assert_eq!(later.offset(), &utc_offset!(-05:00));
+        assert_eq!(later - Duration::from_secs(7200), dt);
+        assert_eq!(later.duration_since(&dt), Ok(Duration::from_secs(7200)));
+        assert_eq!(dt + Duration::from_nanos(1), dt.with_nanosecond(1).unwrap());
+
+        let utc = dt.in_timezone(Utc);
+        assert_eq!(dt + Duration::from_secs(90), utc + Duration::from_secs(90));
+        assert_eq!(dt - Duration::from_secs(90), utc - Duration::from_secs(90));
+    }
+
+    #[test]
+    fn test_macro_fractional_seconds_and_utc_marker() {
+        const TIME: Time = time!(12:30:45.5);
+        assert_eq!(
+            TIME,
+            Time::new(12, 30, 45).unwrap().with_nanosecond(500_000_000).unwrap()
+        );
+        assert_eq!(time!(00:00:00.000000001).nanosecond(), 1);
+        assert_eq!(
+            time!(00:00:59.999999999),
+            Time::new(0, 0, 59).unwrap().with_nanosecond(999_999_999).unwrap()
+        );
+        assert_eq!(time!(00:00:01.), time!(00:00:01));
+        assert_eq!(
+            time!(00:00:01.000_100),
+            time!(00:00:01).with_nanosecond(100_000).unwrap()
+        );
+        assert_eq!(time!(12:00:00.25 am), time!(00:00:00.25));
+        assert_eq!(time!(1:00:00.25 pm), time!(13:00:00.25));
+
+        const UTC: DateTime = datetime!(2022-01-01 10:00 Z);
+        assert_eq!(UTC, datetime!(2022-01-01 10:00));
+        assert_eq!(datetime!(2022-01-01 10:00:30 pm Z), datetime!(2022-01-01 22:00:30));
+        assert_eq!(datetime!(2022-01-01 10:00:30.75 Z).nanosecond(), 750_000_000);
+        assert_eq!(datetime!(2022-048 10:00 Z), datetime!(2022-02-17 10:00));
+        assert_eq!(datetime!(2022-W07-3 10:00 +01:00), datetime!(2022-02-16 10:00 +01:00));
+        assert_eq!(datetime!(2022-W07-3 10:00:00.5 -01:00).nanosecond(), 500_000_000);
+    }
+
+    #[test]
+    fn test_interval_assign_operators() {
+        use crate::ext::IntervalLiteral;
+
+        let datetimes = [
+            datetime!(2020-01-31 12:00 +00:00),
+            datetime!(2020-02-29 00:00 +00:00),
+            datetime!(2021-02-28 23:59:59 +00:00),
+            datetime!(2021-03-31 06:30 -05:00),
+            datetime!(2021-12-31 23:00 +09:00),
+        ];
+        let intervals = [
+            Interval::ZERO,
+            1.months(),
+            (-1).months(),
+            1.years(),
+            30.days(),
+            25.hours(),
+            (-90).minutes(),
+            1.months() + 1.days() + 1.hours(),
+            Interval::from_months(13) - 61.seconds(),
+        ];
+
+        for dt in datetimes {
+            for interval in intervals {
+                let mut cursor = dt;
+                cursor += interval;
+                assert_eq!(cursor, dt + interval);
+                assert_eq!(cursor.offset(), dt.offset());
+                cursor -= interval;
+                assert_eq!(cursor, (dt + interval) - interval);
+
+                let mut date = *dt.date();
+                date += interval;
+                assert_eq!(date, *dt.date() + interval);
+
+                let mut time = *dt.time();
+                time += interval;
+                assert_eq!(time, *dt.time() + interval);
+                time -= interval;
+                assert_eq!(time, (*dt.time() + interval) - interval);
+            }
+        }
+
+        // Every month end of a leap and a common year, shifted by each month count
+        for year in [2020, 2021] {
+            for month in 1..=12 {
+                let date = Date::new(year, month, 1).unwrap().last_of_month();
+                let dt = date.at(time!(18:45:30));
+                for months in (-25..=25).filter(|&m| m != 0) {
+                    let interval = Interval::from_months(months);
+
+                    let mut cursor = dt;
+                    cursor += interval;
+                    assert_eq!(cursor, dt + interval);
+                    cursor -= interval;
+                    assert_eq!(cursor, (dt + interval) - interval);
+
+                    let mut cursor = date;
+                    cursor += interval;
+                    assert_eq!(cursor, date + interval);
+                    cursor -= interval;
+                    assert_eq!(cursor, (date + interval) - interval);
+                }
+            }
+        }
+
+        // Month-end clamping is not undone when accumulating
+        let mut cursor = datetime!(2021-01-31 00:00);
+        cursor += 1.months();
+        assert_eq!(cursor, datetime!(2021-02-28 00:00));
+        cursor += 1.months();
+        assert_eq!(cursor, datetime!(2021-03-28 00:00));
+
+        let mut time = time!(23:00);
+        time += 2.hours();
+        assert_eq!(time, time!(01:00));
+    }
+
+    #[test]
+    fn test_assign_operators_match_binary() {
+        // A small xorshift generator keeps the inputs reproducible
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move |range: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % range
+        };
+
+        for _ in 0..2000 {
+            let date = Date::new(next(4000) as i16 - 1000, next(12) as u8 + 1, next(28) as u8 + 1).unwrap();
+            let time = Time::new(next(24) as u8, next(60) as u8, next(60) as u8)
+                .unwrap()
+                .with_nanosecond(next(1_000_000_000) as u32)
+                .unwrap();
+            let offset = UtcOffset::from_seconds(next(86_400 * 2 - 1) as i32 - 86_399).unwrap();
+            let dt = date.at(time).with_timezone(offset);
+            let interval = Interval::from_months(next(600) as i32 - 300)
+                + Interval::from_days(next(20_000) as i32 - 10_000)
+                + Interval::from_microseconds(next(1 << 40) as i64 - (1 << 39));
+            let duration = Duration::new(next(1 << 32), next(1_000_000_000) as u32);
+
+            let mut value = dt;
+            value += interval;
+            assert_eq!((value, value.offset()), (dt + interval, (dt + interval).offset()));
+            let mut value = dt;
+            value -= interval;
+            assert_eq!((value, value.offset()), (dt - interval, (dt - interval).offset()));
+            let mut value = dt;
+            value += duration;
+            assert_eq!((value, value.offset()), (dt + duration, (dt + duration).offset()));
+            let mut value = dt;
+            value -= duration;
+            assert_eq!((value, value.offset()), (dt - duration, (dt - duration).offset()));
+
+            let mut value = date;
+            value += interval;
+            assert_eq!(value, date + interval);
+            let mut value = date;
+            value -= interval;
+            assert_eq!(value, date - interval);
+
+            let mut value = time;
+            value += duration;
+            assert_eq!(value, time + duration);
+            let mut value = time;
+            value -= duration;
+            assert_eq!(value, time - duration);
+            let mut value = time;
+            value += interval;
+            assert_eq!(value, time + interval);
+
+            let other = Interval::from_days(next(1000) as i32) + Interval::from_microseconds(next(1 << 30) as i64);
+            let mut value = interval;
+            value += other;
+            assert_eq!(value, interval + other);
+            let mut value = interval;
+            value -= other;
+            assert_eq!(value, interval - other);
+        }
+    }
+
+    #[test]
+    fn test_duration_since() {
+        let earlier = datetime!(2022-01-01 23:59:59 +00:00)
+            .with_nanosecond(999_999_999)
+            .unwrap();
+        let later = datetime!(2022-01-01 19:00 -05:00);
+        assert_eq!(later.duration_since(&earlier), Ok(Duration::from_nanos(1)));
+        assert_eq!(earlier.duration_since(&later), Err(Duration::from_nanos(1)));
+        assert_eq!(later.checked_duration_since(&earlier), Some(Duration::from_nanos(1)));
+        assert_eq!(earlier.checked_duration_since(&later), None);
+        assert_eq!(earlier.saturating_duration_since(&later), Duration::ZERO);
+
+        let earlier = datetime!(2022-01-02 08:59:59 +09:00)
+            .with_nanosecond(999_999_999)
+            .unwrap();
+        let later = datetime!(2022-01-01 16:00 -08:00);
+        assert_eq!(later.duration_since(&earlier), Ok(Duration::from_nanos(1)));
+        assert_eq!(later.duration_since(&later), Ok(Duration::ZERO));
+
+        use crate::gregorian::{MAX_EPOCH_DAYS, MIN_EPOCH_DAYS};
+        let days = (MAX_EPOCH_DAYS - MIN_EPOCH_DAYS) as u64;
+        let span = Duration::new(days * 86_400 + 86_399, 999_999_999);
+        assert_eq!(DateTime::MAX.duration_since(&DateTime::MIN), Ok(span));
+        assert_eq!(DateTime::MIN.duration_since(&DateTime::MAX), Err(span));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_elapsed() {
+        let hour = Duration::from_secs(3600);
+        let past = DateTime::utc_now().in_timezone(utc_offset!(-05:00)) - hour;
+        assert!(past.elapsed() >= hour);
+        assert!(past.try_elapsed().unwrap() >= hour);
+
+        let future = DateTime::utc_now().in_timezone(utc_offset!(+09:00)) + hour;
+        assert_eq!(future.elapsed(), Duration::ZERO);
+        let remaining = future.try_elapsed().unwrap_err();
+        assert!(remaining <= hour && remaining > Duration::ZERO);
+
+        assert_eq!(DateTime::MAX.elapsed(), Duration::ZERO);
+        assert!(DateTime::MIN.try_elapsed().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_conversions() {
+        let dt = datetime!(1969-12-31 23:59:58).with_nanosecond(250_000_000).unwrap();
+        let time = SystemTime::UNIX_EPOCH - Duration::new(1, 750_000_000);
+        assert_eq!(SystemTime::try_from(dt), Ok(time));
+        assert_eq!(SystemTime::try_from(dt.in_timezone(utc_offset!(+09:00))), Ok(time));
+        assert_eq!(DateTime::try_from_system_time(time), Ok(dt));
+        assert_eq!(DateTime::from(time), dt);
+
+        let dt = datetime!(2022-01-03 01:38:45).with_nanosecond(1).unwrap();
+        let time = SystemTime::try_from(dt).unwrap();
+        assert_eq!(DateTime::try_from_system_time(time), Ok(dt));
+
+        // Values far outside of the range saturate instead of panicking
+        if let Some(far) = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(u64::MAX / 2)) {
+            assert_eq!(DateTime::from(far), DateTime::MAX);
+            assert_eq!(DateTime::try_from_system_time(far), Err(Error::OutOfRange));
+        }
+        if let Some(far) = SystemTime::UNIX_EPOCH.checked_sub(Duration::from_secs(u64::MAX / 2)) {
+            assert_eq!(DateTime::from(far), DateTime::MIN);
+            assert_eq!(DateTime::try_from_system_time(far), Err(Error::OutOfRange));
+        }
+
+        // The bounds depend on the platform, but they must round trip if representable
+        for dt in [DateTime::MIN, DateTime::MAX] {
+            if let Ok(time) = SystemTime::try_from(dt) {
+                assert_eq!(DateTime::try_from_system_time(time), Ok(dt));
+                if let Some(outside) = time.checked_add(Duration::from_secs(86_400 * 366 * 2)) {
+                    assert_eq!(DateTime::try_from_system_time(outside).is_err(), dt == DateTime::MAX);
+                    if dt == DateTime::MAX {
+                        assert_eq!(DateTime::from(outside), DateTime::MAX);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_max() {
+        use crate::ext::IntervalLiteral;
+
+        assert_eq!(DateTime::MIN, Date::MIN.at(Time::MIN));
+        assert_eq!(DateTime::MAX, Date::MAX.at(Time::MAX));
+        assert!(DateTime::MIN < DateTime::UNIX_EPOCH);
+        assert!(DateTime::MAX > DateTime::UNIX_EPOCH);
+        assert!(DateTime::MIN <= datetime!(0001-01-01 00:00));
+        assert!(DateTime::MAX >= datetime!(9999-12-31 23:59:59));
+        assert!(DateTime::MAX > Date::MAX.at(time!(23:59:59)));
+
+        assert_eq!(DateTime::MAX + Interval::ZERO, DateTime::MAX);
+        assert_eq!(DateTime::MAX - Interval::ZERO, DateTime::MAX);
+        assert_eq!(DateTime::MIN + Interval::ZERO, DateTime::MIN);
+        assert_eq!(DateTime::MIN - Interval::ZERO, DateTime::MIN);
+        assert_eq!(DateTime::MAX - DateTime::MAX, Interval::ZERO);
+        assert_eq!(DateTime::MAX - 1.days(), Date::MAX.add_days(-1).at(Time::MAX));
+        assert_eq!(DateTime::MIN + 1.days(), Date::MIN.add_days(1).at(Time::MIN));
+        assert_eq!(DateTime::MAX.checked_add(1.microseconds()), None);
+        assert_eq!(DateTime::MIN.checked_sub(1.microseconds()), None);
+        assert_eq!(DateTime::MAX.timestamp().to_utc(), DateTime::MAX);
+        assert_eq!(DateTime::MIN.timestamp().to_utc(), DateTime::MIN);
+
+        assert_eq!(DateTime::MIN.to_string(), "-32768-01-01T00:00:00+00:00");
+        assert_eq!(DateTime::MAX.to_string(), "+32767-12-31T23:59:59.999999999+00:00");
+        #[cfg(feature = "formatting")]
+        {
+            assert_eq!(DateTime::MIN.to_iso_format(), "-32768-01-01T00:00:00+00:00");
+            assert_eq!(DateTime::MAX.to_iso_format(), "+32767-12-31T23:59:59.999999999+00:00");
+        }
+    }
 
     #[test]
     fn test_regular_comparisons() {
@@ -1394,6 +3644,83 @@ mod tests {
         assert_eq!(left, utc);
     }
 
+    #[test]
+    fn test_cross_timezone_sub_day_comparisons() {
+        // The earlier instant has the later local wall clock time
+        let east = datetime!(2022-01-01 23:00 +10:00);
+        let utc = datetime!(2022-01-01 20:00);
+        assert_eq!(east.cmp_cross_timezone(&utc), Ordering::Less);
+        assert_eq!(utc.cmp_cross_timezone(&east), Ordering::Greater);
+        assert!(east < utc);
+        assert!(utc > east);
+
+        // Same UTC second but different nanoseconds
+        let east = datetime!(2022-01-01 23:00 +10:00).with_nanosecond(500_000_000).unwrap();
+        let utc = datetime!(2022-01-01 13:00).with_nanosecond(750_000_000).unwrap();
+        assert_eq!(east.cmp_cross_timezone(&utc), Ordering::Less);
+        assert_eq!(utc.cmp_cross_timezone(&east), Ordering::Greater);
+        assert_eq!(
+            east.cmp_cross_timezone(&datetime!(2022-01-01 13:00).with_nanosecond(500_000_000).unwrap()),
+            Ordering::Equal
+        );
+
+        // Offsets that push the UTC time to a different day
+        let west = datetime!(2022-01-01 20:00 -05:00);
+        let east = datetime!(2022-01-02 09:59:59 +09:00)
+            .with_nanosecond(999_999_999)
+            .unwrap();
+        assert_eq!(west.cmp_cross_timezone(&east), Ordering::Greater);
+        assert_eq!(east.cmp_cross_timezone(&west), Ordering::Less);
+        assert_eq!(
+            west.cmp_cross_timezone(&datetime!(2022-01-02 10:00 +09:00)),
+            Ordering::Equal
+        );
+        assert_eq!(west.cmp_cross_timezone(&datetime!(2022-01-02 01:00)), Ordering::Equal);
+
+        // Check against timestamps for a spread of times and offsets
+        let base = datetime!(2022-01-01 00:00);
+        for hour in (-30..=30).step_by(3) {
+            for offset in [
+                utc_offset!(-12:00),
+                utc_offset!(-03:30),
+                utc_offset!(05:45),
+                utc_offset!(14:00),
+            ] {
+                let left = (base + Interval::from_hours(hour)).in_timezone(offset);
+                let right = base.with_nanosecond(1).unwrap();
+                assert_eq!(
+                    left.cmp_cross_timezone(&right),
+                    left.timestamp().cmp(&right.timestamp())
+                );
+                assert_eq!(
+                    right.cmp_cross_timezone(&left),
+                    right.timestamp().cmp(&left.timestamp())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leap_second_comparisons() {
+        let leap = datetime!(2016-12-31 23:59:59).with_nanosecond(1_000_000_000).unwrap();
+        let next_utc = datetime!(2017-01-01 00:00);
+        let next_p1 = datetime!(2017-01-01 01:00 +01:00);
+
+        // Equality is transitive regardless of whether the offsets match
+        assert_eq!(leap, next_p1);
+        assert_eq!(next_utc, next_p1);
+        assert_eq!(leap, next_utc);
+        assert_eq!(leap.cmp(&next_utc), Ordering::Equal);
+        assert_eq!(leap.in_timezone(Utc), leap);
+
+        let before = datetime!(2016-12-31 23:59:59).with_nanosecond(999_999_999).unwrap();
+        assert!(before < leap);
+        assert!(before.with_timezone(utc_offset!(00:00)) < next_p1);
+        let after = leap.with_nanosecond(1_000_000_001).unwrap();
+        assert!(after > next_utc);
+        assert!(after > next_p1);
+    }
+
     #[test]
     fn test_timestamp() {
         assert_eq!(datetime!(1970-01-01 00:00).timestamp().as_seconds(), 0);
@@ -1405,8 +3732,344 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_timestamp_bounds() {
+        let max = DateTime::MAX.timestamp();
+        let min = DateTime::MIN.timestamp();
+        assert_eq!(DateTime::from_timestamp(max, Utc), DateTime::MAX);
+        assert_eq!(DateTime::from_timestamp(min, Utc), DateTime::MIN);
+        assert_eq!(DateTime::try_from_timestamp(max, Utc), Ok(DateTime::MAX));
+        assert_eq!(DateTime::try_from_timestamp(min, Utc), Ok(DateTime::MIN));
+
+        let after = Timestamp::from_seconds(max.as_seconds() + 1);
+        let before = Timestamp::from_seconds(min.as_seconds() - 1);
+        assert_eq!(DateTime::try_from_timestamp(after, Utc), Err(Error::OutOfRange));
+        assert_eq!(DateTime::try_from_timestamp(before, Utc), Err(Error::OutOfRange));
+
+        for seconds in [i64::MIN, i64::MIN + 1, -1 << 62, 1 << 62, i64::MAX - 1, i64::MAX] {
+            let ts = Timestamp::new(seconds, 999_999_999);
+            let expected = if seconds < 0 { DateTime::MIN } else { DateTime::MAX };
+            assert_eq!(DateTime::from_timestamp(ts, Utc), expected);
+            assert_eq!(DateTime::try_from_timestamp(ts, Utc), Err(Error::OutOfRange));
+            assert_eq!(
+                DateTime::try_from_timestamp(ts, utc_offset!(-05:00)),
+                Err(Error::OutOfRange)
+            );
+        }
+
+        // Check the divmod math against the calendar for a spread of timestamps
+        let mut seconds = min.as_seconds();
+        while seconds <= max.as_seconds() {
+            let dt = DateTime::try_from_timestamp(Timestamp::from_seconds(seconds), Utc).unwrap();
+            assert_eq!(dt.timestamp().as_seconds(), seconds);
+            seconds += 9_876_543_211;
+        }
+    }
+
+    #[test]
+    fn test_sub_second_timestamps() -> Result<(), Error> {
+        // Before the epoch the sub-second part counts forward from the previous second
+        let dt = datetime!(1969-12-31 23:59:58).with_nanosecond(250_000_000).unwrap();
+        assert_eq!(dt.timestamp().as_seconds(), -2);
+        assert_eq!(dt.timestamp_millis(), -1750);
+        assert_eq!(dt.timestamp_micros(), -1_750_000);
+        assert_eq!(dt.timestamp_nanos(), -1_750_000_000);
+        assert_eq!(DateTime::from_timestamp_millis(-1750, Utc), Ok(dt));
+        assert_eq!(DateTime::from_timestamp_micros(-1_750_000, Utc), Ok(dt));
+        assert_eq!(DateTime::from_timestamp_nanos(-1_750_000_000, Utc), Ok(dt));
+        assert_eq!(
+            DateTime::from_timestamp_nanos(-1_750_000_000, Utc)?.nanosecond(),
+            250_000_000
+        );
+
+        let dt = DateTime::from_timestamp_nanos(-1, Utc)?;
+        assert_eq!(dt.second(), 59);
+        assert_eq!(dt.nanosecond(), 999_999_999);
+        assert_eq!(DateTime::from_timestamp_micros(-1, Utc)?.nanosecond(), 999_999_000);
+        assert_eq!(DateTime::from_timestamp_millis(-1, Utc)?.nanosecond(), 999_000_000);
+
+        // The offset doesn't change the instant
+        let dt = DateTime::from_timestamp_micros(1_641_173_925_000_001, utc_offset!(-05:00))?;
+        assert_eq!(dt.hour(), 20);
+        assert_eq!(dt.timestamp_micros(), 1_641_173_925_000_001);
+
+        // Round trip the extremes
+        for dt in [DateTime::MIN, DateTime::MAX] {
+            assert_eq!(DateTime::from_timestamp_nanos(dt.timestamp_nanos(), Utc), Ok(dt));
+            let micros = DateTime::from_timestamp_micros(dt.timestamp_micros(), Utc)?;
+            assert_eq!(micros.timestamp_micros(), dt.timestamp_micros());
+            let millis = DateTime::from_timestamp_millis(dt.timestamp_millis(), Utc)?;
+            assert_eq!(millis.timestamp_millis(), dt.timestamp_millis());
+        }
+        let max = DateTime::MAX.timestamp_nanos();
+        let min = DateTime::MIN.timestamp_nanos();
+        assert_eq!(DateTime::from_timestamp_nanos(max + 1, Utc), Err(Error::OutOfRange));
+        assert_eq!(DateTime::from_timestamp_nanos(min - 1, Utc), Err(Error::OutOfRange));
+        assert_eq!(DateTime::from_timestamp_nanos(i128::MIN, Utc), Err(Error::OutOfRange));
+        assert_eq!(DateTime::from_timestamp_micros(i64::MIN, Utc), Err(Error::OutOfRange));
+        assert_eq!(DateTime::from_timestamp_millis(i64::MAX, Utc), Err(Error::OutOfRange));
+        Ok(())
+    }
+
+    #[test]
+    fn test_julian_date() {
+        assert_eq!(date!(-4713 - 11 - 24).at(time!(12:00)).julian_date(), 0.0);
+        assert_eq!(date!(-4713 - 11 - 24).at(time!(00:00)).julian_date(), -0.5);
+        assert_eq!(date!(-4713 - 11 - 23).at(time!(18:00)).julian_date(), -0.75);
+        assert_eq!(datetime!(2000-01-01 00:00 +12:00).julian_date(), 2_451_544.0);
+        assert_eq!(
+            datetime!(2000-01-01 00:00)
+                .with_millisecond(500)
+                .unwrap()
+                .modified_julian_date(),
+            51_544.0 + 0.5 / 86400.0
+        );
+
+        for dt in [DateTime::MIN, DateTime::MAX] {
+            assert!((dt.julian_date() - dt.modified_julian_date() - 2_400_000.5).abs() < 1e-5);
+        }
+
+        for dt in [
+            datetime!(1858-11-17 00:00),
+            date!(-1 - 06 - 15).at(time!(03:00)),
+            datetime!(2024-02-29 21:00),
+        ] {
+            let jd = dt.julian_date();
+            assert_eq!((jd + 0.5).floor() as i32, dt.date().julian_day_number());
+            assert_eq!(
+                dt.modified_julian_date().floor() as i32,
+                dt.date().julian_day_number() - 2_400_001
+            );
+        }
+    }
+
     #[test]
     fn test_tzname() {
         assert_eq!(DateTime::UNIX_EPOCH.tzname(), Some("UTC"));
     }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        use crate::ext::IntervalLiteral;
+
+        let max = DateTime::MAX;
+        let min = DateTime::MIN;
+        assert_eq!(max.saturating_add(Interval::ZERO), max);
+        assert_eq!(max.saturating_add(1.microseconds()), max);
+        assert_eq!(max.saturating_add(1.months()), max);
+        assert_eq!(max.saturating_sub(Interval::from_days(i32::MIN)), max);
+        assert_eq!(max.saturating_sub(Interval::from_months(i32::MIN)), max);
+        assert_eq!(min.saturating_sub(90.days()), min);
+        assert_eq!(min.saturating_add(Interval::from_days(i32::MIN)), min);
+        assert_eq!(min.saturating_add((-1).microseconds()), min);
+        assert_eq!(max.saturating_sub(1.days()), Date::MAX.add_days(-1).at(Time::MAX));
+
+        let dt = datetime!(32767-12-01 12:00 -05:00);
+        let expected = datetime!(32767-12-31 00:00 -05:00).with_time(Time::MAX);
+        assert_eq!(dt.saturating_add(1.months()), expected);
+        assert_eq!(dt.saturating_add(1.months()).offset(), &utc_offset!(-05:00));
+
+        let dt = datetime!(2022-01-31 12:00 -05:00);
+        assert_eq!(dt.saturating_add(1.months()), dt + 1.months());
+        assert_eq!(dt.saturating_sub(25.hours()), dt - 25.hours());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        use crate::ext::IntervalLiteral;
+
+        let max = DateTime::MAX;
+        assert_eq!(max.checked_add(Interval::ZERO), Some(max));
+        assert_eq!(max.checked_add(1.microseconds()), None);
+        assert_eq!(max.checked_add(1.days()), None);
+        assert_eq!(max.checked_add(1.months()), None);
+        assert_eq!(max.checked_add(Interval::from_days(i32::MAX)), None);
+        assert_eq!(max.checked_sub(Interval::from_months(i32::MIN)), None);
+        assert_eq!(max.checked_sub(Interval::from_days(i32::MIN)), None);
+        assert_eq!(max.checked_add_duration(Duration::from_nanos(1)), None);
+        assert_eq!(max.checked_add_duration(Duration::MAX), None);
+        assert_eq!(max.checked_sub(1.days()), Some(Date::MAX.add_days(-1).at(Time::MAX)));
+
+        let min = Date::MIN.at(Time::MIN);
+        assert_eq!(min.checked_sub(1.microseconds()), None);
+        assert_eq!(min.checked_add((-1).years()), None);
+        assert_eq!(min.checked_sub_duration(Duration::MAX), None);
+        assert_eq!(min.checked_add(1.days()), Some(Date::MIN.add_days(1).at(Time::MIN)));
+
+        let dt = datetime!(2022-01-31 12:00 -05:00);
+        assert_eq!(dt.checked_add(1.months()), Some(dt + 1.months()));
+        assert_eq!(dt.checked_sub(25.hours()), Some(dt - 25.hours()));
+        assert_eq!(dt.checked_add((-25).hours()), Some(dt - 25.hours()));
+        assert_eq!(
+            dt.checked_add_duration(Duration::from_secs(90000)),
+            Some(datetime!(2022-02-01 13:00 -05:00))
+        );
+    }
+
+    #[test]
+    fn test_first_and_last_of() {
+        let dt = datetime!(2023-02-14 13:45:10 +09:00);
+        assert_eq!(dt.first_of_month(), datetime!(2023-02-01 13:45:10 +09:00));
+        assert_eq!(dt.last_of_month(), datetime!(2023-02-28 13:45:10 +09:00));
+        assert_eq!(dt.first_of_year(), datetime!(2023-01-01 13:45:10 +09:00));
+        assert_eq!(dt.last_of_year(), datetime!(2023-12-31 13:45:10 +09:00));
+        assert!(dt.last_of_month().is_last_of_month());
+        assert!(dt.first_of_month().is_first_of_month());
+        assert!(!dt.is_first_of_month() && !dt.is_last_of_month());
+        assert_eq!(DateTime::MAX.first_of_year().last_of_year(), DateTime::MAX);
+    }
+
+    #[test]
+    fn test_tomorrow_and_yesterday() {
+        let dt = datetime!(2024-02-28 13:45:10 -05:00);
+        assert_eq!(dt.tomorrow(), datetime!(2024-02-29 13:45:10 -05:00));
+        assert_eq!(dt.tomorrow().tomorrow(), datetime!(2024-03-01 13:45:10 -05:00));
+        assert_eq!(dt.yesterday(), datetime!(2024-02-27 13:45:10 -05:00));
+        assert_eq!(datetime!(2023-03-01 00:00).yesterday(), datetime!(2023-02-28 00:00));
+        assert_eq!(
+            datetime!(2023-12-31 23:59:59).tomorrow(),
+            datetime!(2024-01-01 23:59:59)
+        );
+        assert_eq!(
+            datetime!(2024-01-01 00:00).checked_yesterday(),
+            Some(datetime!(2023-12-31 00:00))
+        );
+
+        assert_eq!(DateTime::MAX.tomorrow(), DateTime::MAX);
+        assert_eq!(DateTime::MIN.yesterday(), DateTime::MIN);
+        assert_eq!(DateTime::MAX.checked_tomorrow(), None);
+        assert_eq!(DateTime::MIN.checked_yesterday(), None);
+        assert_eq!(DateTime::MAX.yesterday().checked_tomorrow(), Some(DateTime::MAX));
+        assert_eq!(DateTime::MIN.tomorrow().checked_yesterday(), Some(DateTime::MIN));
+    }
+
+    #[test]
+    fn test_start_and_end_of() {
+        let dt = datetime!(2024-02-14 13:45:10 -05:00);
+        let end = |dt: DateTime<UtcOffset>| dt.with_time(Time::MAX);
+        assert_eq!(dt.start_of_day(), datetime!(2024-02-14 00:00 -05:00));
+        assert_eq!(dt.end_of_day(), end(datetime!(2024-02-14 00:00 -05:00)));
+        assert_eq!(dt.start_of_month(), datetime!(2024-02-01 00:00 -05:00));
+        assert_eq!(dt.end_of_month(), end(datetime!(2024-02-29 00:00 -05:00)));
+        assert_eq!(dt.start_of_quarter(), datetime!(2024-01-01 00:00 -05:00));
+        assert_eq!(dt.end_of_quarter(), end(datetime!(2024-03-31 00:00 -05:00)));
+        assert_eq!(dt.start_of_year(), datetime!(2024-01-01 00:00 -05:00));
+        assert_eq!(dt.end_of_year(), end(datetime!(2024-12-31 00:00 -05:00)));
+        assert_eq!(dt.end_of_day().offset(), &utc_offset!(-05:00));
+
+        // February 14th 2024 was a Wednesday
+        assert_eq!(dt.start_of_week(Weekday::Monday), datetime!(2024-02-12 00:00 -05:00));
+        assert_eq!(dt.start_of_week(Weekday::Thursday), datetime!(2024-02-08 00:00 -05:00));
+        assert_eq!(dt.start_of_week(Weekday::Wednesday), datetime!(2024-02-14 00:00 -05:00));
+        assert_eq!(dt.end_of_week(Weekday::Monday).date(), &date!(2024 - 02 - 18));
+        assert_eq!(dt.end_of_week(Weekday::Thursday).date(), &date!(2024 - 02 - 14));
+        assert_eq!(dt.end_of_week(Weekday::Wednesday).date(), &date!(2024 - 02 - 20));
+
+        assert_eq!(
+            datetime!(2023-02-14 00:00).end_of_month().date(),
+            &date!(2023 - 02 - 28)
+        );
+        assert_eq!(
+            datetime!(2100-02-14 00:00).end_of_month().date(),
+            &date!(2100 - 02 - 28)
+        );
+        assert_eq!(
+            datetime!(2000-02-14 00:00).end_of_month().date(),
+            &date!(2000 - 02 - 29)
+        );
+        assert_eq!(
+            datetime!(2021-11-30 00:00).end_of_quarter().date(),
+            &date!(2021 - 12 - 31)
+        );
+        assert_eq!(
+            datetime!(2021-11-30 00:00).start_of_quarter().date(),
+            &date!(2021 - 10 - 01)
+        );
+
+        // Nothing overflows at the edges
+        assert_eq!(DateTime::MAX.end_of_year(), DateTime::MAX);
+        assert_eq!(DateTime::MAX.end_of_day(), DateTime::MAX);
+        assert_eq!(DateTime::MAX.end_of_week(Weekday::Monday), DateTime::MAX);
+        assert_eq!(DateTime::MIN.start_of_week(Weekday::Monday), DateTime::MIN);
+        assert_eq!(DateTime::MIN.start_of_year(), DateTime::MIN);
+    }
+
+    #[test]
+    fn test_subsecond_precision() {
+        let dt = datetime!(2021-12-31 23:59:59 -05:00)
+            .with_nanosecond(999_600_000)
+            .unwrap();
+        assert_eq!(
+            dt.truncate_subsec(SubsecondPrecision::Millisecond),
+            datetime!(2021-12-31 23:59:59 -05:00)
+                .with_nanosecond(999_000_000)
+                .unwrap()
+        );
+        assert_eq!(
+            dt.with_precision_rounded(SubsecondPrecision::Millisecond),
+            datetime!(2022-01-01 00:00 -05:00)
+        );
+        assert_eq!(dt.with_precision_rounded(SubsecondPrecision::Microsecond), dt);
+        assert_eq!(
+            dt.with_precision_rounded(SubsecondPrecision::Second).date(),
+            &date!(2022 - 01 - 01)
+        );
+
+        // There is no day after the maximum date to carry into
+        assert_eq!(
+            DateTime::MAX.with_precision_rounded(SubsecondPrecision::Second),
+            DateTime::MAX
+        );
+        let dt = DateTime::MAX.with_timezone(utc_offset!(-05:00));
+        let rounded = dt.with_precision_rounded(SubsecondPrecision::Millisecond);
+        assert_eq!(rounded, dt);
+        assert_eq!(rounded.offset(), &utc_offset!(-05:00));
+
+        // Ties round up, anything below doesn't
+        let t = time!(10:00:00).with_nanosecond(500).unwrap();
+        assert_eq!(
+            t.with_precision_rounded(SubsecondPrecision::Microsecond),
+            (0, time!(10:00).with_nanosecond(1_000).unwrap())
+        );
+        let t = time!(10:00:00).with_nanosecond(499).unwrap();
+        assert_eq!(
+            t.with_precision_rounded(SubsecondPrecision::Microsecond),
+            (0, time!(10:00))
+        );
+
+        // Leap seconds carry into the next minute
+        let leap = time!(23:59:59).with_nanosecond(1_999_600_000).unwrap();
+        assert_eq!(
+            leap.with_precision_rounded(SubsecondPrecision::Millisecond),
+            (1, time!(00:00))
+        );
+        assert_eq!(
+            leap.truncate_subsec(SubsecondPrecision::Second).nanosecond(),
+            1_000_000_000
+        );
+        let leap = time!(23:59:59).with_nanosecond(1_400_000_000).unwrap();
+        let expected = leap.truncate_subsec(SubsecondPrecision::Second);
+        assert_eq!(leap.with_precision_rounded(SubsecondPrecision::Second), (0, expected));
+    }
+
+    #[test]
+    fn test_add_wall_clock() {
+        // Crossing midnight and the end of a month keeps the offset
+        let dt = datetime!(2022-01-31 23:59:30 +09:00).add_wall_clock(utc_offset!(+00:00:45));
+        assert_eq!(dt.date(), &date!(2022 - 02 - 01));
+        assert_eq!(dt.time(), &time!(00:00:15));
+        assert_eq!(dt.offset(), &utc_offset!(+09:00));
+
+        // Going backwards through a leap day and the end of a year
+        let dt = datetime!(2024-03-01 00:15 -05:00).add_wall_clock(utc_offset!(-00:30));
+        assert_eq!((dt.date(), dt.time()), (&date!(2024 - 02 - 29), &time!(23:45)));
+        let dt = datetime!(2022-01-01 00:00).add_wall_clock(utc_offset!(-24:00));
+        assert_eq!(dt, datetime!(2021-12-31 00:00));
+
+        // The instant changes by the same amount
+        let dt = datetime!(2022-06-30 22:00 -07:00);
+        let shifted = dt.add_wall_clock(utc_offset!(+03:00));
+        assert_eq!(shifted.duration_since(&dt), Ok(Duration::from_secs(3 * 3600)));
+        assert_eq!(dt.add_wall_clock(UtcOffset::UTC), dt);
+    }
 }
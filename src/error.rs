@@ -1,36 +1,136 @@
 use crate::{Date, Time};
 
 /// The error type for most errors that can be encountered when using the library.
+///
+/// When a single field such as a month or an hour is the problem, [`Error::FieldOutOfRange`]
+/// is returned so that the field can be told apart programmatically:
+///
+/// ```
+/// use eos::{Date, Error};
+///
+/// match Date::try_new(2021, 2, 29) {
+///     Err(Error::FieldOutOfRange { field, value, max, .. }) => {
+///         assert_eq!(field, "day");
+///         assert_eq!(value, 29);
+///         assert_eq!(max, 28);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub enum Error {
     /// Construction or modification of a date or time was out of range.
     ///
     /// This is mainly returned when using the [`crate::Builder`] interface,
-    /// most out of bound errors are done using [`Option`] instead.
+    /// most out of bound errors are done using [`Option`] instead. When a
+    /// specific field is out of range then [`Error::FieldOutOfRange`] is
+    /// returned instead.
     OutOfRange,
+    /// A field of a date or time was outside of its valid range.
+    FieldOutOfRange {
+        /// The name of the field, such as `"month"` or `"day"`.
+        field: &'static str,
+        /// The value that was given.
+        value: i64,
+        /// The smallest valid value.
+        min: i64,
+        /// The largest valid value. This can depend on the other fields,
+        /// e.g. the number of days in the month.
+        max: i64,
+    },
     /// Could not get the system time or timezone information
     NoSystemTime,
     /// The [`DateTime`] cannot be represented.
     ///
     /// [`DateTime`]: crate::DateTime
+    #[doc(alias = "SkippedLocalTime")]
     SkippedDateTime(Date, Time),
     /// The [`DateTime`] is ambiguous.
     ///
     /// [`DateTime`]: crate::DateTime
+    #[doc(alias = "AmbiguousLocalTime")]
     AmbiguousDateTime(Date, Time),
+    /// More than one way of specifying the same value was given.
+    ///
+    /// This is returned when using the [`crate::Builder`] interface, e.g. when both
+    /// a month and day and an ordinal day are given.
+    ConflictingFields,
+    /// A string could not be parsed.
+    #[cfg(feature = "parsing")]
+    Parse(crate::fmt::ParseError),
+    /// A format specifier string was invalid.
+    #[cfg(any(feature = "formatting", feature = "parsing"))]
+    FormatSpec(crate::fmt::Error),
+    /// A POSIX TZ string could not be parsed.
+    #[cfg(feature = "posix")]
+    PosixTz(crate::extra::PosixTzError),
+}
+
+impl Error {
+    /// Returns [`Error::FieldOutOfRange`] if the value is not within `min..=max`.
+    pub(crate) const fn check_field(field: &'static str, value: i64, min: i64, max: i64) -> Result<(), Self> {
+        if value < min || value > max {
+            Err(Self::FieldOutOfRange { field, value, min, max })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::OutOfRange => f.write_str("value out of range"),
+            Error::FieldOutOfRange { field, value, min, max } => {
+                write!(f, "{} {} is out of range, expected {}..={}", field, value, min, max)
+            }
             Error::NoSystemTime => f.write_str("could not fetch system time or timezone"),
             Error::SkippedDateTime(date, time) => write!(f, "{}T{} was skipped", date, time),
             Error::AmbiguousDateTime(date, time) => write!(f, "{}T{} is ambiguous", date, time),
+            Error::ConflictingFields => f.write_str("conflicting fields were given"),
+            #[cfg(feature = "parsing")]
+            Error::Parse(e) => write!(f, "parse error: {}", e),
+            #[cfg(any(feature = "formatting", feature = "parsing"))]
+            Error::FormatSpec(e) => write!(f, "invalid format spec: {}", e),
+            #[cfg(feature = "posix")]
+            Error::PosixTz(e) => e.fmt(f),
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {}
+#[cfg(feature = "parsing")]
+impl From<crate::fmt::ParseError> for Error {
+    fn from(e: crate::fmt::ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+impl From<crate::fmt::Error> for Error {
+    fn from(e: crate::fmt::Error) -> Self {
+        Self::FormatSpec(e)
+    }
+}
+
+#[cfg(feature = "posix")]
+impl From<crate::extra::PosixTzError> for Error {
+    fn from(e: crate::extra::PosixTzError) -> Self {
+        Self::PosixTz(e)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "nightly"))]
+impl crate::utils::ErrorTrait for Error {
+    fn source(&self) -> Option<&(dyn crate::utils::ErrorTrait + 'static)> {
+        match self {
+            #[cfg(feature = "parsing")]
+            Error::Parse(e) => Some(e),
+            #[cfg(any(feature = "formatting", feature = "parsing"))]
+            Error::FormatSpec(e) => Some(e),
+            #[cfg(feature = "posix")]
+            Error::PosixTz(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,19 @@
 //! Extension traits to numeric types for [`Interval`] construction.
-
-use crate::Interval;
+//!
+//! This module is meant to be glob imported, similar to a prelude:
+//!
+//! ```rust
+//! use eos::{datetime, ext::*};
+//!
+//! let dt = datetime!(2022-01-31 10:00);
+//! assert_eq!(dt + 3.days() + 4.hours(), datetime!(2022-02-03 14:00));
+//! assert_eq!(dt + 1.months(), datetime!(2022-02-28 10:00));
+//! ```
+
+use crate::{
+    interval::{MICROS_PER_HOUR, MICROS_PER_MIN, MICROS_PER_SEC},
+    Interval,
+};
 
 mod private {
     pub trait Sealed {}
@@ -13,7 +26,15 @@ mod private {
 
 /// A trait that allows you to create [`Interval`] from integer literals.
 ///
-/// This is merely syntax sugar.
+/// This is merely syntax sugar for the `Interval::from_*` constructors. No calendar math is done
+/// when creating the interval, e.g. `1.months()` is just "one month" until it's added to a date.
+/// Values that do not fit in the interval are saturated to the bounds.
+///
+/// Trait methods cannot be `const`, so the [`Interval`] constructors such as
+/// [`Interval::from_days`] should be used in `const` contexts instead.
+///
+/// This is implemented for `i32`, `i64`, and `u32`.
+///
 /// # Examples
 ///
 /// Basic construction:
@@ -29,6 +50,7 @@ mod private {
 /// assert_eq!(10.seconds(), Interval::from_seconds(10));
 /// assert_eq!(10.milliseconds(), Interval::from_milliseconds(10));
 /// assert_eq!(10.microseconds(), Interval::from_microseconds(10));
+/// assert_eq!(10_000.nanoseconds(), Interval::from_nanoseconds(10_000));
 /// ```
 ///
 /// Negative numbers:
@@ -44,6 +66,7 @@ mod private {
 /// assert_eq!((-10).seconds(), Interval::from_seconds(-10));
 /// assert_eq!((-10).milliseconds(), Interval::from_milliseconds(-10));
 /// assert_eq!((-10).microseconds(), Interval::from_microseconds(-10));
+/// assert_eq!((-10_000).nanoseconds(), Interval::from_nanoseconds(-10_000));
 /// ```
 ///
 /// Arithmetic:
@@ -80,6 +103,22 @@ pub trait IntervalLiteral: private::Sealed {
 
     /// Creates a [`Interval`] representing the specified number of microseconds.
     fn microseconds(self) -> Interval;
+
+    /// Creates a [`Interval`] representing the specified number of nanoseconds.
+    ///
+    /// The nanoseconds are truncated toward zero, see [`Interval::from_nanoseconds`].
+    fn nanoseconds(self) -> Interval;
+}
+
+#[inline]
+const fn saturate_i32(value: i64) -> i32 {
+    if value > i32::MAX as i64 {
+        i32::MAX
+    } else if value < i32::MIN as i64 {
+        i32::MIN
+    } else {
+        value as i32
+    }
 }
 
 macro_rules! impl_for_literal {
@@ -88,51 +127,56 @@ macro_rules! impl_for_literal {
             impl IntervalLiteral for $type {
                 #[inline]
                 fn years(self) -> Interval {
-                    Interval::from_years(self as _)
+                    Interval::from_months(saturate_i32((self as i64).saturating_mul(12)))
                 }
 
                 #[inline]
                 fn days(self) -> Interval {
-                    Interval::from_days(self as _)
+                    Interval::from_days(saturate_i32(self as i64))
                 }
 
                 #[inline]
                 fn months(self) -> Interval {
-                    Interval::from_months(self as _)
+                    Interval::from_months(saturate_i32(self as i64))
                 }
 
                 #[inline]
                 fn weeks(self) -> Interval {
-                    Interval::from_weeks(self as _)
+                    Interval::from_days(saturate_i32((self as i64).saturating_mul(7)))
                 }
 
                 #[inline]
                 fn hours(self) -> Interval {
-                    Interval::from_hours(self as _)
+                    Interval::from_microseconds((self as i64).saturating_mul(MICROS_PER_HOUR))
                 }
 
                 #[inline]
                 fn minutes(self) -> Interval {
-                    Interval::from_minutes(self as _)
+                    Interval::from_microseconds((self as i64).saturating_mul(MICROS_PER_MIN))
                 }
 
                 #[inline]
                 fn seconds(self) -> Interval {
-                    Interval::from_seconds(self as _)
+                    Interval::from_microseconds((self as i64).saturating_mul(MICROS_PER_SEC))
                 }
 
                 #[inline]
                 fn milliseconds(self) -> Interval {
-                    Interval::from_milliseconds(self as _)
+                    Interval::from_milliseconds(self as i64)
                 }
 
                 #[inline]
                 fn microseconds(self) -> Interval {
-                    Interval::from_microseconds(self as _)
+                    Interval::from_microseconds(self as i64)
+                }
+
+                #[inline]
+                fn nanoseconds(self) -> Interval {
+                    Interval::from_nanoseconds(self as i64)
                 }
             }
         )+
     };
 }
 
-impl_for_literal!(i32);
+impl_for_literal!(i32 i64 u32);
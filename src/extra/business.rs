@@ -0,0 +1,256 @@
+use crate::Date;
+
+use super::WeekdaySet;
+
+/// A calendar of working days, used for business day arithmetic.
+///
+/// A business day is a date that falls on one of the working weekdays and is not
+/// one of the holidays. The holidays are borrowed as a sorted slice, so a calendar
+/// can be built without allocating.
+///
+/// Dates outside of the supported range saturate to [`Date::MIN`] or [`Date::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// use eos::date;
+/// use eos::extra::{BusinessCalendar, WeekdaySet};
+///
+/// let holidays = [date!(2022-12-26), date!(2023-01-02)];
+/// let calendar = BusinessCalendar::new(WeekdaySet::WEEKDAYS, &holidays).unwrap();
+///
+/// // T+2 settlement for a trade on Friday, December 23rd 2022
+/// let trade = date!(2022-12-23);
+/// assert_eq!(calendar.add_business_days(trade, 2), date!(2022-12-28));
+/// assert_eq!(calendar.add_business_days(date!(2022-12-28), -2), trade);
+/// assert_eq!(calendar.business_days_between(&trade, &date!(2022-12-28)), 2);
+/// assert!(!calendar.is_business_day(&date!(2022-12-26)));
+/// assert_eq!(calendar.next_business_day(date!(2022-12-30)), date!(2023-01-03));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar<'a> {
+    working_days: WeekdaySet,
+    holidays: &'a [Date],
+}
+
+impl<'a> BusinessCalendar<'a> {
+    /// Creates a new [`BusinessCalendar`] from the working weekdays and a list of holidays.
+    ///
+    /// The holidays must be sorted in ascending order without duplicates, otherwise
+    /// [`None`] is returned. Holidays that fall outside of the working weekdays are
+    /// allowed and have no effect.
+    pub fn new(working_days: WeekdaySet, holidays: &'a [Date]) -> Option<Self> {
+        if holidays.windows(2).all(|w| w[0] < w[1]) {
+            Some(Self { working_days, holidays })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the working weekdays of this calendar.
+    pub fn working_days(&self) -> WeekdaySet {
+        self.working_days
+    }
+
+    /// Returns the holidays of this calendar, in ascending order.
+    pub fn holidays(&self) -> &'a [Date] {
+        self.holidays
+    }
+
+    /// Returns `true` if the date is a working weekday and not a holiday.
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        date.weekday_in(&self.working_days) && self.holidays.binary_search(date).is_err()
+    }
+
+    /// Returns the first business day strictly after the given date.
+    ///
+    /// If there is no such date then [`Date::MAX`] is returned.
+    pub fn next_business_day(&self, date: Date) -> Date {
+        let mut date = date;
+        loop {
+            date = match date.next_date_in_set(&self.working_days) {
+                Some(d) => d,
+                None => return Date::MAX,
+            };
+            if self.holidays.binary_search(&date).is_err() {
+                return date;
+            }
+        }
+    }
+
+    /// Returns the last business day strictly before the given date.
+    ///
+    /// If there is no such date then [`Date::MIN`] is returned.
+    pub fn previous_business_day(&self, date: Date) -> Date {
+        let mut date = date;
+        loop {
+            let days = match self.working_days.days_since_previous(date.weekday()) {
+                Some(days) => days,
+                None => return Date::MIN,
+            };
+            date = match date.checked_add_days(-(days as i32)) {
+                Some(d) => d,
+                None => return Date::MIN,
+            };
+            if self.holidays.binary_search(&date).is_err() {
+                return date;
+            }
+        }
+    }
+
+    /// Moves the date by the given number of business days.
+    ///
+    /// A positive count moves forward and a negative count moves backward, skipping
+    /// over any non-business days. The starting date does not need to be a business day,
+    /// e.g. adding one business day to a Saturday returns the following Monday. If the
+    /// count is zero then the date is returned unchanged.
+    ///
+    /// If the result would be out of range then [`Date::MAX`] or [`Date::MIN`] is returned.
+    pub fn add_business_days(&self, date: Date, days: i32) -> Date {
+        let per_week = self.working_days.len() as i64;
+        let mut date = date;
+        let mut remaining = (days as i64).abs();
+        let (step, limit) = if days < 0 { (-1, Date::MIN) } else { (1, Date::MAX) };
+        if per_week == 0 && remaining != 0 {
+            return limit;
+        }
+
+        while remaining > 0 {
+            if remaining > per_week {
+                // Skip whole weeks without overshooting, holidays are made up for afterwards
+                let weeks = (remaining - 1) / per_week;
+                let target = match i32::try_from(weeks * 7 * step)
+                    .ok()
+                    .and_then(|days| date.checked_add_days(days))
+                {
+                    Some(target) => target,
+                    None => return limit,
+                };
+                remaining -= self.business_days_between(&date, &target).abs() as i64;
+                date = target;
+            } else {
+                date = if step > 0 {
+                    self.next_business_day(date)
+                } else {
+                    self.previous_business_day(date)
+                };
+                remaining -= 1;
+            }
+        }
+        date
+    }
+
+    /// Returns the number of business days between two dates.
+    ///
+    /// This counts the business days after `start` up to and including `end`, so that
+    /// adding the result to `start` with [`Self::add_business_days`] lands on `end` if it
+    /// is a business day. If `end` is before `start` then the result is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::date;
+    /// use eos::extra::{BusinessCalendar, WeekdaySet};
+    ///
+    /// let calendar = BusinessCalendar::new(WeekdaySet::WEEKDAYS, &[]).unwrap();
+    /// // 2023-01-06 is a Friday
+    /// assert_eq!(calendar.business_days_between(&date!(2023-01-06), &date!(2023-01-09)), 1);
+    /// assert_eq!(calendar.business_days_between(&date!(2023-01-09), &date!(2023-01-06)), -1);
+    /// assert_eq!(calendar.business_days_between(&date!(2023-01-06), &date!(2023-01-08)), 0);
+    /// ```
+    pub fn business_days_between(&self, start: &Date, end: &Date) -> i32 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+
+        let (weeks, rest) = (end.days_since(start) / 7, end.days_since(start) % 7);
+        let weekdays = weeks * self.working_days.len() as i32
+            + self.working_days.count_following(start.weekday(), rest as u8) as i32;
+        let lo = self.holidays.partition_point(|d| d <= start);
+        let hi = self.holidays.partition_point(|d| d <= end);
+        let holidays = self.holidays[lo..hi]
+            .iter()
+            .filter(|d| d.weekday_in(&self.working_days))
+            .count();
+        weekdays - holidays as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date, Weekday};
+
+    #[test]
+    fn test_add_business_days() {
+        let holidays = [
+            date!(2022 - 12 - 26),
+            date!(2022 - 12 - 31),
+            date!(2023 - 01 - 02),
+            date!(2023 - 01 - 16),
+        ];
+        let calendar = BusinessCalendar::new(WeekdaySet::WEEKDAYS, &holidays).unwrap();
+
+        // 2022-12-24 is a Saturday
+        let saturday = date!(2022 - 12 - 24);
+        assert_eq!(calendar.add_business_days(saturday, 0), saturday);
+        assert_eq!(calendar.add_business_days(saturday, 1), date!(2022 - 12 - 27));
+        assert_eq!(calendar.add_business_days(saturday, -1), date!(2022 - 12 - 23));
+        assert_eq!(
+            calendar.add_business_days(date!(2022 - 12 - 27), -1),
+            date!(2022 - 12 - 23)
+        );
+        assert_eq!(
+            calendar.add_business_days(date!(2023 - 01 - 03), -1),
+            date!(2022 - 12 - 30)
+        );
+        assert_eq!(
+            calendar.add_business_days(date!(2022 - 12 - 30), 1),
+            date!(2023 - 01 - 03)
+        );
+        assert_eq!(
+            calendar.previous_business_day(date!(2023 - 01 - 03)),
+            date!(2022 - 12 - 30)
+        );
+
+        // Compare against stepping one day at a time
+        let start = date!(2022 - 11 - 01);
+        for days in -80i32..=80 {
+            let mut expected = start;
+            for _ in 0..days.abs() {
+                expected = expected.add_days(days.signum());
+                while !calendar.is_business_day(&expected) {
+                    expected = expected.add_days(days.signum());
+                }
+            }
+            let result = calendar.add_business_days(start, days);
+            assert_eq!(result, expected, "{}", days);
+            assert_eq!(calendar.business_days_between(&start, &result), days);
+        }
+
+        let sundays = BusinessCalendar::new(WeekdaySet::from(Weekday::Sunday), &[]).unwrap();
+        assert_eq!(sundays.add_business_days(saturday, 1), date!(2022 - 12 - 25));
+        assert_eq!(sundays.add_business_days(saturday, -2), date!(2022 - 12 - 11));
+        assert_eq!(sundays.add_business_days(saturday, 1000), date!(2042 - 02 - 16));
+        assert_eq!(sundays.business_days_between(&saturday, &date!(2042 - 02 - 16)), 1000);
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert!(BusinessCalendar::new(WeekdaySet::ALL, &[date!(2022 - 01 - 02), date!(2022 - 01 - 01)]).is_none());
+        assert!(BusinessCalendar::new(WeekdaySet::ALL, &[date!(2022 - 01 - 01), date!(2022 - 01 - 01)]).is_none());
+
+        let calendar = BusinessCalendar::new(WeekdaySet::WEEKDAYS, &[]).unwrap();
+        assert_eq!(calendar.add_business_days(Date::MIN, i32::MAX), Date::MAX);
+        assert_eq!(calendar.add_business_days(Date::MAX, i32::MIN), Date::MIN);
+        assert_eq!(calendar.next_business_day(Date::MAX), Date::MAX);
+        assert_eq!(calendar.previous_business_day(Date::MIN), Date::MIN);
+        assert_eq!(calendar.business_days_between(&Date::MIN, &Date::MAX), 17_097_521);
+
+        let empty = BusinessCalendar::new(WeekdaySet::EMPTY, &[]).unwrap();
+        assert_eq!(empty.add_business_days(Date::MIN, 1), Date::MAX);
+        assert_eq!(empty.add_business_days(Date::MAX, -1), Date::MIN);
+        assert_eq!(empty.business_days_between(&Date::MIN, &Date::MAX), 0);
+        assert!(!empty.is_business_day(&Date::MIN));
+    }
+}
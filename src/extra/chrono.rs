@@ -0,0 +1,97 @@
+//! Conversions to and from [`chrono`](https://docs.rs/chrono) types.
+//!
+//! This allows eos and chrono to be used side by side while migrating between them.
+//! The conversions are as follows:
+//!
+//! | eos               | chrono                            | eos to chrono | chrono to eos |
+//! |:------------------|:----------------------------------|:--------------|:--------------|
+//! | [`Date`]          | [`NaiveDate`]                     | `From`        | `TryFrom`     |
+//! | [`Time`]          | [`NaiveTime`]                     | `From`        | `From`        |
+//! | [`DateTime<Utc>`] | [`chrono::DateTime<chrono::Utc>`] | `From`        | `TryFrom`     |
+//! | [`UtcOffset`]     | [`FixedOffset`]                   | `TryFrom`     | `From`        |
+//!
+//! chrono supports a wider range of years than the `i16` used by eos, so converting a
+//! date outside of that range returns [`Error::FieldOutOfRange`] for the year. chrono
+//! does not allow an offset of exactly 24 hours, so [`UtcOffset::MIN`] and
+//! [`UtcOffset::MAX`] can't be converted either.
+//!
+//! # Leap seconds
+//!
+//! Both libraries represent a leap second as second 59 with a nanosecond value of
+//! `1_000_000_000` or more, so leap seconds are kept as-is in both directions. chrono
+//! only allows this for second 59, so any other [`Time`] with a nanosecond value past
+//! `999_999_999` is clamped to the last nanosecond of that second instead.
+
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveTime, Timelike};
+
+use crate::{Date, DateTime, Error, Time, Utc, UtcOffset};
+
+impl From<Date> for NaiveDate {
+    fn from(date: Date) -> Self {
+        NaiveDate::from_ymd_opt(date.year() as i32, date.month() as u32, date.day() as u32)
+            .expect("every eos date is within chrono's range")
+    }
+}
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = Error;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        let year = date.year();
+        Error::check_field("year", year as i64, i16::MIN as i64, i16::MAX as i64)?;
+        Date::try_new(year as i16, date.month() as u8, date.day() as u8)
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> Self {
+        let (hour, minute, second) = (time.hour() as u32, time.minute() as u32, time.second() as u32);
+        NaiveTime::from_hms_nano_opt(hour, minute, second, time.nanosecond())
+            .or_else(|| NaiveTime::from_hms_nano_opt(hour, minute, second, 999_999_999))
+            .expect("every eos time is within chrono's range")
+    }
+}
+
+impl From<NaiveTime> for Time {
+    fn from(time: NaiveTime) -> Self {
+        Time {
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+            nanosecond: time.nanosecond(),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for chrono::DateTime<chrono::Utc> {
+    fn from(dt: DateTime<Utc>) -> Self {
+        let naive = NaiveDate::from(dt.date).and_time(NaiveTime::from(dt.time));
+        chrono::TimeZone::from_utc_datetime(&chrono::Utc, &naive)
+    }
+}
+
+impl TryFrom<chrono::DateTime<chrono::Utc>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        let naive = dt.naive_utc();
+        let date = Date::try_from(naive.date())?;
+        Ok(date.at(Time::from(naive.time())))
+    }
+}
+
+impl TryFrom<UtcOffset> for FixedOffset {
+    type Error = Error;
+
+    fn try_from(offset: UtcOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.total_seconds();
+        Error::check_field("offset", seconds as i64, -86_399, 86_399)?;
+        Ok(FixedOffset::east_opt(seconds).expect("offset was checked to be within chrono's range"))
+    }
+}
+
+impl From<FixedOffset> for UtcOffset {
+    fn from(offset: FixedOffset) -> Self {
+        UtcOffset::from_seconds_unchecked(offset.local_minus_utc())
+    }
+}
@@ -0,0 +1,216 @@
+use core::{cell::Cell, time::Duration};
+
+use crate::{DateTime, Utc};
+
+fn saturating_add(dt: DateTime<Utc>, duration: Duration) -> DateTime<Utc> {
+    dt.checked_add_duration(duration).unwrap_or(DateTime::MAX)
+}
+
+/// A source of the current time.
+///
+/// Code that asks a [`Clock`] for the time rather than calling [`DateTime::utc_now`]
+/// directly can be given a [`FixedClock`] or [`SteppingClock`] in tests to make them
+/// deterministic, while using [`SystemClock`] in production.
+///
+/// See also [`crate::now_in_with`] to get the current time in a specific timezone.
+///
+/// # Examples
+///
+/// ```
+/// use eos::datetime;
+/// use eos::extra::{Clock, FixedClock, WeekdaySet};
+///
+/// fn is_weekend(clock: &impl Clock) -> bool {
+///     clock.now().date().weekday_in(&WeekdaySet::WEEKEND)
+/// }
+///
+/// // 2022-01-01 is a Saturday
+/// assert!(is_weekend(&FixedClock::new(datetime!(2022-01-01 12:00))));
+/// assert!(!is_weekend(&FixedClock::new(datetime!(2022-01-03 12:00))));
+/// ```
+pub trait Clock {
+    /// Returns the current date and time in UTC.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
+
+/// A [`Clock`] that reads the system time.
+///
+/// This is equivalent to calling [`DateTime::utc_now`]. Note that the system time
+/// is not monotonic, it can go backwards if the system clock is adjusted.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        DateTime::utc_now()
+    }
+}
+
+/// A [`Clock`] that always returns the same time until it is changed manually.
+///
+/// This is meant for tests. The time is stored in a [`Cell`] so that it can be
+/// changed through a shared reference while the code under test holds onto the clock.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use eos::datetime;
+/// use eos::extra::{Clock, FixedClock};
+///
+/// let clock = FixedClock::new(datetime!(2022-01-01 00:00));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:00));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:00));
+///
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:01:30));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedClock {
+    now: Cell<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    /// Creates a new [`FixedClock`] that returns the given time.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now: Cell::new(now) }
+    }
+
+    /// Sets the time returned by the clock.
+    pub fn set(&self, now: DateTime<Utc>) {
+        self.now.set(now);
+    }
+
+    /// Moves the clock forward by the given duration.
+    ///
+    /// This saturates at [`DateTime::MAX`].
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(saturating_add(self.now.get(), duration));
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.now.get()
+    }
+}
+
+/// A [`Clock`] that moves forward by a fixed step every time it is read.
+///
+/// This is meant for tests where every call should observe a later time, e.g.
+/// for measuring elapsed time. The first read returns the starting time. Since the
+/// step is a [`Duration`], the clock never goes backwards.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use eos::datetime;
+/// use eos::extra::{Clock, SteppingClock};
+///
+/// let clock = SteppingClock::new(datetime!(2022-01-01 00:00), Duration::from_secs(1));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:00:00));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:00:01));
+///
+/// clock.advance(Duration::from_secs(60));
+/// assert_eq!(clock.now(), datetime!(2022-01-01 00:01:02));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteppingClock {
+    next: Cell<DateTime<Utc>>,
+    step: Duration,
+}
+
+impl SteppingClock {
+    /// Creates a new [`SteppingClock`] that starts at the given time and moves
+    /// forward by `step` every time it is read.
+    pub fn new(start: DateTime<Utc>, step: Duration) -> Self {
+        Self {
+            next: Cell::new(start),
+            step,
+        }
+    }
+
+    /// Returns the step the clock moves forward by when read.
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the time the next read will return without advancing the clock.
+    pub fn peek(&self) -> DateTime<Utc> {
+        self.next.get()
+    }
+
+    /// Moves the clock forward by the given duration, in addition to the regular steps.
+    ///
+    /// This saturates at [`DateTime::MAX`].
+    pub fn advance(&self, duration: Duration) {
+        self.next.set(saturating_add(self.next.get(), duration));
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> DateTime<Utc> {
+        let now = self.next.get();
+        self.next.set(saturating_add(now, self.step));
+        now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{datetime, now_in_with, utc_offset};
+
+    #[test]
+    fn test_fixed_clock() {
+        let clock = FixedClock::new(datetime!(2022-01-01 00:00));
+        let by_ref: &dyn Clock = &clock;
+        assert_eq!(by_ref.now(), datetime!(2022-01-01 00:00));
+        clock.set(datetime!(2023-06-01 12:00));
+        assert_eq!(Clock::now(&&clock), datetime!(2023-06-01 12:00));
+        assert_eq!(
+            now_in_with(&clock, utc_offset!(-05:00)),
+            datetime!(2023-06-01 07:00 -05:00)
+        );
+
+        clock.set(DateTime::MAX);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(clock.now(), DateTime::MAX);
+
+        let clock = SteppingClock::new(DateTime::MAX, Duration::from_secs(1));
+        assert_eq!(clock.now(), DateTime::MAX);
+        assert_eq!(clock.now(), DateTime::MAX);
+    }
+
+    #[test]
+    fn test_stepping_clock() {
+        let clock = SteppingClock::new(datetime!(2022-01-01 00:00), Duration::from_millis(500));
+        assert_eq!(clock.peek(), datetime!(2022-01-01 00:00));
+        let first = clock.now();
+        let second = clock.now();
+        assert_eq!(second.duration_since(&first), Ok(Duration::from_millis(500)));
+        assert_eq!(clock.peek(), datetime!(2022-01-01 00:00:01));
+        assert_eq!(
+            now_in_with(&clock, utc_offset!(+09:00)),
+            datetime!(2022-01-01 09:00:01 +09:00)
+        );
+        assert_eq!(clock.step(), Duration::from_millis(500));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_clock() {
+        let before = DateTime::utc_now();
+        let now = SystemClock.now();
+        assert!(before <= now && now <= DateTime::utc_now());
+    }
+}
@@ -0,0 +1,490 @@
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use crate::{fmt::ParseError, Date, DateTime, Time, TimeZone};
+
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Parses a single value in a field, either a number or a three letter name.
+fn parse_value(s: &str, min: u8, names: &[&str]) -> Result<u8, ParseError> {
+    match s.chars().next() {
+        None => Err(ParseError::UnexpectedEnd),
+        Some(c) if c.is_ascii_digit() => {
+            if let Some(c) = s.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(ParseError::UnexpectedChar(c));
+            }
+            s.parse().map_err(|_| ParseError::OutOfBounds)
+        }
+        Some(c) => names
+            .iter()
+            .position(|name| s.eq_ignore_ascii_case(name))
+            .map(|index| index as u8 + min)
+            .ok_or(ParseError::UnexpectedChar(c)),
+    }
+}
+
+/// Parses a field into a bitset of the allowed values and whether it starts with `*`.
+fn parse_field(field: &str, min: u8, max: u8, names: &[&str]) -> Result<(u64, bool), ParseError> {
+    let mut mask = 0u64;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(parse_value(step, 0, &[])?)),
+            None => (item, None),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, min, names)?, parse_value(end, min, names)?)
+        } else {
+            let value = parse_value(range, min, names)?;
+            (value, if step.is_some() { max } else { value })
+        };
+        if start < min || end > max || start > end || step == Some(0) {
+            return Err(ParseError::OutOfBounds);
+        }
+        for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok((mask, field.starts_with('*')))
+}
+
+/// Returns the smallest value in the bitset that is greater than or equal to `from`.
+fn next_in(mask: u64, from: u8) -> Option<u8> {
+    let shifted = mask.checked_shr(from as u32)?;
+    (shifted != 0).then(|| from + shifted.trailing_zeros() as u8)
+}
+
+/// Returns the largest value in the bitset that is less than or equal to `from`.
+fn prev_in(mask: u64, from: u8) -> Option<u8> {
+    let shifted = mask << (63 - from as u32);
+    (shifted != 0).then(|| from - shifted.leading_zeros() as u8)
+}
+
+/// A schedule described by a cron expression.
+///
+/// Both the classic 5 field syntax (`minute hour day-of-month month day-of-week`) and a 6 field
+/// syntax with a leading seconds field are supported. Every field accepts `*`, single values,
+/// ranges such as `1-5`, steps such as `*/15` or `10-30/5`, and comma separated lists of these.
+/// Months and weekdays can also be given by their three letter English names, such as `JAN`
+/// or `MON`. Weekdays go from 0 to 7 where both 0 and 7 are Sunday.
+///
+/// Like most cron implementations, if both the day of the month and the day of the week are
+/// restricted (i.e. neither starts with `*`) then a date matches if *either* of them match.
+///
+/// This requires the `cron` feature.
+///
+/// # Daylight saving time
+///
+/// The schedule is matched against the local wall clock time of the datetime's timezone.
+/// Wall clock times that are skipped by a transition, such as 02:30 when the clocks move
+/// from 02:00 to 03:00, do not fire at all. Wall clock times that happen twice when the
+/// clocks move backwards only fire once, on the earlier of the two instants.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, extra::Cron};
+///
+/// // Every 15 minutes between 02:00 and 04:59 on weekdays
+/// let cron: Cron = "*/15 2-4 * * MON-FRI".parse()?;
+/// let friday = datetime!(2022-03-11 04:50);
+/// assert_eq!(cron.next_after(&friday), Some(datetime!(2022-03-14 02:00)));
+/// assert_eq!(cron.prev_before(&friday), Some(datetime!(2022-03-11 04:45)));
+///
+/// let fires: Vec<_> = cron.after(datetime!(2022-03-14 02:50)).take(3).collect();
+/// assert_eq!(fires, [datetime!(2022-03-14 03:00), datetime!(2022-03-14 03:15), datetime!(2022-03-14 03:30)]);
+/// # Ok::<_, eos::fmt::ParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cron {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl Cron {
+    /// Parses a cron expression with either 5 or 6 fields.
+    ///
+    /// See the [type documentation](Cron) for the supported syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the expression is malformed, has the wrong
+    /// number of fields, or has values that are out of bounds for their field.
+    ///
+    /// ```
+    /// use eos::extra::Cron;
+    ///
+    /// assert!(Cron::parse("0 0 * * *").is_ok());
+    /// assert!(Cron::parse("30 0 0 1 JAN *").is_ok());
+    /// assert!(Cron::parse("0 24 * * *").is_err());
+    /// assert!(Cron::parse("* * *").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut fields = [""; 6];
+        let mut count = 0;
+        for field in s.split_ascii_whitespace() {
+            if count == fields.len() {
+                return Err(ParseError::UnexpectedChar(field.chars().next().unwrap_or(' ')));
+            }
+            fields[count] = field;
+            count += 1;
+        }
+        let fields = match count {
+            5 => ["0", fields[0], fields[1], fields[2], fields[3], fields[4]],
+            6 => fields,
+            _ => return Err(ParseError::UnexpectedEnd),
+        };
+
+        let (seconds, _) = parse_field(fields[0], 0, 59, &[])?;
+        let (minutes, _) = parse_field(fields[1], 0, 59, &[])?;
+        let (hours, _) = parse_field(fields[2], 0, 23, &[])?;
+        let (days, any_day) = parse_field(fields[3], 1, 31, &[])?;
+        let (months, _) = parse_field(fields[4], 1, 12, &MONTH_NAMES)?;
+        let (mut weekdays, any_weekday) = parse_field(fields[5], 0, 7, &WEEKDAY_NAMES)?;
+        // 7 is an alias for Sunday
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            seconds,
+            minutes,
+            hours,
+            days,
+            months,
+            weekdays,
+            any_day,
+            any_weekday,
+        })
+    }
+
+    /// Returns `true` if the schedule can never fire, e.g. on February 30th.
+    fn is_impossible(&self) -> bool {
+        if self.any_day || !self.any_weekday {
+            return false;
+        }
+        // 2000 is a leap year so every month has its maximum length
+        !(1..=12).any(|month| {
+            self.months & (1 << month) != 0
+                && next_in(self.days, 1).map_or(false, |day| day <= crate::gregorian::days_in_month(2000, month))
+        })
+    }
+
+    fn matches_date(&self, date: &Date) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().days_from_sunday()) != 0;
+        if self.any_day || self.any_weekday {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+
+    /// Returns the earliest matching time of the day at or after `from`.
+    fn next_time(&self, from: (u8, u8, u8)) -> Option<Time> {
+        let mut hour = next_in(self.hours, from.0)?;
+        loop {
+            let min_from = if hour == from.0 { from.1 } else { 0 };
+            let mut minute = next_in(self.minutes, min_from);
+            while let Some(m) = minute {
+                let sec_from = if hour == from.0 && m == from.1 { from.2 } else { 0 };
+                if let Some(second) = next_in(self.seconds, sec_from) {
+                    return Time::new(hour, m, second);
+                }
+                minute = next_in(self.minutes, m + 1);
+            }
+            hour = next_in(self.hours, hour + 1)?;
+        }
+    }
+
+    /// Returns the latest matching time of the day at or before `from`.
+    fn prev_time(&self, from: (u8, u8, u8)) -> Option<Time> {
+        let mut hour = prev_in(self.hours, from.0)?;
+        loop {
+            let min_from = if hour == from.0 { from.1 } else { 59 };
+            let mut minute = prev_in(self.minutes, min_from);
+            while let Some(m) = minute {
+                let sec_from = if hour == from.0 && m == from.1 { from.2 } else { 59 };
+                if let Some(second) = prev_in(self.seconds, sec_from) {
+                    return Time::new(hour, m, second);
+                }
+                minute = m.checked_sub(1).and_then(|m| prev_in(self.minutes, m));
+            }
+            hour = prev_in(self.hours, hour.checked_sub(1)?)?;
+        }
+    }
+
+    /// Resolves a matching wall clock time in the timezone, following the DST rules.
+    fn resolve<Tz: TimeZone>(dt: &DateTime<Tz>, date: Date, time: Time) -> Option<DateTime<Tz>> {
+        // Skipped times are an error and ambiguous times use the first occurrence
+        dt.timezone().clone().resolve(date, time).earlier().ok()
+    }
+
+    /// Returns the first time the schedule fires strictly after `dt`.
+    ///
+    /// Returns `None` if the schedule can never fire or if the next time is out of range.
+    pub fn next_after<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        if self.is_impossible() {
+            return None;
+        }
+        let mut date = *dt.date();
+        let mut from = (dt.hour(), dt.minute(), dt.second());
+        // Start from the next whole second
+        from = match from {
+            (23, 59, 59) => {
+                date = date.checked_add_days(1)?;
+                (0, 0, 0)
+            }
+            (h, 59, 59) => (h + 1, 0, 0),
+            (h, m, 59) => (h, m + 1, 0),
+            (h, m, s) => (h, m, s + 1),
+        };
+        loop {
+            if self.matches_date(&date) {
+                if let Some(time) = self.next_time(from) {
+                    match Self::resolve(dt, date, time) {
+                        Some(found) if found.cmp_cross_timezone(dt).is_gt() => return Some(found),
+                        _ => {}
+                    }
+                    if (time.hour(), time.minute(), time.second()) != (23, 59, 59) {
+                        from = (time.hour(), time.minute(), time.second() + 1);
+                        from = match from {
+                            (h, 59, 60) => (h + 1, 0, 0),
+                            (h, m, 60) => (h, m + 1, 0),
+                            other => other,
+                        };
+                        continue;
+                    }
+                }
+            }
+            date = date.checked_add_days(1)?;
+            from = (0, 0, 0);
+        }
+    }
+
+    /// Returns the last time the schedule fired strictly before `dt`.
+    ///
+    /// Returns `None` if the schedule can never fire or if the previous time is out of range.
+    pub fn prev_before<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        if self.is_impossible() {
+            return None;
+        }
+        // If the datetime is in the second pass of a repeated wall clock time then the
+        // first pass has already fired up to the same instant, which is later on the wall
+        // clock. Searching from the wall clock time in the earlier offset covers those.
+        let resolution = dt.timezone().clone().resolve(*dt.date(), *dt.time());
+        let (mut date, time) = if resolution.is_ambiguous() && dt.offset() == resolution.later_offset() {
+            let shifted = dt.clone().in_timezone(*resolution.earlier_offset());
+            (*shifted.date(), *shifted.time())
+        } else {
+            (*dt.date(), *dt.time())
+        };
+        let mut from = (time.hour(), time.minute(), time.second());
+        // A datetime with a fractional second is already after its own whole second
+        if dt.nanosecond() == 0 {
+            from = match from {
+                (0, 0, 0) => {
+                    date = date.checked_add_days(-1)?;
+                    (23, 59, 59)
+                }
+                (h, 0, 0) => (h - 1, 59, 59),
+                (h, m, 0) => (h, m - 1, 59),
+                (h, m, s) => (h, m, s - 1),
+            };
+        }
+        loop {
+            if self.matches_date(&date) {
+                if let Some(time) = self.prev_time(from) {
+                    match Self::resolve(dt, date, time) {
+                        Some(found) if found.cmp_cross_timezone(dt).is_lt() => return Some(found),
+                        _ => {}
+                    }
+                    if (time.hour(), time.minute(), time.second()) != (0, 0, 0) {
+                        from = match (time.hour(), time.minute(), time.second()) {
+                            (h, 0, 0) => (h - 1, 59, 59),
+                            (h, m, 0) => (h, m - 1, 59),
+                            (h, m, s) => (h, m, s - 1),
+                        };
+                        continue;
+                    }
+                }
+            }
+            date = date.checked_add_days(-1)?;
+            from = (23, 59, 59);
+        }
+    }
+
+    /// Returns an iterator over every time the schedule fires strictly after `dt`.
+    pub fn after<Tz: TimeZone>(&self, dt: DateTime<Tz>) -> CronIter<Tz> {
+        CronIter {
+            cron: *self,
+            last: Some(dt),
+        }
+    }
+}
+
+impl FromStr for Cron {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An iterator over the times a [`Cron`] schedule fires.
+///
+/// This struct is created via [`Cron::after`]. It ends once the next time is out of range.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CronIter<Tz: TimeZone> {
+    cron: Cron,
+    last: Option<DateTime<Tz>>,
+}
+
+impl<Tz: TimeZone> Iterator for CronIter<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.cron.next_after(self.last.as_ref()?);
+        self.last = next.clone();
+        next
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for CronIter<Tz> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date, datetime, time, Weekday};
+
+    #[test]
+    fn test_parse() {
+        let cron = Cron::parse("*/15 2-4 * * MON-FRI").unwrap();
+        assert_eq!(cron.seconds, 1);
+        assert_eq!(cron.minutes, (1 << 0) | (1 << 15) | (1 << 30) | (1 << 45));
+        assert_eq!(cron.hours, 0b11100);
+        assert_eq!(cron.weekdays, 0b0111110);
+        assert!(cron.any_day && !cron.any_weekday);
+
+        let cron = Cron::parse("5,10-20/5 0 0 1 jan,Jul 0,7").unwrap();
+        assert_eq!(cron.seconds, (1 << 5) | (1 << 10) | (1 << 15) | (1 << 20));
+        assert_eq!(cron.minutes, 1);
+        assert_eq!(cron.hours, 1);
+        assert_eq!(cron.days, 1 << 1);
+        assert_eq!(cron.months, (1 << 1) | (1 << 7));
+        assert_eq!(cron.weekdays, 1);
+        assert_eq!(
+            "5/20 * * * *".parse::<Cron>().unwrap().minutes,
+            (1 << 5) | (1 << 25) | (1 << 45)
+        );
+
+        assert_eq!(Cron::parse(""), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Cron::parse("* * * *"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Cron::parse("* * * * * * x"), Err(ParseError::UnexpectedChar('x')));
+        assert_eq!(Cron::parse("60 * * * *"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("* * 0 * *"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("* * * 13 *"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("* * * * 8"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("5-1 * * * *"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("*/0 * * * *"), Err(ParseError::OutOfBounds));
+        assert_eq!(Cron::parse("1,,2 * * * *"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Cron::parse("1a * * * *"), Err(ParseError::UnexpectedChar('a')));
+        assert_eq!(Cron::parse("* * * foo *"), Err(ParseError::UnexpectedChar('f')));
+    }
+
+    #[test]
+    fn test_next_and_prev() {
+        let cron = Cron::parse("0 9 * * *").unwrap();
+        let dt = datetime!(2022-03-10 09:00);
+        assert_eq!(cron.next_after(&dt), Some(datetime!(2022-03-11 09:00)));
+        assert_eq!(cron.prev_before(&dt), Some(datetime!(2022-03-09 09:00)));
+        let fraction = dt.with_nanosecond(1).unwrap();
+        assert_eq!(cron.prev_before(&fraction), Some(dt));
+        assert_eq!(cron.next_after(&datetime!(2022-03-10 08:59:59)), Some(dt));
+
+        // Both day fields restricted means either can match
+        let cron = Cron::parse("0 0 13 * FRI").unwrap();
+        let fires: Vec<_> = cron
+            .after(datetime!(2022-05-01 00:00))
+            .take(4)
+            .map(|dt| *dt.date())
+            .collect();
+        assert_eq!(
+            fires,
+            [
+                date!(2022 - 05 - 06),
+                date!(2022 - 05 - 13),
+                date!(2022 - 05 - 20),
+                date!(2022 - 05 - 27)
+            ]
+        );
+        let cron = Cron::parse("0 0 13 * *").unwrap();
+        assert_eq!(
+            cron.next_after(&datetime!(2022-05-01 00:00)).map(|dt| *dt.date()),
+            Some(date!(2022 - 05 - 13))
+        );
+        let cron = Cron::parse("0 0 * * FRI").unwrap();
+        assert!(cron
+            .after(datetime!(2022-05-01 00:00))
+            .take(10)
+            .all(|dt| dt.weekday() == Weekday::Friday));
+
+        // Seconds field
+        let cron = Cron::parse("*/20 * * * * *").unwrap();
+        let fires: Vec<_> = cron.after(datetime!(2022-01-01 23:59:30)).take(3).collect();
+        assert_eq!(
+            fires,
+            [
+                datetime!(2022-01-01 23:59:40),
+                datetime!(2022-01-02 00:00),
+                datetime!(2022-01-02 00:00:20)
+            ]
+        );
+        assert_eq!(
+            cron.prev_before(&datetime!(2022-01-02 00:00)),
+            Some(datetime!(2022-01-01 23:59:40))
+        );
+
+        // Leap days only
+        let cron = Cron::parse("0 12 29 2 *").unwrap();
+        assert_eq!(
+            cron.next_after(&datetime!(2097-03-01 00:00)),
+            Some(datetime!(2104-02-29 12:00))
+        );
+        assert_eq!(
+            cron.prev_before(&datetime!(2104-02-29 12:00)),
+            Some(datetime!(2096-02-29 12:00))
+        );
+
+        // Impossible schedules and the end of the range
+        let cron = Cron::parse("0 0 30 2 *").unwrap();
+        assert_eq!(cron.next_after(&datetime!(2022-01-01 00:00)), None);
+        assert_eq!(cron.prev_before(&datetime!(2022-01-01 00:00)), None);
+        let cron = Cron::parse("0 0 * * *").unwrap();
+        let end = Date::MAX.at(time!(00:00));
+        assert_eq!(cron.next_after(&end), None);
+        assert_eq!(cron.after(end).count(), 0);
+        assert_eq!(cron.prev_before(&Date::MIN.at(time!(00:00))), None);
+    }
+
+    #[test]
+    fn test_fixed_offsets() {
+        let cron = Cron::parse("30 1 * * *").unwrap();
+        let dt = datetime!(2022-03-10 01:00 +05:00);
+        assert_eq!(cron.next_after(&dt), Some(datetime!(2022-03-10 01:30 +05:00)));
+        assert_eq!(cron.prev_before(&dt), Some(datetime!(2022-03-09 01:30 +05:00)));
+    }
+}
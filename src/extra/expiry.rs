@@ -0,0 +1,194 @@
+use core::time::Duration;
+
+use crate::{utils::divmod, DateTime, TimeZone, Timestamp, Utc};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+fn timestamp_to_nanos(ts: Timestamp) -> i128 {
+    ts.as_seconds() as i128 * NANOS_PER_SEC + ts.nanoseconds as i128
+}
+
+fn timestamp_from_nanos(nanos: i128) -> Timestamp {
+    let (seconds, nanos) = divmod!(nanos, NANOS_PER_SEC);
+    Timestamp::new(seconds.clamp(i64::MIN as i128, i64::MAX as i128) as i64, nanos as u32)
+}
+
+/// A point in time after which something is considered expired.
+///
+/// This is a small wrapper around a [`Timestamp`] meant for caches and other
+/// time-to-live bookkeeping. All operations saturate rather than overflow, and
+/// an expiry that has already passed reports no time remaining rather than
+/// wrapping around.
+///
+/// Expiries are ordered by when they expire, with the earliest being the smallest.
+/// To pop the earliest expiry from a [`BinaryHeap`](std::collections::BinaryHeap),
+/// wrap it in [`Reverse`](core::cmp::Reverse).
+///
+/// # Examples
+///
+/// ```
+/// use eos::Timestamp;
+/// use eos::extra::Expiry;
+/// use core::time::Duration;
+///
+/// let now = Timestamp::from_seconds(1_000);
+/// let expiry = Expiry::after(now, Duration::from_secs(30));
+/// assert!(!expiry.is_expired(now));
+/// assert_eq!(expiry.remaining(now), Duration::from_secs(30));
+///
+/// let later = Timestamp::from_seconds(1_045);
+/// assert!(expiry.is_expired(later));
+/// assert_eq!(expiry.remaining(later), Duration::ZERO);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Expiry {
+    deadline: Timestamp,
+}
+
+impl Expiry {
+    /// Creates a new [`Expiry`] that expires at the given timestamp.
+    pub const fn new(deadline: Timestamp) -> Self {
+        Self { deadline }
+    }
+
+    /// Creates a new [`Expiry`] that expires after the given duration has passed since `now`.
+    pub fn after(now: Timestamp, ttl: Duration) -> Self {
+        Self {
+            deadline: timestamp_from_nanos(timestamp_to_nanos(now) + ttl.as_nanos() as i128),
+        }
+    }
+
+    /// Creates a new [`Expiry`] that expires after the given duration has passed from the current time.
+    #[cfg(feature = "std")]
+    pub fn from_now(ttl: Duration) -> Self {
+        Self::after(DateTime::utc_now().timestamp(), ttl)
+    }
+
+    /// Creates a new [`Expiry`] that expires at the given [`DateTime`].
+    pub fn from_datetime<Tz>(dt: &DateTime<Tz>) -> Self
+    where
+        Tz: TimeZone,
+    {
+        Self {
+            deadline: dt.timestamp(),
+        }
+    }
+
+    /// Returns the [`Timestamp`] this expires at.
+    pub const fn timestamp(&self) -> Timestamp {
+        self.deadline
+    }
+
+    /// Returns the [`DateTime`] in UTC this expires at.
+    pub fn to_utc(&self) -> DateTime<Utc> {
+        self.deadline.to_utc()
+    }
+
+    /// Returns `true` if the expiry has been reached at the given time.
+    ///
+    /// An expiry is considered expired at the exact moment of its deadline.
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        now >= self.deadline
+    }
+
+    /// Returns how much time is left until the expiry is reached.
+    ///
+    /// If the expiry has already been reached then this returns [`Duration::ZERO`].
+    pub fn remaining(&self, now: Timestamp) -> Duration {
+        let nanos = timestamp_to_nanos(self.deadline) - timestamp_to_nanos(now);
+        if nanos <= 0 {
+            return Duration::ZERO;
+        }
+        let (seconds, nanos) = divmod!(nanos, NANOS_PER_SEC);
+        match u64::try_from(seconds) {
+            Ok(seconds) => Duration::new(seconds, nanos as u32),
+            Err(_) => Duration::MAX,
+        }
+    }
+
+    /// Pushes the deadline back by the given duration.
+    pub fn extend(&mut self, duration: Duration) {
+        self.deadline = timestamp_from_nanos(timestamp_to_nanos(self.deadline) + duration.as_nanos() as i128);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datetime;
+
+    #[test]
+    fn test_expiry() {
+        let now = Timestamp::new(100, 500_000_000);
+        let expiry = Expiry::after(now, Duration::from_millis(1500));
+        assert_eq!(expiry.timestamp(), Timestamp::from_seconds(102));
+        assert!(!expiry.is_expired(now));
+        assert!(!expiry.is_expired(Timestamp::new(101, 999_999_999)));
+        assert!(expiry.is_expired(Timestamp::from_seconds(102)));
+        assert_eq!(expiry.remaining(now), Duration::from_millis(1500));
+        assert_eq!(expiry.remaining(Timestamp::from_seconds(102)), Duration::ZERO);
+        assert_eq!(expiry.remaining(Timestamp::from_seconds(200)), Duration::ZERO);
+
+        let mut extended = expiry;
+        extended.extend(Duration::from_secs(10));
+        assert_eq!(extended.timestamp(), Timestamp::from_seconds(112));
+        assert!(extended > expiry);
+        assert!(!extended.is_expired(Timestamp::from_seconds(102)));
+    }
+
+    #[test]
+    fn test_expiry_in_the_past() {
+        let now = Timestamp::from_seconds(1_000);
+        let expiry = Expiry::new(Timestamp::from_seconds(-1_000));
+        assert!(expiry.is_expired(now));
+        assert_eq!(expiry.remaining(now), Duration::ZERO);
+        assert_eq!(
+            expiry.remaining(Timestamp::from_seconds(i64::MIN)),
+            Duration::from_secs(i64::MAX as u64 - 999)
+        );
+
+        let mut extended = expiry;
+        extended.extend(Duration::from_secs(2_500));
+        assert!(!extended.is_expired(now));
+        assert_eq!(extended.remaining(now), Duration::from_secs(500));
+    }
+
+    #[test]
+    fn test_expiry_saturates() {
+        let expiry = Expiry::after(Timestamp::from_seconds(i64::MAX), Duration::MAX);
+        assert_eq!(expiry.timestamp().as_seconds(), i64::MAX);
+        assert_eq!(expiry.remaining(Timestamp::from_seconds(i64::MIN)), Duration::MAX);
+
+        let mut expiry = Expiry::new(Timestamp::from_seconds(0));
+        expiry.extend(Duration::MAX);
+        expiry.extend(Duration::MAX);
+        assert_eq!(expiry.timestamp().as_seconds(), i64::MAX);
+    }
+
+    #[test]
+    fn test_expiry_datetime() {
+        let dt = datetime!(2022-03-01 09:00 -05:00);
+        let expiry = Expiry::from_datetime(&dt);
+        assert_eq!(expiry.to_utc(), datetime!(2022-03-01 14:00));
+        assert_eq!(expiry.timestamp(), dt.timestamp());
+        assert!(expiry.is_expired(datetime!(2022-03-01 14:00).timestamp()));
+        assert!(!expiry.is_expired(datetime!(2022-03-01 13:59).timestamp()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_expiry_ordering() {
+        use core::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let now = Timestamp::from_seconds(0);
+        let mut heap = BinaryHeap::new();
+        for seconds in [30, 10, 20] {
+            heap.push(Reverse(Expiry::after(now, Duration::from_secs(seconds))));
+        }
+        let order: Vec<_> = core::iter::from_fn(|| heap.pop().map(|e| e.0.timestamp().as_seconds())).collect();
+        assert_eq!(order, [10, 20, 30]);
+
+        assert!(!Expiry::from_now(Duration::from_secs(60)).is_expired(DateTime::utc_now().timestamp()));
+    }
+}
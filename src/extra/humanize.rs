@@ -0,0 +1,498 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{DateTime, Interval, TimeZone};
+
+const MICROS_PER_SEC: i128 = 1_000_000;
+const MICROS_PER_DAY: i128 = 86_400 * MICROS_PER_SEC;
+/// The average length of a month in the Gregorian calendar, 30.436875 days.
+const MICROS_PER_AVERAGE_MONTH: i128 = 146_097 * MICROS_PER_DAY / 4800;
+
+/// A unit of time used when describing a [`RelativeTime`].
+///
+/// Units are ordered from smallest to largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RelativeUnit {
+    /// A second.
+    Second,
+    /// A minute.
+    Minute,
+    /// An hour.
+    Hour,
+    /// A day.
+    Day,
+    /// A week. Equal to 7 days.
+    Week,
+    /// A month.
+    Month,
+    /// A year. Equal to 12 months.
+    Year,
+}
+
+impl RelativeUnit {
+    /// The length of a unit in microseconds, if it has a fixed length.
+    const fn micros(self) -> Option<i128> {
+        match self {
+            Self::Second => Some(MICROS_PER_SEC),
+            Self::Minute => Some(60 * MICROS_PER_SEC),
+            Self::Hour => Some(3_600 * MICROS_PER_SEC),
+            Self::Day => Some(MICROS_PER_DAY),
+            Self::Week => Some(7 * MICROS_PER_DAY),
+            Self::Month | Self::Year => None,
+        }
+    }
+
+    /// The next larger unit with a fixed length.
+    const fn next_fixed(self) -> Option<Self> {
+        match self {
+            Self::Second => Some(Self::Minute),
+            Self::Minute => Some(Self::Hour),
+            Self::Hour => Some(Self::Day),
+            Self::Day => Some(Self::Week),
+            Self::Week | Self::Month | Self::Year => None,
+        }
+    }
+}
+
+/// An amount of time relative to some point, rounded to a single unit.
+///
+/// This is what a [`RelativeFormatter`] receives to produce strings such as
+/// `"in 5 minutes"` or `"3 hours ago"`. It is created using a [`Humanizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeTime {
+    amount: u64,
+    unit: RelativeUnit,
+    past: bool,
+}
+
+impl RelativeTime {
+    /// Returns the rounded amount of [`Self::unit`]. This is `0` if the time is considered now.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the unit of the amount.
+    pub const fn unit(&self) -> RelativeUnit {
+        self.unit
+    }
+
+    /// Returns `true` if the time is in the past.
+    pub const fn is_past(&self) -> bool {
+        self.past
+    }
+
+    /// Returns `true` if the time is in the future.
+    pub const fn is_future(&self) -> bool {
+        !self.past && self.amount != 0
+    }
+
+    /// Returns `true` if the time rounds to nothing, i.e. it is "now" at the current granularity.
+    pub const fn is_now(&self) -> bool {
+        self.amount == 0
+    }
+}
+
+/// A trait for turning a [`RelativeTime`] into text.
+///
+/// This is the extension point for localization. The library provides [`English`]
+/// and [`EnglishShort`].
+pub trait RelativeFormatter {
+    /// Writes the relative time into `out`.
+    fn format(&self, relative: &RelativeTime, out: &mut String);
+}
+
+impl<F: RelativeFormatter + ?Sized> RelativeFormatter for &F {
+    fn format(&self, relative: &RelativeTime, out: &mut String) {
+        (**self).format(relative, out)
+    }
+}
+
+/// Formats relative times in English using long unit names, e.g. `"in 5 minutes"`.
+///
+/// An amount of zero is written as `"now"`, or as `"today"`, `"this week"`, etc. for
+/// units of a day or larger. One day is written as `"yesterday"` or `"tomorrow"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct English;
+
+impl RelativeFormatter for English {
+    fn format(&self, relative: &RelativeTime, out: &mut String) {
+        let unit = match relative.unit {
+            RelativeUnit::Second => "second",
+            RelativeUnit::Minute => "minute",
+            RelativeUnit::Hour => "hour",
+            RelativeUnit::Day => "day",
+            RelativeUnit::Week => "week",
+            RelativeUnit::Month => "month",
+            RelativeUnit::Year => "year",
+        };
+        match (relative.amount, relative.unit) {
+            (0, RelativeUnit::Second | RelativeUnit::Minute | RelativeUnit::Hour) => out.push_str("now"),
+            (0, RelativeUnit::Day) => out.push_str("today"),
+            (0, _) => {
+                out.push_str("this ");
+                out.push_str(unit);
+            }
+            (1, RelativeUnit::Day) => out.push_str(if relative.past { "yesterday" } else { "tomorrow" }),
+            (amount, _) => {
+                let plural = if amount == 1 { "" } else { "s" };
+                // Writing into a String cannot fail
+                let _ = if relative.past {
+                    write!(out, "{} {}{} ago", amount, unit, plural)
+                } else {
+                    write!(out, "in {} {}{}", amount, unit, plural)
+                };
+            }
+        }
+    }
+}
+
+/// Formats relative times in English using abbreviated unit names, e.g. `"in 5m"` or `"3h ago"`.
+///
+/// An amount of zero is always written as `"now"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EnglishShort;
+
+impl RelativeFormatter for EnglishShort {
+    fn format(&self, relative: &RelativeTime, out: &mut String) {
+        if relative.amount == 0 {
+            out.push_str("now");
+            return;
+        }
+        let unit = match relative.unit {
+            RelativeUnit::Second => "s",
+            RelativeUnit::Minute => "m",
+            RelativeUnit::Hour => "h",
+            RelativeUnit::Day => "d",
+            RelativeUnit::Week => "w",
+            RelativeUnit::Month => "mo",
+            RelativeUnit::Year => "y",
+        };
+        let _ = if relative.past {
+            write!(out, "{}{} ago", relative.amount, unit)
+        } else {
+            write!(out, "in {}{}", relative.amount, unit)
+        };
+    }
+}
+
+/// Describes datetimes and intervals relative to each other in human terms.
+///
+/// The largest unit that fits at least once is picked and the amount is then rounded
+/// to the nearest whole number of that unit, with halves rounding up. For example,
+/// 89 minutes is `"1 hour"` while 90 minutes is `"2 hours"`. If rounding reaches the
+/// next unit then that is used instead, so 23 hours and 45 minutes is `"1 day"`.
+///
+/// Months and years are only used when the interval has months in it, such as one
+/// returned by [`Interval::between`]. A month rounds up once there are at least 15 days
+/// left over and a year rounds up once there are at least 6 months left over. Days are
+/// never converted into months since that depends on the calendar.
+///
+/// The granularity sets the smallest unit that is used. Anything smaller than half
+/// of it is considered now.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, ext::IntervalLiteral};
+/// use eos::extra::{EnglishShort, Humanizer, RelativeUnit};
+///
+/// let now = datetime!(2022-03-10 12:00);
+/// let humanizer = Humanizer::new();
+/// assert_eq!(humanizer.humanize(&datetime!(2022-03-10 12:05), &now), "in 5 minutes");
+/// assert_eq!(humanizer.humanize(&datetime!(2022-03-10 09:00), &now), "3 hours ago");
+/// assert_eq!(humanizer.humanize(&datetime!(2022-03-09 10:00), &now), "yesterday");
+/// assert_eq!(humanizer.humanize(&datetime!(2022-02-24 12:00), &now), "2 weeks ago");
+/// assert_eq!(humanizer.humanize(&datetime!(2023-05-01 12:00), &now), "in 1 year");
+/// assert_eq!(humanizer.humanize_interval(89.minutes()), "in 1 hour");
+///
+/// let short = Humanizer::new().with_formatter(EnglishShort);
+/// assert_eq!(short.humanize(&datetime!(2022-03-10 09:00), &now), "3h ago");
+///
+/// let daily = Humanizer::new().with_granularity(RelativeUnit::Day);
+/// assert_eq!(daily.humanize(&datetime!(2022-03-10 17:00), &now), "today");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Humanizer<F = English> {
+    granularity: RelativeUnit,
+    formatter: F,
+}
+
+impl Humanizer {
+    /// Creates a new [`Humanizer`] with a granularity of seconds that formats using [`English`].
+    pub const fn new() -> Self {
+        Self {
+            granularity: RelativeUnit::Second,
+            formatter: English,
+        }
+    }
+}
+
+impl Default for Humanizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: RelativeFormatter> Humanizer<F> {
+    /// Returns a new [`Humanizer`] with the smallest unit set to `granularity`.
+    pub fn with_granularity(self, granularity: RelativeUnit) -> Self {
+        Self { granularity, ..self }
+    }
+
+    /// Returns a new [`Humanizer`] that uses a different formatter.
+    pub fn with_formatter<G: RelativeFormatter>(self, formatter: G) -> Humanizer<G> {
+        Humanizer {
+            granularity: self.granularity,
+            formatter,
+        }
+    }
+
+    /// Returns the granularity of this humanizer.
+    pub fn granularity(&self) -> RelativeUnit {
+        self.granularity
+    }
+
+    /// Returns the formatter of this humanizer.
+    pub fn formatter(&self) -> &F {
+        &self.formatter
+    }
+
+    /// Returns how far `dt` is from `relative_to`, rounded to a single unit.
+    ///
+    /// If `dt` is earlier than `relative_to` then the time is in the past.
+    pub fn relative<Tz, OtherTz>(&self, dt: &DateTime<Tz>, relative_to: &DateTime<OtherTz>) -> RelativeTime
+    where
+        Tz: TimeZone,
+        OtherTz: TimeZone,
+    {
+        self.relative_interval(Interval::between(relative_to, dt))
+    }
+
+    /// Returns the interval rounded to a single unit.
+    ///
+    /// Positive intervals are in the future and negative intervals are in the past.
+    /// When rounding to months or years, days and smaller units are converted using
+    /// the average length of a month.
+    pub fn relative_interval(&self, interval: Interval) -> RelativeTime {
+        let exact = interval.as_nanoseconds() / 1_000;
+        let months = interval.total_months();
+        let past = if months != 0 { months < 0 } else { exact < 0 };
+        // Work with the magnitude from here on, the sub-month part could still be
+        // negative if the interval has mixed signs
+        let (months, exact) = if past {
+            (-(months as i64), -exact)
+        } else {
+            (months as i64, exact)
+        };
+
+        // Days and smaller units are only approximated as months when months are shown,
+        // otherwise 100 days would round to at most a single month
+        let (months, exact) = if months >= 1 || self.granularity >= RelativeUnit::Month {
+            let whole = exact / MICROS_PER_AVERAGE_MONTH;
+            (months + whole as i64, exact - whole * MICROS_PER_AVERAGE_MONTH)
+        } else {
+            (months, exact)
+        };
+
+        let (amount, unit) = if months >= 12 || self.granularity == RelativeUnit::Year {
+            let months = months + (exact >= 15 * MICROS_PER_DAY) as i64;
+            let years = months / 12 + (months % 12 >= 6) as i64;
+            (years, RelativeUnit::Year)
+        } else if months >= 1 || self.granularity == RelativeUnit::Month {
+            let months = months + (exact >= 15 * MICROS_PER_DAY) as i64;
+            if months == 12 {
+                (1, RelativeUnit::Year)
+            } else {
+                (months, RelativeUnit::Month)
+            }
+        } else {
+            let mut unit = RelativeUnit::Second;
+            while let Some(next) = unit.next_fixed() {
+                match next.micros() {
+                    Some(len) if exact >= len => unit = next,
+                    _ => break,
+                }
+            }
+            let mut unit = unit.max(self.granularity);
+            let len = unit.micros().unwrap_or(MICROS_PER_DAY);
+            let mut amount = (exact + len / 2) / len;
+            if let Some(next) = unit.next_fixed() {
+                if let Some(next_len) = next.micros() {
+                    if amount * len >= next_len {
+                        amount = 1;
+                        unit = next;
+                    }
+                }
+            }
+            (amount as i64, unit)
+        };
+
+        RelativeTime {
+            amount: amount.max(0) as u64,
+            unit,
+            past: past && amount > 0,
+        }
+    }
+
+    /// Describes `dt` relative to `relative_to`, e.g. `"in 5 minutes"`.
+    pub fn humanize<Tz, OtherTz>(&self, dt: &DateTime<Tz>, relative_to: &DateTime<OtherTz>) -> String
+    where
+        Tz: TimeZone,
+        OtherTz: TimeZone,
+    {
+        let mut out = String::new();
+        self.formatter.format(&self.relative(dt, relative_to), &mut out);
+        out
+    }
+
+    /// Describes an interval relative to now, e.g. `"3 hours ago"` for a negative interval.
+    pub fn humanize_interval(&self, interval: Interval) -> String {
+        let mut out = String::new();
+        self.formatter.format(&self.relative_interval(interval), &mut out);
+        out
+    }
+}
+
+/// Describes `dt` relative to `relative_to` in English, e.g. `"in 5 minutes"` or `"3 hours ago"`.
+///
+/// This is a shortcut for [`Humanizer::humanize`] with the default settings.
+///
+/// # Examples
+///
+/// ```
+/// use eos::datetime;
+/// use eos::extra::humanize;
+///
+/// let now = datetime!(2022-03-10 12:00);
+/// assert_eq!(humanize(&datetime!(2022-03-10 13:29), &now), "in 1 hour");
+/// assert_eq!(humanize(&datetime!(2022-03-10 12:00), &now), "now");
+/// ```
+pub fn humanize<Tz, OtherTz>(dt: &DateTime<Tz>, relative_to: &DateTime<OtherTz>) -> String
+where
+    Tz: TimeZone,
+    OtherTz: TimeZone,
+{
+    Humanizer::new().humanize(dt, relative_to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{datetime, ext::IntervalLiteral};
+
+    #[test]
+    fn test_rounding() {
+        let humanizer = Humanizer::new();
+        assert_eq!(humanizer.humanize_interval(Interval::ZERO), "now");
+        assert_eq!(humanizer.humanize_interval(400.milliseconds()), "now");
+        assert_eq!(humanizer.humanize_interval(500.milliseconds()), "in 1 second");
+        assert_eq!(humanizer.humanize_interval((-59).seconds()), "59 seconds ago");
+        assert_eq!(humanizer.humanize_interval(89.seconds()), "in 1 minute");
+        assert_eq!(humanizer.humanize_interval(90.seconds()), "in 2 minutes");
+        assert_eq!(humanizer.humanize_interval((-89).minutes()), "1 hour ago");
+        assert_eq!(humanizer.humanize_interval((-90).minutes()), "2 hours ago");
+        assert_eq!(humanizer.humanize_interval(59.minutes() + 30.seconds()), "in 1 hour");
+        assert_eq!(humanizer.humanize_interval(23.hours() + 45.minutes()), "tomorrow");
+        assert_eq!(humanizer.humanize_interval(36.hours()), "in 2 days");
+        assert_eq!(humanizer.humanize_interval((-6).days() - 12.hours()), "1 week ago");
+        assert_eq!(humanizer.humanize_interval(100.days()), "in 14 weeks");
+        assert_eq!(humanizer.humanize_interval(1.months() + 14.days()), "in 1 month");
+        assert_eq!(humanizer.humanize_interval(1.months() + 15.days()), "in 2 months");
+        assert_eq!(humanizer.humanize_interval(1.months() - 3.days()), "in 1 month");
+        assert_eq!(humanizer.humanize_interval(11.months() + 20.days()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval((-17).months()), "1 year ago");
+        assert_eq!(humanizer.humanize_interval((-18).months()), "2 years ago");
+    }
+
+    #[test]
+    fn test_granularity() {
+        let humanizer = Humanizer::new().with_granularity(RelativeUnit::Day);
+        assert_eq!(humanizer.humanize_interval(11.hours()), "today");
+        assert_eq!(humanizer.humanize_interval((-13).hours()), "yesterday");
+        assert_eq!(humanizer.humanize_interval(9.days()), "in 1 week");
+
+        let humanizer = humanizer.with_granularity(RelativeUnit::Month);
+        assert_eq!(humanizer.humanize_interval(10.days()), "this month");
+        assert_eq!(humanizer.humanize_interval((-20).days()), "1 month ago");
+
+        assert_eq!(humanizer.humanize_interval(45.days()), "in 1 month");
+        assert_eq!(humanizer.humanize_interval(46.days()), "in 2 months");
+        assert_eq!(humanizer.humanize_interval(100.days()), "in 3 months");
+        assert_eq!(humanizer.humanize_interval((-200).days()), "7 months ago");
+        assert_eq!(humanizer.humanize_interval(350.days()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval(400.days()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval(2.months() + 75.days()), "in 4 months");
+
+        let humanizer = humanizer.with_granularity(RelativeUnit::Year);
+        assert_eq!(humanizer.humanize_interval(5.months()), "this year");
+        assert_eq!(humanizer.humanize_interval(6.months()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval(5.months() + 20.days()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval(100.days()), "this year");
+        assert_eq!(humanizer.humanize_interval(200.days()), "in 1 year");
+        assert_eq!(humanizer.humanize_interval(800.days()), "in 2 years");
+        assert_eq!(humanizer.humanize_interval((-1000).days()), "3 years ago");
+
+        // The default granularity approximates days once months are involved
+        let humanizer = Humanizer::new();
+        assert_eq!(humanizer.humanize_interval(1.months() + 100.days()), "in 4 months");
+        assert_eq!(humanizer.humanize_interval(11.months() + 400.days()), "in 2 years");
+    }
+
+    #[test]
+    fn test_short() {
+        let humanizer = Humanizer::new().with_formatter(EnglishShort);
+        assert_eq!(humanizer.humanize_interval(Interval::ZERO), "now");
+        assert_eq!(humanizer.humanize_interval((-3).hours()), "3h ago");
+        assert_eq!(humanizer.humanize_interval(5.minutes()), "in 5m");
+        assert_eq!(humanizer.humanize_interval((-1).days()), "1d ago");
+        assert_eq!(humanizer.humanize_interval(2.months()), "in 2mo");
+        assert_eq!(humanizer.humanize_interval(3.years()), "in 3y");
+    }
+
+    #[test]
+    fn test_datetimes() {
+        let now = datetime!(2022-03-10 12:00);
+        let relative = Humanizer::new().relative(&datetime!(2022-03-10 09:00), &now);
+        assert_eq!(relative.amount(), 3);
+        assert_eq!(relative.unit(), RelativeUnit::Hour);
+        assert!(relative.is_past());
+        assert!(!relative.is_future());
+
+        let relative = Humanizer::new().relative(&now, &now);
+        assert!(relative.is_now());
+        assert!(!relative.is_past());
+        assert!(!relative.is_future());
+
+        assert_eq!(humanize(&datetime!(2022-03-10 14:00 +02:00), &now), "now");
+        assert_eq!(humanize(&datetime!(2022-03-11 11:00), &now), "in 23 hours");
+        assert_eq!(humanize(&datetime!(2022-03-11 13:00), &now), "tomorrow");
+        assert_eq!(humanize(&datetime!(2022-01-10 12:00), &now), "2 months ago");
+        assert_eq!(humanize(&datetime!(2020-02-29 12:00), &now), "2 years ago");
+    }
+
+    struct Spanish;
+
+    impl RelativeFormatter for Spanish {
+        fn format(&self, relative: &RelativeTime, out: &mut String) {
+            let unit = match relative.unit() {
+                RelativeUnit::Hour => "horas",
+                _ => "unidades",
+            };
+            let _ = if relative.is_past() {
+                write!(out, "hace {} {}", relative.amount(), unit)
+            } else {
+                write!(out, "dentro de {} {}", relative.amount(), unit)
+            };
+        }
+    }
+
+    #[test]
+    fn test_custom_formatter() {
+        let humanizer = Humanizer::new().with_formatter(Spanish);
+        assert_eq!(humanizer.humanize_interval((-3).hours()), "hace 3 horas");
+        assert_eq!(
+            humanizer.with_formatter(&Spanish).humanize_interval(2.hours()),
+            "dentro de 2 horas"
+        );
+    }
+}
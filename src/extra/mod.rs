@@ -1,7 +1,49 @@
 //! Internal module for ecosystem integration extras.
 //!
-//! This is where trait implementations go if they are requested.
+//! This is where trait implementations go if they are requested, along with
+//! higher level utilities that are built on top of the core types.
 
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rand")]
+pub mod rand;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
 #[cfg(all(feature = "parsing", feature = "formatting", feature = "rusqlite"))]
 pub mod rusqlite;
+#[cfg(feature = "schemars")]
+pub mod schemars;
+#[cfg(feature = "time")]
+pub mod time;
 
+mod business;
+mod clock;
+#[cfg(feature = "cron")]
+mod cron;
+mod expiry;
+#[cfg(feature = "alloc")]
+mod humanize;
+mod month;
+#[cfg(feature = "posix")]
+mod posix;
+mod retail;
+mod schedule;
+mod weekday_set;
+
+pub use business::BusinessCalendar;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock, SteppingClock};
+#[cfg(feature = "cron")]
+pub use cron::{Cron, CronIter};
+pub use expiry::Expiry;
+#[cfg(feature = "alloc")]
+pub use humanize::{humanize, English, EnglishShort, Humanizer, RelativeFormatter, RelativeTime, RelativeUnit};
+pub use month::Month;
+#[cfg(feature = "posix")]
+pub use posix::{PosixTimeZone, PosixTzError};
+pub use retail::{Retail445Calendar, RetailDate, WeekRule};
+pub use schedule::DailySchedule;
+pub use weekday_set::{WeekdaySet, WeekdaySetIter};
@@ -0,0 +1,224 @@
+use crate::{gregorian::days_in_month, Error};
+
+#[cfg(feature = "parsing")]
+use crate::fmt::ParseError;
+
+/// An enum representing the months of the Gregorian calendar.
+///
+/// This is an alternative to the raw `1..=12` month numbers used throughout the
+/// library. It converts to and from those numbers with [`From`] and [`TryFrom`].
+///
+/// # Examples
+///
+/// ```
+/// use eos::{date, Date};
+/// use eos::extra::Month;
+///
+/// let date = Date::new_with_month(2022, Month::March, 4).unwrap();
+/// assert_eq!(date, date!(2022-03-04));
+/// assert_eq!(date.month_enum(), Month::March);
+/// assert_eq!(u8::from(Month::March), 3);
+/// assert_eq!(Month::try_from(12), Ok(Month::December));
+/// assert_eq!(Month::February.length(2024), 29);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Month {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+impl Month {
+    /// Creates a [`Month`] from its number, where January is 1.
+    ///
+    /// If the number is out of bounds (`1..=12`) then [`None`] is returned.
+    #[inline]
+    pub const fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::January),
+            2 => Some(Self::February),
+            3 => Some(Self::March),
+            4 => Some(Self::April),
+            5 => Some(Self::May),
+            6 => Some(Self::June),
+            7 => Some(Self::July),
+            8 => Some(Self::August),
+            9 => Some(Self::September),
+            10 => Some(Self::October),
+            11 => Some(Self::November),
+            12 => Some(Self::December),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of this month, where January is 1.
+    #[inline]
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the next month, wrapping from December to January.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(Month::March.next(), Month::April);
+    /// assert_eq!(Month::December.next(), Month::January);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match Self::from_number(self as u8 % 12 + 1) {
+            Some(month) => month,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the previous month, wrapping from January to December.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(Month::March.prev(), Month::February);
+    /// assert_eq!(Month::January.prev(), Month::December);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn prev(self) -> Self {
+        match Self::from_number((self as u8 + 10) % 12 + 1) {
+            Some(month) => month,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the number of days in this month for the given year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::extra::Month;
+    ///
+    /// assert_eq!(Month::January.length(2023), 31);
+    /// assert_eq!(Month::February.length(2023), 28);
+    /// assert_eq!(Month::February.length(2024), 29);
+    /// assert_eq!(Month::April.length(2024), 30);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn length(self, year: i16) -> u8 {
+        days_in_month(year, self as u8)
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month as u8
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Error::check_field("month", value as i64, 1, 12)?;
+        Self::from_number(value).ok_or(Error::OutOfRange)
+    }
+}
+
+/// Parses a month from either its full English name or its three letter abbreviation.
+///
+/// The comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use eos::extra::Month;
+///
+/// assert_eq!("March".parse(), Ok(Month::March));
+/// assert_eq!("sep".parse(), Ok(Month::September));
+/// assert_eq!("MAY".parse(), Ok(Month::May));
+/// assert!("Sept".parse::<Month>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for Month {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: [(&str, Month); 12] = [
+            ("january", Month::January),
+            ("february", Month::February),
+            ("march", Month::March),
+            ("april", Month::April),
+            ("may", Month::May),
+            ("june", Month::June),
+            ("july", Month::July),
+            ("august", Month::August),
+            ("september", Month::September),
+            ("october", Month::October),
+            ("november", Month::November),
+            ("december", Month::December),
+        ];
+
+        crate::fmt::parse_english_name(s, &NAMES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date, Date};
+
+    #[test]
+    fn test_conversions() {
+        for number in 0..=u8::MAX {
+            match Month::try_from(number) {
+                Ok(month) => {
+                    assert_eq!(u8::from(month), number);
+                    assert_eq!(month.next().prev(), month);
+                    assert_eq!(month.next().number(), number % 12 + 1);
+                    assert_eq!(month.length(2023), Date::max_day(2023, number).unwrap());
+                    assert_eq!(month.length(2024), Date::max_day(2024, number).unwrap());
+                }
+                Err(e) => {
+                    assert!(matches!(e, Error::FieldOutOfRange { field: "month", .. }));
+                    assert!(number == 0 || number > 12);
+                }
+            }
+        }
+
+        let date = date!(2024 - 02 - 29);
+        assert_eq!(Date::new_with_month(2024, Month::February, 29), Some(date));
+        assert_eq!(Date::new_with_month(2023, Month::February, 29), None);
+        assert_eq!(date.month_enum(), Month::February);
+        assert_eq!(date.month_enum().length(date.year()), date.days_in_month());
+    }
+
+    #[test]
+    #[cfg(feature = "parsing")]
+    fn test_from_str() {
+        assert_eq!("january".parse(), Ok(Month::January));
+        assert_eq!("JUNE".parse(), Ok(Month::June));
+        assert_eq!("Jun".parse(), Ok(Month::June));
+        assert_eq!("jul".parse(), Ok(Month::July));
+        assert_eq!("May".parse(), Ok(Month::May));
+        assert_eq!("".parse::<Month>(), Err(ParseError::UnexpectedEnd));
+        assert_eq!("Ju".parse::<Month>(), Err(ParseError::UnexpectedEnd));
+        assert_eq!("Mayo".parse::<Month>(), Err(ParseError::UnexpectedChar('o')));
+        assert_eq!("Octobre".parse::<Month>(), Err(ParseError::UnexpectedChar('r')));
+    }
+}
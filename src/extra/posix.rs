@@ -1,19 +1,83 @@
-use std::{
+use core::{
     iter::Peekable,
     str::{Chars, FromStr},
 };
 
-use eos::{
+use alloc::string::String;
+
+use crate::{
     gregorian::{date_to_epoch_days, days_in_month, is_leap_year, weekday_difference, weekday_from_days},
-    utc_offset, Time, UtcOffset,
+    timezone::ZoneInfo,
+    Date, DateTime, DateTimeResolution, DateTimeResolutionKind, Time, TimeZone, Timestamp, Utc, UtcOffset,
+};
+
+/// The error returned when a POSIX TZ string fails to parse.
+///
+/// Each variant identifies the field of the TZ string that was invalid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum PosixTzError {
+    /// The standard time abbreviation was missing or invalid.
+    StdAbbreviation,
+    /// The standard time offset was invalid.
+    StdOffset,
+    /// The DST abbreviation was invalid.
+    DstAbbreviation,
+    /// The DST offset was invalid.
+    DstOffset,
+    /// The date of the DST start rule was invalid.
+    StartRule,
+    /// The time of the DST start rule was invalid.
+    StartTime,
+    /// The date of the DST end rule was invalid.
+    EndRule,
+    /// The time of the DST end rule was invalid.
+    EndTime,
+    /// There were characters remaining after the TZ string was parsed.
+    TrailingCharacters,
+}
+
+impl core::fmt::Display for PosixTzError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StdAbbreviation => f.write_str("invalid standard time abbreviation in POSIX TZ string"),
+            Self::StdOffset => f.write_str("invalid standard time offset in POSIX TZ string"),
+            Self::DstAbbreviation => f.write_str("invalid DST abbreviation in POSIX TZ string"),
+            Self::DstOffset => f.write_str("invalid DST offset in POSIX TZ string"),
+            Self::StartRule => f.write_str("invalid DST start date in POSIX TZ string"),
+            Self::StartTime => f.write_str("invalid DST start time in POSIX TZ string"),
+            Self::EndRule => f.write_str("invalid DST end date in POSIX TZ string"),
+            Self::EndTime => f.write_str("invalid DST end time in POSIX TZ string"),
+            Self::TrailingCharacters => f.write_str("unexpected trailing characters in POSIX TZ string"),
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "nightly"))]
+impl crate::utils::ErrorTrait for PosixTzError {}
+
+/// The default DST offset from standard time.
+const ONE_HOUR: UtcOffset = UtcOffset {
+    hours: 1,
+    minutes: 0,
+    seconds: 0,
 };
 
-use crate::{error::ParseError, timestamp::NaiveTimestamp};
+/// Returns the number of seconds since the Unix epoch of a local date and time.
+const fn local_seconds(date: &Date, time: &Time) -> i64 {
+    date.days_since_epoch() as i64 * 86400
+        + time.hour() as i64 * 3600
+        + time.minute() as i64 * 60
+        + time.second() as i64
+}
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub(crate) enum DstTransitionRule {
+enum DstTransitionRule {
+    /// `Jn`: 1-based day of the year where February 29th is never counted.
     JulianDay(u16, i64),
+    /// `n`: 0-based day of the year where February 29th is counted.
     Day(u16, i64),
+    /// `Mm.n.d`: the `n`th weekday `d` of month `m`, where `n = 5` is the last one.
     Calendar { month: u8, n: u8, weekday: u8, offset: i64 },
 }
 
@@ -32,23 +96,24 @@ impl DstTransitionRule {
             },
         }
     }
-    pub(crate) fn timestamp_in_year(&self, year: i16) -> NaiveTimestamp {
+
+    /// Returns the local transition time of the given year, in seconds since the Unix epoch.
+    fn local_seconds_in_year(&self, year: i16) -> i64 {
         match self {
             Self::JulianDay(day, offset) => {
-                let d = if *day >= 59 && is_leap_year(year) {
+                // February 29th is skipped, so day 60 is always March 1st
+                let d = if *day >= 60 && is_leap_year(year) {
                     day + 1
                 } else {
                     *day
                 };
                 let epoch = date_to_epoch_days(year, 1, 1) as i64;
-                let seconds = (epoch - 1 + d as i64) * 86400 + offset;
-                NaiveTimestamp::from_seconds(seconds)
+                (epoch - 1 + d as i64) * 86400 + offset
             }
             Self::Day(day, offset) => {
                 // day is already range checked as part of the contract
                 let epoch = date_to_epoch_days(year, 1, 1) as i64;
-                let seconds = (epoch - 1 + *day as i64) * 86400 + offset;
-                NaiveTimestamp::from_seconds(seconds)
+                (epoch + *day as i64) * 86400 + offset
             }
             Self::Calendar {
                 month,
@@ -63,25 +128,27 @@ impl DstTransitionRule {
                     day -= 7;
                 }
                 let epoch = date_to_epoch_days(year, *month, day) as i64;
-                let seconds = epoch * 86400 + offset;
-                NaiveTimestamp::from_seconds(seconds)
+                epoch * 86400 + offset
             }
         }
     }
 }
 
-fn display_time(f: &mut std::fmt::Formatter<'_>, offset: i64) -> std::fmt::Result {
-    let (hours, seconds) = (offset.div_euclid(3600), offset.rem_euclid(3600));
-    let (minutes, seconds) = (seconds.div_euclid(60), seconds.rem_euclid(60));
-    if minutes != 0 || seconds != 0 {
-        write!(f, "{}:{:02}:{:02}", hours, minutes, seconds)
+fn display_time(f: &mut core::fmt::Formatter<'_>, offset: i64) -> core::fmt::Result {
+    let sign = if offset < 0 { "-" } else { "" };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset % 3600 / 60, offset % 60);
+    if seconds != 0 {
+        write!(f, "{}{}:{:02}:{:02}", sign, hours, minutes, seconds)
+    } else if minutes != 0 {
+        write!(f, "{}{}:{:02}", sign, hours, minutes)
     } else {
-        write!(f, "{}", hours)
+        write!(f, "{}{}", sign, hours)
     }
 }
 
-impl std::fmt::Display for DstTransitionRule {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for DstTransitionRule {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match &self {
             DstTransitionRule::JulianDay(d, offset) => {
                 write!(f, "J{}/", d)?;
@@ -106,7 +173,7 @@ impl std::fmt::Display for DstTransitionRule {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct DstTransitionInfo {
+struct DstTransitionInfo {
     abbr: String,
     offset: UtcOffset,
     start: DstTransitionRule,
@@ -116,10 +183,10 @@ pub(crate) struct DstTransitionInfo {
 
 impl DstTransitionInfo {
     /// Returns true if DST is active
-    pub(crate) fn is_active(&self, date: &eos::Date, time: &eos::Time) -> bool {
-        let ts = NaiveTimestamp::new(date, time);
-        let start = self.start.timestamp_in_year(date.year());
-        let end = self.end.timestamp_in_year(date.year());
+    fn is_active(&self, date: &Date, time: &Time) -> bool {
+        let ts = local_seconds(date, time);
+        let start = self.start.local_seconds_in_year(date.year());
+        let end = self.end.local_seconds_in_year(date.year());
         if start < end {
             start <= ts && ts < end
         } else {
@@ -128,10 +195,11 @@ impl DstTransitionInfo {
     }
 
     /// Returns true if the UNIX timestamp is in DST
-    pub(crate) fn is_dst_utc(&self, ts: eos::Timestamp, std_offset: &UtcOffset) -> bool {
-        let utc = ts.to_utc();
-        let start = self.start.timestamp_in_year(utc.year()).to_regular(std_offset);
-        let end = self.end.timestamp_in_year(utc.year()).to_regular(&self.offset);
+    fn is_dst_utc(&self, ts: Timestamp, std_offset: &UtcOffset) -> bool {
+        let year = ts.to_utc().year();
+        let ts = ts.as_seconds();
+        let start = self.start.local_seconds_in_year(year) - std_offset.total_seconds() as i64;
+        let end = self.end.local_seconds_in_year(year) - self.offset.total_seconds() as i64;
         if start < end {
             start <= ts && ts < end
         } else {
@@ -140,21 +208,39 @@ impl DstTransitionInfo {
     }
 }
 
-/// A POSIX-string specified time zone rule.
+/// A timezone described by a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
 ///
 /// The details of this format are specified under the POSIX TZ rules
 /// under [Section 8.3]. These mainly show up either in the `TZ` environment
-/// variable or at the end of a TZif footer as specified by [RFC8536].
+/// variable or at the end of a TZif footer as specified by [RFC8536]. This allows
+/// DST aware timezones without requiring the IANA database.
+///
+/// The full syntax is supported:
+///
+/// - The standard abbreviation and offset, e.g. `EST5` or `<+0330>-3:30`.
+/// - An optional DST abbreviation and offset, which defaults to one hour ahead of standard time.
+/// - The `Jn`, `n`, and `Mm.n.d` transition rules, each with an optional `/time` that defaults
+///   to 02:00. As an extension from [RFC8536], the time may be negative or as large as 167 hours.
+///
+/// If a DST abbreviation is given without any transition rules then the US rules of
+/// `M3.2.0,M11.1.0` are used, matching the behaviour of most C libraries.
+///
+/// This requires the `posix` feature to be enabled.
 ///
 /// The typical way to create a [`PosixTimeZone`] is through the [`FromStr`] trait
 /// or via [`PosixTimeZone::new`].
 ///
 /// ```
-/// use eos_tz::PosixTimeZone;
-/// use std::str::FromStr;
+/// use eos::{datetime, extra::PosixTimeZone, TimeZone};
 ///
-/// let tz = PosixTimeZone::from_str("EST+5EDT,M3.2.0/2,M11.1.0/2")?;
-/// # Ok::<_, eos_tz::ParseError>(())
+/// let tz = PosixTimeZone::new("CET-1CEST,M3.5.0,M10.5.0/3")?;
+/// let dt = datetime!(2022-07-01 12:00).in_timezone(tz.clone());
+/// assert_eq!(dt, datetime!(2022-07-01 14:00 +02:00));
+/// assert_eq!(dt.tzname(), Some("CEST"));
+///
+/// // 02:30 on the last Sunday of March does not exist
+/// assert!(tz.resolve(eos::date!(2022-03-27), eos::time!(02:30)).is_missing());
+/// # Ok::<_, eos::extra::PosixTzError>(())
 /// ```
 ///
 /// [Section 8.3]: https://pubs.opengroup.org/onlinepubs/9699919799/
@@ -168,50 +254,61 @@ pub struct PosixTimeZone {
 
 impl PosixTimeZone {
     /// Creates a new [`PosixTimeZone`] with the given TZ string.
-    pub fn new(tz: &str) -> Result<Self, ParseError> {
-        // std[offset[dst[offset],start[/time],end[/time]]]
+    pub fn new(tz: &str) -> Result<Self, PosixTzError> {
+        // std[offset[dst[offset][,start[/time],end[/time]]]]
         let mut parser = tz.chars().peekable();
-        let std_abbr = parse_abbr(&mut parser)?;
+        let std_abbr = parse_abbr(&mut parser, PosixTzError::StdAbbreviation)?;
         let std_offset = if parser.peek().is_none() {
             UtcOffset::default()
         } else {
-            parse_offset(&mut parser)?
+            parse_offset(&mut parser, PosixTzError::StdOffset)?
         };
-        let dst = {
-            match parser.peek() {
-                None => None,
-                Some(_) => {
-                    let abbr = parse_abbr(&mut parser)?;
-                    let offset = match parser.peek() {
-                        Some(',') => {
-                            parser.next();
-                            std_offset.saturating_add(utc_offset!(01:00))
-                        }
-                        Some(_) => {
-                            let offset = parse_offset(&mut parser)?;
-                            if parser.next_if_eq(&',').is_none() {
-                                return Err(ParseError::InvalidPosixTz);
-                            }
-                            offset
+        let dst = match parser.peek() {
+            None => None,
+            Some(_) => {
+                let abbr = parse_abbr(&mut parser, PosixTzError::DstAbbreviation)?;
+                let offset = match parser.peek() {
+                    Some(',') | None => std_offset.saturating_add(ONE_HOUR),
+                    Some(_) => parse_offset(&mut parser, PosixTzError::DstOffset)?,
+                };
+                let (start, end) = match parser.next() {
+                    None => (
+                        DstTransitionRule::Calendar {
+                            month: 3,
+                            n: 2,
+                            weekday: 0,
+                            offset: 7200,
+                        },
+                        DstTransitionRule::Calendar {
+                            month: 11,
+                            n: 1,
+                            weekday: 0,
+                            offset: 7200,
+                        },
+                    ),
+                    Some(',') => {
+                        let start = parse_dst_transition_rule(&mut parser, PosixTzError::StartRule)?;
+                        if parser.next_if_eq(&',').is_none() {
+                            return Err(PosixTzError::EndRule);
                         }
-                        None => return Err(ParseError::InvalidPosixTz),
-                    };
-                    let start = parse_dst_transition_rule(&mut parser)?;
-                    if parser.next_if_eq(&',').is_none() {
-                        return Err(ParseError::InvalidPosixTz);
+                        let end = parse_dst_transition_rule(&mut parser, PosixTzError::EndRule)?;
+                        (start, end)
                     }
-                    let end = parse_dst_transition_rule(&mut parser)?;
-                    let base_offset = offset.saturating_sub(std_offset);
-                    Some(DstTransitionInfo {
-                        abbr,
-                        offset,
-                        start,
-                        end,
-                        base_offset,
-                    })
-                }
+                    Some(_) => return Err(PosixTzError::DstOffset),
+                };
+                let base_offset = offset.saturating_sub(std_offset);
+                Some(DstTransitionInfo {
+                    abbr,
+                    offset,
+                    start,
+                    end,
+                    base_offset,
+                })
             }
         };
+        if parser.next().is_some() {
+            return Err(PosixTzError::TrailingCharacters);
+        }
         Ok(PosixTimeZone {
             std_abbr,
             std_offset,
@@ -220,57 +317,30 @@ impl PosixTimeZone {
     }
 
     /// Returns `true` if the given local date and time are in DST
-    pub fn is_dst(&self, date: &eos::Date, time: &eos::Time) -> bool {
+    pub fn is_dst(&self, date: &Date, time: &Time) -> bool {
         match &self.dst {
             Some(dst) => dst.is_active(date, time),
             None => false,
         }
     }
 
-    pub(crate) fn shift_utc(&self, utc: &mut eos::DateTime<eos::Utc>) {
-        let ts = NaiveTimestamp::new(utc.date(), utc.time());
-        match self.dst.as_ref() {
-            None => {
-                utc.shift(self.std_offset);
-            }
-            Some(dst) => {
-                let mut dst_on = dst.start.timestamp_in_year(utc.year());
-                let mut dst_off = dst.end.timestamp_in_year(utc.year());
-                dst_on.0 -= self.std_offset.total_seconds() as i64;
-                dst_off.0 -= dst.offset.total_seconds() as i64;
-
-                let is_dst = if dst_on < dst_off {
-                    dst_on <= ts && ts < dst_off
-                } else {
-                    !(dst_off <= ts && ts < dst_on)
-                };
-                if is_dst {
-                    utc.shift(dst.offset);
-                } else {
-                    utc.shift(self.std_offset);
-                }
-            }
-        }
-    }
-
-    /// A "hack" to partially construct an eos::DateTimeResolution due to the lack of
-    /// Copy semantics in this type and how it requires moving the TimeZone type.
-    pub(crate) fn partial_resolution(
-        &self,
-        date: &eos::Date,
-        time: &eos::Time,
-    ) -> (eos::DateTimeResolutionKind, UtcOffset, UtcOffset) {
+    /// Resolves the given local date and time without consuming the timezone.
+    ///
+    /// This returns the kind of resolution along with the earlier and later offsets,
+    /// which are the same offset if the resolution is unambiguous. This is useful
+    /// when this timezone is wrapped by another [`TimeZone`] implementation.
+    pub fn resolve_offsets(&self, date: &Date, time: &Time) -> (DateTimeResolutionKind, UtcOffset, UtcOffset) {
         match &self.dst {
             Some(dst) => {
-                let ts = NaiveTimestamp::new(date, time).into_inner();
+                let ts = local_seconds(date, time);
                 // Ambiguous time if the offset is positive happens when DST ends,
                 // otherwise it happens when DST starts.
                 // On the other hand, when the offset is positive missing times happen
                 // when DST starts, otherwise when it ends.
                 // This can get pretty confusing, admittedly.
                 let dst_diff = dst.base_offset.total_seconds() as i64;
-                let end = dst.end.timestamp_in_year(date.year()).into_inner();
-                let start = dst.start.timestamp_in_year(date.year()).into_inner();
+                let end = dst.end.local_seconds_in_year(date.year());
+                let start = dst.start.local_seconds_in_year(date.year());
                 let is_dst = if start < end {
                     start <= ts && ts < end
                 } else {
@@ -285,17 +355,13 @@ impl PosixTimeZone {
                     // then 2:30am is missing
                     // so: 2:30am >= 2AM && 2:30am < (2am + 1 hour)
                     if (end - dst_diff) <= ts && ts < end {
-                        (eos::DateTimeResolutionKind::Ambiguous, dst.offset, self.std_offset)
+                        (DateTimeResolutionKind::Ambiguous, dst.offset, self.std_offset)
                     } else if start <= ts && ts < (start + dst_diff) {
-                        (eos::DateTimeResolutionKind::Missing, self.std_offset, dst.offset)
+                        (DateTimeResolutionKind::Missing, self.std_offset, dst.offset)
                     } else if is_dst {
-                        (eos::DateTimeResolutionKind::Unambiguous, dst.offset, dst.offset)
+                        (DateTimeResolutionKind::Unambiguous, dst.offset, dst.offset)
                     } else {
-                        (
-                            eos::DateTimeResolutionKind::Unambiguous,
-                            self.std_offset,
-                            self.std_offset,
-                        )
+                        (DateTimeResolutionKind::Unambiguous, self.std_offset, self.std_offset)
                     }
                 } else {
                     // Ambiguous
@@ -312,78 +378,94 @@ impl PosixTimeZone {
                     // Similar to above the "earlier" gap is the DST time (UTC+0) and the later time is
                     // after DST ends (UTC+1).
                     if (start + dst_diff) <= ts && ts < start {
-                        (eos::DateTimeResolutionKind::Ambiguous, self.std_offset, dst.offset)
+                        (DateTimeResolutionKind::Ambiguous, self.std_offset, dst.offset)
                     } else if end <= ts && ts < (end - dst_diff) {
-                        (eos::DateTimeResolutionKind::Missing, dst.offset, self.std_offset)
+                        (DateTimeResolutionKind::Missing, dst.offset, self.std_offset)
                     } else if is_dst {
-                        (eos::DateTimeResolutionKind::Unambiguous, dst.offset, dst.offset)
+                        (DateTimeResolutionKind::Unambiguous, dst.offset, dst.offset)
                     } else {
-                        (
-                            eos::DateTimeResolutionKind::Unambiguous,
-                            self.std_offset,
-                            self.std_offset,
-                        )
+                        (DateTimeResolutionKind::Unambiguous, self.std_offset, self.std_offset)
                     }
                 }
             }
-            None => (
-                eos::DateTimeResolutionKind::Unambiguous,
-                self.std_offset,
-                self.std_offset,
-            ),
+            None => (DateTimeResolutionKind::Unambiguous, self.std_offset, self.std_offset),
         }
     }
 }
 
-impl eos::TimeZone for PosixTimeZone {
-    fn name(&self, ts: eos::Timestamp) -> Option<&str> {
+impl TimeZone for PosixTimeZone {
+    fn name(&self, ts: Timestamp) -> Option<&str> {
         match &self.dst {
-            Some(dst) => {
-                if dst.is_dst_utc(ts, &self.std_offset) {
-                    Some(dst.abbr.as_str())
-                } else {
-                    Some(self.std_abbr.as_str())
-                }
-            }
-            None => Some(self.std_abbr.as_str()),
+            Some(dst) if dst.is_dst_utc(ts, &self.std_offset) => Some(dst.abbr.as_str()),
+            _ => Some(self.std_abbr.as_str()),
         }
     }
 
-    fn offset(&self, ts: eos::Timestamp) -> UtcOffset {
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
         match &self.dst {
-            Some(dst) => {
-                if dst.is_dst_utc(ts, &self.std_offset) {
-                    dst.offset
-                } else {
-                    self.std_offset
-                }
+            Some(dst) if dst.is_dst_utc(ts, &self.std_offset) => dst.offset,
+            _ => self.std_offset,
+        }
+    }
+
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        match &self.dst {
+            Some(dst) if dst.is_dst_utc(ts, &self.std_offset) => {
+                ZoneInfo::new(dst.offset, Some(dst.abbr.clone().into()), true)
             }
-            None => self.std_offset,
+            _ => ZoneInfo::new(self.std_offset, Some(self.std_abbr.clone().into()), false),
         }
     }
 
-    fn resolve(self, date: eos::Date, time: Time) -> eos::DateTimeResolution<Self>
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
-        let (kind, earlier, later) = self.partial_resolution(&date, &time);
+        let (kind, earlier, later) = self.resolve_offsets(&date, &time);
         match kind {
-            eos::DateTimeResolutionKind::Missing => eos::DateTimeResolution::missing(date, time, earlier, later, self),
-            eos::DateTimeResolutionKind::Unambiguous => eos::DateTimeResolution::unambiguous(date, time, earlier, self),
-            eos::DateTimeResolutionKind::Ambiguous => {
-                eos::DateTimeResolution::ambiguous(date, time, earlier, later, self)
-            }
+            DateTimeResolutionKind::Missing => DateTimeResolution::missing(date, time, earlier, later, self),
+            DateTimeResolutionKind::Unambiguous => DateTimeResolution::unambiguous(date, time, earlier, self),
+            DateTimeResolutionKind::Ambiguous => DateTimeResolution::ambiguous(date, time, earlier, later, self),
         }
     }
 
-    fn convert_utc(self, mut utc: eos::DateTime<eos::Utc>) -> eos::DateTime<Self>
+    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
-        self.shift_utc(&mut utc);
+        utc.shift(self.offset(utc.timestamp()));
         utc.with_timezone(self)
     }
 
+    fn next_transition(&self, after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)> {
+        let dst = self.dst.as_ref()?;
+        let ts = after.timestamp().as_seconds();
+        // A transition rule can push the transition into a neighbouring year,
+        // so the rules of the surrounding years are checked as well.
+        let year = after.year();
+        let mut candidates = [
+            year.checked_sub(1),
+            Some(year),
+            year.checked_add(1),
+            year.checked_add(2),
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|year| {
+            let start = dst.start.local_seconds_in_year(year) - self.std_offset.total_seconds() as i64;
+            let end = dst.end.local_seconds_in_year(year) - dst.offset.total_seconds() as i64;
+            [start, end]
+        })
+        .filter(|&t| t > ts)
+        .collect::<alloc::vec::Vec<_>>();
+        candidates.sort_unstable();
+        candidates.into_iter().find_map(|t| {
+            let before = self.offset(Timestamp::from_seconds(t - 1));
+            let after = self.offset(Timestamp::from_seconds(t));
+            (before != after).then(|| (Timestamp::from_seconds(t).to_utc(), before, after))
+        })
+    }
+
     fn is_fixed(&self) -> bool {
         self.dst.is_none()
     }
@@ -392,15 +474,15 @@ impl eos::TimeZone for PosixTimeZone {
 /*
     The format for the TZ string has the following format:
 
-    std[offset[dst[offset],start[/time],end[/time]]]
+    std[offset[dst[offset][,start[/time],end[/time]]]]
 
     std and dst must be between 3 to TZNAME_MAX characters long and
     may be quoted using < and >.
 
     The format of the offset is `[+-]hh[:mm[:ss]]`.
 
-    The DST rule format is `date[/time],date[/time]`. It must be provided
-    if a DST offset is provided. The `date` rule can be:
+    The DST rule format is `date[/time],date[/time]`. If it's omitted then
+    the US rules are used. The `date` rule can be:
 
     J<n> where 1 <= n <= 365 which is days not including leap years. So day 59
     is Feb 28th and day 60 is March 1st.
@@ -408,15 +490,16 @@ impl eos::TimeZone for PosixTimeZone {
     <n> where 0 <= n <= 365 which is days including leap years.
 
     M<m>.<n>.<d> which is `n`th weekday (`d`) of month `m` where `d` starts at
-    0 = Sunday and 6 = Monday. `n=5` means last `d` of the month.
+    0 = Sunday and 6 = Saturday. `n=5` means last `d` of the month.
 
-    The `time` rule is the same as the `offset` rule except without a leading +/-.
+    The `time` rule is the same as the `offset` rule except that RFC 8536 extends
+    the hours to be within [-167, 167].
 */
 
 type Parser<'a> = Peekable<Chars<'a>>;
-type ParseResult<T> = Result<T, ParseError>;
+type ParseResult<T> = Result<T, PosixTzError>;
 
-fn parse_abbr(parser: &mut Parser) -> ParseResult<String> {
+fn parse_abbr(parser: &mut Parser, error: PosixTzError) -> ParseResult<String> {
     // abbr are disambiguated by the fact that one of these must hold:
     // if it starts with < then it continues until it finds a >
     // else it continues until it finds one of the forbidden characters.
@@ -424,56 +507,34 @@ fn parse_abbr(parser: &mut Parser) -> ParseResult<String> {
     let mut abbr = String::new();
 
     match parser.next() {
-        Some('<') => {
-            for ch in parser.by_ref() {
-                if ch.is_ascii_alphanumeric() || ch == '+' || ch == '-' {
-                    abbr.push(ch);
-                } else if ch == '>' {
-                    break;
-                } else {
-                    return Err(ParseError::InvalidPosixTz);
-                }
+        Some('<') => loop {
+            match parser.next() {
+                Some(ch) if ch.is_ascii_alphanumeric() || ch == '+' || ch == '-' => abbr.push(ch),
+                Some('>') => break,
+                _ => return Err(error),
             }
-        }
-        Some(c) => {
-            if c.is_alphabetic() {
+        },
+        Some(c) if c.is_alphabetic() => {
+            abbr.push(c);
+            while let Some(c) = parser.next_if(|c| c.is_alphabetic()) {
                 abbr.push(c);
-            } else {
-                return Err(ParseError::InvalidPosixTz);
-            }
-            while let Some(c) = parser.peek() {
-                if c.is_alphabetic() {
-                    abbr.push(*c);
-                    parser.next();
-                } else {
-                    break;
-                }
             }
         }
-        None => {
-            return Err(ParseError::InvalidPosixTz);
-        }
+        _ => return Err(error),
     }
 
     if abbr.len() < 3 {
-        Err(ParseError::InvalidPosixTz)
+        Err(error)
     } else {
         Ok(abbr)
     }
 }
 
-#[inline]
-fn parse_digit(parser: &mut Parser) -> ParseResult<u8> {
-    match parser.next() {
-        Some(c) if c.is_ascii_digit() => Ok(c as u8 - b'0'),
-        _ => Err(ParseError::InvalidPosixTz),
-    }
-}
-
-fn parse_three_digit_number(parser: &mut Parser) -> ParseResult<u16> {
-    let mut read_any: bool = false;
+/// Parses a number with up to `max_digits` digits.
+fn parse_number(parser: &mut Parser, max_digits: usize, error: PosixTzError) -> ParseResult<u16> {
+    let mut read_any = false;
     let mut n: u16 = 0;
-    for _ in 0..3 {
+    for _ in 0..max_digits {
         match parser.next_if(char::is_ascii_digit) {
             Some(c) => {
                 n = n * 10 + (c as u8 - b'0') as u16;
@@ -486,113 +547,79 @@ fn parse_three_digit_number(parser: &mut Parser) -> ParseResult<u16> {
     if read_any {
         Ok(n)
     } else {
-        Err(ParseError::InvalidPosixTz)
+        Err(error)
+    }
+}
+
+/// Parses the optional `:mm[:ss]` portion of a time or offset into seconds.
+fn parse_minutes_and_seconds(parser: &mut Parser, error: PosixTzError) -> ParseResult<i64> {
+    let mut total = 0;
+    for multiplier in [60, 1] {
+        if parser.next_if_eq(&':').is_none() {
+            break;
+        }
+        let value = match parser.next().zip(parser.next()) {
+            Some((c, d)) if c.is_ascii_digit() && d.is_ascii_digit() => (c as u8 - b'0') * 10 + (d as u8 - b'0'),
+            _ => return Err(error),
+        };
+        if value > 59 {
+            return Err(error);
+        }
+        total += value as i64 * multiplier;
     }
+    Ok(total)
 }
 
-fn parse_offset(parser: &mut Parser) -> ParseResult<UtcOffset> {
+fn parse_offset(parser: &mut Parser, error: PosixTzError) -> ParseResult<UtcOffset> {
     // ([+-]?\d{1,2}(:\d{2}(:\d{2})?)?)
     // In POSIX, this is actually swapped.
     // HST10 is UTC -10 not UTC+10
     // + is *west* or left of UTC (i.e. negative) and - is *east* of UTC (i.e. positive).
-    let negative = match parser.peek() {
-        Some('+') => {
-            parser.next();
-            true
-        }
-        Some('-') => {
-            parser.next();
-            false
-        }
-        None => return Err(ParseError::InvalidPosixTz),
-        _ => true,
-    };
-
-    let hours = match parser.next() {
-        Some(c) if c.is_ascii_digit() => match parser.next_if(char::is_ascii_digit) {
-            Some(d) => (c as u8 - b'0') * 10 + (d as u8 - b'0'),
-            None => c as u8 - b'0',
-        },
-        _ => return Err(ParseError::InvalidPosixTz),
-    };
-
-    let minutes = match parser.next_if(|x| *x == ':') {
-        Some(_) => match parser.next().zip(parser.next()) {
-            Some((c, d)) => ((c as u8 - b'0') * 10 + (d as u8 - b'0')),
-            None => return Err(ParseError::InvalidPosixTz),
-        },
-        None => 0u8,
-    };
-
-    let seconds = match parser.next_if(|x| *x == ':') {
-        Some(_) => match parser.next().zip(parser.next()) {
-            Some((c, d)) => ((c as u8 - b'0') * 10 + (d as u8 - b'0')),
-            None => return Err(ParseError::InvalidPosixTz),
-        },
-        None => 0u8,
-    };
-
-    let mut seconds = hours as i32 * 3600 + minutes as i32 * 60 + seconds as i32;
+    let negative = parser.next_if(|c| *c == '+' || *c == '-') != Some('-');
+    let hours = parse_number(parser, 2, error)?;
+    if hours > 24 {
+        return Err(error);
+    }
+    let mut seconds = hours as i64 * 3600 + parse_minutes_and_seconds(parser, error)?;
     if negative {
         seconds = -seconds;
     }
 
-    UtcOffset::from_seconds(seconds).ok_or(ParseError::InvalidOffset)
+    UtcOffset::from_seconds(seconds as i32).ok_or(error)
 }
 
-fn parse_time(parser: &mut Parser) -> ParseResult<i64> {
-    let hour = match parser.next() {
-        Some(c) if c.is_ascii_digit() => match parser.next_if(char::is_ascii_digit) {
-            Some(d) => (c as u8 - b'0') * 10 + (d as u8 - b'0'),
-            None => c as u8 - b'0',
-        },
-        _ => return Err(ParseError::InvalidPosixTz),
-    };
-
-    let minute = match parser.next_if(|x| *x == ':') {
-        Some(_) => match parser.next().zip(parser.next()) {
-            Some((c, d)) => ((c as u8 - b'0') * 10 + (d as u8 - b'0')),
-            None => return Err(ParseError::InvalidPosixTz),
-        },
-        None => 0u8,
-    };
-
-    let second = match parser.next_if(|x| *x == ':') {
-        Some(_) => match parser.next().zip(parser.next()) {
-            Some((c, d)) => ((c as u8 - b'0') * 10 + (d as u8 - b'0')),
-            None => return Err(ParseError::InvalidPosixTz),
-        },
-        None => 0u8,
-    };
-
-    Ok(hour as i64 * 3600 + minute as i64 * 60 + second as i64)
+fn parse_time(parser: &mut Parser, error: PosixTzError) -> ParseResult<i64> {
+    // ([+-]?\d{1,3}(:\d{2}(:\d{2})?)?)
+    let negative = parser.next_if(|c| *c == '+' || *c == '-') == Some('-');
+    let hours = parse_number(parser, 3, error)?;
+    if hours > 167 {
+        return Err(error);
+    }
+    let seconds = hours as i64 * 3600 + parse_minutes_and_seconds(parser, error)?;
+    Ok(if negative { -seconds } else { seconds })
 }
 
-fn parse_dst_transition_rule(parser: &mut Parser) -> ParseResult<DstTransitionRule> {
+fn parse_dst_transition_rule(parser: &mut Parser, error: PosixTzError) -> ParseResult<DstTransitionRule> {
     // date[/time]
     // date can be either (J\d{1,3}|\d{1,3}|M\d{1,2}.\d.\d)
-    let rule = match parser.next() {
+    let rule = match parser.peek() {
         Some('J') => {
-            let day = parse_three_digit_number(parser)?;
-            if day < 1 || day > 365 {
-                return Err(ParseError::InvalidPosixTz);
+            parser.next();
+            let day = parse_number(parser, 3, error)?;
+            if !(1..=365).contains(&day) {
+                return Err(error);
             }
             DstTransitionRule::JulianDay(day, 0)
         }
         Some('M') => {
-            let month = match parser.next() {
-                Some(c) if c.is_ascii_digit() => match parser.next_if(char::is_ascii_digit) {
-                    Some(d) => (c as u8 - b'0') * 10 + (d as u8 - b'0'),
-                    None => c as u8 - b'0',
-                },
-                _ => return Err(ParseError::InvalidPosixTz),
-            };
-            parser.next_if_eq(&'.').ok_or(ParseError::InvalidPosixTz)?;
-            let n = parse_digit(parser)?;
-            parser.next_if_eq(&'.').ok_or(ParseError::InvalidPosixTz)?;
-            let weekday = parse_digit(parser)?;
-            if month > 12 || month < 1 || n < 1 || n > 5 || weekday > 6 {
-                return Err(ParseError::InvalidPosixTz);
+            parser.next();
+            let month = parse_number(parser, 2, error)? as u8;
+            parser.next_if_eq(&'.').ok_or(error)?;
+            let n = parse_number(parser, 1, error)? as u8;
+            parser.next_if_eq(&'.').ok_or(error)?;
+            let weekday = parse_number(parser, 1, error)? as u8;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&n) || weekday > 6 {
+                return Err(error);
             }
 
             DstTransitionRule::Calendar {
@@ -603,17 +630,23 @@ fn parse_dst_transition_rule(parser: &mut Parser) -> ParseResult<DstTransitionRu
             }
         }
         Some(c) if c.is_ascii_digit() => {
-            let day = parse_three_digit_number(parser)?;
+            let day = parse_number(parser, 3, error)?;
             if day > 365 {
-                return Err(ParseError::InvalidPosixTz);
+                return Err(error);
             }
             DstTransitionRule::Day(day, 0)
         }
-        _ => return Err(ParseError::InvalidPosixTz),
+        _ => return Err(error),
     };
 
-    let offset = match parser.next_if(|&x| x == '/') {
-        Some(_) => parse_time(parser)?,
+    let offset = match parser.next_if_eq(&'/') {
+        Some(_) => {
+            let error = match error {
+                PosixTzError::StartRule => PosixTzError::StartTime,
+                _ => PosixTzError::EndTime,
+            };
+            parse_time(parser, error)?
+        }
         None => 7200,
     };
 
@@ -621,38 +654,36 @@ fn parse_dst_transition_rule(parser: &mut Parser) -> ParseResult<DstTransitionRu
 }
 
 impl FromStr for PosixTimeZone {
-    type Err = ParseError;
+    type Err = PosixTzError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::new(s)
     }
 }
 
-fn display_offset(f: &mut std::fmt::Formatter<'_>, offset: &UtcOffset) -> std::fmt::Result {
+fn display_offset(f: &mut core::fmt::Formatter<'_>, offset: &UtcOffset) -> core::fmt::Result {
     // Offsets are swapped in POSIX timezones.
     // i.e. an offset of -5 UTC is represented as 5
-    let hours = -offset.hours();
-    let (minutes, seconds) = (offset.minutes().abs(), offset.seconds().abs());
-    if minutes != 0 || seconds != 0 {
-        write!(f, "{}:{:02}:{:02}", hours, minutes, seconds)
+    display_time(f, -offset.total_seconds() as i64)
+}
+
+fn display_abbr(f: &mut core::fmt::Formatter<'_>, abbr: &str) -> core::fmt::Result {
+    if abbr.chars().all(char::is_alphabetic) {
+        f.write_str(abbr)
     } else {
-        write!(f, "{}", hours)
+        write!(f, "<{}>", abbr)
     }
 }
 
-impl std::fmt::Display for PosixTimeZone {
+impl core::fmt::Display for PosixTimeZone {
     /// Converts the [`PosixTimeZone`] back into its original representation.
     ///
     /// Note that this does *not* roundtrip and makes no guarantee to do so.
     /// It just returns a suitable display representing the original data
     /// faithfully.
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // std[offset[dst[offset],start[/time],end[/time]]]
-        if self.std_abbr.as_bytes().iter().any(|x| !x.is_ascii_alphabetic()) {
-            write!(f, "<{}>", &self.std_abbr)?;
-        } else {
-            f.write_str(self.std_abbr.as_str())?;
-        }
+        display_abbr(f, &self.std_abbr)?;
         match &self.dst {
             None => {
                 if !self.std_offset.is_utc() {
@@ -661,13 +692,8 @@ impl std::fmt::Display for PosixTimeZone {
             }
             Some(dst) => {
                 display_offset(f, &self.std_offset)?;
-                if dst.abbr.as_bytes().iter().any(|x| !x.is_ascii_alphabetic()) {
-                    write!(f, "<{}>", &dst.abbr)?;
-                } else {
-                    f.write_str(dst.abbr.as_str())?;
-                }
-                let default = self.std_offset.saturating_add(utc_offset!(01:00));
-                if dst.offset != default {
+                display_abbr(f, &dst.abbr)?;
+                if dst.base_offset != ONE_HOUR {
                     display_offset(f, &dst.offset)?;
                 }
                 write!(f, ",{},{}", &dst.start, &dst.end)?;
@@ -678,10 +704,10 @@ impl std::fmt::Display for PosixTimeZone {
 }
 
 #[cfg(test)]
+#[allow(clippy::assign_op_pattern)]
 mod tests {
-    use eos::{datetime, ext::IntervalLiteral, DateTime, TimeZone, Utc};
-
     use super::*;
+    use crate::{date, datetime, ext::IntervalLiteral, time, utc_offset};
 
     #[test]
     fn test_utc_posix() {
@@ -1037,7 +1063,7 @@ mod tests {
     }
 
     #[test]
-    fn america_santiago_regression() -> Result<(), ParseError> {
+    fn america_santiago_regression() -> Result<(), PosixTzError> {
         let posix = PosixTimeZone::new("<-04>4<-03>,M9.1.6/24,M4.1.6/24")?;
         // DST doesn't end until 2040-04-08 00:00
         let dt = datetime!(2040-04-06 00:00);
@@ -1048,7 +1074,7 @@ mod tests {
     }
 
     #[test]
-    fn test_display_repr() -> Result<(), ParseError> {
+    fn test_display_repr() -> Result<(), PosixTzError> {
         let posix = PosixTimeZone::new("GMT5")?;
         assert_eq!(posix.to_string(), "GMT5");
         let posix = PosixTimeZone::new("<-04>4<-03>,M9.1.6/24,M4.1.6/24")?;
@@ -1057,4 +1083,108 @@ mod tests {
         assert_eq!(posix.to_string(), "EST5EDT,M3.2.0/2,M11.1.0/2");
         Ok(())
     }
+
+    #[test]
+    fn test_error_fields() {
+        let cases = [
+            ("", PosixTzError::StdAbbreviation),
+            ("E5", PosixTzError::StdAbbreviation),
+            ("<EST5", PosixTzError::StdAbbreviation),
+            ("EST25", PosixTzError::StdOffset),
+            ("EST5:7", PosixTzError::StdOffset),
+            ("EST5:60", PosixTzError::StdOffset),
+            ("EST5ED,M3.2.0,M11.1.0", PosixTzError::DstAbbreviation),
+            ("EST5EDT4:xx,M3.2.0,M11.1.0", PosixTzError::DstOffset),
+            ("EST5EDT,M13.2.0,M11.1.0", PosixTzError::StartRule),
+            ("EST5EDT,J0,M11.1.0", PosixTzError::StartRule),
+            ("EST5EDT,M3.2.0/168,M11.1.0", PosixTzError::StartTime),
+            ("EST5EDT,M3.2.0", PosixTzError::EndRule),
+            ("EST5EDT,M3.2.0,366", PosixTzError::EndRule),
+            ("EST5EDT,M3.2.0,M11.1.0/x", PosixTzError::EndTime),
+            ("EST5EDT,M3.2.0,M11.1.0 ", PosixTzError::TrailingCharacters),
+        ];
+
+        for (tz, error) in cases {
+            assert_eq!(PosixTimeZone::new(tz), Err(error), "{:?}", tz);
+        }
+    }
+
+    #[test]
+    fn test_day_rules() -> Result<(), PosixTzError> {
+        // J60 is always March 1st while 60 is March 1st only in leap years
+        let julian = PosixTimeZone::new("XXX0YYY,J60/0,J300/0")?;
+        let zero_based = PosixTimeZone::new("XXX0YYY,60/0,300/0")?;
+        let midnight = time!(00:00);
+        for march_first in [date!(2023 - 03 - 01), date!(2024 - 03 - 01)] {
+            let end_of_february = march_first.pred().unwrap();
+            assert!(!julian.is_dst(&end_of_february, &midnight));
+            assert!(julian.is_dst(&march_first, &midnight));
+            assert_eq!(
+                zero_based.is_dst(&march_first, &midnight),
+                is_leap_year(march_first.year())
+            );
+        }
+
+        // Day 0 is January 1st
+        let tz = PosixTimeZone::new("XXX0YYY,0/0,J365/0")?;
+        assert!(tz.is_dst(&date!(2022 - 01 - 01), &time!(00:00)));
+        assert!(!tz.is_dst(&date!(2021 - 12 - 31), &time!(23:59)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_extended_transition_times() -> Result<(), PosixTzError> {
+        // America/Godthab uses a negative transition time: DST starts the
+        // day before the last Sunday of March at 22:00
+        let tz = PosixTimeZone::new("<-02>2<-01>,M3.5.0/-1,M10.5.0/0")?;
+        let start = tz.clone().resolve(date!(2022 - 03 - 26), time!(23:30));
+        assert!(start.is_missing());
+        assert_eq!(tz.to_string(), "<-02>2<-01>,M3.5.0/-1,M10.5.0/0");
+
+        // Asia/Jerusalem uses transition times past 24 hours in RFC 8536 examples
+        let tz = PosixTimeZone::new("IST-2IDT,M3.4.4/26,M10.5.0")?;
+        assert!(tz.clone().resolve(date!(2022 - 03 - 25), time!(02:30)).is_missing());
+        assert_eq!(tz.to_string(), "IST-2IDT,M3.4.4/26,M10.5.0/2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_rules() -> Result<(), PosixTzError> {
+        let tz = PosixTimeZone::new("EST5EDT")?;
+        assert_eq!(tz, PosixTimeZone::new("EST5EDT,M3.2.0/2,M11.1.0/2")?);
+        assert!(tz.clone().resolve(date!(2021 - 03 - 14), time!(02:30)).is_missing());
+        assert!(tz.resolve(date!(2021 - 11 - 07), time!(01:30)).is_ambiguous());
+
+        let tz = PosixTimeZone::new("<+0330>-3:30")?;
+        assert!(tz.is_fixed());
+        assert_eq!(tz.offset(Timestamp::from_seconds(0)), utc_offset!(+03:30));
+        assert_eq!(tz.to_string(), "<+0330>-3:30");
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_transition() -> Result<(), PosixTzError> {
+        let tz = PosixTimeZone::new("EST5EDT,M3.2.0,M11.1.0")?;
+        let est = utc_offset!(-05:00);
+        let edt = utc_offset!(-04:00);
+        let start = datetime!(2021-03-14 07:00);
+        let end = datetime!(2021-11-07 06:00);
+        assert_eq!(
+            tz.next_transition(&datetime!(2021-01-01 00:00)),
+            Some((start, est, edt))
+        );
+        assert_eq!(tz.next_transition(&(start - 1.seconds())), Some((start, est, edt)));
+        assert_eq!(tz.next_transition(&start), Some((end, edt, est)));
+        assert_eq!(tz.next_transition(&end), Some((datetime!(2022-03-13 07:00), est, edt)));
+
+        // Southern hemisphere rules wrap around the year
+        let tz = PosixTimeZone::new("AEST-10AEDT,M10.1.0,M4.1.0/3")?;
+        assert_eq!(
+            tz.next_transition(&datetime!(2021-12-31 00:00)),
+            Some((datetime!(2022-04-02 16:00), utc_offset!(+11:00), utc_offset!(+10:00)))
+        );
+
+        assert_eq!(PosixTimeZone::new("UTC")?.next_transition(&start), None);
+        Ok(())
+    }
 }
@@ -0,0 +1,129 @@
+//! [`Arbitrary`] implementations for [`proptest`](https://docs.rs/proptest).
+//!
+//! Every strategy only produces valid values, shrinks toward the UNIX epoch and
+//! midnight, and picks an edge case one time out of five. The distributions are:
+//!
+//! - [`Date`]: uniform over the days from [`Date::MIN`] to [`Date::MAX`]. The edge
+//!   cases are January 1st, December 31st, or February 29th of a uniformly chosen year,
+//!   along with [`Date::MIN`] and [`Date::MAX`].
+//! - [`Time`]: uniform over every nanosecond of the day. The edge cases are midnight,
+//!   noon, `23:59:59`, and `23:59:59.999999999`.
+//! - [`DateTime<Utc>`]: an independently chosen [`Date`] and [`Time`].
+//! - [`Interval`]: each component is uniform over [`INTERVAL_RANGE`], or one time out
+//!   of five independently one of `-1`, `0`, or `1`.
+//!
+//! February 29th is taken from the closest leap year at or before the chosen year.
+//! Leap seconds are never produced.
+//!
+//! Values are a pure function of proptest's RNG, so the seeds proptest persists for
+//! failing cases reproduce the same values.
+
+use alloc::vec;
+use proptest::{
+    arbitrary::Arbitrary,
+    prop_oneof,
+    sample::select,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+use crate::{gregorian::is_leap_year, Date, DateTime, Interval, Time, Utc};
+
+/// The number of nanoseconds in a day.
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// The ranges used for the months, days, and microseconds of an arbitrary [`Interval`].
+///
+/// These cover the distance between [`Date::MIN`] and [`Date::MAX`], so adding one of
+/// these intervals to a date can go out of range, but can't overflow the interval itself.
+pub const INTERVAL_RANGE: (i32, i32, i64) = (
+    u16::MAX as i32 * 12,
+    u16::MAX as i32 * 366,
+    u16::MAX as i64 * 366 * 86_400_000_000,
+);
+
+fn edge_date(offset: i32, kind: u8) -> Date {
+    let year = (1970 + offset) as i16;
+    match kind {
+        0 => Date::new(year, 1, 1),
+        1 => Date::new(year, 12, 31),
+        _ => {
+            let mut year = year - year.rem_euclid(4);
+            if !is_leap_year(year) {
+                year -= 4;
+            }
+            Date::new(year, 2, 29)
+        }
+    }
+    .expect("edge case dates are valid")
+}
+
+impl Arbitrary for Date {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let years = (Date::MIN.year() as i32 - 1970)..=(Date::MAX.year() as i32 - 1970);
+        let edges = prop_oneof![
+            4 => (years, 0..3u8).prop_map(|(offset, kind)| edge_date(offset, kind)),
+            1 => select(vec![Date::MIN, Date::MAX]),
+        ];
+        prop_oneof![
+            4 => (Date::MIN.days_since_epoch()..=Date::MAX.days_since_epoch())
+                .prop_map(|days| Date::from_epoch_days(days).expect("days are within range")),
+            1 => edges,
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Time {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let noon = Time::new(12, 0, 0).expect("noon is valid");
+        let last_second = Time::new(23, 59, 59).expect("the last second is valid");
+        prop_oneof![
+            4 => (0..NANOS_PER_DAY).prop_map(|nanos| Time::adjust_from_nanos(nanos as i64).1),
+            1 => select(vec![Time::MIDNIGHT, noon, last_second, Time::MAX]),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for DateTime<Utc> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (Date::arbitrary(), Time::arbitrary())
+            .prop_map(|(date, time)| date.at(time))
+            .boxed()
+    }
+}
+
+fn component<T>(range: core::ops::RangeInclusive<T>, small: core::ops::RangeInclusive<T>) -> BoxedStrategy<T>
+where
+    T: core::fmt::Debug + 'static,
+    core::ops::RangeInclusive<T>: Strategy<Value = T> + 'static,
+{
+    prop_oneof![4 => range, 1 => small].boxed()
+}
+
+impl Arbitrary for Interval {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let (months, days, micros) = INTERVAL_RANGE;
+        (
+            component(-months..=months, -1..=1),
+            component(-days..=days, -1..=1),
+            component(-micros..=micros, -1..=1),
+        )
+            .prop_map(|(months, days, micros)| {
+                Interval::from_months(months) + Interval::from_days(days) + Interval::from_microseconds(micros)
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,182 @@
+//! Random generation of dates and times with [`rand`](https://docs.rs/rand).
+//!
+//! [`Date`], [`Time`], and [`DateTime<Utc>`] implement [`SampleUniform`], so they
+//! can be used with [`Rng::gen_range`] and [`Uniform`](rand::distributions::Uniform).
+//! Sampling is uniform over the underlying number of days since the UNIX epoch for
+//! [`Date`], nanoseconds since midnight for [`Time`], and nanoseconds since the UNIX
+//! epoch for [`DateTime<Utc>`]. Empty ranges panic the same way they do for integers.
+//!
+//! The [`Standard`] distribution produces any valid value of each type. Leap seconds
+//! are never produced.
+//!
+//! ```
+//! use eos::date;
+//! use rand::Rng;
+//!
+//! let mut rng = rand::thread_rng();
+//! let date = rng.gen_range(date!(2000-01-01)..date!(2030-01-01));
+//! assert!(date >= date!(2000-01-01) && date < date!(2030-01-01));
+//! ```
+
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+        Distribution, Standard,
+    },
+    Rng,
+};
+
+use crate::{utils::divmod, Date, DateTime, Time, Timestamp, Utc};
+
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// The number of nanoseconds since midnight, with leap seconds clamped to the last
+/// nanosecond of the day so that every value maps back to a valid [`Time`].
+fn time_to_nanos(time: &Time) -> u64 {
+    time.total_nanos().min(NANOS_PER_DAY - 1)
+}
+
+fn nanos_to_time(nanos: u64) -> Time {
+    Time::adjust_from_nanos(nanos as i64).1
+}
+
+fn datetime_to_nanos(dt: &DateTime<Utc>) -> i128 {
+    dt.timestamp().as_nanoseconds()
+}
+
+fn nanos_to_datetime(nanos: i128) -> DateTime<Utc> {
+    let (seconds, nanoseconds) = divmod!(nanos, 1_000_000_000);
+    Timestamp::new(seconds as i64, nanoseconds as u32).to_utc()
+}
+
+/// The back-end for sampling [`Date`] values uniformly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformDate(UniformInt<i32>);
+
+impl UniformSampler for UniformDate {
+    type X = Date;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(
+            low.borrow().days_since_epoch(),
+            high.borrow().days_since_epoch(),
+        ))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(
+            low.borrow().days_since_epoch(),
+            high.borrow().days_since_epoch(),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Date::from_epoch_days(self.0.sample(rng)).expect("sampled between two valid dates")
+    }
+}
+
+impl SampleUniform for Date {
+    type Sampler = UniformDate;
+}
+
+/// The back-end for sampling [`Time`] values uniformly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformTime(UniformInt<u64>);
+
+impl UniformSampler for UniformTime {
+    type X = Time;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(
+            time_to_nanos(low.borrow()),
+            time_to_nanos(high.borrow()),
+        ))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(
+            time_to_nanos(low.borrow()),
+            time_to_nanos(high.borrow()),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        nanos_to_time(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for Time {
+    type Sampler = UniformTime;
+}
+
+/// The back-end for sampling [`DateTime<Utc>`] values uniformly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformDateTime(UniformInt<i128>);
+
+impl UniformSampler for UniformDateTime {
+    type X = DateTime<Utc>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(
+            datetime_to_nanos(low.borrow()),
+            datetime_to_nanos(high.borrow()),
+        ))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(
+            datetime_to_nanos(low.borrow()),
+            datetime_to_nanos(high.borrow()),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        nanos_to_datetime(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for DateTime<Utc> {
+    type Sampler = UniformDateTime;
+}
+
+impl Distribution<Date> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Date {
+        rng.gen_range(Date::MIN..=Date::MAX)
+    }
+}
+
+impl Distribution<Time> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time {
+        nanos_to_time(rng.gen_range(0..NANOS_PER_DAY))
+    }
+}
+
+impl Distribution<DateTime<Utc>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DateTime<Utc> {
+        rng.gen_range(DateTime::MIN..=DateTime::MAX)
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{
+    gregorian::{date_to_epoch_days, days_in_month, weekday_difference, weekday_from_days},
+    utils::ensure_in_range,
+    Date, Weekday,
+};
+
+/// The number of days in a 400 year Gregorian cycle.
+const DAYS_IN_400_YEARS: i32 = 146_097;
+
+/// The rule used to determine the first week of a year relative to an anchor date.
+///
+/// Every week starts on the same weekday, which is specified separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekRule {
+    /// The first week is the one that contains the anchor date.
+    Containing,
+    /// The first week is the first one that starts on or after the anchor date.
+    FirstFull,
+    /// The first week starts on the week start day nearest to the anchor date.
+    ///
+    /// For example, the ISO week calendar starts on the Monday nearest January 1st.
+    Nearest,
+    /// The first week is the first one with at least this many days on or after the anchor date.
+    ///
+    /// This must be between 1 and 7. The other rules are special cases of this one:
+    /// [`WeekRule::Containing`] is 1 day, [`WeekRule::Nearest`] is 4 days, and
+    /// [`WeekRule::FirstFull`] is 7 days.
+    MinimumDays(u8),
+}
+
+/// A retail calendar using the 4-4-5 week pattern.
+///
+/// Retail (or 52-53 week) calendars split a fiscal year into four quarters of 13 weeks,
+/// each consisting of 3 periods of 4, 4, and 5 weeks respectively. Since a year is not a
+/// whole number of weeks, every 5 or 6 years gets an extra 53rd week which is added to the
+/// last period of the year.
+///
+/// The start of a fiscal year is determined by an anchor date, a weekday that every week
+/// starts on, and a [`WeekRule`]. A fiscal year is named after the calendar year of its
+/// anchor date.
+///
+/// # Examples
+///
+/// A calendar where the year starts on the Sunday nearest February 1st:
+///
+/// ```
+/// use eos::{date, Weekday};
+/// use eos::extra::{Retail445Calendar, WeekRule};
+///
+/// let calendar = Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::Nearest).unwrap();
+/// assert_eq!(calendar.start_of_year(2023), date!(2023-01-29));
+/// assert_eq!(calendar.weeks_in_year(2023), 53);
+///
+/// let fiscal = calendar.fiscal_date(date!(2023-04-10));
+/// assert_eq!(fiscal.year(), 2023);
+/// assert_eq!(fiscal.quarter(), 1);
+/// assert_eq!(fiscal.period(), 3);
+/// assert_eq!(fiscal.week(), 11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Retail445Calendar {
+    month: u8,
+    day: u8,
+    week_start: Weekday,
+    rule: WeekRule,
+}
+
+/// A date within a [`Retail445Calendar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RetailDate {
+    year: i16,
+    period: u8,
+    week: u8,
+}
+
+impl Retail445Calendar {
+    /// Creates a new [`Retail445Calendar`] from the given anchor month and day, the weekday
+    /// that each week starts on, and the rule used to find the first week of the year.
+    ///
+    /// Returns `None` if the anchor month and day are out of range, or if the days of a
+    /// [`WeekRule::MinimumDays`] rule are not between 1 and 7. Since the anchor has to
+    /// exist every year, February 29th is not a valid anchor.
+    pub fn new(month: u8, day: u8, week_start: Weekday, rule: WeekRule) -> Option<Self> {
+        ensure_in_range!(month, 1 => 12);
+        // 2001 is not a leap year
+        ensure_in_range!(day, 1 => days_in_month(2001, month));
+        if let WeekRule::MinimumDays(days) = rule {
+            ensure_in_range!(days, 1 => 7);
+        }
+        Some(Self {
+            month,
+            day,
+            week_start,
+            rule,
+        })
+    }
+
+    fn start_epoch(&self, year: i32) -> i32 {
+        // The Gregorian calendar repeats every 400 years, which is a whole number of weeks.
+        // This allows computing the bounds of the years at either end of the range.
+        if year > i16::MAX as i32 {
+            return self.start_epoch(year - 400) + DAYS_IN_400_YEARS;
+        }
+        if year < i16::MIN as i32 {
+            return self.start_epoch(year + 400) - DAYS_IN_400_YEARS;
+        }
+
+        let anchor = date_to_epoch_days(year as i16, self.month, self.day);
+        let behind = weekday_difference(weekday_from_days(anchor), self.week_start.days_from_sunday()) as i32;
+        let minimum_days = match self.rule {
+            WeekRule::Containing => 1,
+            WeekRule::FirstFull => 7,
+            WeekRule::Nearest => 4,
+            WeekRule::MinimumDays(days) => days as i32,
+        };
+        // The week containing the anchor has 7 - behind days on or after it
+        if 7 - behind >= minimum_days {
+            anchor - behind
+        } else {
+            anchor + 7 - behind
+        }
+    }
+
+    /// Returns the date the given fiscal year starts on.
+    pub fn start_of_year(&self, year: i16) -> Date {
+        Date::UNIX_EPOCH.add_days(self.start_epoch(year as i32))
+    }
+
+    /// Returns the number of weeks in the given fiscal year. This is either 52 or 53.
+    pub fn weeks_in_year(&self, year: i16) -> u8 {
+        let year = year as i32;
+        ((self.start_epoch(year + 1) - self.start_epoch(year)) / 7) as u8
+    }
+
+    /// Returns the fiscal year, period, and week that the given date falls in.
+    ///
+    /// Dates near [`Date::MIN`] and [`Date::MAX`] can belong to a fiscal year outside
+    /// of the range of an `i16`. These saturate to the first week of the first year
+    /// or the last week of the last year respectively.
+    pub fn fiscal_date(&self, date: Date) -> RetailDate {
+        let epoch = date.days_since_epoch();
+        let mut year = date.year();
+        let mut start = self.start_epoch(year as i32);
+        if epoch < start {
+            // Anchors late in the year can start the fiscal year in the next calendar year
+            while epoch < start && year != i16::MIN {
+                year -= 1;
+                start = self.start_epoch(year as i32);
+            }
+        } else if year != i16::MAX {
+            let next = self.start_epoch(year as i32 + 1);
+            if epoch >= next {
+                year += 1;
+                start = next;
+            }
+        }
+
+        let weeks = ((epoch - start) / 7).clamp(0, 52) as u8;
+        let quarter = (weeks / 13).min(3);
+        let period = match weeks - quarter * 13 {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+
+        RetailDate {
+            year,
+            period: quarter * 3 + period + 1,
+            week: weeks + 1,
+        }
+    }
+}
+
+impl RetailDate {
+    /// Returns the fiscal year.
+    pub fn year(&self) -> i16 {
+        self.year
+    }
+
+    /// Returns the fiscal quarter, from 1 to 4.
+    pub fn quarter(&self) -> u8 {
+        (self.period - 1) / 3 + 1
+    }
+
+    /// Returns the fiscal period, from 1 to 12.
+    pub fn period(&self) -> u8 {
+        self.period
+    }
+
+    /// Returns the week of the fiscal year, from 1 to 53.
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::date;
+
+    #[test]
+    fn test_construction() {
+        assert!(Retail445Calendar::new(0, 1, Weekday::Sunday, WeekRule::Nearest).is_none());
+        assert!(Retail445Calendar::new(13, 1, Weekday::Sunday, WeekRule::Nearest).is_none());
+        assert!(Retail445Calendar::new(2, 29, Weekday::Sunday, WeekRule::Nearest).is_none());
+        assert!(Retail445Calendar::new(4, 31, Weekday::Sunday, WeekRule::Nearest).is_none());
+        assert!(Retail445Calendar::new(12, 31, Weekday::Sunday, WeekRule::Nearest).is_some());
+        assert!(Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::MinimumDays(0)).is_none());
+        assert!(Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::MinimumDays(8)).is_none());
+        assert!(Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::MinimumDays(1)).is_some());
+        assert!(Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::MinimumDays(7)).is_some());
+    }
+
+    #[test]
+    fn test_nearest_matches_iso_weeks() {
+        // The ISO week calendar starts on the Monday nearest January 1st
+        let calendar = Retail445Calendar::new(1, 1, Weekday::Monday, WeekRule::Nearest).unwrap();
+        // It also starts on the week containing January 4th
+        let containing = Retail445Calendar::new(1, 4, Weekday::Monday, WeekRule::Containing).unwrap();
+        let mut date = date!(1990 - 01 - 01);
+        while date < date!(2040 - 01 - 01) {
+            let iso = date.iso_week();
+            let fiscal = calendar.fiscal_date(date);
+            assert_eq!((fiscal.year(), fiscal.week()), (iso.year(), iso.week()), "{}", date);
+            assert_eq!(
+                calendar.weeks_in_year(iso.year()),
+                crate::gregorian::iso_weeks_in_year(iso.year())
+            );
+            assert_eq!(containing.fiscal_date(date), fiscal);
+            date = date.add_days(1);
+        }
+    }
+
+    #[test]
+    fn test_retail_year_boundaries() {
+        // The fiscal year starts on the Sunday nearest February 1st
+        let calendar = Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::Nearest).unwrap();
+        let starts = [
+            (2012, date!(2012 - 01 - 29), 53),
+            (2013, date!(2013 - 02 - 03), 52),
+            (2016, date!(2016 - 01 - 31), 52),
+            (2017, date!(2017 - 01 - 29), 53),
+            (2018, date!(2018 - 02 - 04), 52),
+            (2022, date!(2022 - 01 - 30), 52),
+            (2023, date!(2023 - 01 - 29), 53),
+            (2024, date!(2024 - 02 - 04), 52),
+        ];
+
+        for (year, start, weeks) in starts {
+            assert_eq!(calendar.start_of_year(year), start);
+            assert_eq!(calendar.weeks_in_year(year), weeks);
+            let first = calendar.fiscal_date(start);
+            assert_eq!((first.year(), first.period(), first.week()), (year, 1, 1));
+            let last = calendar.fiscal_date(start.add_days(-1));
+            assert_eq!(last.year(), year - 1);
+            assert_eq!(last.period(), 12);
+            assert_eq!(last.week(), calendar.weeks_in_year(year - 1));
+        }
+    }
+
+    #[test]
+    fn test_periods() {
+        let calendar = Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::Nearest).unwrap();
+        let start = calendar.start_of_year(2023);
+        let expected_weeks = [4, 4, 5, 4, 4, 5, 4, 4, 5, 4, 4, 6];
+        let mut offset = 0;
+        for (index, weeks) in expected_weeks.iter().enumerate() {
+            let period = index as u8 + 1;
+            for day in 0..(weeks * 7) {
+                let fiscal = calendar.fiscal_date(start.add_days(offset + day));
+                assert_eq!(fiscal.year(), 2023);
+                assert_eq!(fiscal.period(), period);
+                assert_eq!(fiscal.quarter(), (period - 1) / 3 + 1);
+            }
+            offset += weeks * 7;
+        }
+        assert_eq!(calendar.fiscal_date(start.add_days(offset)).year(), 2024);
+    }
+
+    #[test]
+    fn test_rules() {
+        // 2022-02-01 is a Tuesday
+        let anchor = date!(2022 - 02 - 01);
+        let containing = Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::Containing).unwrap();
+        let first_full = Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::FirstFull).unwrap();
+        let nearest = Retail445Calendar::new(2, 1, Weekday::Friday, WeekRule::Nearest).unwrap();
+        assert_eq!(containing.start_of_year(2022), date!(2022 - 01 - 30));
+        assert_eq!(first_full.start_of_year(2022), date!(2022 - 02 - 06));
+        assert_eq!(nearest.start_of_year(2022), date!(2022 - 02 - 04));
+        assert_eq!(first_full.fiscal_date(anchor).year(), 2021);
+        assert_eq!(containing.fiscal_date(anchor).year(), 2022);
+
+        // When the anchor is on the week start they all agree
+        let anchor = date!(2022 - 01 - 30);
+        for rule in [WeekRule::Containing, WeekRule::FirstFull, WeekRule::Nearest] {
+            let calendar = Retail445Calendar::new(1, 30, Weekday::Sunday, rule).unwrap();
+            assert_eq!(calendar.start_of_year(2022), anchor);
+        }
+
+        // A December anchor can start the year in the previous calendar year
+        let calendar = Retail445Calendar::new(12, 31, Weekday::Monday, WeekRule::Nearest).unwrap();
+        assert_eq!(calendar.start_of_year(2021), date!(2022 - 01 - 03));
+        let fiscal = calendar.fiscal_date(date!(2022 - 01 - 02));
+        assert_eq!((fiscal.year(), fiscal.week()), (2020, 53));
+        assert_eq!(calendar.weeks_in_year(2020), 53);
+    }
+
+    #[test]
+    fn test_minimum_days_rule() {
+        let equivalent = [
+            (WeekRule::Containing, 1),
+            (WeekRule::Nearest, 4),
+            (WeekRule::FirstFull, 7),
+        ];
+        for (rule, days) in equivalent {
+            for week_start in [Weekday::Sunday, Weekday::Monday, Weekday::Saturday] {
+                let calendar = Retail445Calendar::new(2, 1, week_start, rule).unwrap();
+                let custom = Retail445Calendar::new(2, 1, week_start, WeekRule::MinimumDays(days)).unwrap();
+                for year in 1990..2040 {
+                    assert_eq!(calendar.start_of_year(year), custom.start_of_year(year));
+                }
+            }
+        }
+
+        // 2022-02-01 is a Tuesday, so the week starting on Sunday January 30th has 5 days
+        // on or after it and the week after that starts on February 6th
+        let at_least = |days| Retail445Calendar::new(2, 1, Weekday::Sunday, WeekRule::MinimumDays(days)).unwrap();
+        assert_eq!(at_least(5).start_of_year(2022), date!(2022 - 01 - 30));
+        assert_eq!(at_least(6).start_of_year(2022), date!(2022 - 02 - 06));
+    }
+
+    #[test]
+    fn test_year_bounds() {
+        for rule in [
+            WeekRule::Containing,
+            WeekRule::FirstFull,
+            WeekRule::Nearest,
+            WeekRule::MinimumDays(2),
+        ] {
+            for (month, day) in [(1, 1), (2, 1), (12, 31)] {
+                let calendar = Retail445Calendar::new(month, day, Weekday::Monday, rule).unwrap();
+                for year in [i16::MIN, i16::MIN + 1, i16::MAX - 1, i16::MAX] {
+                    assert!((52..=53).contains(&calendar.weeks_in_year(year)), "{:?} {}", rule, year);
+                }
+
+                let first = calendar.fiscal_date(Date::MIN);
+                assert_eq!(first.year(), i16::MIN);
+                assert!((1..=53).contains(&first.week()));
+                // A late anchor can start the last fiscal year after Date::MAX
+                let last = calendar.fiscal_date(Date::MAX);
+                assert!(last.year() >= i16::MAX - 1);
+                assert!((1..=53).contains(&last.week()));
+            }
+        }
+    }
+}
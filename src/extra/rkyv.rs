@@ -0,0 +1,214 @@
+//! Zero-copy serialization with [`rkyv`](https://docs.rs/rkyv).
+//!
+//! [`Date`], [`Time`], [`UtcOffset`], [`Utc`], [`Interval`], and [`DateTime`] with
+//! either [`Utc`] or [`UtcOffset`] implement [`Archive`](rkyv::Archive),
+//! [`Serialize`](rkyv::Serialize), and [`Deserialize`](rkyv::Deserialize).
+//!
+//! # Layout
+//!
+//! The archived types are `#[repr(C)]` and store their fields in the order listed
+//! below, with padding only where a field needs it for alignment. Sizes are for rkyv's
+//! default format, which uses aligned little-endian integers. The `unaligned` and
+//! `big_endian` features of rkyv change the padding and byte order but not the order
+//! of the fields.
+//!
+//! | Type                            | Fields                                             | Size     |
+//! |:--------------------------------|:---------------------------------------------------|:---------|
+//! | [`ArchivedDate`]                | year (`i16`), month (`u8`), day (`u8`)             | 4 bytes  |
+//! | [`ArchivedTime`]                | hour, minute, second (`u8`), nanosecond (`u32`)    | 8 bytes  |
+//! | [`ArchivedUtcOffset`]           | hours, minutes, seconds (`i8`)                     | 3 bytes  |
+//! | [`ArchivedUtc`]                 | none                                               | 0 bytes  |
+//! | [`ArchivedInterval`]            | months (`i32`), days (`i32`), microseconds (`i64`) | 16 bytes |
+//! | [`ArchivedDateTime<Utc>`]       | date, time, offset, timezone                       | 16 bytes |
+//! | [`ArchivedDateTime<UtcOffset>`] | date, time, offset, timezone                       | 20 bytes |
+//!
+//! The date and time of an archived [`DateTime`] are local to its offset, which is the
+//! same representation [`DateTime`] uses in memory.
+//!
+//! An archived value can be turned back into its regular type with [`From`], which is
+//! cheaper than going through [`Deserialize`](rkyv::Deserialize).
+//!
+//! # Validation
+//!
+//! Validating an archive with `rkyv::access` or `rkyv::from_bytes` checks the same
+//! invariants the constructors do, so an untrusted archive can't create an invalid
+//! value. Months and days must form a valid date, times must be valid with at most a
+//! leap second's worth of nanoseconds, offsets must be within 24 hours with consistent
+//! signs, and the offset of an archived [`DateTime`] must match its timezone. A failed
+//! check reports the [`Error`] the constructors would return.
+
+use rkyv::{
+    bytecheck::Verify,
+    rancor::{fail, Fallible, Source},
+};
+
+pub use crate::date::ArchivedDate;
+pub use crate::datetime::ArchivedDateTime;
+pub use crate::interval::ArchivedInterval;
+pub use crate::time::ArchivedTime;
+pub use crate::timezone::{ArchivedUtc, ArchivedUtcOffset};
+
+use crate::{Date, DateTime, Error, Interval, Time, TimeZone, Utc, UtcOffset};
+
+/// An archived timezone whose UTC offset is known without deserializing it.
+///
+/// This is used to check that the offset of an [`ArchivedDateTime`] agrees with its
+/// timezone.
+pub trait ArchivedTimeZone {
+    /// Returns the UTC offset of this timezone in seconds.
+    fn offset_seconds(&self) -> i32;
+}
+
+impl ArchivedTimeZone for ArchivedUtc {
+    fn offset_seconds(&self) -> i32 {
+        0
+    }
+}
+
+impl ArchivedTimeZone for ArchivedUtcOffset {
+    fn offset_seconds(&self) -> i32 {
+        self.hours as i32 * 3600 + self.minutes as i32 * 60 + self.seconds as i32
+    }
+}
+
+impl From<&ArchivedDate> for Date {
+    fn from(date: &ArchivedDate) -> Self {
+        Self {
+            year: date.year.to_native(),
+            month: date.month,
+            day: date.day,
+        }
+    }
+}
+
+impl From<&ArchivedTime> for Time {
+    fn from(time: &ArchivedTime) -> Self {
+        Self {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond.to_native(),
+        }
+    }
+}
+
+impl From<&ArchivedUtcOffset> for UtcOffset {
+    fn from(offset: &ArchivedUtcOffset) -> Self {
+        Self {
+            hours: offset.hours,
+            minutes: offset.minutes,
+            seconds: offset.seconds,
+        }
+    }
+}
+
+impl From<&ArchivedInterval> for Interval {
+    fn from(interval: &ArchivedInterval) -> Self {
+        Self {
+            months: interval.months.to_native(),
+            days: interval.days.to_native(),
+            microseconds: interval.microseconds.to_native(),
+        }
+    }
+}
+
+impl From<&ArchivedDateTime<Utc>> for DateTime<Utc> {
+    fn from(dt: &ArchivedDateTime<Utc>) -> Self {
+        Self {
+            date: (&dt.date).into(),
+            time: (&dt.time).into(),
+            offset: (&dt.offset).into(),
+            timezone: Utc,
+        }
+    }
+}
+
+impl From<&ArchivedDateTime<UtcOffset>> for DateTime<UtcOffset> {
+    fn from(dt: &ArchivedDateTime<UtcOffset>) -> Self {
+        Self {
+            date: (&dt.date).into(),
+            time: (&dt.time).into(),
+            offset: (&dt.offset).into(),
+            timezone: (&dt.timezone).into(),
+        }
+    }
+}
+
+fn check<C>(result: Result<(), Error>) -> Result<(), C::Error>
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    match result {
+        Ok(()) => Ok(()),
+        Err(e) => fail!(e),
+    }
+}
+
+// SAFETY: every field of ArchivedDate is a plain integer, so verify only checks the
+// invariants that Date adds on top of them.
+unsafe impl<C> Verify<C> for ArchivedDate
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        check::<C>(Date::try_new(self.year.to_native(), self.month, self.day).map(|_| ()))
+    }
+}
+
+// SAFETY: every field of ArchivedTime is a plain integer, so verify only checks the
+// invariants that Time adds on top of them.
+unsafe impl<C> Verify<C> for ArchivedTime
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        check::<C>(
+            Time::try_new(self.hour, self.minute, self.second)
+                .and_then(|_| Error::check_field("nanosecond", self.nanosecond.to_native() as i64, 0, 1_999_999_999)),
+        )
+    }
+}
+
+// SAFETY: every field of ArchivedUtcOffset is a plain integer, so verify only checks
+// the invariants that UtcOffset adds on top of them.
+unsafe impl<C> Verify<C> for ArchivedUtcOffset
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        let seconds = self.offset_seconds();
+        let result = match UtcOffset::from_seconds(seconds) {
+            Some(offset) if offset.into_hms() == (self.hours, self.minutes, self.seconds) => Ok(()),
+            Some(_) => Err(Error::OutOfRange),
+            None => Err(Error::FieldOutOfRange {
+                field: "offset",
+                value: seconds as i64,
+                min: -86400,
+                max: 86400,
+            }),
+        };
+        check::<C>(result)
+    }
+}
+
+// SAFETY: the fields of ArchivedDateTime are checked before this is called, so verify
+// only checks that the offset agrees with the timezone.
+unsafe impl<Tz, C> Verify<C> for ArchivedDateTime<Tz>
+where
+    Tz: TimeZone + rkyv::Archive,
+    Tz::Archived: ArchivedTimeZone,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        if self.offset.offset_seconds() == self.timezone.offset_seconds() {
+            Ok(())
+        } else {
+            fail!(Error::OutOfRange)
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Date, DateTime, Time, TimeZone, Utc};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A recurring schedule that fires once a day at a given local wall time.
+///
+/// This is different from firing every 24 hours from some starting point, since
+/// the schedule stays aligned to the local time across UTC offset changes. For example,
+/// a schedule for 09:00 in a timezone with daylight saving time will fire 23 or 25
+/// hours apart around the transitions.
+///
+/// Wall times that do not exist or are ambiguous are handled as follows:
+///
+/// - If the time falls in a gap (e.g. 02:30 when 02:00 skips to 03:00) then it is shifted
+///   forward by the length of the gap (e.g. 03:30).
+/// - If the time occurs twice (e.g. 01:30 when 02:00 falls back to 01:00) then the schedule
+///   fires only once, at the earlier of the two.
+///
+/// This is the same policy as [`crate::DateTimeResolution::lenient`].
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, time, utc_offset};
+/// use eos::extra::DailySchedule;
+///
+/// let schedule = DailySchedule::new(time!(09:00), utc_offset!(-05:00));
+/// let last = datetime!(2022-03-01 14:00);
+/// assert_eq!(schedule.next_after(&last), Some(datetime!(2022-03-02 14:00)));
+/// assert_eq!(schedule.next_after(&datetime!(2022-03-01 13:59)), Some(last));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DailySchedule<Tz: TimeZone> {
+    time: Time,
+    timezone: Tz,
+}
+
+impl<Tz: TimeZone> DailySchedule<Tz> {
+    /// Creates a new [`DailySchedule`] that fires every day at the given local time.
+    pub fn new(time: Time, timezone: Tz) -> Self {
+        Self { time, timezone }
+    }
+
+    /// Returns the local wall time this schedule fires at.
+    pub fn time(&self) -> &Time {
+        &self.time
+    }
+
+    /// Returns the timezone this schedule is aligned to.
+    pub fn timezone(&self) -> &Tz {
+        &self.timezone
+    }
+
+    /// Returns the instant the schedule fires on the given local date.
+    pub fn occurrence_on(&self, date: Date) -> DateTime<Utc> {
+        self.timezone.clone().resolve(date, self.time).lenient().into_utc()
+    }
+
+    /// Returns the first time this schedule fires strictly after the given instant.
+    ///
+    /// If the given instant is the last time the schedule fired then this returns
+    /// the next one. Any occurrences that would have been missed between the two
+    /// are skipped, see [`Self::missed_between`] to retrieve them.
+    ///
+    /// If there is no such time before [`DateTime::MAX`] then [`None`] is returned.
+    pub fn next_after(&self, dt: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        // Start a day early since the local date might lag behind the UTC one
+        let mut date = dt.in_timezone(self.timezone.clone()).date().add_days(-1);
+        loop {
+            let occurrence = self.occurrence_on(date);
+            if occurrence > *dt {
+                return Some(occurrence);
+            }
+            if date == Date::MAX {
+                return None;
+            }
+            date = date.add_days(1);
+        }
+    }
+
+    /// Returns every time this schedule would have fired after `start` up to and including `end`.
+    ///
+    /// This is useful to catch up after downtime, where `start` is the last time
+    /// the schedule fired and `end` is the current time. If `end` is before `start`
+    /// then the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, time, Utc};
+    /// use eos::extra::DailySchedule;
+    ///
+    /// let schedule = DailySchedule::new(time!(09:00), Utc);
+    /// let missed = schedule.missed_between(&datetime!(2022-03-01 09:00), &datetime!(2022-03-03 12:00));
+    /// assert_eq!(missed, vec![datetime!(2022-03-02 09:00), datetime!(2022-03-03 09:00)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn missed_between(&self, start: &DateTime<Utc>, end: &DateTime<Utc>) -> Vec<DateTime<Utc>> {
+        let mut result = Vec::new();
+        let mut current = *start;
+        while let Some(next) = self.next_after(&current) {
+            if next > *end {
+                break;
+            }
+            result.push(next);
+            current = next;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{datetime, time, utc_offset};
+
+    #[test]
+    fn test_next_after_near_max() {
+        let schedule = DailySchedule::new(time!(09:00), Utc);
+        let last = Date::MAX.at(time!(09:00));
+        assert_eq!(
+            schedule.next_after(&Date::MAX.add_days(-1).at(time!(09:00))),
+            Some(last)
+        );
+        assert_eq!(schedule.next_after(&last), None);
+        assert_eq!(schedule.next_after(&DateTime::MAX), None);
+
+        // The last local occurrence is past DateTime::MAX in UTC
+        let schedule = DailySchedule::new(time!(23:00), utc_offset!(-05:00));
+        let dt = datetime!(32767-12-31 03:00);
+        assert_eq!(schedule.next_after(&dt), Some(datetime!(32767-12-31 04:00)));
+        assert_eq!(schedule.next_after(&datetime!(32767-12-31 04:00)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_missed_between_near_max() {
+        let schedule = DailySchedule::new(time!(09:00), Utc);
+        let start = Date::MAX.add_days(-2).at(time!(12:00));
+        let missed = schedule.missed_between(&start, &DateTime::MAX);
+        assert_eq!(
+            missed,
+            alloc::vec![Date::MAX.add_days(-1).at(time!(09:00)), Date::MAX.at(time!(09:00))]
+        );
+    }
+}
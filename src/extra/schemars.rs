@@ -0,0 +1,128 @@
+//! JSON Schema generation with [`schemars`](https://docs.rs/schemars).
+//!
+//! The schemas describe the human readable representation produced by the [`serde`]
+//! implementations, which is the one used by JSON. Every type is a string with the
+//! following format and a pattern that matches exactly what is serialized:
+//!
+//! | Type           | Format      | Example                     |
+//! |:---------------|:------------|:----------------------------|
+//! | [`Date`]       | `date`      | `2022-01-01`                |
+//! | [`Time`]       | `time`      | `10:00:00.000000005`        |
+//! | [`DateTime`]   | `date-time` | `2022-01-01T10:00:00-05:00` |
+//! | [`Interval`]   | `duration`  | `P1Y2M3DT4H5M6.5S`          |
+//!
+//! The formats are the closest ones JSON Schema defines, but eos can represent values
+//! they don't allow. Years outside of `0000` to `9999` are written with a sign and at
+//! least four digits, e.g. `-0001-01-01` or `+10000-01-01`. [`Time`] has no UTC offset. An [`Interval`] can have
+//! negative components, e.g. `P-1Y-2M`. The patterns account for all of these.
+//!
+//! [`serde`]: crate::serde
+
+use alloc::{borrow::Cow, format};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{Date, DateTime, Interval, Time, TimeZone};
+
+/// The pattern of a serialized [`Date`].
+const DATE: &str = "([0-9]{4}|[+-][0-9]{4,5})-[0-9]{2}-[0-9]{2}";
+
+/// The pattern of a serialized [`Time`], including leap seconds.
+const TIME: &str = "[0-9]{2}:[0-9]{2}:[0-9]{2}(\\.[0-9]{9})?";
+
+/// The pattern of a serialized [`UtcOffset`](crate::UtcOffset).
+const OFFSET: &str = "[+-][0-9]{2}:[0-9]{2}(:[0-9]{2})?";
+
+/// The pattern of a serialized [`Interval`].
+const INTERVAL: &str = "^P(-?[0-9]+Y)?(-?[0-9]+M)?(-?[0-9]+D)?(T(-?[0-9]+H)?(-?[0-9]+M)?(-?[0-9]+(\\.[0-9]+)?S)?)?$";
+
+impl JsonSchema for Date {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Date".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eos::Date".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date",
+            "pattern": format!("^{}$", DATE),
+        })
+    }
+}
+
+impl JsonSchema for Time {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Time".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eos::Time".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "time",
+            "pattern": format!("^{}$", TIME),
+        })
+    }
+}
+
+impl<Tz> JsonSchema for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "DateTime".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eos::DateTime".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date-time",
+            "pattern": format!("^{}T{}{}$", DATE, TIME, OFFSET),
+        })
+    }
+}
+
+impl JsonSchema for Interval {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Interval".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "eos::Interval".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "duration",
+            "pattern": INTERVAL,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! Conversions to and from [`time`](https://docs.rs/time) types.
+//!
+//! This allows eos and the `time` crate to be used side by side, such as when a
+//! dependency uses the `time` crate in its public interface. The conversions are
+//! as follows:
+//!
+//! | eos                     | time                       | eos to time | time to eos |
+//! |:------------------------|:---------------------------|:------------|:------------|
+//! | [`Date`]                | [`time::Date`]             | `TryFrom`   | `TryFrom`   |
+//! | [`Time`]                | [`time::Time`]             | `From`      | `From`      |
+//! | [`DateTime<UtcOffset>`] | [`time::OffsetDateTime`]   | `TryFrom`   | `TryFrom`   |
+//! | [`UtcOffset`]           | [`time::UtcOffset`]        | `From`      | `TryFrom`   |
+//!
+//! By default the `time` crate supports years in `-9999..=9999` while eos supports the
+//! years of an `i16`, so dates outside of either range return [`Error::FieldOutOfRange`]
+//! for the year. The `time` crate supports UTC offsets of up to 25:59:59 while eos
+//! supports up to 24:00:00, so larger offsets return [`Error::FieldOutOfRange`] too.
+//!
+//! The `time` crate does not support leap seconds, so a leap second is clamped to the
+//! last nanosecond of the second before it, i.e. `23:59:59.999999999`.
+//!
+//! [`time::Date`]: ::time::Date
+//! [`time::Time`]: ::time::Time
+//! [`time::OffsetDateTime`]: ::time::OffsetDateTime
+//! [`time::UtcOffset`]: ::time::UtcOffset
+
+use crate::{Date, DateTime, Error, Time, TimeZone, UtcOffset};
+
+impl TryFrom<Date> for ::time::Date {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let (min, max) = (::time::Date::MIN.year(), ::time::Date::MAX.year());
+        Error::check_field("year", date.year() as i64, min as i64, max as i64)?;
+        let month = ::time::Month::try_from(date.month()).map_err(|_| Error::OutOfRange)?;
+        ::time::Date::from_calendar_date(date.year() as i32, month, date.day()).map_err(|_| Error::OutOfRange)
+    }
+}
+
+impl TryFrom<::time::Date> for Date {
+    type Error = Error;
+
+    fn try_from(date: ::time::Date) -> Result<Self, Self::Error> {
+        let year = date.year();
+        Error::check_field("year", year as i64, i16::MIN as i64, i16::MAX as i64)?;
+        Date::try_new(year as i16, date.month() as u8, date.day())
+    }
+}
+
+impl From<Time> for ::time::Time {
+    fn from(time: Time) -> Self {
+        ::time::Time::from_hms_nano(
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.nanosecond().min(999_999_999),
+        )
+        .expect("every eos time is within the time crate's range")
+    }
+}
+
+impl From<::time::Time> for Time {
+    fn from(time: ::time::Time) -> Self {
+        Time {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            nanosecond: time.nanosecond(),
+        }
+    }
+}
+
+impl From<UtcOffset> for ::time::UtcOffset {
+    fn from(offset: UtcOffset) -> Self {
+        ::time::UtcOffset::from_whole_seconds(offset.total_seconds())
+            .expect("every eos offset is within the time crate's range")
+    }
+}
+
+impl TryFrom<::time::UtcOffset> for UtcOffset {
+    type Error = Error;
+
+    fn try_from(offset: ::time::UtcOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.whole_seconds();
+        Error::check_field("offset", seconds as i64, -86_400, 86_400)?;
+        Ok(UtcOffset::from_seconds_unchecked(seconds))
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for ::time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(dt: DateTime<Tz>) -> Result<Self, Self::Error> {
+        let date = ::time::Date::try_from(dt.date)?;
+        let time = ::time::Time::from(dt.time);
+        let offset = ::time::UtcOffset::from(dt.offset);
+        Ok(::time::PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+}
+
+impl TryFrom<::time::OffsetDateTime> for DateTime<UtcOffset> {
+    type Error = Error;
+
+    fn try_from(dt: ::time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let date = Date::try_from(dt.date())?;
+        let time = Time::from(dt.time());
+        let offset = UtcOffset::try_from(dt.offset())?;
+        Ok(DateTime {
+            date,
+            time,
+            offset,
+            timezone: offset,
+        })
+    }
+}
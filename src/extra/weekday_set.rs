@@ -0,0 +1,354 @@
+use core::iter::FusedIterator;
+
+use crate::Weekday;
+
+/// A set of [`Weekday`]s.
+///
+/// This is a small bitset meant for recurrence rules, such as "every Monday, Wednesday, and
+/// Friday". It is `Copy` and all operations are constant time.
+///
+/// Iteration always goes in ISO order, from Monday to Sunday, regardless of the order
+/// the weekdays were inserted in.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{date, Weekday};
+/// use eos::extra::WeekdaySet;
+///
+/// let set: WeekdaySet = [Weekday::Friday, Weekday::Monday, Weekday::Wednesday].into_iter().collect();
+/// assert!(set.contains(Weekday::Monday));
+/// assert!(!set.contains(Weekday::Tuesday));
+/// assert_eq!(set.len(), 3);
+///
+/// let weekdays: Vec<_> = set.iter().collect();
+/// assert_eq!(weekdays, [Weekday::Monday, Weekday::Wednesday, Weekday::Friday]);
+///
+/// // March 17th 2021 is a Wednesday
+/// assert!(date!(2021-03-17).weekday_in(&set));
+/// assert_eq!(date!(2021-03-17).next_date_in_set(&set), Some(date!(2021-03-19)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WeekdaySet {
+    // Bit 0 is Monday and bit 6 is Sunday.
+    bits: u8,
+}
+
+const fn bit(weekday: Weekday) -> u8 {
+    1 << weekday.days_from_monday()
+}
+
+impl WeekdaySet {
+    /// A set with no weekdays.
+    pub const EMPTY: Self = Self { bits: 0 };
+
+    /// A set with every weekday.
+    pub const ALL: Self = Self { bits: 0b111_1111 };
+
+    /// A set with the weekdays from Monday to Friday.
+    pub const WEEKDAYS: Self = Self { bits: 0b001_1111 };
+
+    /// A set with Saturday and Sunday.
+    pub const WEEKEND: Self = Self { bits: 0b110_0000 };
+
+    /// Creates a new empty [`WeekdaySet`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Creates a [`WeekdaySet`] from its bit representation.
+    ///
+    /// Bit 0 represents Monday and bit 6 represents Sunday. If the highest bit is set
+    /// then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Weekday;
+    /// use eos::extra::WeekdaySet;
+    ///
+    /// assert_eq!(
+    ///     WeekdaySet::from_bits(0b1000001),
+    ///     Some(WeekdaySet::new().with(Weekday::Monday).with(Weekday::Sunday))
+    /// );
+    /// assert_eq!(WeekdaySet::from_bits(0b10000000), None);
+    /// ```
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL.bits != 0 {
+            None
+        } else {
+            Some(Self { bits })
+        }
+    }
+
+    /// Returns the bit representation of this set.
+    ///
+    /// See [`WeekdaySet::from_bits`] for the layout.
+    #[inline]
+    #[must_use]
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Returns a new set with the given weekday added.
+    ///
+    /// This is useful for building sets in a `const` context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Weekday;
+    /// use eos::extra::WeekdaySet;
+    ///
+    /// const WEEKEND: WeekdaySet = WeekdaySet::new().with(Weekday::Saturday).with(Weekday::Sunday);
+    /// assert_eq!(WEEKEND, WeekdaySet::WEEKEND);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with(self, weekday: Weekday) -> Self {
+        Self {
+            bits: self.bits | bit(weekday),
+        }
+    }
+
+    /// Adds a weekday to the set.
+    ///
+    /// Returns `true` if the weekday was not already in the set.
+    #[inline]
+    pub fn insert(&mut self, weekday: Weekday) -> bool {
+        let inserted = !self.contains(weekday);
+        self.bits |= bit(weekday);
+        inserted
+    }
+
+    /// Removes a weekday from the set.
+    ///
+    /// Returns `true` if the weekday was in the set.
+    #[inline]
+    pub fn remove(&mut self, weekday: Weekday) -> bool {
+        let removed = self.contains(weekday);
+        self.bits &= !bit(weekday);
+        removed
+    }
+
+    /// Returns `true` if the set contains the given weekday.
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, weekday: Weekday) -> bool {
+        self.bits & bit(weekday) != 0
+    }
+
+    /// Returns the number of weekdays in the set.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns `true` if the set has no weekdays.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the weekdays in the set, from Monday to Sunday.
+    #[inline]
+    pub fn iter(&self) -> WeekdaySetIter {
+        WeekdaySetIter { bits: self.bits }
+    }
+
+    /// Returns the number of days from the given weekday until the next weekday
+    /// in the set, not including the given weekday itself.
+    ///
+    /// The result is within `1..=7`, or [`None`] if the set is empty.
+    pub(crate) fn days_until_next(&self, weekday: Weekday) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+        // Rotate the bits so that bit 0 is the day after the given weekday
+        let shift = (weekday.days_from_monday() + 1) % 7;
+        let rotated = ((self.bits >> shift) | (self.bits << (7 - shift))) & Self::ALL.bits;
+        Some(rotated.trailing_zeros() as u8 + 1)
+    }
+
+    /// Returns the number of days from the previous weekday in the set until the
+    /// given weekday, not including the given weekday itself.
+    ///
+    /// The result is within `1..=7`, or [`None`] if the set is empty.
+    pub(crate) fn days_since_previous(&self, weekday: Weekday) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+        // Rotate the bits so that bit 6 is the day before the given weekday
+        let shift = weekday.days_from_monday();
+        let rotated = ((self.bits >> shift) | (self.bits << (7 - shift))) & Self::ALL.bits;
+        Some(rotated.leading_zeros() as u8)
+    }
+
+    /// Returns how many of the `days` weekdays following the given weekday are in the set.
+    ///
+    /// The number of days must be within `0..=7`.
+    pub(crate) fn count_following(&self, weekday: Weekday, days: u8) -> u8 {
+        debug_assert!(days <= 7);
+        let shift = (weekday.days_from_monday() + 1) % 7;
+        let rotated = ((self.bits >> shift) | (self.bits << (7 - shift))) & Self::ALL.bits;
+        let mask = ((1u16 << days) - 1) as u8;
+        (rotated & mask).count_ones() as u8
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    fn from(weekday: Weekday) -> Self {
+        Self::EMPTY.with(weekday)
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<T: IntoIterator<Item = Weekday>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Weekday> for WeekdaySet {
+    fn extend<T: IntoIterator<Item = Weekday>>(&mut self, iter: T) {
+        for weekday in iter {
+            self.insert(weekday);
+        }
+    }
+}
+
+impl IntoIterator for WeekdaySet {
+    type Item = Weekday;
+    type IntoIter = WeekdaySetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &WeekdaySet {
+    type Item = Weekday;
+    type IntoIter = WeekdaySetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the weekdays in a [`WeekdaySet`].
+///
+/// This struct is created via [`WeekdaySet::iter`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct WeekdaySetIter {
+    bits: u8,
+}
+
+impl Iterator for WeekdaySetIter {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+        let index = self.bits.trailing_zeros() as u8;
+        self.bits &= self.bits - 1;
+        Weekday::from_monday_number(index + 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for WeekdaySetIter {}
+
+impl FusedIterator for WeekdaySetIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date, Date};
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = WeekdaySet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Weekday::Sunday));
+        assert!(!set.insert(Weekday::Sunday));
+        assert!(set.insert(Weekday::Tuesday));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Weekday::Sunday));
+        assert!(!set.contains(Weekday::Monday));
+        assert!(set.iter().eq([Weekday::Tuesday, Weekday::Sunday]));
+
+        assert!(set.remove(Weekday::Sunday));
+        assert!(!set.remove(Weekday::Sunday));
+        assert_eq!(set, WeekdaySet::from(Weekday::Tuesday));
+
+        assert_eq!(WeekdaySet::ALL.len(), 7);
+        assert!(WeekdaySet::ALL.iter().eq(Weekday::ALL));
+        assert!(WeekdaySet::WEEKDAYS.iter().eq(Weekday::ALL[..5].iter().copied()));
+        assert!(WeekdaySet::WEEKEND.iter().eq(Weekday::ALL[5..].iter().copied()));
+        assert_eq!(Weekday::ALL.into_iter().collect::<WeekdaySet>(), WeekdaySet::ALL);
+        assert_eq!(WeekdaySet::ALL.iter().len(), 7);
+
+        for bits in 0..=u8::MAX {
+            match WeekdaySet::from_bits(bits) {
+                Some(set) => {
+                    assert_eq!(set.bits(), bits);
+                    assert_eq!(set.iter().collect::<WeekdaySet>(), set);
+                }
+                None => assert!(bits >= 0x80),
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_date_in_set() {
+        // 2024-01-01 is a Monday
+        let monday = date!(2024 - 01 - 01);
+        for bits in 1..=0x7f {
+            let set = WeekdaySet::from_bits(bits).unwrap();
+            for offset in 0..14 {
+                let date = monday.add_days(offset);
+                let mut expected = date.add_days(1);
+                while !expected.weekday_in(&set) {
+                    expected = expected.add_days(1);
+                }
+                assert_eq!(date.next_date_in_set(&set), Some(expected));
+                assert_eq!(date.weekday_in(&set), set.contains(date.weekday()));
+            }
+        }
+
+        for weekday in Weekday::ALL {
+            assert_eq!(WeekdaySet::from(weekday).days_until_next(weekday), Some(7));
+            assert_eq!(WeekdaySet::from(weekday).days_since_previous(weekday), Some(7));
+            assert_eq!(WeekdaySet::from(weekday).count_following(weekday, 6), 0);
+            assert_eq!(WeekdaySet::from(weekday).count_following(weekday, 7), 1);
+        }
+        for bits in 1..=0x7f {
+            let set = WeekdaySet::from_bits(bits).unwrap();
+            for offset in 0..7 {
+                let date = monday.add_days(offset);
+                let previous = date.add_days(-(set.days_since_previous(date.weekday()).unwrap() as i32));
+                assert!(previous.weekday_in(&set));
+                assert!((1..previous.days_since(&date).abs()).all(|n| !date.add_days(-n).weekday_in(&set)));
+                for days in 0..=7 {
+                    let expected = (1..=days).filter(|&n| date.add_days(n as i32).weekday_in(&set)).count();
+                    assert_eq!(set.count_following(date.weekday(), days) as usize, expected);
+                }
+            }
+        }
+        assert_eq!(WeekdaySet::EMPTY.days_since_previous(Weekday::Monday), None);
+        assert_eq!(monday.next_date_in_set(&WeekdaySet::EMPTY), None);
+        assert_eq!(Date::MAX.next_date_in_set(&WeekdaySet::ALL), None);
+    }
+}
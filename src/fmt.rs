@@ -24,6 +24,10 @@
 //! RFC 3339 formatting is only provided by [`DateTime`] using [`DateTime::to_rfc3339`]
 //! and [`DateTime::from_rfc3339`].
 //!
+//! If a stricter or specific subset of ISO-8601 is required, such as rejecting week dates
+//! or requiring exactly three fractional second digits, then a [`Profile`] can be given to
+//! [`DateTime::parse_with_profile`] and [`DateTime::format_with_profile`].
+//!
 //! # Format
 //!
 //! Extended formatting is done through functions such as [`DateTime::format`]. These
@@ -67,10 +71,10 @@
 //! |   `%p`    | The time meridiem (am or pm).                                   | AM, PM                           |
 //! |   `%M`    | Minute as a zero-padded number.[^1]                             | 00, 01, ..., 59                  |
 //! |   `%S`    | Second as a zero-padded number.[^1][^2]                         | 00, 01, ..., 59                  |
-//! |   `%f`    | Nanoseconds as a zero-padded number.[^1][^3]                    | 0000000, 0000001, ..., 999999999 |
+//! |   `%f`    | Nanoseconds as a zero-padded number.[^1][^3]                    | 000000000, ..., 999999999        |
 //! |   `%z`    | UTC offset as `±HHMM[SS]` or empty.                             | +0000, -0500, +102340, ...       |
 //! |   `%o`    | UTC offset as `±HH:MM[:SS]` or empty.                           | +00:00, -05:00, +10:23:40, ...   |
-//! |   `%Z`    | Timezone name or empty.[^4]                                     | UTC, EST, ...                    |
+//! |   `%Z`    | Timezone name or the UTC offset as `±HH:MM[:SS]`.[^4]           | UTC, EST, +01:00, ...            |
 //! |   `%%`    | The literal `%` character.                                      | %                                |
 //!
 //! ### Modifiers
@@ -111,6 +115,13 @@ use core::{iter::Peekable, str::Bytes};
 #[cfg(feature = "std")]
 use crate::utils::divmod;
 
+pub mod presets;
+pub(crate) mod profile;
+
+#[cfg(feature = "formatting")]
+pub use profile::ProfileFormatter;
+pub use profile::{Profile, ProfileOptions};
+
 /// The error type that occurs during parsing a string.
 ///
 /// For example, this is given as a result of a failure in the [`FromIsoFormat`] trait.
@@ -149,8 +160,8 @@ impl core::fmt::Display for ParseError {
     }
 }
 
-#[cfg(all(feature = "std", feature = "parsing"))]
-impl std::error::Error for ParseError {}
+#[cfg(all(any(feature = "std", feature = "nightly"), feature = "parsing"))]
+impl crate::utils::ErrorTrait for ParseError {}
 
 #[cfg(feature = "parsing")]
 impl From<core::num::TryFromIntError> for ParseError {
@@ -159,6 +170,34 @@ impl From<core::num::TryFromIntError> for ParseError {
     }
 }
 
+/// Parses one of the given lowercase English names, either in full or as its three letter
+/// abbreviation. The comparison is case-insensitive.
+///
+/// On failure, the first character that doesn't match any of the names is reported.
+#[cfg(feature = "parsing")]
+pub(crate) fn parse_english_name<T: Copy>(s: &str, names: &[(&str, T)]) -> Result<T, ParseError> {
+    for &(name, value) in names {
+        if s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]) {
+            return Ok(value);
+        }
+    }
+
+    let matched = names
+        .iter()
+        .map(|(name, _)| {
+            s.bytes()
+                .zip(name.bytes())
+                .take_while(|(a, b)| a.to_ascii_lowercase() == *b)
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    match s[matched..].chars().next() {
+        Some(c) => Err(ParseError::UnexpectedChar(c)),
+        None => Err(ParseError::UnexpectedEnd),
+    }
+}
+
 /* ISO 8601 related functionality */
 
 /// Converts a value from an ISO-8601-1:2019 formatted string.
@@ -384,13 +423,17 @@ impl<'a> Parser<'a> {
     /// Parses up to N digits, returning the number being represented.
     ///
     /// This is mainly used for parsing between 2-3 digits without leading zeroes.
+    /// If the number is too large to fit in an u16 then it errors out.
     pub(crate) fn parse_u16<const N: usize>(&mut self) -> Result<u16, ParseError> {
         let mut read_any: bool = false;
         let mut n: u16 = 0;
         for _ in 0..N {
             match self.advance_if(u8::is_ascii_digit) {
                 Some(c) => {
-                    n = n * 10 + (c - b'0') as u16;
+                    n = n
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add((c - b'0') as u16))
+                        .ok_or(ParseError::OutOfBounds)?;
                     read_any = true;
                 }
                 None => break,
@@ -505,13 +548,13 @@ impl<'a> Parser<'a> {
 
         let year = digits[0] as i32 * 1000 + digits[1] as i32 * 100 + digits[2] as i32 * 10 + digits[3] as i32;
         let year = if let Some(b) = self.advance_if(u8::is_ascii_digit) {
-            let new_year = year * 10 + (b - b'0') as i32;
-            i16::try_from(new_year)?
+            year * 10 + (b - b'0') as i32
         } else {
-            year as i16
+            year
         };
 
-        Ok(if negative { -year } else { year })
+        // The sign is applied before narrowing so that -32768 is accepted
+        Ok(i16::try_from(if negative { -year } else { year })?)
     }
 
     /// Parses a two digit unit (e.g. `02`) into their integer representation.
@@ -882,8 +925,18 @@ impl<'a> FormatSpec<'a> {
                         };
                     }
                     Some(b'A') => {
-                        parser.expect_str(b"ug")?;
-                        builder.month(8);
+                        match parser.advance() {
+                            Some(b'p') => {
+                                parser.expect(b'r')?;
+                                builder.month(4);
+                            }
+                            Some(b'u') => {
+                                parser.expect(b'g')?;
+                                builder.month(8);
+                            }
+                            Some(c) => return Err(ParseError::UnexpectedChar(c as char)),
+                            None => return Err(ParseError::UnexpectedEnd),
+                        };
                     }
                     Some(b'S') => {
                         parser.expect_str(b"ep")?;
@@ -949,8 +1002,18 @@ impl<'a> FormatSpec<'a> {
                         };
                     }
                     Some(b'A') => {
-                        parser.expect_str(b"ugust")?;
-                        builder.month(8);
+                        match parser.advance() {
+                            Some(b'p') => {
+                                parser.expect_str(b"ril")?;
+                                builder.month(4);
+                            }
+                            Some(b'u') => {
+                                parser.expect_str(b"gust")?;
+                                builder.month(8);
+                            }
+                            Some(c) => return Err(ParseError::UnexpectedChar(c as char)),
+                            None => return Err(ParseError::UnexpectedEnd),
+                        };
                     }
                     Some(b'S') => {
                         parser.expect_str(b"eptember")?;
@@ -976,10 +1039,15 @@ impl<'a> FormatSpec<'a> {
                 let month = parser.parse_u16::<2>()? as u8;
                 builder.month(month);
             }
-            FormatSpecKind::Year | FormatSpecKind::SignedYear | FormatSpecKind::IsoWeekYear => {
+            FormatSpecKind::Year | FormatSpecKind::SignedYear => {
                 let negative = parser.parse_sign();
-                let year = i16::try_from(parser.parse_u16::<5>()?)?;
-                builder.year(if negative { -year } else { year });
+                let year = parser.parse_u16::<5>()? as i32;
+                builder.year(i16::try_from(if negative { -year } else { year })?);
+            }
+            FormatSpecKind::IsoWeekYear => {
+                let negative = parser.parse_sign();
+                let year = parser.parse_u16::<5>()? as i32;
+                builder.iso_year(i16::try_from(if negative { -year } else { year })?);
             }
             FormatSpecKind::IsoWeek => {
                 let iso_week = parser.parse_u16::<2>()? as u8;
@@ -1063,7 +1131,7 @@ impl<'a> FormatSpec<'a> {
 }
 
 /// The error that occurred during parsing in [`parse_spec`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub enum Error {
     /// An unknown format specifier was found
@@ -1081,8 +1149,8 @@ impl core::fmt::Display for Error {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {}
+#[cfg(any(feature = "std", feature = "nightly"))]
+impl crate::utils::ErrorTrait for Error {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum FormatSpecPadding {
@@ -1264,6 +1332,128 @@ pub fn parse_spec(s: &str) -> Result<Vec<FormatSpec<'_>>, Error> {
     FormatSpecParser::new(s).collect()
 }
 
+#[cfg(feature = "parsing")]
+pub(crate) fn parse_with_spec(s: &str, spec: &[FormatSpec<'_>]) -> Result<DateTime<crate::UtcOffset>, ParseError> {
+    let mut parser = Parser::new(s);
+    let mut builder = crate::Builder::new().timezone(crate::UtcOffset::UTC);
+    for s in spec {
+        s.parse_into(&mut builder, &mut parser)?;
+    }
+    builder.fix_leap_seconds();
+    builder.build().map_err(|_| ParseError::OutOfBounds)
+}
+
+/// An iterator that parses every input using the same format specification.
+///
+/// This is created by the [`parse_iter`] function.
+#[derive(Debug, Clone)]
+#[cfg(feature = "parsing")]
+pub struct ParseIter<I, S> {
+    inputs: I,
+    spec: S,
+}
+
+#[cfg(feature = "parsing")]
+impl<'s, 'a, I, S> Iterator for ParseIter<I, S>
+where
+    I: Iterator<Item = &'s str>,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+    type Item = Result<DateTime<crate::UtcOffset>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.inputs.next()?;
+        Some(parse_with_spec(input, self.spec.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'s, 'a, I, S> ExactSizeIterator for ParseIter<I, S>
+where
+    I: ExactSizeIterator<Item = &'s str>,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+}
+
+#[cfg(feature = "parsing")]
+impl<'s, 'a, I, S> core::iter::FusedIterator for ParseIter<I, S>
+where
+    I: core::iter::FusedIterator<Item = &'s str>,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+}
+
+/// Returns an iterator that parses every input using the given format specification.
+///
+/// Each item is the same as the result of calling [`DateTime::parse_from_spec`] on the
+/// input, except the format specification is only given once and shared between every
+/// input. A failure to parse one input does not stop the iteration.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, fmt::{format_spec, parse_iter}};
+///
+/// let inputs = ["2022-09-14 13:00", "bad", "2022-09-15 08:30"];
+/// let mut iter = parse_iter(inputs, format_spec!("%Y-%m-%d %H:%M"));
+/// assert_eq!(iter.next(), Some(Ok(datetime!(2022-09-14 13:00 +00:00))));
+/// assert!(matches!(iter.next(), Some(Err(_))));
+/// assert_eq!(iter.next(), Some(Ok(datetime!(2022-09-15 08:30 +00:00))));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[cfg(feature = "parsing")]
+pub fn parse_iter<'s, 'a, I, S>(inputs: I, spec: S) -> ParseIter<I::IntoIter, S>
+where
+    I: IntoIterator<Item = &'s str>,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+    ParseIter {
+        inputs: inputs.into_iter(),
+        spec,
+    }
+}
+
+/// Parses every input using the given format specification and collects the results.
+///
+/// This returns a pair where the first element contains every successfully parsed date
+/// time converted to UTC, and the second element contains the index of every input that
+/// failed to parse along with its error. The successfully parsed date times retain the
+/// order they were given in.
+///
+/// See [`parse_iter`] to keep the parsed UTC offsets or to avoid collecting.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, fmt::{format_spec, parse_many, ParseError}};
+///
+/// let inputs = ["2022-09-14 13:00 +0900", "2022-09-14", "2022-09-15 08:30 -0500"];
+/// let (parsed, errors) = parse_many(inputs, format_spec!("%Y-%m-%d %H:%M %z"));
+/// assert_eq!(parsed, [datetime!(2022-09-14 04:00), datetime!(2022-09-15 13:30)]);
+/// assert_eq!(errors, [(1, ParseError::UnexpectedEnd)]);
+/// ```
+#[cfg(feature = "parsing")]
+pub fn parse_many<'s, 'a, I, S>(inputs: I, spec: S) -> (Vec<DateTime<crate::Utc>>, Vec<(usize, ParseError)>)
+where
+    I: IntoIterator<Item = &'s str>,
+    S: AsRef<[FormatSpec<'a>]>,
+{
+    let iter = parse_iter(inputs, spec);
+    let mut parsed = Vec::with_capacity(iter.size_hint().0);
+    let mut errors = Vec::new();
+    for (index, result) in iter.enumerate() {
+        match result {
+            Ok(dt) => parsed.push(dt.in_timezone(crate::Utc)),
+            Err(e) => errors.push((index, e)),
+        }
+    }
+    (parsed, errors)
+}
+
 /// Parses and validates format string at compile time.
 #[doc(inline)]
 #[cfg(feature = "macros")]
@@ -1469,6 +1659,26 @@ where
     }
 }
 
+/// Returns the minimum width of a zero-padded year, where the sign of a negative year counts towards it.
+#[cfg(feature = "formatting")]
+const fn year_padding(year: i16) -> usize {
+    if year < 0 {
+        5
+    } else {
+        4
+    }
+}
+
+/// Converts an hour in the 24-hour clock to the 12-hour clock, i.e. `1..=12`.
+#[cfg(feature = "formatting")]
+const fn hour12(hour: u8) -> u8 {
+    match hour {
+        0 => 12,
+        13.. => hour - 12,
+        _ => hour,
+    }
+}
+
 #[cfg(feature = "formatting")]
 impl<'a, 'b, Spec> core::fmt::Display for DateFormatter<'a, 'b, Spec>
 where
@@ -1496,11 +1706,8 @@ where
                     pad_number(f, self.date.month(), spec.padding, 2)?;
                 }
                 FormatSpecKind::Year => {
-                    // This one's a bit special since the padding depends on whether
-                    // it's 4 or 5 digits
                     let year = self.date.year();
-                    let padding = if !(-9999..=9999).contains(&year) { 5 } else { 4 };
-                    pad_number(f, year, spec.padding, padding)?;
+                    pad_number(f, year, spec.padding, year_padding(year))?;
                 }
                 FormatSpecKind::SignedYear => {
                     // This one needs to be done manually
@@ -1518,7 +1725,8 @@ where
                     }
                 }
                 FormatSpecKind::IsoWeekYear => {
-                    pad_number(f, self.date.iso_week().year(), spec.padding, 4)?;
+                    let year = self.date.iso_week().year();
+                    pad_number(f, year, spec.padding, year_padding(year))?;
                 }
                 FormatSpecKind::IsoWeek => {
                     pad_number(f, self.date.iso_week().week(), spec.padding, 2)?;
@@ -1542,14 +1750,7 @@ where
             match spec.kind {
                 FormatSpecKind::Raw(s) => f.write_str(s)?,
                 FormatSpecKind::Hour => pad_number(f, self.time.hour(), spec.padding, 2)?,
-                FormatSpecKind::Hour12 => {
-                    let h = self.time.hour();
-                    if h <= 12 {
-                        pad_number(f, h, spec.padding, 2)?;
-                    } else {
-                        pad_number(f, h - 12, spec.padding, 2)?;
-                    }
-                }
+                FormatSpecKind::Hour12 => pad_number(f, hour12(self.time.hour()), spec.padding, 2)?,
                 FormatSpecKind::Meridiem => {
                     if self.time.hour() >= 12 {
                         f.write_str("PM")?
@@ -1558,21 +1759,8 @@ where
                     }
                 }
                 FormatSpecKind::Minute => pad_number(f, self.time.minute(), spec.padding, 2)?,
-                FormatSpecKind::Second => {
-                    let second = if self.time.nanosecond() >= 1_000_000_000 {
-                        self.time.second() + 1
-                    } else {
-                        self.time.second()
-                    };
-                    pad_number(f, second, spec.padding, 2)?
-                }
-                FormatSpecKind::Nanosecond => {
-                    let mut ns = self.time.nanosecond();
-                    if ns >= 1_000_000_000 {
-                        ns -= 1_000_000_000;
-                    }
-                    pad_number(f, ns, spec.padding, 7)?
-                }
+                FormatSpecKind::Second => pad_number(f, self.time.display_second().0, spec.padding, 2)?,
+                FormatSpecKind::Nanosecond => pad_number(f, self.time.display_second().1, spec.padding, 9)?,
                 FormatSpecKind::Escape => f.write_char('%')?,
                 // Unsupported
                 _ => continue,
@@ -1610,11 +1798,8 @@ where
                     pad_number(f, self.dt.month(), spec.padding, 2)?;
                 }
                 FormatSpecKind::Year => {
-                    // This one's a bit special since the padding depends on whether
-                    // it's 4 or 5 digits
                     let year = self.dt.year();
-                    let padding = if !(-9999..=9999).contains(&year) { 5 } else { 4 };
-                    pad_number(f, year, spec.padding, padding)?;
+                    pad_number(f, year, spec.padding, year_padding(year))?;
                 }
                 FormatSpecKind::SignedYear => {
                     // This one needs to be done manually
@@ -1632,20 +1817,14 @@ where
                     }
                 }
                 FormatSpecKind::IsoWeekYear => {
-                    pad_number(f, self.dt.iso_week().year(), spec.padding, 4)?;
+                    let year = self.dt.iso_week().year();
+                    pad_number(f, year, spec.padding, year_padding(year))?;
                 }
                 FormatSpecKind::IsoWeek => {
                     pad_number(f, self.dt.iso_week().week(), spec.padding, 2)?;
                 }
                 FormatSpecKind::Hour => pad_number(f, self.dt.hour(), spec.padding, 2)?,
-                FormatSpecKind::Hour12 => {
-                    let h = self.dt.hour();
-                    if h <= 12 {
-                        pad_number(f, h, spec.padding, 2)?;
-                    } else {
-                        pad_number(f, h - 12, spec.padding, 2)?;
-                    }
-                }
+                FormatSpecKind::Hour12 => pad_number(f, hour12(self.dt.hour()), spec.padding, 2)?,
                 FormatSpecKind::Meridiem => {
                     if self.dt.hour() >= 12 {
                         f.write_str("PM")?
@@ -1654,34 +1833,25 @@ where
                     }
                 }
                 FormatSpecKind::Minute => pad_number(f, self.dt.minute(), spec.padding, 2)?,
-                FormatSpecKind::Second => {
-                    let second = if self.dt.nanosecond() >= 1_000_000_000 {
-                        self.dt.second() + 1
-                    } else {
-                        self.dt.second()
-                    };
-                    pad_number(f, second, spec.padding, 2)?
-                }
-                FormatSpecKind::Nanosecond => {
-                    let mut ns = self.dt.nanosecond();
-                    if ns >= 1_000_000_000 {
-                        ns -= 1_000_000_000;
-                    }
-                    pad_number(f, ns, spec.padding, 7)?
-                }
+                FormatSpecKind::Second => pad_number(f, self.dt.time().display_second().0, spec.padding, 2)?,
+                FormatSpecKind::Nanosecond => pad_number(f, self.dt.time().display_second().1, spec.padding, 9)?,
                 FormatSpecKind::UtcOffset => self.dt.offset().fmt(f)?,
                 FormatSpecKind::UtcOffsetBrief => {
-                    let (hour, minute, second) = self.dt.offset().into_hms();
-                    let (minute, second) = (minute.abs(), second.abs());
+                    let offset = self.dt.offset();
+                    let sign = if offset.is_negative() { '-' } else { '+' };
+                    let (hour, minute, second) = offset.into_hms();
+                    let (hour, minute, second) = (hour.unsigned_abs(), minute.unsigned_abs(), second.unsigned_abs());
                     if second > 0 {
-                        write!(f, "{:+03}{:02}{:02}", hour, minute, second)?
+                        write!(f, "{}{:02}{:02}{:02}", sign, hour, minute, second)?
                     } else {
-                        write!(f, "{:+03}{:02}", hour, minute)?
+                        write!(f, "{}{:02}{:02}", sign, hour, minute)?
                     }
                 }
                 FormatSpecKind::ZoneName => {
-                    if let Some(name) = self.dt.tzname() {
-                        f.write_str(name)?;
+                    match self.dt.zone_info().name() {
+                        Some(name) => f.write_str(name)?,
+                        // Zones without a name fall back to the offset, e.g. +01:00
+                        None => self.dt.offset().fmt(f)?,
                     }
                 }
                 FormatSpecKind::Escape => f.write_char('%')?,
@@ -1697,36 +1867,35 @@ where
     Tz: TimeZone,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (h, m, _) = self.dt.offset().into_hms();
-        let m = m.abs();
-        let time = self.dt.time();
-        let mut us = time.microsecond();
-        let mut s = time.second();
-        if us >= 1_000_000 {
-            s += 1;
-            us -= 1_000_000;
-        }
+        let (date, time, offset) = self.dt.whole_minute_parts();
+        let sign = if offset.is_negative() { '-' } else { '+' };
+        let (h, m, _) = offset.into_hms();
+        let (h, m) = (h.unsigned_abs(), m.unsigned_abs());
+        let (s, ns) = time.display_second();
+        let us = ns / 1_000;
 
         if us != 0 {
             write!(
                 f,
-                "{} {:02}:{:02}:{:02}.{:06}{:+03}:{:02}",
-                self.dt.date(),
+                "{} {:02}:{:02}:{:02}.{:06}{}{:02}:{:02}",
+                date,
                 time.hour(),
                 time.minute(),
                 s,
                 us,
+                sign,
                 h,
                 m
             )
         } else {
             write!(
                 f,
-                "{} {:02}:{:02}:{:02}{:+03}:{:02}",
-                self.dt.date(),
+                "{} {:02}:{:02}:{:02}{}{:02}:{:02}",
+                date,
                 time.hour(),
                 time.minute(),
                 s,
+                sign,
                 h,
                 m
             )
@@ -0,0 +1,51 @@
+//! Commonly used format specifications.
+//!
+//! These are slices of [`FormatSpec`] that can be passed anywhere a format specification
+//! is accepted, such as [`DateTime::format`] or [`DateTime::parse_from_spec`].
+//!
+//! [`DateTime::format`]: crate::DateTime::format
+//! [`DateTime::parse_from_spec`]: crate::DateTime::parse_from_spec
+
+use super::{FormatSpec, FormatSpecKind};
+
+/// A filesystem safe format that sorts lexically in chronological order.
+///
+/// This is equivalent to `%Y-%m-%d_%H-%M-%S.%fZ`, e.g. `2024-03-01_09-30-00.000000000Z`.
+/// It has no colons or spaces and every field is zero-padded to a fixed width, including
+/// all nine digits of the fractional seconds. The fields are separated so that the
+/// string can also be parsed back using this format.
+///
+/// Sorting is only guaranteed when the date time is in UTC and the year is within
+/// `0000` to `9999`. Years outside this range change the width or add a sign which
+/// breaks the ordering. [`DateTime::to_filename_string`] takes care of both of these.
+///
+/// [`DateTime::to_filename_string`]: crate::DateTime::to_filename_string
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, DateTime};
+/// use eos::fmt::presets::FILENAME;
+///
+/// let dt = datetime!(2024-03-01 09:30:00);
+/// let s = dt.format(FILENAME).to_string();
+/// assert_eq!(s, "2024-03-01_09-30-00.000000000Z");
+/// assert_eq!(DateTime::parse_from_spec(&s, FILENAME)?, dt);
+/// # Ok::<_, eos::fmt::ParseError>(())
+/// ```
+pub const FILENAME: &[FormatSpec<'static>] = &[
+    FormatSpec::new(FormatSpecKind::Year),
+    FormatSpec::raw("-"),
+    FormatSpec::new(FormatSpecKind::Month),
+    FormatSpec::raw("-"),
+    FormatSpec::new(FormatSpecKind::Day),
+    FormatSpec::raw("_"),
+    FormatSpec::new(FormatSpecKind::Hour),
+    FormatSpec::raw("-"),
+    FormatSpec::new(FormatSpecKind::Minute),
+    FormatSpec::raw("-"),
+    FormatSpec::new(FormatSpecKind::Second),
+    FormatSpec::raw("."),
+    FormatSpec::new(FormatSpecKind::Nanosecond),
+    FormatSpec::raw("Z"),
+];
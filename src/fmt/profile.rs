@@ -0,0 +1,501 @@
+use crate::DateTime;
+
+#[cfg(feature = "formatting")]
+use crate::{Error, TimeZone};
+
+#[cfg(feature = "parsing")]
+use super::{ParseError, Parser};
+#[cfg(feature = "parsing")]
+use crate::{Date, Time, UtcOffset};
+
+/// An ISO-8601 profile that controls which representations are accepted or produced.
+///
+/// Different consumers of date time strings enforce different subsets of ISO-8601. This
+/// type consolidates these decisions so that a single value can be passed to
+/// [`DateTime::parse_with_profile`] and [`DateTime::format_with_profile`].
+///
+/// The following table describes the decisions made by each of the built-in profiles:
+///
+/// | Decision                     | [`Rfc3339`] | [`Iso8601Extended`] | [`Iso8601Basic`] |
+/// |:-----------------------------|:-----------:|:-------------------:|:----------------:|
+/// | Separators (`-` and `:`)     | Yes         | Yes                 | No               |
+/// | Space instead of `T`         | Yes         | No                  | No               |
+/// | `-00:00` offset              | Yes         | No                  | No               |
+/// | Week dates (`2021-W10-1`)    | No          | Yes                 | Yes              |
+/// | Ordinal dates (`2021-048`)   | No          | Yes                 | Yes              |
+/// | Expanded years (`+10000`)    | No          | Yes                 | Yes              |
+/// | Decimal comma (`00:00:00,5`) | No          | Yes                 | Yes              |
+/// | Missing offset means UTC     | No          | No                  | No               |
+/// | Fractional second digits     | 0 to 9      | 0 to 9              | 0 to 9           |
+///
+/// If none of these fit then a [`ProfileOptions`] can be used with [`Profile::Custom`].
+///
+/// Regardless of profile, the seconds are always required, the hour must be below 24,
+/// and the UTC offset must have exactly hours and minutes or be `Z`.
+///
+/// When parsing, a representation that the profile does not allow results in
+/// [`ParseError::UnsupportedSpecifier`].
+///
+/// [`Rfc3339`]: Profile::Rfc3339
+/// [`Iso8601Extended`]: Profile::Iso8601Extended
+/// [`Iso8601Basic`]: Profile::Iso8601Basic
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, DateTime};
+/// use eos::fmt::{Profile, ProfileOptions};
+///
+/// let dt = DateTime::parse_with_profile("2021-02-03 04:05:06-00:00", Profile::Rfc3339)?;
+/// assert_eq!(dt, datetime!(2021-02-03 04:05:06));
+/// assert!(DateTime::parse_with_profile("2021-02-03 04:05:06-00:00", Profile::Iso8601Extended).is_err());
+///
+/// let millis = Profile::Custom(ProfileOptions::new().fraction_digits(3, 3));
+/// assert!(DateTime::parse_with_profile("2021-02-03T04:05:06.123Z", millis).is_ok());
+/// assert!(DateTime::parse_with_profile("2021-02-03T04:05:06.1Z", millis).is_err());
+/// assert_eq!(dt.format_with_profile(millis)?.to_string(), "2021-02-03T04:05:06.000+00:00");
+/// assert_eq!(dt.format_with_profile(Profile::Iso8601Basic)?.to_string(), "20210203T040506+0000");
+/// # Ok::<_, eos::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Profile {
+    /// The profile described by [RFC 3339].
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    Rfc3339,
+    /// The ISO-8601 extended format, e.g. `2021-02-03T04:05:06+01:00`.
+    Iso8601Extended,
+    /// The ISO-8601 basic format, e.g. `20210203T040506+0100`.
+    Iso8601Basic,
+    /// A profile with custom options.
+    Custom(ProfileOptions),
+}
+
+impl Profile {
+    /// Returns the options that this profile uses.
+    pub const fn options(&self) -> ProfileOptions {
+        match self {
+            Self::Rfc3339 => ProfileOptions::new().space_separator(true).negative_zero_offset(true),
+            Self::Iso8601Extended => ProfileOptions::new()
+                .week_dates(true)
+                .ordinal_dates(true)
+                .expanded_years(true)
+                .decimal_comma(true),
+            Self::Iso8601Basic => Self::Iso8601Extended.options().basic(true),
+            Self::Custom(options) => *options,
+        }
+    }
+}
+
+/// The individual decisions that make up a [`Profile`].
+///
+/// The options start from the strictest settings, which is the extended format with
+/// calendar dates, four digit years, a required UTC offset, and `T` as the only separator
+/// between the date and time. Any number of fractional second digits are allowed.
+///
+/// Options that control leniency only affect parsing. When formatting, the canonical
+/// representation is always used, e.g. `T` is used as the separator and calendar dates
+/// are always written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProfileOptions {
+    basic: bool,
+    space_separator: bool,
+    negative_zero_offset: bool,
+    week_dates: bool,
+    ordinal_dates: bool,
+    expanded_years: bool,
+    decimal_comma: bool,
+    optional_offset: bool,
+    min_fraction_digits: u8,
+    max_fraction_digits: u8,
+}
+
+impl ProfileOptions {
+    /// Creates the strictest set of options.
+    pub const fn new() -> Self {
+        Self {
+            basic: false,
+            space_separator: false,
+            negative_zero_offset: false,
+            week_dates: false,
+            ordinal_dates: false,
+            expanded_years: false,
+            decimal_comma: false,
+            optional_offset: false,
+            min_fraction_digits: 0,
+            max_fraction_digits: 9,
+        }
+    }
+
+    /// Sets whether the basic format without `-` and `:` separators is used instead of
+    /// the extended format.
+    pub const fn basic(mut self, basic: bool) -> Self {
+        self.basic = basic;
+        self
+    }
+
+    /// Sets whether a space may be used instead of `T` to separate the date and time.
+    pub const fn space_separator(mut self, allow: bool) -> Self {
+        self.space_separator = allow;
+        self
+    }
+
+    /// Sets whether the `-00:00` offset is accepted.
+    ///
+    /// In RFC 3339 this denotes that the local offset is unknown. ISO-8601 forbids it.
+    /// Since this library cannot represent an unknown offset it's treated as UTC.
+    pub const fn negative_zero_offset(mut self, allow: bool) -> Self {
+        self.negative_zero_offset = allow;
+        self
+    }
+
+    /// Sets whether ISO week dates such as `2021-W10-1` are accepted.
+    pub const fn week_dates(mut self, allow: bool) -> Self {
+        self.week_dates = allow;
+        self
+    }
+
+    /// Sets whether ordinal dates such as `2021-048` are accepted.
+    pub const fn ordinal_dates(mut self, allow: bool) -> Self {
+        self.ordinal_dates = allow;
+        self
+    }
+
+    /// Sets whether years outside of `0000` to `9999` are allowed.
+    ///
+    /// Expanded years always have a sign. In the extended format they have four
+    /// or five digits and in the basic format they always have five digits.
+    pub const fn expanded_years(mut self, allow: bool) -> Self {
+        self.expanded_years = allow;
+        self
+    }
+
+    /// Sets whether `,` is accepted as the decimal separator for fractional seconds.
+    pub const fn decimal_comma(mut self, allow: bool) -> Self {
+        self.decimal_comma = allow;
+        self
+    }
+
+    /// Sets whether the UTC offset can be omitted, in which case UTC is assumed.
+    pub const fn optional_offset(mut self, allow: bool) -> Self {
+        self.optional_offset = allow;
+        self
+    }
+
+    /// Sets the allowed number of fractional second digits.
+    ///
+    /// A minimum of `0` means that the fractional seconds can be omitted entirely.
+    /// The maximum is clamped to `9` and the minimum is clamped to the maximum.
+    ///
+    /// When formatting, the fewest number of digits in this range that can represent
+    /// the value is used. If the value needs more digits than the maximum then it's
+    /// truncated.
+    pub const fn fraction_digits(mut self, min: u8, max: u8) -> Self {
+        let max = if max > 9 { 9 } else { max };
+        self.max_fraction_digits = max;
+        self.min_fraction_digits = if min > max { max } else { min };
+        self
+    }
+}
+
+impl Default for ProfileOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<'a> Parser<'a> {
+    /// Parses exactly `N` digits into their integer representation.
+    fn parse_exact_digits<const N: usize>(&mut self) -> Result<u32, ParseError> {
+        let mut result = 0;
+        for _ in 0..N {
+            result = result * 10 + self.parse_digit()? as u32;
+        }
+        Ok(result)
+    }
+
+    fn parse_profile_year(&mut self, options: &ProfileOptions) -> Result<i16, ParseError> {
+        let year = match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                if !options.expanded_years {
+                    return Err(ParseError::UnsupportedSpecifier);
+                }
+                self.advance();
+                let year = if options.basic {
+                    self.parse_exact_digits::<5>()?
+                } else {
+                    let year = self.parse_exact_digits::<4>()?;
+                    match self.advance_if(u8::is_ascii_digit) {
+                        Some(b) => year * 10 + (b - b'0') as u32,
+                        None => year,
+                    }
+                };
+                let year = year as i32;
+                i16::try_from(if sign == b'-' { -year } else { year })?
+            }
+            _ => self.parse_exact_digits::<4>()? as i16,
+        };
+        Ok(year)
+    }
+
+    fn parse_profile_date(&mut self, options: &ProfileOptions) -> Result<Date, ParseError> {
+        let year = self.parse_profile_year(options)?;
+        if !options.basic {
+            self.expect(b'-')?;
+        }
+        if self.peek() == Some(b'W') {
+            if !options.week_dates {
+                return Err(ParseError::UnsupportedSpecifier);
+            }
+            self.advance();
+            let week = self.parse_two_digits()?;
+            if !options.basic {
+                self.expect(b'-')?;
+            }
+            let weekday = self.parse_digit()?;
+            if week == 0 || week > crate::gregorian::iso_weeks_in_year(year) || !(1..=7).contains(&weekday) {
+                return Err(ParseError::OutOfBounds);
+            }
+            let epoch =
+                crate::gregorian::iso_week_start_epoch_from_year(year) + (week as i32 - 1) * 7 + weekday as i32 - 1;
+            let (year, month, day) = crate::gregorian::date_from_epoch_days(epoch);
+            return Ok(Date { year, month, day });
+        }
+
+        // In the basic format a calendar date has 4 digits and an ordinal date has 3.
+        // In the extended format a calendar date has 2 digits followed by a `-`.
+        let (digits, count) = self.parse_up_to_n_digits::<4>();
+        if count == 3 {
+            if !options.ordinal_dates {
+                return Err(ParseError::UnsupportedSpecifier);
+            }
+            let ordinal = digits[0] as u16 * 100 + digits[1] as u16 * 10 + digits[2] as u16;
+            return Date::from_ordinal(year, ordinal).ok_or(ParseError::OutOfBounds);
+        }
+
+        let (month, day) = match (options.basic, count) {
+            (true, 4) => (digits[0] * 10 + digits[1], digits[2] * 10 + digits[3]),
+            (false, 2) => {
+                self.expect(b'-')?;
+                (digits[0] * 10 + digits[1], self.parse_two_digits()?)
+            }
+            _ => {
+                return Err(match self.peek() {
+                    Some(c) => ParseError::UnexpectedChar(c as char),
+                    None => ParseError::UnexpectedEnd,
+                })
+            }
+        };
+        Date::new(year, month, day).ok_or(ParseError::OutOfBounds)
+    }
+
+    fn parse_profile_time(&mut self, options: &ProfileOptions) -> Result<Time, ParseError> {
+        let hour = self.parse_two_digits()?;
+        if !options.basic {
+            self.expect(b':')?;
+        }
+        let minute = self.parse_two_digits()?;
+        if !options.basic {
+            self.expect(b':')?;
+        }
+        let mut second = self.parse_two_digits()?;
+        let has_fraction = match self.peek() {
+            Some(b'.') => true,
+            Some(b',') => options.decimal_comma,
+            _ => false,
+        };
+        let (digits, count) = if has_fraction {
+            self.advance();
+            self.parse_up_to_n_digits::<9>()
+        } else {
+            ([0; 9], 0)
+        };
+        if (has_fraction && count == 0) || self.peek().map_or(false, |c| c.is_ascii_digit()) {
+            return Err(ParseError::UnexpectedNonDigit);
+        }
+        if count < options.min_fraction_digits as usize || count > options.max_fraction_digits as usize {
+            return Err(ParseError::UnsupportedSpecifier);
+        }
+        let mut nanosecond = digits
+            .iter()
+            .enumerate()
+            .map(|(index, value)| *value as u32 * super::POW10[8 - index])
+            .sum::<u32>();
+
+        if second == 60 {
+            second -= 1;
+            nanosecond += crate::interval::NANOS_PER_SEC as u32;
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(ParseError::OutOfBounds);
+        }
+        Ok(Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+
+    fn parse_profile_offset(&mut self, options: &ProfileOptions) -> Result<UtcOffset, ParseError> {
+        let negative = match self.advance() {
+            Some(b'Z') => return Ok(UtcOffset::UTC),
+            Some(b'+') => false,
+            Some(b'-') => true,
+            Some(c) => return Err(ParseError::UnexpectedChar(c as char)),
+            None if options.optional_offset => return Ok(UtcOffset::UTC),
+            None => return Err(ParseError::UnexpectedEnd),
+        };
+        let hours = self.parse_two_digits()? as i8;
+        if !options.basic {
+            self.expect(b':')?;
+        }
+        let minutes = self.parse_two_digits()? as i8;
+        if hours > 23 || minutes > 59 {
+            return Err(ParseError::OutOfBounds);
+        }
+        if negative && hours == 0 && minutes == 0 && !options.negative_zero_offset {
+            return Err(ParseError::UnsupportedSpecifier);
+        }
+        Ok(if negative {
+            UtcOffset {
+                hours: -hours,
+                minutes: -minutes,
+                seconds: 0,
+            }
+        } else {
+            UtcOffset {
+                hours,
+                minutes,
+                seconds: 0,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "parsing")]
+pub(crate) fn parse_datetime(s: &str, profile: Profile) -> Result<DateTime<UtcOffset>, ParseError> {
+    let options = profile.options();
+    let mut parser = Parser::new(s);
+    let date = parser.parse_profile_date(&options)?;
+    match parser.advance() {
+        Some(b'T') => {}
+        Some(b' ') if options.space_separator => {}
+        Some(c) => return Err(ParseError::UnexpectedChar(c as char)),
+        None => return Err(ParseError::UnexpectedEnd),
+    }
+    let time = parser.parse_profile_time(&options)?;
+    let offset = parser.parse_profile_offset(&options)?;
+    if let Some(c) = parser.peek() {
+        return Err(ParseError::UnexpectedChar(c as char));
+    }
+    Ok(DateTime {
+        date,
+        time,
+        offset,
+        timezone: offset,
+    })
+}
+
+/// Formats a [`DateTime`] according to a [`Profile`].
+///
+/// This is created by [`DateTime::format_with_profile`], which checks that the datetime
+/// can be represented by the profile. Since profiles do not allow seconds in the UTC
+/// offset, the offset is rounded to the nearest minute and the local time is adjusted
+/// to represent the same instant.
+#[cfg(feature = "formatting")]
+#[must_use]
+pub struct ProfileFormatter<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    dt: &'a DateTime<Tz>,
+    options: ProfileOptions,
+}
+
+#[cfg(feature = "formatting")]
+impl<'a, Tz> ProfileFormatter<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    pub(crate) fn new(dt: &'a DateTime<Tz>, options: ProfileOptions) -> Result<Self, Error> {
+        let (date, _, offset) = dt.whole_minute_parts();
+        if offset.hours().unsigned_abs() > 23 {
+            return Err(Error::FieldOutOfRange {
+                field: "offset",
+                value: offset.total_seconds() as i64,
+                min: -86340,
+                max: 86340,
+            });
+        }
+        if !options.expanded_years && !(0..=9999).contains(&date.year()) {
+            return Err(Error::FieldOutOfRange {
+                field: "year",
+                value: date.year() as i64,
+                min: 0,
+                max: 9999,
+            });
+        }
+        Ok(Self { dt, options })
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl<'a, Tz> core::fmt::Display for ProfileFormatter<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let options = &self.options;
+        let (date_sep, time_sep) = if options.basic { ("", "") } else { ("-", ":") };
+        let (date, time, offset) = self.dt.whole_minute_parts();
+        let year = date.year();
+        if (0..=9999).contains(&year) {
+            write!(f, "{:04}", year)?;
+        } else if options.basic {
+            write!(f, "{:+06}", year)?;
+        } else {
+            write!(f, "{:+05}", year)?;
+        }
+        write!(f, "{}{:02}{}{:02}T", date_sep, date.month(), date_sep, date.day())?;
+
+        let (second, nanos) = time.display_second();
+        write!(
+            f,
+            "{:02}{}{:02}{}{:02}",
+            time.hour(),
+            time_sep,
+            time.minute(),
+            time_sep,
+            second
+        )?;
+
+        let mut needed = 9;
+        if nanos == 0 {
+            needed = 0;
+        } else {
+            let mut n = nanos;
+            while n % 10 == 0 {
+                n /= 10;
+                needed -= 1;
+            }
+        }
+        let digits = needed.clamp(options.min_fraction_digits, options.max_fraction_digits);
+        if digits != 0 {
+            let value = nanos / 10u32.pow(9 - digits as u32);
+            write!(f, ".{:0width$}", value, width = digits as usize)?;
+        }
+
+        let sign = if offset.is_negative() { '-' } else { '+' };
+        write!(
+            f,
+            "{}{:02}{}{:02}",
+            sign,
+            offset.hours().unsigned_abs(),
+            time_sep,
+            offset.minutes().unsigned_abs()
+        )
+    }
+}
@@ -10,17 +10,42 @@ Thanks to Joda-Time and Noda-Time for being inspirations as well.
 
 */
 
+use crate::Date;
+
 // The first index is unused
 pub(crate) const DAYS_IN_MONTH: [u8; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 pub(crate) const DAYS_BEFORE_MONTH: [u16; 13] = [0, 0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
 
 /// Returns `true` if the year is a leap year or not.
+///
+/// ```
+/// # use eos::gregorian::is_leap_year;
+/// assert!(!is_leap_year(1900));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(2100));
+/// assert!(is_leap_year(2024));
+/// ```
 #[inline]
 pub const fn is_leap_year(year: i16) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
 /// Returns the number of days in that given month and year.
+///
+/// # Panics
+///
+/// Panics if the month is not within `1..=12`.
+///
+/// ```
+/// # use eos::gregorian::days_in_month;
+/// assert_eq!(days_in_month(1900, 2), 28);
+/// assert_eq!(days_in_month(2000, 2), 29);
+/// assert_eq!(days_in_month(2100, 2), 28);
+/// assert_eq!(days_in_month(2100, 12), 31);
+///
+/// // Usable in constant contexts
+/// const DAYS: [u8; days_in_month(2000, 2) as usize] = [0; 29];
+/// ```
 #[inline]
 pub const fn days_in_month(year: i16, month: u8) -> u8 {
     if month == 2 && is_leap_year(year) {
@@ -30,6 +55,23 @@ pub const fn days_in_month(year: i16, month: u8) -> u8 {
     }
 }
 
+/// Returns the number of days in the given year.
+///
+/// ```
+/// # use eos::gregorian::days_in_year;
+/// assert_eq!(days_in_year(1900), 365);
+/// assert_eq!(days_in_year(2000), 366);
+/// assert_eq!(days_in_year(2100), 365);
+/// ```
+#[inline]
+pub const fn days_in_year(year: i16) -> u16 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
 /// Returns how many days preceed the first day of the given month in the year.
 #[inline]
 pub const fn days_before_month(year: i16, month: u8) -> u16 {
@@ -54,6 +96,10 @@ const ERA_DURATION: i32 = 400;
 const DAYS_IN_ERA: i32 = 146097;
 /// The number of days to go from 0000-03-01 to 1970-01-01
 const DAYS_TO_EPOCH: i32 = 719468;
+/// The Julian day number of 1970-01-01.
+pub(crate) const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+/// The Julian day number of the Modified Julian Date epoch, 1858-11-17.
+pub(crate) const MODIFIED_JULIAN_DAY_OFFSET: i32 = 2_400_001;
 
 /// Returns the number of days from 1970-01-01 of a given year, month, and day.
 ///
@@ -107,14 +153,23 @@ pub const MAX_EPOCH_DAYS: i32 = date_to_epoch_days(i16::MAX, 12, 31);
 /// Note that 0 is Sunday and 6 is Saturday.
 #[inline]
 pub const fn end_of_year_weekday(year: i16) -> u8 {
-    let count = year + year / 4 - year / 100 + year / 400;
+    wide_end_of_year_weekday(year as i32)
+}
+
+/// Same as [`end_of_year_weekday`] but works for years outside of the `i16` range.
+///
+/// This is done in `i32` since the intermediate sum overflows an `i16` for large years
+/// and the divisions round towards negative infinity so that negative years work.
+#[inline]
+const fn wide_end_of_year_weekday(year: i32) -> u8 {
+    let count = year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
     count.rem_euclid(7) as u8
 }
 
 /// Returns the number of ISO weeks in a given year
 #[inline]
 pub const fn iso_weeks_in_year(year: i16) -> u8 {
-    if end_of_year_weekday(year) == 4 || end_of_year_weekday(year - 1) == 3 {
+    if end_of_year_weekday(year) == 4 || wide_end_of_year_weekday(year as i32 - 1) == 3 {
         53
     } else {
         52
@@ -136,6 +191,10 @@ pub(crate) const fn find_iso_week_start_epoch(year: i16, epoch: i32) -> i32 {
     if epoch < start {
         return iso_week_start_epoch_from_year(year - 1);
     }
+    // There's no next year to check past the maximum year
+    if year == i16::MAX {
+        return start;
+    }
     let next_start = iso_week_start_epoch_from_year(year + 1);
     if epoch >= next_start {
         next_start
@@ -144,6 +203,15 @@ pub(crate) const fn find_iso_week_start_epoch(year: i16, epoch: i32) -> i32 {
     }
 }
 
+/// Returns the number of days from 1970-01-01 of a given ISO year, week, and weekday.
+///
+/// The weekday is numbered from 1 for Monday to 7 for Sunday. Much like
+/// [`date_to_epoch_days`], this does *not* do any bound checking on `week` or `weekday`.
+#[inline]
+pub const fn iso_week_date_to_epoch_days(year: i16, week: u8, weekday: u8) -> i32 {
+    iso_week_start_epoch_from_year(year) + (week as i32 - 1) * 7 + weekday as i32 - 1
+}
+
 /// Returns the difference between two weekdays.
 ///
 /// This returns a number between [0, 6] and assumes both x and y are <= 6.
@@ -191,9 +259,79 @@ pub const fn prev_weekday(wd: u8) -> u8 {
     }
 }
 
+/// Returns the date of Easter Sunday in the given year.
+///
+/// This uses the Anonymous Gregorian algorithm (also known as the Meeus/Jones/Butcher algorithm)
+/// to compute the date of Western Easter. It is extended proleptically to every year
+/// that a [`Date`] supports.
+///
+/// ```
+/// # use eos::{date, gregorian::easter_sunday};
+/// assert_eq!(easter_sunday(1961), date!(1961-04-02));
+/// assert_eq!(easter_sunday(2024), date!(2024-03-31));
+/// assert_eq!(easter_sunday(2038), date!(2038-04-25));
+/// ```
+pub const fn easter_sunday(year: i16) -> Date {
+    let y = year as i32;
+    let a = y.rem_euclid(19);
+    let (b, c) = (y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c / 4, c % 4);
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    Date {
+        year,
+        month: (n / 31) as u8,
+        day: (n % 31 + 1) as u8,
+    }
+}
+
+/// Returns the date of Easter Sunday moved by the given number of days.
+const fn easter_offset(year: i16, days: i32) -> Date {
+    let easter = easter_sunday(year);
+    // Easter is always between March 22nd and April 25th so this never leaves the year
+    let (year, month, day) = date_from_epoch_days(date_to_epoch_days(easter.year, easter.month, easter.day) + days);
+    Date { year, month, day }
+}
+
+/// Returns the date of Good Friday in the given year, two days before Easter Sunday.
+///
+/// ```
+/// # use eos::{date, gregorian::good_friday};
+/// assert_eq!(good_friday(2024), date!(2024-03-29));
+/// ```
+pub const fn good_friday(year: i16) -> Date {
+    easter_offset(year, -2)
+}
+
+/// Returns the date of Ascension Day in the given year, 39 days after Easter Sunday.
+///
+/// ```
+/// # use eos::{date, gregorian::ascension};
+/// assert_eq!(ascension(2024), date!(2024-05-09));
+/// ```
+pub const fn ascension(year: i16) -> Date {
+    easter_offset(year, 39)
+}
+
+/// Returns the date of Pentecost in the given year, 49 days after Easter Sunday.
+///
+/// ```
+/// # use eos::{date, gregorian::pentecost};
+/// assert_eq!(pentecost(2024), date!(2024-05-19));
+/// ```
+pub const fn pentecost(year: i16) -> Date {
+    easter_offset(year, 49)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Weekday;
 
     #[test]
     fn test_low_level_algorithms() {
@@ -223,6 +361,8 @@ mod tests {
                     prev_wd = wd;
                 }
             }
+            assert_eq!(end_of_year_weekday(y), prev_wd);
+            assert!((52..=53).contains(&iso_weeks_in_year(y)));
         }
     }
 
@@ -246,4 +386,52 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_easter() {
+        // Published dates of Easter Sunday from 1900 to 2100, as days since the end of February
+        #[rustfmt::skip]
+        const EASTER: [u8; 201] = [
+            46, 38, 30, 43, 34, 54, 46, 31, 50, 42, // 1900
+            27, 47, 38, 23, 43, 35, 54, 39, 31, 51, // 1910
+            35, 27, 47, 32, 51, 43, 35, 48, 39, 31, // 1920
+            51, 36, 27, 47, 32, 52, 43, 28, 48, 40, // 1930
+            24, 44, 36, 56, 40, 32, 52, 37, 28, 48, // 1940
+            40, 25, 44, 36, 49, 41, 32, 52, 37, 29, // 1950
+            48, 33, 53, 45, 29, 49, 41, 26, 45, 37, // 1960
+            29, 42, 33, 53, 45, 30, 49, 41, 26, 46, // 1970
+            37, 50, 42, 34, 53, 38, 30, 50, 34, 26, // 1980
+            46, 31, 50, 42, 34, 47, 38, 30, 43, 35, // 1990
+            54, 46, 31, 51, 42, 27, 47, 39, 23, 43, // 2000
+            35, 55, 39, 31, 51, 36, 27, 47, 32, 52, // 2010
+            43, 35, 48, 40, 31, 51, 36, 28, 47, 32, // 2020
+            52, 44, 28, 48, 40, 25, 44, 36, 56, 41, // 2030
+            32, 52, 37, 29, 48, 40, 25, 45, 36, 49, // 2040
+            41, 33, 52, 37, 29, 49, 33, 53, 45, 30, // 2050
+            49, 41, 26, 46, 37, 29, 42, 34, 53, 45, // 2060
+            30, 50, 41, 26, 46, 38, 50, 42, 34, 54, // 2070
+            38, 30, 50, 35, 26, 46, 31, 51, 42, 34, // 2080
+            47, 39, 30, 43, 35, 55, 46, 31, 51, 43, // 2090
+            28, // 2100
+        ];
+
+        for (year, day) in (1900..=2100).zip(EASTER) {
+            let expected = if day > 31 { (year, 4, day - 31) } else { (year, 3, day) };
+            let easter = easter_sunday(year);
+            assert_eq!((easter.year(), easter.month(), easter.day()), expected);
+        }
+
+        for year in i16::MIN..=i16::MAX {
+            let easter = easter_sunday(year);
+            assert_eq!(easter.year(), year);
+            assert!((3, 22) <= (easter.month(), easter.day()), "{}", easter);
+            assert!((easter.month(), easter.day()) <= (4, 25), "{}", easter);
+            assert_eq!(easter.weekday(), Weekday::Sunday);
+            assert_eq!(good_friday(year).weekday(), Weekday::Friday);
+            assert_eq!(ascension(year).weekday(), Weekday::Thursday);
+            assert_eq!(pentecost(year).weekday(), Weekday::Sunday);
+            assert_eq!(easter.days_since(&good_friday(year)), 2);
+            assert_eq!(pentecost(year).days_since(&ascension(year)), 10);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use core::fmt::Write;
 use core::{
     cmp::Ordering,
-    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     time::Duration,
 };
 
@@ -26,6 +26,7 @@ pub(crate) const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MIN;
 pub(crate) const MICROS_PER_SEC: i64 = 1_000_000;
 pub(crate) const MICROS_PER_MIN: i64 = 60 * MICROS_PER_SEC;
 pub(crate) const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MIN;
+pub(crate) const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
 
 /// An interval of time such as 2 years, 30 minutes, etc.
 ///
@@ -42,11 +43,66 @@ pub(crate) const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MIN;
 /// accessors operate in a way is normalized to the nearest unit. For example, `1234` months
 /// would be equivalent to 102 years and 10 months. Therefore, [`Interval::months`]
 /// would return `10` rather than `1234`.
+///
+/// # Components and totals
+///
+/// There are two kinds of accessors:
+///
+/// - Component accessors such as [`Interval::hours`] and [`Interval::minutes`] return a single
+///   component after splitting the interval into years, months, days, hours, minutes, seconds,
+///   and sub-second parts. For example, 90 minutes has an [`Interval::hours`] of 1 and an
+///   [`Interval::minutes`] of 30. All components can be retrieved at once with
+///   [`Interval::into_parts`].
+/// - Total accessors prefixed with `total_` such as [`Interval::total_minutes`] return the
+///   whole time span in that unit. For example, 90 minutes has an [`Interval::total_minutes`]
+///   of 90 and an [`Interval::total_hours`] of 1.
+///
+/// The totals for units of time such as hours and seconds only include the time components,
+/// they do not convert days or months since those vary in length. The exact accessors
+/// [`Interval::as_seconds`] and [`Interval::as_nanoseconds`] count every day as 24 hours.
+///
+/// # Equality
+///
+/// Intervals are compared by their stored months, days, and microseconds. The time
+/// components are all stored together, so 90 minutes is already equal to 1 hour and 30
+/// minutes. Days and months are compared separately since they're calendar units. A month
+/// does not have a fixed number of days, and a [`Date`] ignores the time components, so
+/// adding 24 hours to it does nothing while adding a day moves it to the next date. This
+/// means 24 hours and 1 day are not equal, see [`Interval::normalized`] to compare them.
+///
+/// ```
+/// use eos::ext::IntervalLiteral;
+///
+/// assert_eq!(90.minutes(), 1.hours() + 30.minutes());
+/// assert_ne!(24.hours(), 1.days());
+/// assert_eq!(24.hours().normalized(), 1.days());
+/// ```
+///
+/// ```
+/// use eos::{Interval, IntervalParts};
+///
+/// let interval = Interval::from_days(2) + Interval::from_minutes(90);
+/// assert_eq!(interval.hours(), 1);
+/// assert_eq!(interval.minutes(), 30);
+/// assert_eq!(interval.total_minutes(), 90);
+/// assert_eq!(interval.total_hours(), 1);
+/// assert_eq!(interval.as_seconds(), 2 * 86400 + 90 * 60);
+/// assert_eq!(interval.days(), 2);
+/// assert_eq!(
+///     interval.into_parts(),
+///     IntervalParts { days: 2, hours: 1, minutes: 30, ..IntervalParts::default() }
+/// );
+/// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 pub struct Interval {
-    months: i32,
-    days: i32,
-    microseconds: i64,
+    pub(crate) months: i32,
+    pub(crate) days: i32,
+    pub(crate) microseconds: i64,
 }
 
 impl Interval {
@@ -82,16 +138,22 @@ impl Interval {
     }
 
     /// Creates a [`Interval`] representing the specified number of weeks.
+    ///
+    /// Note that the internal structure only stores days. If the computation
+    /// would end up overflowing then the value is saturated to the upper bounds.
     #[inline]
     #[must_use]
     pub const fn from_weeks(weeks: i32) -> Self {
         Self {
-            days: weeks * 7,
+            days: weeks.saturating_mul(7),
             ..Self::ZERO
         }
     }
 
     /// Creates a [`Interval`] representing the specified number of hours.
+    ///
+    /// Every `i32` is representable, since `i32::MAX` hours is around 7.7 × 10<sup>18</sup>
+    /// microseconds and the limit is around 9.2 × 10<sup>18</sup>.
     #[inline]
     #[must_use]
     pub const fn from_hours(hours: i32) -> Self {
@@ -147,12 +209,325 @@ impl Interval {
         }
     }
 
+    /// Creates a [`Interval`] representing the specified number of nanoseconds.
+    ///
+    /// Note that the internal structure only stores microseconds, so the
+    /// nanoseconds are truncated toward zero.
+    #[inline]
+    #[must_use]
+    pub const fn from_nanoseconds(nanoseconds: i64) -> Self {
+        Self {
+            microseconds: nanoseconds / 1_000,
+            ..Self::ZERO
+        }
+    }
+
+    /// Adds the components of another interval, panicking on overflow.
+    const fn and(self, other: Self) -> Self {
+        let months = match self.months.checked_add(other.months) {
+            Some(v) => v,
+            None => panic!("overflow adding months to interval"),
+        };
+        let days = match self.days.checked_add(other.days) {
+            Some(v) => v,
+            None => panic!("overflow adding days to interval"),
+        };
+        let microseconds = match self.microseconds.checked_add(other.microseconds) {
+            Some(v) => v,
+            None => panic!("overflow adding time to interval"),
+        };
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "macros")]
+    pub const fn __and_unit_from_macro(self, value: i64, unit: &str) -> Self {
+        const fn to_i32(value: Option<i64>) -> i32 {
+            match value {
+                Some(v) if v >= i32::MIN as i64 && v <= i32::MAX as i64 => v as i32,
+                _ => panic!("interval component is out of range"),
+            }
+        }
+
+        const fn to_i64(value: Option<i64>) -> i64 {
+            match value {
+                Some(v) => v,
+                None => panic!("interval component is out of range"),
+            }
+        }
+
+        let other = match unit.as_bytes() {
+            // The same range as `Interval::from_years` and the ISO-8601 form
+            b"year" | b"years" if value >= i16::MIN as i64 && value <= i16::MAX as i64 => {
+                Self::from_years(value as i16)
+            }
+            b"year" | b"years" => panic!("interval component is out of range"),
+            b"month" | b"months" => Self::from_months(to_i32(Some(value))),
+            b"week" | b"weeks" => Self::from_days(to_i32(value.checked_mul(7))),
+            b"day" | b"days" => Self::from_days(to_i32(Some(value))),
+            b"hour" | b"hours" => Self::from_microseconds(to_i64(value.checked_mul(MICROS_PER_HOUR))),
+            b"minute" | b"minutes" => Self::from_microseconds(to_i64(value.checked_mul(MICROS_PER_MIN))),
+            b"second" | b"seconds" => Self::from_microseconds(to_i64(value.checked_mul(MICROS_PER_SEC))),
+            b"millisecond" | b"milliseconds" => Self::from_microseconds(to_i64(value.checked_mul(1_000))),
+            b"microsecond" | b"microseconds" => Self::from_microseconds(value),
+            _ => panic!(
+                "unit must be one of `years`, `months`, `weeks`, `days`, `hours`, `minutes`, \
+                 `seconds`, `milliseconds`, or `microseconds`"
+            ),
+        };
+        self.and(other)
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "macros")]
+    pub const fn __from_iso_from_macro(s: &str) -> Self {
+        const fn invalid() -> ! {
+            panic!("interval must be an ISO-8601 duration such as `P2Y3M4DT5H`")
+        }
+
+        let bytes = s.as_bytes();
+        let mut index = 0;
+        let negative = index < bytes.len() && bytes[index] == b'-';
+        if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+            index += 1;
+        }
+        if index >= bytes.len() || bytes[index] != b'P' {
+            invalid();
+        }
+        index += 1;
+
+        let mut result = Self::ZERO;
+        let mut time_units = false;
+        let mut parsed_once = false;
+        while index < bytes.len() {
+            if bytes[index] == b'T' {
+                if time_units {
+                    invalid();
+                }
+                time_units = true;
+                index += 1;
+                // At least one time component has to follow
+                if index >= bytes.len() {
+                    invalid();
+                }
+                continue;
+            }
+
+            let value_negative = bytes[index] == b'-';
+            if bytes[index] == b'-' || bytes[index] == b'+' {
+                index += 1;
+            }
+            let mut value: i64 = 0;
+            let mut digits = 0;
+            while index < bytes.len() && digits < 9 && bytes[index].is_ascii_digit() {
+                value = value * 10 + (bytes[index] - b'0') as i64;
+                digits += 1;
+                index += 1;
+            }
+            if digits == 0 || index >= bytes.len() {
+                invalid();
+            }
+            if value_negative {
+                value = -value;
+            }
+
+            let unit = bytes[index];
+            index += 1;
+            result = match (unit, time_units) {
+                (b'Y', false) => result.__and_unit_from_macro(value, "years"),
+                (b'M', false) => result.__and_unit_from_macro(value, "months"),
+                (b'D', false) => result.__and_unit_from_macro(value, "days"),
+                (b'H', true) => result.__and_unit_from_macro(value, "hours"),
+                (b'M', true) => result.__and_unit_from_macro(value, "minutes"),
+                (b'S', true) => result.__and_unit_from_macro(value, "seconds"),
+                (b'.', true) => {
+                    let mut micros: i64 = 0;
+                    let mut scale: i64 = 100_000;
+                    while index < bytes.len() && scale > 0 && bytes[index].is_ascii_digit() {
+                        micros += (bytes[index] - b'0') as i64 * scale;
+                        scale /= 10;
+                        index += 1;
+                    }
+                    // The fraction must be the last component
+                    if scale == 100_000 || index + 1 != bytes.len() || bytes[index] != b'S' {
+                        invalid();
+                    }
+                    index += 1;
+                    let micros = if value_negative { -micros } else { micros };
+                    result
+                        .__and_unit_from_macro(value, "seconds")
+                        .__and_unit_from_macro(micros, "microseconds")
+                }
+                _ => invalid(),
+            };
+            parsed_once = true;
+        }
+
+        if !parsed_once {
+            invalid();
+        }
+
+        if negative {
+            match result.checked_mul(-1) {
+                Some(v) => v,
+                None => panic!("interval component is out of range"),
+            }
+        } else {
+            result
+        }
+    }
+
+    /// Returns the interval with the specified number of years added.
+    ///
+    /// This, along with the other `and_` methods, is meant for building
+    /// composite intervals in a `const` context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component overflows. In a `const` context this is
+    /// a compile error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Interval;
+    ///
+    /// const BACKOFF: Interval = Interval::from_days(1).and_hours(6).and_minutes(30);
+    /// assert_eq!(BACKOFF.days(), 1);
+    /// assert_eq!(BACKOFF.hours(), 6);
+    /// assert_eq!(BACKOFF.minutes(), 30);
+    /// assert_eq!(Interval::from_years(1).and_months(2), Interval::from_months(14));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_years(self, years: i16) -> Self {
+        self.and(Self::from_years(years))
+    }
+
+    /// Returns the interval with the specified number of months added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_months(self, months: i32) -> Self {
+        self.and(Self::from_months(months))
+    }
+
+    /// Returns the interval with the specified number of weeks added.
+    ///
+    /// Like [`Self::from_weeks`], the weeks are saturated before being added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_weeks(self, weeks: i32) -> Self {
+        self.and(Self::from_weeks(weeks))
+    }
+
+    /// Returns the interval with the specified number of days added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_days(self, days: i32) -> Self {
+        self.and(Self::from_days(days))
+    }
+
+    /// Returns the interval with the specified number of hours added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_hours(self, hours: i32) -> Self {
+        self.and(Self::from_hours(hours))
+    }
+
+    /// Returns the interval with the specified number of minutes added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_minutes(self, minutes: i32) -> Self {
+        self.and(Self::from_minutes(minutes))
+    }
+
+    /// Returns the interval with the specified number of seconds added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_seconds(self, seconds: i32) -> Self {
+        self.and(Self::from_seconds(seconds))
+    }
+
+    /// Returns the interval with the specified number of milliseconds added.
+    ///
+    /// Like [`Self::from_milliseconds`], the milliseconds are saturated before being added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_milliseconds(self, milliseconds: i64) -> Self {
+        self.and(Self::from_milliseconds(milliseconds))
+    }
+
+    /// Returns the interval with the specified number of microseconds added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_microseconds(self, microseconds: i64) -> Self {
+        self.and(Self::from_microseconds(microseconds))
+    }
+
+    /// Returns the interval with the specified number of nanoseconds added.
+    ///
+    /// Like [`Self::from_nanoseconds`], the nanoseconds are truncated to microseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows. See [`Self::and_years`].
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn and_nanoseconds(self, nanoseconds: i64) -> Self {
+        self.and(Self::from_nanoseconds(nanoseconds))
+    }
+
     /// Returns the number of *whole* years within this interval.
+    ///
+    /// The months are stored as an `i32` so this can exceed the range of an `i16`,
+    /// in which case it saturates. Use [`Self::total_months`] for the exact value.
     #[inline]
     #[must_use]
     pub const fn years(&self) -> i16 {
-        // This could technically overflow
-        (self.months / 12) as i16
+        let years = self.months / 12;
+        if years > i16::MAX as i32 {
+            i16::MAX
+        } else if years < i16::MIN as i32 {
+            i16::MIN
+        } else {
+            years as i16
+        }
     }
 
     /// Returns the number of *whole* days within this interval.
@@ -206,6 +581,55 @@ impl Interval {
         self.microseconds % MICROS_PER_SEC
     }
 
+    /// Returns the number of *whole* nanoseconds within this interval.
+    ///
+    /// Since intervals have microsecond precision, this is always a multiple of 1000.
+    /// Like [`Self::microseconds`], this is the sub-second component.
+    #[inline]
+    #[must_use]
+    pub const fn nanoseconds(&self) -> i64 {
+        self.microseconds() * 1_000
+    }
+
+    /// Returns every component of this interval.
+    ///
+    /// This is the same as calling each of the component accessors, except the years
+    /// are not clamped to an `i16`. Each component has the same sign as the interval
+    /// it came from, i.e. they are either all non-negative or all non-positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{Interval, IntervalParts};
+    ///
+    /// let interval = Interval::from_months(14) + Interval::from_days(3) + Interval::from_seconds(3661);
+    /// let parts = interval.into_parts();
+    /// assert_eq!(
+    ///     parts,
+    ///     IntervalParts {
+    ///         years: 1,
+    ///         months: 2,
+    ///         days: 3,
+    ///         hours: 1,
+    ///         minutes: 1,
+    ///         seconds: 1,
+    ///         microseconds: 0,
+    ///     }
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn into_parts(self) -> IntervalParts {
+        IntervalParts {
+            years: self.months / 12,
+            months: self.months(),
+            days: self.days,
+            hours: self.hours(),
+            minutes: self.minutes(),
+            seconds: self.seconds(),
+            microseconds: self.microseconds(),
+        }
+    }
+
     /// Returns the total number of weeks within this interval.
     ///
     /// This does not include months, since the number of weeks in a month
@@ -225,6 +649,21 @@ impl Interval {
         self.months
     }
 
+    /// Returns the days and time components combined into microseconds.
+    const fn exact_microseconds(&self) -> i128 {
+        self.days as i128 * MICROS_PER_DAY as i128 + self.microseconds as i128
+    }
+
+    /// Returns the total number of hours within this interval.
+    ///
+    /// This does not include days or months. It is the same as [`Self::hours`]
+    /// since hours are the largest time component.
+    #[inline]
+    #[must_use]
+    pub const fn total_hours(&self) -> i64 {
+        self.microseconds / MICROS_PER_HOUR
+    }
+
     /// Returns the total number of minutes within this interval.
     #[inline]
     #[must_use]
@@ -253,6 +692,111 @@ impl Interval {
         self.microseconds
     }
 
+    /// Returns the total number of nanoseconds within this interval.
+    ///
+    /// This is an `i128` for consistency with [`core::time::Duration::as_nanos`].
+    /// Since intervals have microsecond precision, this is always a multiple of 1000.
+    #[inline]
+    #[must_use]
+    pub const fn total_nanoseconds(&self) -> i128 {
+        self.microseconds as i128 * 1_000
+    }
+
+    /// Returns the exact amount of time in this interval as a number of whole seconds.
+    ///
+    /// Unlike [`Self::total_seconds`], days are included and counted as 24 hours. Months
+    /// are not included since their length varies, see [`Self::try_into_duration`] for
+    /// a conversion that rejects them instead. The result is truncated toward zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!((1.days() + 1.hours() + 500.milliseconds()).as_seconds(), 90_000);
+    /// assert_eq!((1.days() - 1.seconds()).as_seconds(), 86_399);
+    /// assert_eq!((1.months() + 1.seconds()).as_seconds(), 1);
+    /// assert_eq!((-1500).milliseconds().as_seconds(), -1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_seconds(&self) -> i64 {
+        (self.exact_microseconds() / MICROS_PER_SEC as i128) as i64
+    }
+
+    /// Returns the exact amount of time in this interval as a number of nanoseconds.
+    ///
+    /// Unlike [`Self::total_nanoseconds`], days are included and counted as 24 hours.
+    /// Months are not included since their length varies. This is an `i128` so that
+    /// it can't overflow for any interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!((2.days() + 1.microseconds()).as_nanoseconds(), 172_800_000_001_000);
+    /// assert_eq!((1.years() - 1.days()).as_nanoseconds(), -86_400_000_000_000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_nanoseconds(&self) -> i128 {
+        self.exact_microseconds() * 1_000
+    }
+
+    /// Returns the exact amount of time in this interval as a number of seconds.
+    ///
+    /// Days are counted as 24 hours. Months are not included since their length varies,
+    /// see [`Self::try_into_duration`] for a conversion that rejects them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!((1.days() + 1500.milliseconds()).as_seconds_f64(), 86401.5);
+    /// assert_eq!((-90).seconds().as_seconds_f64(), -90.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_seconds_f64(&self) -> f64 {
+        self.exact_microseconds() as f64 / MICROS_PER_SEC as f64
+    }
+
+    /// Converts this interval into an exact [`Duration`].
+    ///
+    /// Days are counted as 24 hours, matching the conversion from a [`Duration`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the interval has any months, since a month
+    /// is not a fixed amount of time, or if the interval is negative overall.
+    ///
+    /// [`Error::OutOfRange`]: crate::Error::OutOfRange
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    /// # use core::time::Duration;
+    ///
+    /// assert_eq!((1.days() + 1.hours()).try_into_duration(), Ok(Duration::from_secs(90_000)));
+    /// assert_eq!((1.days() - 1.hours()).try_into_duration(), Ok(Duration::from_secs(82_800)));
+    /// assert!(1.months().try_into_duration().is_err());
+    /// assert!((-1).seconds().try_into_duration().is_err());
+    /// ```
+    pub fn try_into_duration(self) -> Result<Duration, crate::Error> {
+        if self.months != 0 {
+            return Err(crate::Error::OutOfRange);
+        }
+        let micros = self.exact_microseconds();
+        if micros < 0 {
+            return Err(crate::Error::OutOfRange);
+        }
+        let (seconds, micros) = divrem!(micros, MICROS_PER_SEC as i128);
+        Ok(Duration::new(seconds as u64, micros as u32 * 1_000))
+    }
+
     /// Constructs an [`Interval`] between two dates.
     ///
     /// If `end` is before `start` then each property will be negative.
@@ -287,6 +831,32 @@ impl Interval {
         }
     }
 
+    /// Returns the number of years, months, and days between two dates.
+    ///
+    /// This is the same calculation as [`Interval::between_dates`] with the months split
+    /// into years. Each component is negative if `end` is before `start`.
+    ///
+    /// Years and months are counted first, and if the day of `start` does not exist in the
+    /// resulting month then it's clamped to the last day of that month. For example, one year
+    /// after February 29th is February 28th of the next year, so 2000-02-29 to 2001-02-28 is
+    /// exactly one year while 2000-02-29 to 2001-03-01 is one year and one day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{date, Interval};
+    ///
+    /// assert_eq!(Interval::between_dates_in_ymd(&date!(2000-02-29), &date!(2001-02-28)), (1, 0, 0));
+    /// assert_eq!(Interval::between_dates_in_ymd(&date!(2000-02-29), &date!(2001-03-01)), (1, 0, 1));
+    /// assert_eq!(Interval::between_dates_in_ymd(&date!(2012-01-31), &date!(2013-03-15)), (1, 1, 15));
+    /// assert_eq!(Interval::between_dates_in_ymd(&date!(2013-03-15), &date!(2012-01-31)), (-1, -1, -15));
+    /// ```
+    #[must_use]
+    pub fn between_dates_in_ymd(start: &Date, end: &Date) -> (i32, i32, i32) {
+        let interval = Self::between_dates(start, end);
+        (interval.months / 12, interval.months % 12, interval.days)
+    }
+
     /// Constructs an [`Interval`] between two times.
     ///
     /// If `end` is before `start` then each property will be negative.
@@ -416,37 +986,306 @@ impl Interval {
         }
     }
 
-    /// Returns the number of days and seconds between the two dates
-    pub(crate) fn days_between<Tz, OtherTz>(start: &DateTime<Tz>, end: &DateTime<OtherTz>) -> Self
+    /// Returns the elapsed time between two instants as whole days and a time remainder.
+    ///
+    /// Unlike [`Interval::between`], this never returns a month or year component. A day is
+    /// always treated as exactly 24 hours of elapsed time, regardless of the timezones involved,
+    /// since the two datetimes are compared as instants rather than as wall clock dates.
+    ///
+    /// The number of days is truncated toward zero, and the left-over time has the same
+    /// sign as the number of days. For example, 36 hours is 1 day and 12 hours, while
+    /// -36 hours is -1 day and -12 hours.
+    ///
+    /// If you want the number of local midnights crossed instead, see
+    /// [`Interval::calendar_days_between`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Interval};
+    ///
+    /// let start = datetime!(2022-01-01 12:00);
+    /// let end = datetime!(2022-01-03 00:00);
+    /// let interval = Interval::days_between(&start, &end);
+    /// assert_eq!(interval.days(), 1);
+    /// assert_eq!(interval.hours(), 12);
+    ///
+    /// let interval = Interval::days_between(&end, &start);
+    /// assert_eq!(interval.days(), -1);
+    /// assert_eq!(interval.hours(), -12);
+    ///
+    /// // 2022-01-02 00:00 -05:00 is 2022-01-02 05:00 in UTC
+    /// let end = datetime!(2022-01-02 00:00 -05:00);
+    /// assert_eq!(Interval::days_between(&start, &end).hours(), 17);
+    /// ```
+    #[must_use]
+    pub fn days_between<Tz, OtherTz>(start: &DateTime<Tz>, end: &DateTime<OtherTz>) -> Self
     where
         Tz: TimeZone,
         OtherTz: TimeZone,
     {
-        let days = end.date().days_since_epoch() - start.date().days_since_epoch();
-        let mut seconds = end.time().total_seconds() - start.time().total_seconds();
+        let days = end.date().days_since_epoch() as i64 - start.date().days_since_epoch() as i64;
+        let seconds = end.time().total_seconds() as i64 - start.time().total_seconds() as i64
+            + start.offset().total_seconds() as i64
+            - end.offset().total_seconds() as i64;
         let micros = end.time().microsecond() as i64 - start.time().microsecond() as i64;
 
-        if start.offset() != end.offset() {
-            seconds = seconds + start.offset().total_seconds() - end.offset().total_seconds();
+        // Combine everything to ensure both the days and microseconds have the same signage
+        let total = (days * 86_400 + seconds) * MICROS_PER_SEC + micros;
+        let (days, microseconds) = divrem!(total, MICROS_PER_DAY);
+        Self {
+            days: days as i32,
+            microseconds,
+            ..Self::ZERO
         }
+    }
 
-        // Combine the days and seconds to ensure both of them have the same signage
-        let seconds = days * 86_400 + seconds;
-        let (days, seconds) = divrem!(seconds, 86_400);
-        Self {
+    /// Returns the number of local midnights crossed between two instants in the given timezone.
+    ///
+    /// Both datetimes are first converted to the given timezone and then the difference
+    /// between their local dates is returned. The time of day is not taken into consideration,
+    /// so 23:00 to 01:00 on the next day counts as one day while 01:00 to 23:00 on the same
+    /// day counts as zero.
+    ///
+    /// This differs from [`Interval::days_between`] which counts elapsed 24 hour periods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, Interval, Utc};
+    ///
+    /// let start = datetime!(2022-01-01 23:00);
+    /// let end = datetime!(2022-01-02 01:00);
+    /// assert_eq!(Interval::calendar_days_between(&start, &end, Utc), 1);
+    /// assert_eq!(Interval::days_between(&start, &end).days(), 0);
+    ///
+    /// // In UTC-05:00 both of these are on 2022-01-01
+    /// assert_eq!(Interval::calendar_days_between(&start, &end, utc_offset!(-05:00)), 0);
+    /// ```
+    #[must_use]
+    pub fn calendar_days_between<Tz, OtherTz, Zone>(
+        start: &DateTime<Tz>,
+        end: &DateTime<OtherTz>,
+        timezone: Zone,
+    ) -> i32
+    where
+        Tz: TimeZone,
+        OtherTz: TimeZone,
+        Zone: TimeZone,
+    {
+        let start = start.clone().in_timezone(timezone.clone());
+        let end = end.clone().in_timezone(timezone);
+        end.date().days_since(start.date())
+    }
+
+    /// Returns the interval with whole days carried out of the time components.
+    ///
+    /// The days and the time components are combined and then split again so that
+    /// the hours are within `-23..=23` and share the sign of the days. For example,
+    /// 25 hours becomes 1 day and 1 hour, and 1 day minus 1 hour becomes 23 hours.
+    /// The months are left alone since their length depends on the date.
+    ///
+    /// Two intervals compare equal only if their components are equal, so 24 hours
+    /// and 1 day are not equal. Compare normalized intervals if they should be.
+    ///
+    /// Note that this treats a day as exactly 24 hours. This matches how intervals are
+    /// added to a [`DateTime`], which carries overflowing hours into the local date, but a
+    /// [`Date`] ignores the time components entirely. If the days would overflow then
+    /// the interval is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!(90.minutes().normalized(), (1.hours() + 30.minutes()).normalized());
+    /// assert_ne!(24.hours(), 1.days());
+    /// assert_eq!(24.hours().normalized(), 1.days());
+    /// assert_eq!(25.hours().normalized(), 1.days() + 1.hours());
+    /// assert_eq!((1.days() - 1.hours()).normalized(), 23.hours());
+    /// assert_eq!((1.months() + 48.hours()).normalized(), 1.months() + 2.days());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn normalized(self) -> Self {
+        let total = self.exact_microseconds();
+        let days = total / MICROS_PER_DAY as i128;
+        let microseconds = (total % MICROS_PER_DAY as i128) as i64;
+        match i32::try_from(days) {
+            Ok(days) => Self {
+                months: self.months,
+                days,
+                microseconds,
+            },
+            Err(_) => self,
+        }
+    }
+
+    /// Returns the interval in its canonical form relative to an anchor datetime.
+    ///
+    /// This adds the interval to `anchor` and then computes the [`Interval::between`] the
+    /// anchor and the result. Unlike [`Interval::normalized`], overflowing days are carried
+    /// into months and years according to the calendar at the anchor. For example, 40 days
+    /// starting from January 1st is 1 month and 9 days, but starting from February 1st in a
+    /// non-leap year it's 1 month and 12 days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, ext::IntervalLiteral};
+    ///
+    /// let interval = 40.days() + 25.hours();
+    /// assert_eq!(interval.normalize_in(&datetime!(2021-01-01 00:00)), 1.months() + 10.days() + 1.hours());
+    /// assert_eq!(interval.normalize_in(&datetime!(2021-02-01 00:00)), 1.months() + 13.days() + 1.hours());
+    /// assert_eq!(14.months().normalize_in(&datetime!(2021-02-01 00:00)), 1.years() + 2.months());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn normalize_in<Tz: TimeZone>(self, anchor: &DateTime<Tz>) -> Self {
+        let end = anchor.clone() + self;
+        Self::between(anchor, &end)
+    }
+
+    /// Multiplies every component of the interval by `rhs`, returning `None` if any
+    /// of them overflowed.
+    ///
+    /// The components are multiplied independently, so 1 month and 20 days multiplied
+    /// by 2 is 2 months and 40 days rather than some normalized form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// assert_eq!((1.months() + 20.days()).checked_mul(2), Some(2.months() + 40.days()));
+    /// assert_eq!(90.minutes().checked_mul(-3), Some((-270).minutes()));
+    /// assert_eq!(Interval::from_days(i32::MAX).checked_mul(2), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_mul(self, rhs: i32) -> Option<Self> {
+        let months = match self.months.checked_mul(rhs) {
+            Some(v) => v,
+            None => return None,
+        };
+        let days = match self.days.checked_mul(rhs) {
+            Some(v) => v,
+            None => return None,
+        };
+        let microseconds = match self.microseconds.checked_mul(rhs as i64) {
+            Some(v) => v,
+            None => return None,
+        };
+        Some(Self {
+            months,
             days,
-            microseconds: seconds as i64 * MICROS_PER_SEC + micros,
-            ..Self::ZERO
+            microseconds,
+        })
+    }
+
+    /// Divides every component of the interval by `rhs`, returning `None` if `rhs`
+    /// is zero or any of the components overflowed.
+    ///
+    /// The components are divided independently and truncated toward zero. The remainder
+    /// of a component is *not* carried into the next smaller one, since a month or a day
+    /// does not have a fixed length. For example, 3 months divided by 2 is 1 month rather
+    /// than 1 month and 15 days. The time components are divided down to the microsecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// assert_eq!((3.months() + 3.days() + 3.hours()).checked_div(2), Some(1.months() + 1.days() + 90.minutes()));
+    /// assert_eq!((-7).days().checked_div(2), Some((-3).days()));
+    /// assert_eq!(1.days().checked_div(0), None);
+    /// assert_eq!(Interval::from_months(i32::MIN).checked_div(-1), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_div(self, rhs: i32) -> Option<Self> {
+        let months = match self.months.checked_div(rhs) {
+            Some(v) => v,
+            None => return None,
+        };
+        let days = match self.days.checked_div(rhs) {
+            Some(v) => v,
+            None => return None,
+        };
+        let microseconds = match self.microseconds.checked_div(rhs as i64) {
+            Some(v) => v,
+            None => return None,
+        };
+        Some(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    /// Multiplies the time components of the interval by a floating point factor, returning
+    /// `None` if the interval has a calendar component, `rhs` is not finite, or the result
+    /// overflowed.
+    ///
+    /// Months and days do not have a fixed length, so scaling them by a fraction has no
+    /// meaningful answer and intervals that have them are rejected. Use [`Interval::checked_mul`]
+    /// to scale those by a whole number instead. The result is rounded to the nearest
+    /// microsecond, with ties rounding away from zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{ext::IntervalLiteral, Interval};
+    ///
+    /// assert_eq!(2.seconds().checked_mul_f64(1.5), Some(3.seconds()));
+    /// assert_eq!(1.microseconds().checked_mul_f64(-0.5), Some((-1).microseconds()));
+    /// assert_eq!(1.days().checked_mul_f64(1.5), None);
+    /// assert_eq!(1.hours().checked_mul_f64(f64::NAN), None);
+    /// assert_eq!(Interval::from_microseconds(i64::MAX).checked_mul_f64(2.0), None);
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_mul_f64(self, rhs: f64) -> Option<Self> {
+        if self.months != 0 || self.days != 0 {
+            return None;
+        }
+        let scaled = self.microseconds as f64 * rhs;
+        let rounded = if scaled < 0.0 { scaled - 0.5 } else { scaled + 0.5 };
+        // i64::MIN as f64 is exact while i64::MAX as f64 rounds up to 2^63, NaN fails both
+        if rounded >= i64::MIN as f64 && rounded < i64::MAX as f64 {
+            Some(Self::from_microseconds(rounded as i64))
+        } else {
+            None
         }
     }
 
+    /// Multiplies the time components of the interval by a floating point factor.
+    ///
+    /// See [`Interval::checked_mul_f64`] for how the result is computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval has a month or day component, `rhs` is not finite,
+    /// or the result overflowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::ext::IntervalLiteral;
+    ///
+    /// assert_eq!(400.milliseconds().mul_f64(2.5), 1.seconds());
+    /// assert_eq!((1.hours() + 30.minutes()).mul_f64(-0.5), (-45).minutes());
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn mul_f64(self, rhs: f64) -> Self {
+        self.checked_mul_f64(rhs).expect("invalid interval multiplication")
+    }
+
     /// Returns a duration representing the time components of this interval.
     ///
     /// The first boolean argument is whether the time ended up being negative.
     pub(crate) fn get_time_duration(&self) -> (bool, Duration) {
-        let (seconds, microseconds) = divmod!(self.microseconds, MICROS_PER_SEC);
-        let nanoseconds = (microseconds as u32).saturating_mul(1_000);
-        (seconds < 0, Duration::new(seconds.unsigned_abs(), nanoseconds))
+        // The magnitude has to be split rather than the signed value, otherwise
+        // -1.5 seconds would floor to -2 seconds and +0.5 seconds
+        let microseconds = self.microseconds.unsigned_abs();
+        let (seconds, microseconds) = divrem!(microseconds, MICROS_PER_SEC as u64);
+        let nanoseconds = microseconds as u32 * 1_000;
+        (self.microseconds < 0, Duration::new(seconds, nanoseconds))
     }
 }
 
@@ -497,6 +1336,28 @@ fn months_between(start: &Date, end: &Date) -> i32 {
     }
 }
 
+/// The individual components of an [`Interval`].
+///
+/// This is returned by [`Interval::into_parts`]. Each field is the same as the
+/// component accessor of the same name on [`Interval`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalParts {
+    /// The number of whole years.
+    pub years: i32,
+    /// The number of months, within `-11..=11`.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of hours.
+    pub hours: i64,
+    /// The number of minutes, within `-59..=59`.
+    pub minutes: i64,
+    /// The number of seconds, within `-59..=59`.
+    pub seconds: i64,
+    /// The number of microseconds, within `-999_999..=999_999`.
+    pub microseconds: i64,
+}
+
 impl From<UtcOffset> for Interval {
     fn from(offset: UtcOffset) -> Self {
         Self::from_seconds(offset.total_seconds())
@@ -555,6 +1416,47 @@ impl SubAssign for Interval {
     }
 }
 
+impl Mul<i32> for Interval {
+    type Output = Self;
+
+    /// Multiplies every component of the interval by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the components overflow. See [`Interval::checked_mul`]
+    /// for a non-panicking version.
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.checked_mul(rhs).expect("overflow multiplying interval")
+    }
+}
+
+impl MulAssign<i32> for Interval {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<i32> for Interval {
+    type Output = Self;
+
+    /// Divides every component of the interval by `rhs`, truncating toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or any of the components overflow. See
+    /// [`Interval::checked_div`] for a non-panicking version.
+    fn div(self, rhs: i32) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("divide by zero or overflow dividing interval")
+    }
+}
+
+impl DivAssign<i32> for Interval {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+
 impl TryFrom<Duration> for Interval {
     type Error = crate::Error;
 
@@ -581,6 +1483,17 @@ impl TryFrom<Duration> for Interval {
     }
 }
 
+impl TryFrom<Interval> for Duration {
+    type Error = crate::Error;
+
+    /// Attempts to convert an [`Interval`] into a [`Duration`].
+    ///
+    /// See [`Interval::try_into_duration`] for details.
+    fn try_from(value: Interval) -> Result<Self, Self::Error> {
+        value.try_into_duration()
+    }
+}
+
 impl Add<Duration> for Interval {
     type Output = Self;
 
@@ -627,8 +1540,10 @@ impl core::fmt::Display for Interval {
             return f.write_str("PT0S");
         }
         f.write_char('P')?;
-        if self.years() != 0 {
-            write!(f, "{}Y", self.years())?;
+        // Self::years truncates to an i16 so this is computed manually to avoid that
+        let years = self.months / 12;
+        if years != 0 {
+            write!(f, "{}Y", years)?;
         }
 
         if self.months() != 0 {
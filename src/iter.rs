@@ -1,10 +1,17 @@
 //! Iterator types for iterating over dates and times.
 //!
 //! This module contains the named types that are returned in iterators
-//! such as [`DateTime::every`].
+//! such as [`DateTime::every`], [`Date::iter_year`], and [`Date::iter_to`], along with
+//! functions for building recurrences such as [`every_weekday`] and walking timezone
+//! offset changes with [`transitions`].
 //!
 
-use crate::{DateTime, Interval, Time, TimeZone, Weekday};
+use core::{cmp::Ordering, iter::FusedIterator};
+
+use crate::{
+    gregorian::{date_from_epoch_days, date_to_epoch_days, weekday_difference, weekday_from_days},
+    Date, DateTime, Interval, Time, TimeZone, Utc, UtcOffset, Weekday,
+};
 
 /// An iterator builder over a [`DateTime`] recurrence.
 ///
@@ -154,3 +161,814 @@ impl<Tz: TimeZone> Iterator for EveryIter<Tz> {
         Some(dt)
     }
 }
+
+fn date_from_epoch(days: i32) -> Date {
+    let (year, month, day) = date_from_epoch_days(days);
+    Date { year, month, day }
+}
+
+/// An iterator over every date in a year.
+///
+/// This struct is created via [`Date::iter_year`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct YearDays {
+    // Both are in days since the UNIX epoch, with `back` being exclusive.
+    front: i32,
+    back: i32,
+}
+
+impl YearDays {
+    pub(crate) fn new(year: i16) -> Self {
+        Self {
+            front: date_to_epoch_days(year, 1, 1),
+            back: date_to_epoch_days(year, 12, 31) + 1,
+        }
+    }
+}
+
+impl Iterator for YearDays {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = date_from_epoch(self.front);
+        self.front += 1;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for YearDays {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(date_from_epoch(self.back))
+    }
+}
+
+impl ExactSizeIterator for YearDays {
+    fn len(&self) -> usize {
+        (self.back - self.front).max(0) as usize
+    }
+}
+
+impl FusedIterator for YearDays {}
+
+/// An iterator over every date in a year that falls on a given weekday.
+///
+/// This struct is created via [`Date::iter_year_weekday`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct YearWeekdays {
+    // Both are in days since the UNIX epoch and always fall on the weekday.
+    // Unlike `YearDays`, `back` is inclusive.
+    front: i32,
+    back: i32,
+}
+
+impl YearWeekdays {
+    pub(crate) fn new(year: i16, weekday: Weekday) -> Self {
+        let start = date_to_epoch_days(year, 1, 1);
+        let end = date_to_epoch_days(year, 12, 31);
+        let wd = weekday.days_from_sunday();
+        Self {
+            front: start + weekday_difference(wd, weekday_from_days(start)) as i32,
+            back: end - weekday_difference(weekday_from_days(end), wd) as i32,
+        }
+    }
+}
+
+impl Iterator for YearWeekdays {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        let date = date_from_epoch(self.front);
+        self.front += 7;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for YearWeekdays {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front > self.back {
+            return None;
+        }
+        let date = date_from_epoch(self.back);
+        self.back -= 7;
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for YearWeekdays {
+    fn len(&self) -> usize {
+        if self.front > self.back {
+            0
+        } else {
+            ((self.back - self.front) / 7 + 1) as usize
+        }
+    }
+}
+
+impl FusedIterator for YearWeekdays {}
+
+/// The direction a range step moves in, or `None` if it doesn't move in a single direction.
+fn step_direction(months: i32, days: i32, microseconds: i64) -> Option<bool> {
+    let parts = [months as i64, days as i64, microseconds];
+    if parts.iter().all(|&p| p >= 0) && parts.iter().any(|&p| p > 0) {
+        Some(true)
+    } else if parts.iter().all(|&p| p <= 0) && parts.iter().any(|&p| p < 0) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Returns the number of indices `n` starting from zero for which `in_range(n)` holds.
+///
+/// The predicate must be true for a prefix of the indices and false afterwards.
+fn count_steps(in_range: impl Fn(i32) -> bool) -> i32 {
+    if !in_range(0) {
+        return 0;
+    }
+    // Find an upper bound that is out of range and then binary search below it
+    let mut lo = 0;
+    let mut hi = 1;
+    while in_range(hi) {
+        lo = hi;
+        if hi == i32::MAX {
+            return i32::MAX;
+        }
+        hi = hi.saturating_mul(2);
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if in_range(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+/// An iterator over a range of dates separated by a fixed step.
+///
+/// This struct is created via [`Date::iter_to`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+    step: Interval,
+    inclusive: bool,
+    // The indices of the remaining steps, with `back` being exclusive.
+    front: i32,
+    back: i32,
+}
+
+impl DateRange {
+    pub(crate) fn new(start: Date, end: Date) -> Self {
+        let mut range = Self {
+            start,
+            end,
+            step: Interval::from_days(1),
+            inclusive: false,
+            front: 0,
+            back: 0,
+        };
+        range.reset();
+        range
+    }
+
+    fn nth_date(&self, n: i32) -> Option<Date> {
+        self.start.checked_add(self.step.checked_mul(n)?)
+    }
+
+    fn reset(&mut self) {
+        let forward = step_direction(self.step.total_months(), self.step.days(), 0)
+            .expect("date range step must be non-zero and move in a single direction");
+        let (end, inclusive) = (self.end, self.inclusive);
+        self.front = 0;
+        self.back = count_steps(|n| match self.nth_date(n) {
+            Some(date) if forward => date < end || (inclusive && date == end),
+            Some(date) => date > end || (inclusive && date == end),
+            None => false,
+        });
+    }
+
+    /// Sets the step between each date. By default this is one day.
+    ///
+    /// Only the years, months, and days of the interval are used. Every date is
+    /// computed by adding a multiple of the step to the start, rather than adding
+    /// the step to the previous date. This means that stepping by a month from
+    /// January 31st yields February 28th and then March 31st rather than March 28th.
+    ///
+    /// A negative step iterates backwards, in which case the end should be earlier
+    /// than the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero or its components have mixed signs, such as
+    /// 1 month minus 1 day.
+    pub fn step(mut self, step: Interval) -> Self {
+        self.step = step;
+        self.reset();
+        self
+    }
+
+    /// Sets the step between each date to the given number of days.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is zero.
+    pub fn step_days(self, days: i32) -> Self {
+        self.step(Interval::from_days(days))
+    }
+
+    /// Makes the end date part of the range if a step lands on it.
+    pub fn inclusive(mut self) -> Self {
+        self.inclusive = true;
+        self.reset();
+        self
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = self.nth_date(self.front);
+        self.front += 1;
+        date
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = i32::try_from(n).map_or(self.back, |n| self.front.saturating_add(n).min(self.back));
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.nth_date(self.back)
+    }
+}
+
+impl ExactSizeIterator for DateRange {
+    fn len(&self) -> usize {
+        (self.back - self.front).max(0) as usize
+    }
+}
+
+impl FusedIterator for DateRange {}
+
+/// An iterator over a range of datetimes separated by a fixed step.
+///
+/// This struct is created via [`DateTime::iter_to`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct DateTimeRange<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    step: Interval,
+    inclusive: bool,
+    // The indices of the remaining steps, with `back` being exclusive.
+    front: i32,
+    back: i32,
+}
+
+impl<Tz: TimeZone> DateTimeRange<Tz> {
+    pub(crate) fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Self {
+        let mut range = Self {
+            start,
+            end,
+            step: Interval::from_days(1),
+            inclusive: false,
+            front: 0,
+            back: 0,
+        };
+        range.reset();
+        range
+    }
+
+    fn nth_datetime(&self, n: i32) -> Option<DateTime<Tz>> {
+        self.start.clone().checked_add(self.step.checked_mul(n)?)
+    }
+
+    fn reset(&mut self) {
+        let forward = step_direction(
+            self.step.total_months(),
+            self.step.days(),
+            self.step.total_microseconds(),
+        )
+        .expect("datetime range step must be non-zero and move in a single direction");
+        self.front = 0;
+        self.back = count_steps(|n| match self.nth_datetime(n) {
+            Some(dt) => match dt.cmp_cross_timezone(&self.end) {
+                Ordering::Less => forward,
+                Ordering::Greater => !forward,
+                Ordering::Equal => self.inclusive,
+            },
+            None => false,
+        });
+    }
+
+    /// Sets the step between each datetime. By default this is one day.
+    ///
+    /// Every datetime is computed by adding a multiple of the step to the start, using
+    /// the same semantics as the `+` operator, rather than adding the step to the previous
+    /// datetime. This means that stepping by a month from January 31st yields February 28th
+    /// and then March 31st rather than March 28th. It also means that the wall clock time
+    /// does not drift after a step lands on a time that is skipped by a DST transition,
+    /// although that step may yield the same instant as the one after it.
+    ///
+    /// A negative step iterates backwards, in which case the end should be earlier
+    /// than the start.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero or its components have mixed signs, such as
+    /// 1 day minus 1 hour.
+    pub fn step(mut self, step: Interval) -> Self {
+        self.step = step;
+        self.reset();
+        self
+    }
+
+    /// Makes the end datetime part of the range if a step lands on it.
+    pub fn inclusive(mut self) -> Self {
+        self.inclusive = true;
+        self.reset();
+        self
+    }
+}
+
+impl<Tz: TimeZone> Iterator for DateTimeRange<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let dt = self.nth_datetime(self.front);
+        self.front += 1;
+        dt
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = i32::try_from(n).map_or(self.back, |n| self.front.saturating_add(n).min(self.back));
+        self.next()
+    }
+}
+
+impl<Tz: TimeZone> DoubleEndedIterator for DateTimeRange<Tz> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.nth_datetime(self.back)
+    }
+}
+
+impl<Tz: TimeZone> ExactSizeIterator for DateTimeRange<Tz> {
+    fn len(&self) -> usize {
+        (self.back - self.front).max(0) as usize
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for DateTimeRange<Tz> {}
+
+/// The policy for a recurrence that falls on a day that doesn't exist in some months.
+///
+/// This is used by [`monthly_on_day`] and [`yearly_on`], e.g. for the 31st of every month
+/// or for February 29th every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShortMonthPolicy {
+    /// The recurrence falls on the last day of the month instead.
+    ///
+    /// This is the same behaviour as adding months with an [`Interval`].
+    #[default]
+    Clamp,
+    /// The recurrence is skipped for that month or year.
+    ///
+    /// This is the behaviour of recurrence rules in iCalendar (RFC 5545).
+    Skip,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RecurrenceRule {
+    // The first date, in days since the UNIX epoch
+    Weekly(i32),
+    // The first month, in months since year 0
+    Monthly(i32, u8, ShortMonthPolicy),
+    Yearly(u8, u8, ShortMonthPolicy),
+}
+
+/// An infinite iterator over a recurring date at the same wall clock time.
+///
+/// This struct is created via [`every_weekday`], [`monthly_on_day`], and [`yearly_on`].
+/// See their documentation for more details.
+///
+/// Every occurrence is on or after the starting datetime's date and uses its wall clock time.
+/// If that time is skipped or ambiguous on a given day due to a DST transition then it is
+/// resolved leniently, like [`DateTimeResolution::lenient`]. The iterator only ends when
+/// the dates go past [`Date::MAX`], so it should be bounded using something like
+/// [`Iterator::take_while`].
+///
+/// [`DateTimeResolution::lenient`]: crate::DateTimeResolution::lenient
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Recurrence<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    rule: RecurrenceRule,
+    index: i32,
+    done: bool,
+}
+
+impl<Tz: TimeZone> Recurrence<Tz> {
+    /// Returns the date for the occurrence at the given index.
+    ///
+    /// The outer `None` means the date is out of range while the inner one means it was skipped.
+    fn candidate(&self, index: i32) -> Option<Option<Date>> {
+        match self.rule {
+            RecurrenceRule::Weekly(first) => {
+                let days = index.checked_mul(7).and_then(|d| d.checked_add(first))?;
+                if days > Date::MAX.days_since_epoch() {
+                    return None;
+                }
+                Some(Some(date_from_epoch(days)))
+            }
+            RecurrenceRule::Monthly(first, day, policy) => {
+                let months = first.checked_add(index)?;
+                let year = i16::try_from(months.div_euclid(12)).ok()?;
+                let month = months.rem_euclid(12) as u8 + 1;
+                Some(Self::resolve_day(year, month, day, policy))
+            }
+            RecurrenceRule::Yearly(month, day, policy) => {
+                let year = i16::try_from(self.start.year() as i32 + index).ok()?;
+                Some(Self::resolve_day(year, month, day, policy))
+            }
+        }
+    }
+
+    fn resolve_day(year: i16, month: u8, day: u8, policy: ShortMonthPolicy) -> Option<Date> {
+        let last = crate::gregorian::days_in_month(year, month);
+        match policy {
+            ShortMonthPolicy::Clamp => Date::new(year, month, day.min(last)),
+            ShortMonthPolicy::Skip if day > last => None,
+            ShortMonthPolicy::Skip => Date::new(year, month, day),
+        }
+    }
+}
+
+impl<Tz: TimeZone> Iterator for Recurrence<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let candidate = self.candidate(self.index);
+            self.index = match self.index.checked_add(1) {
+                Some(index) => index,
+                None => {
+                    self.done = true;
+                    self.index
+                }
+            };
+            match candidate {
+                None => self.done = true,
+                Some(Some(date)) if date >= self.start.date => {
+                    let time = self.start.time;
+                    return Some(crate::step::with_local(self.start.clone(), (date, time)));
+                }
+                Some(_) => {}
+            }
+        }
+        None
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Recurrence<Tz> {}
+
+/// Returns an infinite iterator over every date that falls on the given weekday,
+/// starting from `start` at the same wall clock time.
+///
+/// If `start` already falls on the weekday then it is the first occurrence.
+/// See [`Recurrence`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, iter, Weekday};
+///
+/// let start = datetime!(2022-03-09 09:30);
+/// let mondays: Vec<_> = iter::every_weekday(start, Weekday::Monday)
+///     .take_while(|dt| dt < &datetime!(2022-04-01 00:00))
+///     .collect();
+/// assert_eq!(
+///     mondays,
+///     [datetime!(2022-03-14 09:30), datetime!(2022-03-21 09:30), datetime!(2022-03-28 09:30)]
+/// );
+/// ```
+pub fn every_weekday<Tz: TimeZone>(start: DateTime<Tz>, weekday: Weekday) -> Recurrence<Tz> {
+    let first = start.days_since_epoch()
+        + weekday_difference(weekday.days_from_sunday(), weekday_from_days(start.days_since_epoch())) as i32;
+    Recurrence {
+        start,
+        rule: RecurrenceRule::Weekly(first),
+        index: 0,
+        done: false,
+    }
+}
+
+/// Returns an infinite iterator over the given day of every month, starting from `start`
+/// at the same wall clock time.
+///
+/// If `start` is on or before the day in its own month then that month is the first
+/// occurrence. The `policy` decides what happens in months that are too short for the day.
+/// See [`Recurrence`] for more details.
+///
+/// Returns `None` if the day is not between 1 and 31.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, iter::{self, ShortMonthPolicy}};
+///
+/// let start = datetime!(2023-01-15 12:00);
+/// let clamped: Vec<_> = iter::monthly_on_day(start, 31, ShortMonthPolicy::Clamp).unwrap().take(3).collect();
+/// assert_eq!(clamped, [datetime!(2023-01-31 12:00), datetime!(2023-02-28 12:00), datetime!(2023-03-31 12:00)]);
+///
+/// let skipped: Vec<_> = iter::monthly_on_day(start, 31, ShortMonthPolicy::Skip).unwrap().take(3).collect();
+/// assert_eq!(skipped, [datetime!(2023-01-31 12:00), datetime!(2023-03-31 12:00), datetime!(2023-05-31 12:00)]);
+///
+/// assert!(iter::monthly_on_day(start, 32, ShortMonthPolicy::Clamp).is_none());
+/// ```
+pub fn monthly_on_day<Tz: TimeZone>(start: DateTime<Tz>, day: u8, policy: ShortMonthPolicy) -> Option<Recurrence<Tz>> {
+    if !(1..=31).contains(&day) {
+        return None;
+    }
+    let first = start.year() as i32 * 12 + start.month() as i32 - 1;
+    Some(Recurrence {
+        start,
+        rule: RecurrenceRule::Monthly(first, day, policy),
+        index: 0,
+        done: false,
+    })
+}
+
+/// Returns an infinite iterator over the given month and day of every year, starting
+/// from `start` at the same wall clock time.
+///
+/// If `start` is on or before the date in its own year then that year is the first
+/// occurrence. The `policy` decides what happens to February 29th in years that aren't
+/// leap years. See [`Recurrence`] for more details.
+///
+/// Returns `None` if the month and day don't form a valid date in a leap year.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, iter::{self, ShortMonthPolicy}};
+///
+/// let start = datetime!(2023-06-01 00:00);
+/// let clamped: Vec<_> = iter::yearly_on(start, 2, 29, ShortMonthPolicy::Clamp).unwrap().take(2).collect();
+/// assert_eq!(clamped, [datetime!(2024-02-29 00:00), datetime!(2025-02-28 00:00)]);
+///
+/// let skipped: Vec<_> = iter::yearly_on(start, 2, 29, ShortMonthPolicy::Skip).unwrap().take(2).collect();
+/// assert_eq!(skipped, [datetime!(2024-02-29 00:00), datetime!(2028-02-29 00:00)]);
+///
+/// assert!(iter::yearly_on(start, 4, 31, ShortMonthPolicy::Clamp).is_none());
+/// ```
+pub fn yearly_on<Tz: TimeZone>(
+    start: DateTime<Tz>,
+    month: u8,
+    day: u8,
+    policy: ShortMonthPolicy,
+) -> Option<Recurrence<Tz>> {
+    // 2000 is a leap year so every month has its maximum length
+    Date::new(2000, month, day)?;
+    Some(Recurrence {
+        start,
+        rule: RecurrenceRule::Yearly(month, day, policy),
+        index: 0,
+        done: false,
+    })
+}
+
+/// An iterator over the dates in a month's calendar grid.
+///
+/// This struct is created via [`calendar_month`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct CalendarMonth {
+    year: i16,
+    month: u8,
+    // Both are in days since the UNIX epoch, with `back` being exclusive.
+    front: i32,
+    back: i32,
+}
+
+impl CalendarMonth {
+    /// Returns `true` if the date is in the month this grid is for, rather than
+    /// being one of the leading or trailing days of the adjacent months.
+    pub fn is_in_month(&self, date: &Date) -> bool {
+        date.year() == self.year && date.month() == self.month
+    }
+
+    /// Returns the number of weeks (rows) remaining in the grid.
+    ///
+    /// This is between 4 and 6 for a grid that hasn't been iterated yet.
+    pub fn weeks(&self) -> usize {
+        (self.len() + 6) / 7
+    }
+
+    fn date_at(days: i32) -> Option<Date> {
+        if days < Date::MIN.days_since_epoch() || days > Date::MAX.days_since_epoch() {
+            None
+        } else {
+            Some(date_from_epoch(days))
+        }
+    }
+}
+
+impl Iterator for CalendarMonth {
+    type Item = Option<Date>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = Self::date_at(self.front);
+        self.front += 1;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CalendarMonth {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(Self::date_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for CalendarMonth {
+    fn len(&self) -> usize {
+        (self.back - self.front).max(0) as usize
+    }
+}
+
+impl FusedIterator for CalendarMonth {}
+
+/// Returns an iterator over the calendar grid of a month, one week after another.
+///
+/// Every week starts on `first_weekday` and the grid includes the days of the adjacent
+/// months needed to fill the first and last weeks, so the grid is always a rectangle of
+/// 4 to 6 full weeks. Use [`CalendarMonth::is_in_month`] to tell the days of the month
+/// apart from the adjacent ones.
+///
+/// The items are `None` for days that fall outside of the supported range of dates,
+/// which can only happen in the first month of [`Date::MIN`] and the last month of
+/// [`Date::MAX`].
+///
+/// Returns `None` if the month is not between 1 and 12 or the year is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{date, iter, Weekday};
+///
+/// // February 2026 starts on a Sunday and has 28 days
+/// let grid = iter::calendar_month(2026, 2, Weekday::Sunday).unwrap();
+/// assert_eq!(grid.len(), 28);
+/// assert_eq!(grid.weeks(), 4);
+///
+/// // With weeks starting on Monday the grid starts in January and ends in March
+/// let grid = iter::calendar_month(2026, 2, Weekday::Monday).unwrap();
+/// assert_eq!(grid.weeks(), 5);
+/// let dates: Vec<_> = grid.clone().flatten().collect();
+/// assert_eq!(dates.first(), Some(&date!(2026-01-26)));
+/// assert_eq!(dates.last(), Some(&date!(2026-03-01)));
+/// assert!(!grid.is_in_month(&date!(2026-01-31)));
+/// assert!(grid.is_in_month(&date!(2026-02-01)));
+/// ```
+pub fn calendar_month(year: i16, month: u8, first_weekday: Weekday) -> Option<CalendarMonth> {
+    let first = Date::new(year, month, 1)?.days_since_epoch();
+    let leading = weekday_difference(weekday_from_days(first), first_weekday.days_from_sunday()) as i32;
+    let days = crate::gregorian::days_in_month(year, month) as i32 + leading;
+    let front = first - leading;
+    Some(CalendarMonth {
+        year,
+        month,
+        front,
+        back: front + (days + 6) / 7 * 7,
+    })
+}
+
+/// An iterator over the UTC offset transitions of a timezone.
+///
+/// This struct is created via [`transitions`]. See its documentation
+/// for more details.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Transitions<Tz: TimeZone> {
+    timezone: Tz,
+    current: DateTime<Utc>,
+    until: DateTime<Utc>,
+    done: bool,
+}
+
+impl<Tz: TimeZone> Iterator for Transitions<Tz> {
+    type Item = (DateTime<Utc>, UtcOffset, UtcOffset);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.timezone.next_transition(&self.current) {
+            // A transition that doesn't move forward would loop forever
+            Some((at, before, after)) if at > self.current && at <= self.until => {
+                self.current = at;
+                Some((at, before, after))
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Transitions<Tz> {}
+
+/// Returns an iterator over the transitions of a timezone between two instants.
+///
+/// Each item is the instant of the transition along with the UTC offsets before and after
+/// it, as returned by [`TimeZone::next_transition`]. Only transitions strictly after
+/// `from` and at or before `to` are returned. Timezones without transition data, such as
+/// fixed offsets, return an empty iterator.
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, iter, utc_offset};
+///
+/// let tz = utc_offset!(+09:00);
+/// let mut transitions = iter::transitions(tz, datetime!(2022-01-01 00:00), datetime!(2023-01-01 00:00));
+/// assert_eq!(transitions.next(), None);
+/// ```
+pub fn transitions<Tz: TimeZone>(timezone: Tz, from: DateTime<Utc>, to: DateTime<Utc>) -> Transitions<Tz> {
+    Transitions {
+        timezone,
+        current: from,
+        until: to,
+        done: false,
+    }
+}
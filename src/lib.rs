@@ -19,6 +19,7 @@
 //! [`eos-tz`]: https://github.com/Rapptz/eos/tree/master/eos-tz
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
 
 #[cfg(feature = "std")]
 extern crate core;
@@ -52,15 +53,24 @@ pub mod unit;
 mod utils;
 pub mod extra;
 
+#[cfg(test)]
+mod scales;
+
 pub use builder::Builder;
-pub use date::{Date, IsoWeekDate, Weekday};
+pub use date::{Date, IsoWeekDate, LeapDayPolicy, Weekday};
 pub use datetime::DateTime;
 pub use error::Error;
-pub use interval::Interval;
-pub use time::Time;
+pub use interval::{Interval, IntervalParts};
+pub use time::{SubsecondPrecision, Time};
 pub use timestamp::Timestamp;
-pub use timezone::{DateTimeResolution, DateTimeResolutionKind, TimeZone, Utc, UtcOffset};
+#[cfg(feature = "alloc")]
+pub use timezone::ZoneInfo;
+pub use timezone::{AmbiguityError, DateTimeResolution, DateTimeResolutionKind, NamedOffset, TimeZone, Utc, UtcOffset};
+#[cfg(feature = "alloc")]
+pub use timezone::{AnyTimeZone, CustomTimeZone};
 
+#[cfg(all(feature = "system", feature = "std"))]
+pub use timezone::CachedSystem;
 #[cfg(feature = "system")]
 pub use timezone::System;
 
@@ -70,8 +80,59 @@ pub use timezone::System;
 pub use datetime::__create_offset_datetime_from_macro;
 
 /// Returns the current [`DateTime`] in the given timezone.
+///
+/// This reads the clock as UTC and then converts it using [`TimeZone::convert_utc`].
+/// The conversion is cheap for fixed offsets, but for [`System`] it queries the operating
+/// system for the local timezone every time. Prefer [`now_local`] or [`CachedSystem`]
+/// for the local time, since they share a cache of the local timezone information.
 #[cfg(feature = "std")]
 #[must_use]
 pub fn now_in<Tz: TimeZone>(zone: Tz) -> DateTime<Tz> {
     DateTime::utc_now().in_timezone(zone)
 }
+
+/// Returns the current [`DateTime`] in local time.
+///
+/// This is the same as [`DateTime::now`] for [`System`]. With the `std` feature
+/// enabled the local timezone information comes from the cache documented in
+/// [`CachedSystem`], so this doesn't usually query the operating system.
+#[cfg(feature = "system")]
+pub fn now_local() -> Result<DateTime<System>, Error> {
+    DateTime::now()
+}
+
+/// Returns the current calendar [`Date`] in the given timezone.
+///
+/// ```
+/// use eos::utc_offset;
+///
+/// let east = eos::today_in(utc_offset!(+14:00));
+/// let west = eos::today_in(utc_offset!(-12:00));
+/// assert!(east > west);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn today_in<Tz: TimeZone>(zone: Tz) -> Date {
+    *now_in(zone).date()
+}
+
+/// Returns the current [`DateTime`] according to the given [`Clock`] in the given timezone.
+///
+/// This is the same as [`now_in`] except the time comes from the clock, which allows
+/// tests to control it.
+///
+/// [`Clock`]: extra::Clock
+///
+/// # Examples
+///
+/// ```
+/// use eos::{datetime, utc_offset};
+/// use eos::extra::FixedClock;
+///
+/// let clock = FixedClock::new(datetime!(2022-01-01 12:00));
+/// assert_eq!(eos::now_in_with(&clock, utc_offset!(-05:00)), datetime!(2022-01-01 07:00 -05:00));
+/// ```
+#[must_use]
+pub fn now_in_with<C: extra::Clock, Tz: TimeZone>(clock: &C, zone: Tz) -> DateTime<Tz> {
+    clock.now().in_timezone(zone)
+}
@@ -39,6 +39,13 @@
 //! let offset = utc_offset!(-05:00); // Eastern time
 //! ```
 //!
+//! Creating an interval:
+//!
+//! ```
+//! # use eos::interval;
+//! let interval = interval!(1 day 12 hours);
+//! ```
+//!
 
 #[doc(hidden)]
 #[macro_export]
@@ -69,7 +76,7 @@ macro_rules! __meridiem_parser {
     (pm) => { false };
     (PM) => { false };
     (Pm) => { false };
-    ($t:tt+) => {{
+    ($($t:tt)+) => {{
         core::panic!("meridiem must be one of `am`, `AM`, `Am`, `pm`, `PM`, or `Pm`")
     }};
 }
@@ -91,16 +98,88 @@ macro_rules! __expand_or_zero {
 #[cfg(feature = "macros")]
 pub use __expand_or_zero;
 
+/// Parses the seconds component of [`time!`] from its source text.
+///
+/// The fractional part is truncated to nanosecond precision, so at most 9 digits
+/// are allowed. [`None`] is returned if the text isn't a plain decimal number.
+#[doc(hidden)]
+#[cfg(feature = "macros")]
+pub const fn __parse_seconds(s: &str) -> Option<(u8, u32)> {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return Some((0, 0));
+    }
+
+    let mut index = 0;
+    let mut seconds: u32 = 0;
+    let mut digits = 0;
+    while index < bytes.len() && bytes[index] != b'.' {
+        match bytes[index] {
+            b'0'..=b'9' => {
+                // Saturate since anything past 255 is rejected by the range check anyway
+                if seconds <= u8::MAX as u32 {
+                    seconds = seconds * 10 + (bytes[index] - b'0') as u32;
+                }
+                digits += 1;
+            }
+            b'_' => {}
+            _ => return None,
+        }
+        index += 1;
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    let mut nanoseconds: u32 = 0;
+    let mut scale: u32 = 100_000_000;
+    index += 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'0'..=b'9' => {
+                if scale == 0 {
+                    return None;
+                }
+                nanoseconds += (bytes[index] - b'0') as u32 * scale;
+                scale /= 10;
+            }
+            b'_' => {}
+            _ => return None,
+        }
+        index += 1;
+    }
+
+    let seconds = if seconds > u8::MAX as u32 {
+        u8::MAX
+    } else {
+        seconds as u8
+    };
+    Some((seconds, nanoseconds))
+}
+
+/// Parses the week component of an ISO week date in [`date!`], e.g. `W07`.
+///
+/// Returns 0, an invalid week, if the text isn't a `W` followed by one or two digits.
+#[doc(hidden)]
+#[cfg(feature = "macros")]
+pub const fn __parse_iso_week(s: &str) -> u8 {
+    match s.as_bytes() {
+        [b'W', d @ b'0'..=b'9'] => *d - b'0',
+        [b'W', t @ b'0'..=b'9', d @ b'0'..=b'9'] => (*t - b'0') * 10 + (*d - b'0'),
+        _ => 0,
+    }
+}
+
 /// Creates a [`Time`] with compile-time validation and values.
 ///
 /// The resulting type can be used in both `static` and `const` contexts.
 /// All units passed are validated at compile-time. A compile time
 /// error will trigger if any of the units are invalid.
 ///
-/// Currently there is no way to denote sub-second precision.
-///
-/// The syntax supported is `HH:MM:SS (am|pm)` with the AM/PM component
-/// and the seconds components being optional.
+/// The syntax supported is `HH:MM:SS.fffffffff (am|pm)` with the AM/PM component
+/// and the seconds components being optional. The seconds can have up to 9 fractional
+/// digits for sub-second precision.
 ///
 /// # Examples
 ///
@@ -113,6 +192,10 @@ pub use __expand_or_zero;
 /// // AM and PM are supported too
 /// assert_eq!(time!(12:00 am), Time::new(0, 0, 0)?);
 /// assert_eq!(time!(1:12:23 pm), Time::new(13, 12, 23)?);
+///
+/// // So are fractional seconds
+/// assert_eq!(time!(12:30:45.5), Time::new(12, 30, 45)?.with_nanosecond(500_000_000)?);
+/// assert_eq!(time!(1:00:00.000001 pm), Time::new(13, 0, 0)?.with_nanosecond(1_000)?);
 /// # Some(())
 /// # }
 /// # test();
@@ -127,12 +210,14 @@ macro_rules! time {
         const HOURS: u8 = $hours;
         #[allow(clippy::zero_prefixed_literal)]
         const MINUTES: u8 = $minutes;
-        #[allow(clippy::zero_prefixed_literal)]
-        const SECONDS: u8 = $crate::macros::__expand_or_zero!($($seconds)?);
+        const SECONDS: (u8, u32) = match $crate::macros::__parse_seconds(stringify!($($seconds)?)) {
+            Some(seconds) => seconds,
+            None => core::panic!("seconds must be a number with at most 9 fractional digits"),
+        };
         $crate::macros::const_assert!(HOURS <= 23, "hours must be less than 24");
         $crate::macros::const_assert!(MINUTES <= 59, "minutes must be less than 60");
-        $crate::macros::const_assert!(SECONDS <= 59, "seconds must be less than 60");
-        $crate::Time::__new_unchecked_from_macro(HOURS, MINUTES, SECONDS)
+        $crate::macros::const_assert!(SECONDS.0 <= 59, "seconds must be less than 60");
+        $crate::Time::__new_unchecked_from_macro(HOURS, MINUTES, SECONDS.0, SECONDS.1)
     }};
 
     ($hours:literal:$minutes:literal$(:$seconds:literal)? $meridiem:ident) => {{
@@ -140,16 +225,23 @@ macro_rules! time {
         const HOURS: u8 = $hours;
         #[allow(clippy::zero_prefixed_literal)]
         const MINUTES: u8 = $minutes;
-        #[allow(clippy::zero_prefixed_literal)]
-        const SECONDS: u8 = $crate::macros::__expand_or_zero!($($seconds)?);
+        const SECONDS: (u8, u32) = match $crate::macros::__parse_seconds(stringify!($($seconds)?)) {
+            Some(seconds) => seconds,
+            None => core::panic!("seconds must be a number with at most 9 fractional digits"),
+        };
         $crate::macros::const_assert!(HOURS <= 12, "hours must be less than 13");
         $crate::macros::const_assert!(MINUTES <= 59, "minutes must be less than 60");
-        $crate::macros::const_assert!(SECONDS <= 59, "seconds must be less than 60");
+        $crate::macros::const_assert!(SECONDS.0 <= 59, "seconds must be less than 60");
         const MERIDIEM: bool = $crate::macros::__meridiem_parser!($meridiem);
         if MERIDIEM {
-            $crate::Time::__new_unchecked_from_macro(if HOURS == 12 { 0 } else { HOURS }, MINUTES, SECONDS)
+            $crate::Time::__new_unchecked_from_macro(if HOURS == 12 { 0 } else { HOURS }, MINUTES, SECONDS.0, SECONDS.1)
         } else {
-            $crate::Time::__new_unchecked_from_macro(if HOURS == 12 { 12 } else { HOURS + 12 }, MINUTES, SECONDS)
+            $crate::Time::__new_unchecked_from_macro(
+                if HOURS == 12 { 12 } else { HOURS + 12 },
+                MINUTES,
+                SECONDS.0,
+                SECONDS.1,
+            )
         }
     }};
 }
@@ -160,15 +252,28 @@ macro_rules! time {
 /// All units passed are validated at compile-time. A compile time
 /// error will trigger if any of the units are invalid.
 ///
-/// The syntax supported is `YYYY-MM-DD`.
+/// The syntax supported is one of the following:
+///
+/// - `YYYY-MM-DD` for a calendar date.
+/// - `YYYY-DDD` for an ordinal date, where `DDD` is the day of the year.
+/// - `YYYY-Www-D` for an ISO week date, where `ww` is the ISO week and `D` is the
+///   weekday from 1 (Monday) to 7 (Sunday). Note that `YYYY` is the ISO year here.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use eos::{date, Date};
+/// use eos::{date, Date, IsoWeekDate, Weekday};
 /// # fn test() -> Option<()> {
 /// assert_eq!(date!(2012-2-29), Date::new(2012, 2, 29)?);
 /// assert_eq!(date!(2000-01-25), Date::new(2000, 1, 25)?);
+///
+/// // Ordinal dates
+/// assert_eq!(date!(2022-048), Date::new(2022, 2, 17)?);
+/// assert_eq!(date!(2020-366), Date::new(2020, 12, 31)?);
+///
+/// // ISO week dates
+/// assert_eq!(date!(2022-W07-3), Date::new(2022, 2, 16)?);
+/// assert_eq!(date!(2020-W53-7), Date::from(IsoWeekDate::new(2020, 53, Weekday::Sunday)?));
 /// # Some(())
 /// # }
 /// # test();
@@ -194,6 +299,41 @@ macro_rules! date {
 
         $crate::Date::__new_unchecked_from_macro(YEAR, MONTH, DAY)
     }};
+
+    ($year:literal-$week:ident-$weekday:literal) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        const YEAR: i16 = $year;
+        const WEEK: u8 = $crate::macros::__parse_iso_week(stringify!($week));
+        #[allow(clippy::zero_prefixed_literal)]
+        const WEEKDAY: u8 = $weekday;
+        $crate::macros::const_assert!(
+            WEEK >= 1 && WEEK <= $crate::gregorian::iso_weeks_in_year(YEAR),
+            "week must be `W` followed by a week within the ISO year, e.g. `W07`"
+        );
+        $crate::macros::const_assert!(WEEKDAY >= 1 && WEEKDAY <= 7, "weekday must be between [1, 7]");
+
+        const DAYS: i32 = $crate::gregorian::iso_week_date_to_epoch_days(YEAR, WEEK, WEEKDAY);
+        $crate::macros::const_assert!(
+            $crate::Date::from_epoch_days(DAYS).is_ok(),
+            "date must be between `Date::MIN` and `Date::MAX`"
+        );
+
+        $crate::Date::__from_epoch_days_unchecked_from_macro(DAYS)
+    }};
+
+    ($year:literal-$ordinal:literal) => {{
+        #[allow(clippy::zero_prefixed_literal)]
+        const YEAR: i16 = $year;
+        #[allow(clippy::zero_prefixed_literal)]
+        const ORDINAL: u16 = $ordinal;
+        $crate::macros::const_assert!(
+            ORDINAL >= 1 && ORDINAL <= $crate::gregorian::days_in_year(YEAR),
+            "ordinal must be positive and within range of the year"
+        );
+
+        const DAYS: i32 = $crate::gregorian::date_to_epoch_days(YEAR, 1, 1) + ORDINAL as i32 - 1;
+        $crate::Date::__from_epoch_days_unchecked_from_macro(DAYS)
+    }};
 }
 
 /// Creates a [`UtcOffset`] with compile-time validation and values.
@@ -274,13 +414,18 @@ macro_rules! utc_offset {
 /// The resulting [`DateTime`] will either be in [`Utc`] or with a [`UtcOffset`]
 /// if one is provided in the syntax.
 ///
-/// The syntax supported is a combination of the [`date`] and [`time`] macros with
+/// The syntax supported is a combination of the [`date`] and [`time!`] macros with
 /// an optional [`utc_offset`] component. For completeness, the syntax is:
 ///
 /// ```ignore
-/// YYYY-MM-DD HH:MM(:SS)? (am|pm)? (((+|-)?HH:MM(:SS)?))?
+/// DATE HH:MM(:SS(.fffffffff)?)? (am|pm)? (Z|((+|-)?HH:MM(:SS)?))?
 /// ```
 ///
+/// Where `DATE` is any of the forms accepted by [`date`]. A trailing `Z` explicitly
+/// marks the time as UTC and results in a [`DateTime`] in [`Utc`], the same as
+/// leaving the offset out. An offset of `+00:00` on the other hand results in a
+/// [`DateTime`] with a [`UtcOffset`].
+///
 /// Due to a limitation with suffixes on literals, these components must be space separated
 /// to work.
 ///
@@ -330,6 +475,15 @@ macro_rules! utc_offset {
 ///       .with_time(Time::new(20, 12, 0)?)
 ///       .with_timezone(UtcOffset::from_hms(0, -30, 0)?)
 /// );
+///
+/// // An explicit UTC marker
+/// const UTC: DateTime<Utc> = datetime!(2022-01-01 10:00:30.25 Z);
+/// assert_eq!(UTC, datetime!(2022-01-01 10:00:30.25));
+/// assert_eq!(UTC.time(), &Time::new(10, 0, 30)?.with_nanosecond(250_000_000)?);
+///
+/// // Ordinal and ISO week dates
+/// assert_eq!(datetime!(2022-048 8:00 pm -05:00), datetime!(2022-02-17 20:00 -05:00));
+/// assert_eq!(datetime!(2022-W07-3 12:00 Z), datetime!(2022-02-16 12:00));
 /// # Some(())
 /// # }
 /// # test();
@@ -342,36 +496,110 @@ macro_rules! utc_offset {
 #[macro_export]
 #[cfg(feature = "macros")]
 macro_rules! datetime {
+    // The date is collected one token at a time until the time is found, since its
+    // length depends on whether it's a calendar, ordinal, or ISO week date.
+    (@split [$($date:tt)+] $hours:tt:$minutes:tt$(:$seconds:tt)? Z) => {{
+        const DATE: $crate::Date = $crate::date!($($date)+);
+        const TIME: $crate::Time = $crate::time!($hours:$minutes$(:$seconds)?);
+        $crate::DateTime::__new_utc_unchecked_from_macro(DATE, TIME)
+    }};
+
+    (@split [$($date:tt)+] $hours:tt:$minutes:tt$(:$seconds:tt)? $meridiem:ident Z) => {{
+        const DATE: $crate::Date = $crate::date!($($date)+);
+        const TIME: $crate::Time = $crate::time!($hours:$minutes$(:$seconds)? $meridiem);
+        $crate::DateTime::__new_utc_unchecked_from_macro(DATE, TIME)
+    }};
+
     (
-        $year:tt-$month:tt-$day:tt
+        @split [$($date:tt)+]
         $hours:tt:$minutes:tt$(:$seconds:tt)? $($meridiem:ident)?
         -$off_hours:literal$(:$off_minutes:literal$(:$off_seconds:literal)?)?
     ) => {{
-        const DATE: $crate::Date = $crate::date!($year-$month-$day);
+        const DATE: $crate::Date = $crate::date!($($date)+);
         const TIME: $crate::Time = $crate::time!($hours:$minutes$(:$seconds)? $($meridiem)?);
         const OFFSET: $crate::UtcOffset = $crate::utc_offset!(-$off_hours$(:$off_minutes$(:$off_seconds)?)?);
         $crate::__create_offset_datetime_from_macro(DATE, TIME, OFFSET)
     }};
 
     (
-        $year:tt-$month:tt-$day:tt
+        @split [$($date:tt)+]
         $hours:tt:$minutes:tt$(:$seconds:tt)? $($meridiem:ident)?
         $(+)?$off_hours:literal$(:$off_minutes:literal$(:$off_seconds:literal)?)?
     ) => {{
-        const DATE: $crate::Date = $crate::date!($year-$month-$day);
+        const DATE: $crate::Date = $crate::date!($($date)+);
         const TIME: $crate::Time = $crate::time!($hours:$minutes$(:$seconds)? $($meridiem)?);
         const OFFSET: $crate::UtcOffset = $crate::utc_offset!($off_hours$(:$off_minutes$(:$off_seconds)?)?);
         $crate::__create_offset_datetime_from_macro(DATE, TIME, OFFSET)
     }};
 
-    (
-        $year:tt-$month:tt-$day:tt
-        $hours:tt:$minutes:tt$(:$seconds:tt)? $($meridiem:ident)?
-    ) => {{
-        const DATE: $crate::Date = $crate::date!($year-$month-$day);
+    (@split [$($date:tt)+] $hours:tt:$minutes:tt$(:$seconds:tt)? $($meridiem:ident)?) => {{
+        const DATE: $crate::Date = $crate::date!($($date)+);
         const TIME: $crate::Time = $crate::time!($hours:$minutes$(:$seconds)? $($meridiem)?);
         $crate::DateTime::__new_utc_unchecked_from_macro(DATE, TIME)
     }};
+
+    (@split [$($date:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::datetime!(@split [$($date)* $next] $($rest)*)
+    };
+
+    (@split [$($date:tt)*]) => {
+        core::compile_error!("expected a date followed by a time, e.g. `2022-01-01 10:00`")
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::datetime!(@split [] $($tokens)+)
+    };
+}
+
+/// Creates an [`Interval`] with compile-time validation and values.
+///
+/// The resulting type can be used in both `static` and `const` contexts.
+/// A compile time error will trigger if the syntax is invalid or if any of
+/// the components would overflow.
+///
+/// Two syntaxes are supported. The first is a list of integers each followed by a unit,
+/// such as `2 years 3 months`. The units can be given in any order and may be repeated,
+/// in which case they're added together. The accepted units are `years`, `months`, `weeks`,
+/// `days`, `hours`, `minutes`, `seconds`, `milliseconds`, and `microseconds`, along with
+/// their singular forms. Values can be negative. Years must fit in an `i16`, like
+/// [`Interval::from_years`].
+///
+/// The second is a string literal with the same ISO-8601 syntax that `FromIsoFormat`
+/// accepts for an [`Interval`], such as `"P2Y3M4DT5H"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use eos::{interval, Interval};
+///
+/// const TIMEOUT: Interval = interval!(2 years 3 months 4 days 5 hours);
+/// assert_eq!(
+///     TIMEOUT,
+///     Interval::from_years(2).and_months(3).and_days(4).and_hours(5)
+/// );
+/// assert_eq!(interval!("P2Y3M4DT5H"), TIMEOUT);
+///
+/// assert_eq!(interval!(1 week -1 day), Interval::from_days(6));
+/// assert_eq!(interval!(90 minutes), Interval::from_hours(1).and_minutes(30));
+/// assert_eq!(interval!("PT0.5S"), Interval::from_milliseconds(500));
+/// assert_eq!(interval!("-P1M"), Interval::from_months(-1));
+/// ```
+///
+/// [`Interval`]: crate::Interval
+/// [`Interval::from_years`]: crate::Interval::from_years
+#[macro_export]
+#[cfg(feature = "macros")]
+macro_rules! interval {
+    ($iso:literal) => {{
+        const INTERVAL: $crate::Interval = $crate::Interval::__from_iso_from_macro($iso);
+        INTERVAL
+    }};
+
+    ($($value:literal $unit:ident)+) => {{
+        const INTERVAL: $crate::Interval = $crate::Interval::ZERO
+            $(.__and_unit_from_macro($value, stringify!($unit)))+;
+        INTERVAL
+    }};
 }
 
 #[cfg(feature = "macros")]
@@ -379,6 +607,8 @@ pub use date;
 #[cfg(feature = "macros")]
 pub use datetime;
 #[cfg(feature = "macros")]
+pub use interval;
+#[cfg(feature = "macros")]
 pub use time;
 #[cfg(feature = "macros")]
 pub use utc_offset;
@@ -0,0 +1,215 @@
+//! Tests that pin down the numeric boundaries of every internal time scale.
+//!
+//! The public types are bounded by small integers (an `i16` year, a `u32` nanosecond, etc.)
+//! but conversions between them go through wider intermediates. The scales involved are:
+//!
+//! | Quantity                                    | Range                                   | Stored as |
+//! |:--------------------------------------------|:----------------------------------------|:---------:|
+//! | Days since the epoch                        | around ±1.2 × 10<sup>7</sup>            | `i32`     |
+//! | Seconds since the epoch                     | around ±1.0 × 10<sup>12</sup>           | `i64`     |
+//! | Nanoseconds since the epoch                 | around ±1.0 × 10<sup>21</sup>           | `i128`    |
+//! | Nanoseconds within a day (with leap second) | `0..=86_400_999_999_999`                | `u64`     |
+//! | [`Duration`] in nanoseconds                 | up to around 1.8 × 10<sup>28</sup>      | `i128`    |
+//! | [`Interval`] microseconds                   | `i64`                                   | `i64`     |
+//! | [`Interval`] months                         | `i32`, around ±1.8 × 10<sup>8</sup> years | `i32`   |
+//!
+//! Each test here sits at the exact point where a narrower intermediate would overflow,
+//! so widening or narrowing a type in the future shows up as a failure here.
+
+use core::time::Duration;
+
+use crate::{
+    gregorian::{MAX_EPOCH_DAYS, MIN_EPOCH_DAYS},
+    Date, DateTime, Interval, Time, Timestamp, UtcOffset,
+};
+
+const MIN_SECONDS: i64 = MIN_EPOCH_DAYS as i64 * 86_400;
+const MAX_SECONDS: i64 = MAX_EPOCH_DAYS as i64 * 86_400 + 86_399;
+
+#[test]
+fn test_epoch_seconds_boundaries() {
+    assert_eq!(MIN_SECONDS, -1_096_225_401_600);
+    assert_eq!(MAX_SECONDS, 971_890_963_199);
+
+    assert_eq!(DateTime::MIN.timestamp(), Timestamp::from_seconds(MIN_SECONDS));
+    assert_eq!(DateTime::MAX.timestamp(), Timestamp::new(MAX_SECONDS, 999_999_999));
+    assert_eq!(Timestamp::new(MIN_SECONDS, 0).to_utc(), DateTime::MIN);
+    assert_eq!(Timestamp::new(MAX_SECONDS, 999_999_999).to_utc(), DateTime::MAX);
+
+    // The sub-second component survives at both edges
+    let ts = Timestamp::new(MIN_SECONDS, 1);
+    assert_eq!(ts.to_utc().timestamp(), ts);
+    let ts = Timestamp::new(MAX_SECONDS, 0);
+    assert_eq!(ts.to_utc().timestamp(), ts);
+
+    // One past either edge saturates
+    assert_eq!(Timestamp::from_seconds(MIN_SECONDS - 1).to_utc(), DateTime::MIN);
+    assert_eq!(Timestamp::from_seconds(MAX_SECONDS + 1).to_utc(), DateTime::MAX);
+    assert_eq!(Timestamp::from_seconds(i64::MIN).to_utc(), DateTime::MIN);
+    assert_eq!(Timestamp::from_seconds(i64::MAX).to_utc(), DateTime::MAX);
+
+    // The offset is applied in i64 seconds
+    let dt = Date::MIN.at(Time::MIN).with_timezone(UtcOffset::MAX);
+    assert_eq!(dt.timestamp(), Timestamp::from_seconds(MIN_SECONDS - 86_400));
+    let dt = Date::MAX.at(Time::MAX).with_timezone(UtcOffset::MIN);
+    assert_eq!(dt.timestamp(), Timestamp::new(MAX_SECONDS + 86_400, 999_999_999));
+}
+
+#[test]
+fn test_timestamp_milliseconds_boundaries() {
+    let ts = Timestamp::new(i64::MAX, 999_999_999);
+    assert_eq!(ts.as_milliseconds(), i64::MAX as i128 * 1_000 + 999);
+    let ts = Timestamp::new(i64::MIN, 0);
+    assert_eq!(ts.as_milliseconds(), i64::MIN as i128 * 1_000);
+
+    assert_eq!(
+        Timestamp::from_milliseconds(i64::MIN).as_milliseconds(),
+        i64::MIN as i128
+    );
+    assert_eq!(
+        Timestamp::from_milliseconds(i64::MAX).as_milliseconds(),
+        i64::MAX as i128
+    );
+    assert_eq!(
+        Timestamp::from_microseconds(i64::MIN),
+        Timestamp::new(-9_223_372_036_855, 224_192_000)
+    );
+}
+
+#[test]
+fn test_elapsed_nanoseconds_boundaries() {
+    // The widest span is around 2^61 nanoseconds times 1,000, past an i64
+    let span = DateTime::MAX.duration_since(&DateTime::MIN);
+    let expected = Duration::new((MAX_SECONDS - MIN_SECONDS) as u64, 999_999_999);
+    assert_eq!(span, Ok(expected));
+    assert!(expected.as_nanos() > i64::MAX as u128);
+    assert_eq!(DateTime::MIN.duration_since(&DateTime::MAX), Err(expected));
+
+    // Offsets on top of that span
+    let min = DateTime::MIN.with_timezone(UtcOffset::MAX);
+    let max = DateTime::MAX.with_timezone(UtcOffset::MIN);
+    let expected = expected + Duration::from_secs(2 * 86_400);
+    assert_eq!(max.duration_since(&min), Ok(expected));
+}
+
+#[test]
+fn test_time_with_duration_boundaries() {
+    let noon = Time::new(12, 0, 0).unwrap();
+
+    // 292 years is around the point a nanosecond count overflows an i64
+    let i64_overflow = Duration::from_nanos(i64::MAX as u64) + Duration::from_nanos(1);
+    let expected_days = ((i64::MAX as u128 + 1 + 43_200_000_000_000) / 86_400_000_000_000) as i32;
+    let (days, time) = noon.add_with_duration(i64_overflow);
+    assert_eq!(days, expected_days);
+    assert_eq!(
+        time,
+        Time::new(11, 47, 16).unwrap().with_nanosecond(854_775_808).unwrap()
+    );
+    assert_eq!(noon.checked_add_with_duration(i64_overflow), Some((days, time)));
+
+    // Whole days never move the time, regardless of how large they are
+    let four_hundred_years = Duration::from_secs(146_097 * 86_400);
+    assert_eq!(noon + four_hundred_years, noon);
+    assert_eq!(noon - four_hundred_years, noon);
+    assert_eq!(
+        noon + (four_hundred_years + Duration::from_secs(1)),
+        Time::new(12, 0, 1).unwrap()
+    );
+
+    // The largest duration wraps the time correctly but saturates the number of days
+    let expected = Duration::from_secs(u64::MAX % 86_400 + 43_200) + Duration::from_nanos(999_999_999);
+    let expected = Time::MIN + Duration::from_nanos((expected.as_nanos() % 86_400_000_000_000) as u64);
+    assert_eq!(noon.add_with_duration(Duration::MAX), (i32::MAX, expected));
+    assert_eq!(noon.checked_add_with_duration(Duration::MAX), None);
+    assert_eq!(noon.sub_with_duration(Duration::MAX).0, i32::MIN + 1);
+    assert_eq!(noon.checked_sub_with_duration(Duration::MAX), None);
+
+    // The exact point where the number of days stops fitting in an i32
+    let last = Duration::from_secs(i32::MAX as u64 * 86_400 + 43_199);
+    assert_eq!(noon.checked_add_with_duration(last).map(|x| x.0), Some(i32::MAX));
+    assert_eq!(noon.checked_add_with_duration(last + Duration::from_secs(1)), None);
+}
+
+#[test]
+fn test_datetime_with_duration_boundaries() {
+    let dt = DateTime::MIN;
+    assert_eq!(
+        dt + Duration::MAX,
+        Date::MAX.at(dt.time().add_with_duration(Duration::MAX).1)
+    );
+    assert_eq!((dt + Duration::MAX).date(), &Date::MAX);
+    assert_eq!((DateTime::MAX - Duration::MAX).date(), &Date::MIN);
+    assert_eq!(dt.checked_add_duration(Duration::MAX), None);
+
+    // Shifting by the largest offset at the edges of the supported range saturates the date
+    let mut dt = DateTime::MAX;
+    dt.shift(UtcOffset::MAX);
+    assert_eq!(dt.date(), &Date::MAX);
+    assert_eq!(dt.time(), &Time::MAX);
+    let mut dt = DateTime::MIN;
+    dt.shift(UtcOffset::MIN);
+    assert_eq!(dt.date(), &Date::MIN);
+    assert_eq!(dt.time(), &Time::MIN);
+}
+
+#[test]
+fn test_interval_boundaries() {
+    // Every i32 number of hours, minutes, or seconds fits in the i64 microseconds
+    assert_eq!(
+        Interval::from_hours(i32::MAX).total_microseconds(),
+        7_730_941_129_200_000_000
+    );
+    assert_eq!(
+        Interval::from_hours(i32::MIN).total_microseconds(),
+        -7_730_941_132_800_000_000
+    );
+    assert_eq!(Interval::from_hours(i32::MAX).hours(), i32::MAX as i64);
+
+    // These saturate rather than overflow
+    assert_eq!(Interval::from_milliseconds(i64::MAX).total_microseconds(), i64::MAX);
+    assert_eq!(Interval::from_milliseconds(i64::MIN).total_microseconds(), i64::MIN);
+    assert_eq!(Interval::from_weeks(i32::MAX / 7).days(), i32::MAX / 7 * 7);
+    assert_eq!(Interval::from_weeks(i32::MAX / 7 + 1).days(), i32::MAX);
+    assert_eq!(Interval::from_weeks(i32::MIN).days(), i32::MIN);
+
+    // The months are an i32 but the years are an i16
+    assert_eq!(Interval::from_months(i16::MAX as i32 * 12 + 11).years(), i16::MAX);
+    assert_eq!(Interval::from_months((i16::MAX as i32 + 1) * 12).years(), i16::MAX);
+    assert_eq!(Interval::from_months(i32::MAX).years(), i16::MAX);
+    assert_eq!(Interval::from_months(i32::MIN).years(), i16::MIN);
+    assert_eq!(Interval::from_months(i32::MAX).total_months(), i32::MAX);
+    assert_eq!(Interval::from_months(i32::MAX).months(), 7);
+
+    // The time component is split by magnitude so negative values don't floor
+    let noon = Time::new(12, 0, 0).unwrap();
+    let expected = Time::new(11, 59, 58).unwrap().with_nanosecond(500_000_000).unwrap();
+    assert_eq!(noon + Interval::from_milliseconds(-1_500), expected);
+    assert_eq!(noon - Interval::from_milliseconds(1_500), expected);
+    let (sub, duration) = Interval::from_microseconds(i64::MIN).get_time_duration();
+    assert!(sub);
+    assert_eq!(duration, Duration::new(9_223_372_036_854, 775_808_000));
+
+    // The widest interval between two datetimes
+    let interval = Interval::between(&DateTime::MIN, &DateTime::MAX);
+    assert_eq!(interval.total_months(), (u16::MAX as i32) * 12 + 11);
+    assert_eq!(interval.days(), 30);
+    assert_eq!(
+        DateTime::MIN + interval,
+        DateTime::MAX.with_nanosecond(999_999_000).unwrap()
+    );
+}
+
+#[test]
+fn test_date_month_boundaries() {
+    // Month arithmetic goes through an i64 so any i32 is accepted
+    assert_eq!(Date::MAX.add_months(i32::MAX), Date::MAX);
+    assert_eq!(Date::MIN.add_months(i32::MIN), Date::MIN);
+    assert_eq!(Date::MIN.checked_add_months(i32::MAX), None);
+    assert_eq!(
+        Date::MIN.add_months(u16::MAX as i32 * 12 + 11),
+        Date::new(i16::MAX, 12, 1).unwrap()
+    );
+    assert_eq!(Date::MIN.add_days(MAX_EPOCH_DAYS - MIN_EPOCH_DAYS), Date::MAX);
+    assert_eq!(Date::MIN.add_days(i32::MAX), Date::MAX);
+    assert_eq!(Date::MAX.add_days(i32::MIN), Date::MIN);
+}
@@ -4,30 +4,67 @@
 //! ISO-8601 representation. They are meant to be used with the [`with`] annotation.
 //!
 //! [`with`]: https://serde.rs/field-attrs.html#with
+//!
+//! # Binary Representation
+//!
+//! When the format is not human readable (i.e. [`is_human_readable`] returns `false`, such
+//! as with `bincode` or `postcard`) then [`Date`], [`Time`], [`DateTime`], and [`Timestamp`]
+//! are serialized as compact tuples of integers rather than strings. This layout is stable
+//! and can be relied upon for on-disk formats:
+//!
+//! | Type          | Layout                                                             |
+//! |:--------------|:-------------------------------------------------------------------|
+//! | [`Timestamp`] | `(i64, u32)`: seconds since the UNIX epoch and nanoseconds         |
+//! | [`DateTime`]  | `(i64, u32, i32)`: a [`Timestamp`] followed by the offset seconds  |
+//! | [`Date`]      | `i32`: days since the UNIX epoch                                   |
+//! | [`Time`]      | `(u32, u32)`: seconds since midnight and nanoseconds               |
+//!
+//! The nanoseconds component of a [`Time`] can be above 999,999,999 to represent leap seconds.
+//!
+//! An [`Expiry`] is serialized as an epoch value rather than a string. In human readable
+//! formats this is an `i64` of milliseconds since the UNIX epoch, while binary formats use the
+//! same `(i64, u32)` layout as a [`Timestamp`].
+//!
+//! A [`WeekdaySet`] is serialized as a `u8` bitmask in every format, where bit 0 is Monday
+//! and bit 6 is Sunday. See [`WeekdaySet::from_bits`] for more information.
+//!
+//! [`is_human_readable`]: serde::Serializer::is_human_readable
 
-use serde::de::{self, Deserialize, Visitor};
-use serde::ser::Serialize;
+use serde::de::{self, Deserialize, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple};
 
-use crate::fmt::FromIsoFormat;
-use crate::{Date, DateTime, Interval, IsoWeekDate, Time, TimeZone, Utc, UtcOffset};
+use crate::extra::{Expiry, WeekdaySet};
+use crate::fmt::{FromIsoFormat, ParseError, Parser};
+use crate::{Date, DateTime, Interval, IsoWeekDate, Time, TimeZone, Timestamp, Utc, UtcOffset};
 
-/// Serialize into an ISO-8601 string.
+/// Serialize into an ISO-8601 string, or a `(u32, u32)` tuple for binary formats.
 impl Serialize for Time {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&(self.total_seconds() as u32))?;
+            tuple.serialize_element(&self.nanosecond())?;
+            tuple.end()
+        }
     }
 }
 
-/// Serialize into an ISO-8601 string.
+/// Serialize into an ISO-8601 string, or an `i32` for binary formats.
 impl Serialize for Date {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_i32(self.days_since_epoch())
+        }
     }
 }
 
@@ -41,7 +78,7 @@ impl Serialize for IsoWeekDate {
     }
 }
 
-/// Serialize into an ISO-8601 string.
+/// Serialize into an ISO-8601 string, or an `(i64, u32, i32)` tuple for binary formats.
 impl<Tz> Serialize for DateTime<Tz>
 where
     Tz: TimeZone,
@@ -50,7 +87,33 @@ where
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(self)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let ts = self.timestamp();
+            let mut tuple = serializer.serialize_tuple(3)?;
+            tuple.serialize_element(&ts.as_seconds())?;
+            tuple.serialize_element(&ts.nanoseconds)?;
+            tuple.serialize_element(&self.offset().total_seconds())?;
+            tuple.end()
+        }
+    }
+}
+
+/// Serialize into an ISO-8601 string in UTC, or an `(i64, u32)` tuple for binary formats.
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.to_utc())
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.as_seconds())?;
+            tuple.serialize_element(&self.nanoseconds)?;
+            tuple.end()
+        }
     }
 }
 
@@ -79,6 +142,21 @@ impl<'de> Visitor<'de> for TimeVisitor {
     {
         Time::from_iso_format(v).map_err(E::custom)
     }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let seconds: u32 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let nanos: u32 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let (hours, seconds) = (seconds / 3600, seconds % 3600);
+        let (minutes, seconds) = (seconds / 60, seconds % 60);
+        u8::try_from(hours)
+            .ok()
+            .and_then(|h| Time::new(h, minutes as u8, seconds as u8))
+            .and_then(|t| t.with_nanosecond(nanos))
+            .ok_or_else(|| de::Error::custom("time out of range"))
+    }
 }
 
 struct DateVisitor;
@@ -96,6 +174,13 @@ impl<'de> Visitor<'de> for DateVisitor {
     {
         Date::from_iso_format(v).map_err(E::custom)
     }
+
+    fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Date::from_epoch_days(v).map_err(|_| E::custom("date out of range"))
+    }
 }
 
 struct IsoWeekDateVisitor;
@@ -147,6 +232,53 @@ impl<'de> Visitor<'de> for DateTimeVisitor {
     {
         DateTime::from_iso_format(v).map_err(E::custom)
     }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let ts: Timestamp = TimestampTupleVisitor.visit_seq(&mut seq)?;
+        let offset: i32 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let offset = UtcOffset::from_seconds(offset).ok_or_else(|| de::Error::custom("offset out of range"))?;
+        // Converting the timestamp directly would carry a leap second into the next second,
+        // so it's converted without it and then added back to keep the round trip exact
+        let nanos = ts.nanoseconds;
+        let whole = Timestamp::new(ts.as_seconds(), nanos % 1_000_000_000);
+        DateTime::try_from_timestamp(whole, Utc)
+            .ok()
+            .and_then(|dt| dt.in_timezone(offset).with_nanosecond(nanos))
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Signed(ts.as_seconds()), &self))
+    }
+}
+
+struct TimestampTupleVisitor;
+
+impl<'de> Visitor<'de> for TimestampTupleVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("an ISO-8601 formatted date string or a (seconds, nanoseconds) tuple")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DateTimeVisitor.visit_str(v).map(|dt| dt.timestamp())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let seconds: i64 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let nanos: u32 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        // Anything past a whole second is a leap second, see Timestamp::new
+        if nanos >= 2_000_000_000 {
+            return Err(de::Error::invalid_value(de::Unexpected::Unsigned(nanos as u64), &self));
+        }
+        Ok(Timestamp::new(seconds, nanos))
+    }
 }
 
 /// Deserialize from an ISO-8601 string.
@@ -155,7 +287,11 @@ impl<'de> Deserialize<'de> for Time {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(TimeVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TimeVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, TimeVisitor)
+        }
     }
 }
 
@@ -165,7 +301,11 @@ impl<'de> Deserialize<'de> for Date {
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(DateVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DateVisitor)
+        } else {
+            deserializer.deserialize_i32(DateVisitor)
+        }
     }
 }
 
@@ -189,23 +329,130 @@ impl<'de> Deserialize<'de> for Interval {
     }
 }
 
-/// Deserialize from an ISO-8601 string.
+/// Deserialize from an ISO-8601 string, or an `(i64, u32, i32)` tuple for binary formats.
 impl<'de> Deserialize<'de> for DateTime<UtcOffset> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(DateTimeVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DateTimeVisitor)
+        } else {
+            deserializer.deserialize_tuple(3, DateTimeVisitor)
+        }
     }
 }
 
-/// Deserialize from an ISO-8601 string.
+/// Deserialize from an ISO-8601 string, or an `(i64, u32, i32)` tuple for binary formats.
+///
+/// Leap seconds round trip as long as the offset is UTC. With any other offset the
+/// leap second is carried into the next second when converting to UTC.
 impl<'de> Deserialize<'de> for DateTime<Utc> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_str(DateTimeVisitor).map(|dt| dt.into_utc())
+        DateTime::<UtcOffset>::deserialize(deserializer).map(|dt| {
+            if dt.offset == UtcOffset::UTC {
+                DateTime {
+                    date: dt.date,
+                    time: dt.time,
+                    offset: dt.offset,
+                    timezone: Utc,
+                }
+            } else {
+                dt.into_utc()
+            }
+        })
+    }
+}
+
+/// Deserialize from an ISO-8601 string, or an `(i64, u32)` tuple for binary formats.
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TimestampTupleVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, TimestampTupleVisitor)
+        }
+    }
+}
+
+/// Serialize into milliseconds since the UNIX epoch, or an `(i64, u32)` tuple for binary formats.
+impl Serialize for Expiry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let millis = i64::try_from(self.timestamp().as_milliseconds()).map_err(serde::ser::Error::custom)?;
+            serializer.serialize_i64(millis)
+        } else {
+            self.timestamp().serialize(serializer)
+        }
+    }
+}
+
+struct ExpiryMillisVisitor;
+
+impl<'de> Visitor<'de> for ExpiryMillisVisitor {
+    type Value = Expiry;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a UNIX timestamp in milliseconds")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Expiry::new(Timestamp::from_milliseconds(value)))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let value = i64::try_from(value).map_err(E::custom)?;
+        Ok(Expiry::new(Timestamp::from_milliseconds(value)))
+    }
+}
+
+/// Deserialize from milliseconds since the UNIX epoch, or an `(i64, u32)` tuple for binary formats.
+impl<'de> Deserialize<'de> for Expiry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_i64(ExpiryMillisVisitor)
+        } else {
+            Timestamp::deserialize(deserializer).map(Expiry::new)
+        }
+    }
+}
+
+/// Serialize into a `u8` bitmask where bit 0 is Monday and bit 6 is Sunday.
+impl Serialize for WeekdaySet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(self.bits())
+    }
+}
+
+/// Deserialize from a `u8` bitmask where bit 0 is Monday and bit 6 is Sunday.
+impl<'de> Deserialize<'de> for WeekdaySet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bits = u8::deserialize(deserializer)?;
+        WeekdaySet::from_bits(bits).ok_or_else(|| de::Error::custom("weekday set has an invalid bit set"))
     }
 }
 
@@ -309,7 +556,101 @@ pub mod timestamp {
         where
             E: de::Error,
         {
-            Ok(DateTime::from_timestamp(crate::Timestamp::from_seconds(value), Utc))
+            DateTime::try_from_timestamp(crate::Timestamp::from_seconds(value), Utc).map_err(E::custom)
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let value = i64::try_from(value).map_err(E::custom)?;
+            DateTime::try_from_timestamp(crate::Timestamp::from_seconds(value), Utc).map_err(E::custom)
+        }
+    }
+}
+
+/// Serialize into an ISO-8601 string and deserialize from either an ISO-8601 string
+/// or a UNIX timestamp.
+///
+/// Integer timestamps are treated as whole seconds while floating point timestamps
+/// are treated as fractional seconds. Formats that are not self-describing (i.e. when
+/// [`is_human_readable`] returns `false`) only accept the default binary representation.
+///
+/// This is intended to be used with the [`with`] field attribute in `serde`.
+///
+/// [`with`]: https://serde.rs/field-attrs.html#with
+/// [`is_human_readable`]: serde::Deserializer::is_human_readable
+///
+/// # Example
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// use eos::{datetime, DateTime, Utc};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct T {
+///     #[serde(with = "eos::serde::rfc3339_or_timestamp")]
+///     dt: DateTime<Utc>,
+/// }
+///
+/// let a: T = serde_json::from_str(r#"{"dt":"2022-02-01T12:34:56Z"}"#)?;
+/// let b: T = serde_json::from_str(r#"{"dt":1643718896}"#)?;
+/// assert_eq!(a.dt, b.dt);
+/// assert_eq!(a.dt, datetime!(2022-02-01 12:34:56));
+///
+/// let c: T = serde_json::from_str(r#"{"dt":1643718896.5}"#)?;
+/// assert_eq!(c.dt, datetime!(2022-02-01 12:34:56).with_nanosecond(500_000_000).unwrap());
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub mod rfc3339_or_timestamp {
+
+    use super::*;
+
+    /// Serialize a UTC datetime using the default [`Serialize`] implementation.
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        dt.serialize(serializer)
+    }
+
+    /// Deserialize a UTC datetime from either an ISO-8601 string or a UNIX timestamp.
+    ///
+    /// This is intended to be used with the [`deserialize_with`] field attribute in `serde`.
+    ///
+    /// [`deserialize_with`]: https://serde.rs/field-attrs.html#deserialize_with
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            d.deserialize_any(StringOrTimestampVisitor)
+        } else {
+            DateTime::<Utc>::deserialize(d)
+        }
+    }
+
+    struct StringOrTimestampVisitor;
+
+    impl<'de> de::Visitor<'de> for StringOrTimestampVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an ISO-8601 formatted date string or a UNIX timestamp")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::from_iso_format(v).map(|dt| dt.into_utc()).map_err(E::custom)
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::try_from_timestamp(crate::Timestamp::from_seconds(value), Utc).map_err(E::custom)
         }
 
         fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
@@ -317,7 +658,257 @@ pub mod timestamp {
             E: de::Error,
         {
             let value = i64::try_from(value).map_err(E::custom)?;
-            Ok(DateTime::from_timestamp(crate::Timestamp::from_seconds(value), Utc))
+            DateTime::try_from_timestamp(crate::Timestamp::from_seconds(value), Utc).map_err(E::custom)
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if !value.is_finite() || value < i64::MIN as f64 || value >= i64::MAX as f64 {
+                return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+            }
+
+            // f64::floor is not available in core
+            let mut seconds = value as i64;
+            if (seconds as f64) > value {
+                seconds -= 1;
+            }
+            let nanos = ((value - seconds as f64) * 1e9) as u32;
+            DateTime::try_from_timestamp(crate::Timestamp::new(seconds, nanos.min(999_999_999)), Utc).map_err(E::custom)
+        }
+    }
+}
+
+/// Serialize a UTC datetime as only its date and deserialize it back at midnight UTC.
+///
+/// When serializing, the time component is truncated and only the date is written using
+/// the default [`Date`] representation, e.g. `"2024-03-01"`. When deserializing, the date
+/// is inflated into a [`DateTime`] at midnight UTC.
+///
+/// For convenience, deserializing also accepts a full ISO-8601 date time string. The
+/// date time is converted to UTC before its time component is truncated. Use the
+/// [`strict`](date_only::strict) module to reject inputs with a time component instead.
+///
+/// This is intended to be used with the [`with`] field attribute in `serde`.
+///
+/// [`with`]: https://serde.rs/field-attrs.html#with
+///
+/// # Example
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// use eos::{datetime, DateTime, Utc};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct T {
+///     #[serde(with = "eos::serde::date_only")]
+///     dt: DateTime<Utc>,
+/// }
+///
+/// let t = T { dt: datetime!(2024-03-01 12:34:56) };
+/// let string = serde_json::to_string(&t)?;
+/// assert_eq!(string, r#"{"dt":"2024-03-01"}"#);
+///
+/// let t: T = serde_json::from_str(&string)?;
+/// assert_eq!(t.dt, datetime!(2024-03-01 00:00));
+///
+/// let t: T = serde_json::from_str(r#"{"dt":"2024-03-01T22:00:00-05:00"}"#)?;
+/// assert_eq!(t.dt, datetime!(2024-03-02 00:00));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub mod date_only {
+
+    use super::*;
+
+    fn inflate(date: Date) -> DateTime<Utc> {
+        date.at(Time::MIDNIGHT)
+    }
+
+    /// Parses a date that takes up the entire string.
+    fn parse_date(s: &str) -> Result<Date, ParseError> {
+        let mut parser = Parser::new(s);
+        let date = parser.parse_date()?;
+        match parser.peek() {
+            Some(c) => Err(ParseError::UnexpectedChar(c as char)),
+            None => Ok(date),
+        }
+    }
+
+    /// Serialize a UTC datetime as its date, discarding the time component.
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        dt.date().serialize(serializer)
+    }
+
+    /// Deserialize a UTC datetime at midnight from a date or a date time.
+    ///
+    /// This is intended to be used with the [`deserialize_with`] field attribute in `serde`.
+    ///
+    /// [`deserialize_with`]: https://serde.rs/field-attrs.html#deserialize_with
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        if d.is_human_readable() {
+            d.deserialize_str(DateOrDateTimeVisitor)
+        } else {
+            Date::deserialize(d).map(inflate)
+        }
+    }
+
+    struct DateOrDateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for DateOrDateTimeVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an ISO-8601 formatted date or date time string")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match parse_date(v) {
+                Ok(date) => Ok(inflate(date)),
+                Err(e) => match DateTime::from_iso_format(v) {
+                    Ok(dt) => Ok(inflate(*dt.into_utc().date())),
+                    Err(_) => Err(E::custom(e)),
+                },
+            }
+        }
+    }
+
+    /// Like [`date_only`](super::date_only) but deserializing rejects inputs with a time component.
+    ///
+    /// This is intended to be used with the [`with`] field attribute in `serde`.
+    ///
+    /// [`with`]: https://serde.rs/field-attrs.html#with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde_derive::{Deserialize, Serialize};
+    /// use eos::{datetime, DateTime, Utc};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct T {
+    ///     #[serde(with = "eos::serde::date_only::strict")]
+    ///     dt: DateTime<Utc>,
+    /// }
+    ///
+    /// let t: T = serde_json::from_str(r#"{"dt":"2024-03-01"}"#)?;
+    /// assert_eq!(t.dt, datetime!(2024-03-01 00:00));
+    /// assert!(serde_json::from_str::<T>(r#"{"dt":"2024-03-01T12:00:00Z"}"#).is_err());
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub mod strict {
+
+        use super::*;
+
+        /// Serialize a UTC datetime as its date, discarding the time component.
+        pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            super::serialize(dt, serializer)
+        }
+
+        /// Deserialize a UTC datetime at midnight from a date, rejecting any time component.
+        ///
+        /// This is intended to be used with the [`deserialize_with`] field attribute in `serde`.
+        ///
+        /// [`deserialize_with`]: https://serde.rs/field-attrs.html#deserialize_with
+        pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            if d.is_human_readable() {
+                d.deserialize_str(DateOnlyVisitor)
+            } else {
+                Date::deserialize(d).map(inflate)
+            }
+        }
+
+        struct DateOnlyVisitor;
+
+        impl<'de> de::Visitor<'de> for DateOnlyVisitor {
+            type Value = DateTime<Utc>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("an ISO-8601 formatted date string without a time")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match parse_date(v) {
+                    Ok(date) => Ok(inflate(date)),
+                    Err(_) if DateTime::from_iso_format(v).is_ok() => {
+                        Err(E::custom("expected a date without a time component"))
+                    }
+                    Err(e) => Err(E::custom(e)),
+                }
+            }
         }
     }
 }
+
+/// Serialize a UTC datetime as only its time and deserialize it back on the UNIX epoch.
+///
+/// When serializing, the date component is discarded and only the time is written using
+/// the default [`Time`] representation, e.g. `"12:34:56"`. When deserializing, the time
+/// is inflated into a [`DateTime`] on January 1st 1970 in UTC.
+///
+/// This is intended to be used with the [`with`] field attribute in `serde`.
+///
+/// [`with`]: https://serde.rs/field-attrs.html#with
+///
+/// # Example
+///
+/// ```
+/// # use serde_derive::{Deserialize, Serialize};
+/// use eos::{datetime, DateTime, Utc};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct T {
+///     #[serde(with = "eos::serde::time_only")]
+///     dt: DateTime<Utc>,
+/// }
+///
+/// let t = T { dt: datetime!(2024-03-01 12:34:56) };
+/// let string = serde_json::to_string(&t)?;
+/// assert_eq!(string, r#"{"dt":"12:34:56"}"#);
+///
+/// let t: T = serde_json::from_str(&string)?;
+/// assert_eq!(t.dt, datetime!(1970-01-01 12:34:56));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+pub mod time_only {
+
+    use super::*;
+
+    /// Serialize a UTC datetime as its time, discarding the date component.
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        dt.time().serialize(serializer)
+    }
+
+    /// Deserialize a UTC datetime on the UNIX epoch from a time.
+    ///
+    /// This is intended to be used with the [`deserialize_with`] field attribute in `serde`.
+    ///
+    /// [`deserialize_with`]: https://serde.rs/field-attrs.html#deserialize_with
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Time::deserialize(d).map(|time| Date::UNIX_EPOCH.at(time))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{unit, Date, DateTime, Time, TimeZone, Weekday};
+use crate::{unit, utils::divmod, Date, DateTime, Error, Time, TimeZone, Weekday};
 use core::time::Duration;
 
 mod private {
@@ -7,6 +7,7 @@ mod private {
     impl Sealed for Weekday {}
     impl Sealed for Time {}
     impl Sealed for unit::Year {}
+    impl Sealed for unit::Quarter {}
     impl Sealed for unit::Month {}
     impl Sealed for unit::Week {}
     impl Sealed for unit::Day {}
@@ -16,6 +17,15 @@ mod private {
     impl Sealed for unit::Millisecond {}
     impl Sealed for unit::Microsecond {}
     impl Sealed for unit::Nanosecond {}
+    impl Sealed for unit::Unit {}
+
+    /// A unit that a local date and time can be truncated to.
+    pub trait Boundary {
+        /// Returns the local date and time truncated to the start of the unit.
+        fn floor_local(date: Date, time: Time) -> (Date, Time);
+        /// Returns the start of the next unit given the start of the current one.
+        fn next_local(date: Date, time: Time) -> (Date, Time);
+    }
 }
 
 /// A sealed trait for objects that can increment or decrement a date or time.
@@ -26,6 +36,16 @@ pub trait Advance<T>: self::private::Sealed {
     fn prev_from(self, obj: T) -> T;
 }
 
+/// A sealed trait for units that a date or time can be rounded to.
+pub trait Round<T>: self::private::Sealed {
+    /// Returns the object truncated to the start of the unit it's in.
+    fn floor_from(self, obj: T) -> T;
+    /// Returns the object moved to the start of the next unit unless it's already at the start of one.
+    fn ceil_from(self, obj: T) -> T;
+    /// Returns the object moved to the nearest start of a unit, with ties moving forward.
+    fn round_from(self, obj: T) -> T;
+}
+
 impl Advance<Date> for Weekday {
     #[inline]
     fn next_from(self, date: Date) -> Date {
@@ -106,6 +126,16 @@ impl Advance<Date> for unit::Year {
     }
 }
 
+impl Advance<Date> for unit::Quarter {
+    fn next_from(self, date: Date) -> Date {
+        date.add_months(3)
+    }
+
+    fn prev_from(self, date: Date) -> Date {
+        date.add_months(-3)
+    }
+}
+
 impl Advance<Date> for unit::Month {
     fn next_from(self, date: Date) -> Date {
         date.add_months(1)
@@ -216,6 +246,26 @@ impl<Tz: TimeZone> Advance<DateTime<Tz>> for unit::Year {
     }
 }
 
+impl<Tz: TimeZone> Advance<DateTime<Tz>> for unit::Quarter {
+    fn next_from(self, mut dt: DateTime<Tz>) -> DateTime<Tz> {
+        dt.date = dt.date.add_months(3);
+        if dt.timezone().is_fixed() {
+            dt
+        } else {
+            dt.timezone.resolve(dt.date, dt.time).lenient()
+        }
+    }
+
+    fn prev_from(self, mut dt: DateTime<Tz>) -> DateTime<Tz> {
+        dt.date = dt.date.add_months(-3);
+        if dt.timezone().is_fixed() {
+            dt
+        } else {
+            dt.timezone.resolve(dt.date, dt.time).lenient()
+        }
+    }
+}
+
 impl<Tz: TimeZone> Advance<DateTime<Tz>> for unit::Month {
     fn next_from(self, mut dt: DateTime<Tz>) -> DateTime<Tz> {
         dt.date = dt.date.add_months(1);
@@ -420,9 +470,342 @@ impl<Tz: TimeZone> Advance<DateTime<Tz>> for unit::Nanosecond {
     }
 }
 
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+fn wall_nanos(date: &Date, time: &Time) -> i128 {
+    date.days_since_epoch() as i128 * NANOS_PER_DAY + time.total_nanos() as i128
+}
+
+pub(crate) fn with_local<Tz: TimeZone>(mut dt: DateTime<Tz>, (date, time): (Date, Time)) -> DateTime<Tz> {
+    if dt.timezone().is_fixed() {
+        dt.date = date;
+        dt.time = time;
+        dt
+    } else {
+        dt.timezone.resolve(date, time).lenient()
+    }
+}
+
+/// Moves the datetime to a rounded local date and time.
+///
+/// This is like [`with_local`] except an ambiguous result stays in the occurrence the
+/// datetime is already in. Otherwise rounding within the second occurrence of a
+/// repeated hour would move back to the first one, e.g. a ceiling before the input.
+pub(crate) fn round_local<Tz: TimeZone>(mut dt: DateTime<Tz>, (date, time): (Date, Time)) -> DateTime<Tz> {
+    if dt.timezone().is_fixed() {
+        return with_local(dt, (date, time));
+    }
+
+    let resolution = dt.timezone.clone().resolve(date, time);
+    if resolution.is_ambiguous() && resolution.later_offset() == &dt.offset {
+        dt.date = date;
+        dt.time = time;
+        dt
+    } else {
+        resolution.lenient()
+    }
+}
+
+/// The direction used when rounding to an arbitrary duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Rounding {
+    Floor,
+    Ceil,
+    Nearest,
+}
+
+/// Rounds the local date and time to a multiple of the given duration.
+///
+/// Durations that evenly divide a day are anchored at local midnight, otherwise
+/// they're anchored at the Unix epoch in local time.
+pub(crate) fn round_to_duration<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    duration: Duration,
+    rounding: Rounding,
+) -> Result<DateTime<Tz>, Error> {
+    let step = duration.as_nanos() as i128;
+    if step == 0 || step > NANOS_PER_DAY {
+        return Err(Error::OutOfRange);
+    }
+
+    let current = wall_nanos(&dt.date, &dt.time);
+    let anchor = if NANOS_PER_DAY % step == 0 {
+        dt.date.days_since_epoch() as i128 * NANOS_PER_DAY
+    } else {
+        0
+    };
+    let remainder = (current - anchor).rem_euclid(step);
+    let floor = current - remainder;
+    let rounded = match rounding {
+        Rounding::Floor => floor,
+        Rounding::Ceil if remainder == 0 => current,
+        Rounding::Ceil => floor + step,
+        // Ties round up
+        Rounding::Nearest if remainder * 2 >= step => floor + step,
+        Rounding::Nearest => floor,
+    };
+
+    let (days, nanos) = divmod!(rounded, NANOS_PER_DAY);
+    let date = Date::UNIX_EPOCH
+        .checked_add_days(days as i32)
+        .ok_or(Error::OutOfRange)?;
+    let (_, time) = Time::adjust_from_nanos(nanos as i64);
+    Ok(round_local(dt, (date, time)))
+}
+
+impl private::Boundary for unit::Year {
+    fn floor_local(date: Date, _: Time) -> (Date, Time) {
+        (
+            Date {
+                month: 1,
+                day: 1,
+                ..date
+            },
+            Time::MIDNIGHT,
+        )
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        (date.add_years(1), time)
+    }
+}
+
+impl private::Boundary for unit::Quarter {
+    fn floor_local(date: Date, _: Time) -> (Date, Time) {
+        (date.first_day_of_quarter(), Time::MIDNIGHT)
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        (date.add_months(3), time)
+    }
+}
+
+impl private::Boundary for unit::Month {
+    fn floor_local(date: Date, _: Time) -> (Date, Time) {
+        (Date { day: 1, ..date }, Time::MIDNIGHT)
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        (date.add_months(1), time)
+    }
+}
+
+impl private::Boundary for unit::Week {
+    fn floor_local(date: Date, _: Time) -> (Date, Time) {
+        (
+            date.add_days(-(date.weekday().days_from_monday() as i32)),
+            Time::MIDNIGHT,
+        )
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        (date.add_days(7), time)
+    }
+}
+
+impl private::Boundary for unit::Day {
+    fn floor_local(date: Date, _: Time) -> (Date, Time) {
+        (date, Time::MIDNIGHT)
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        (date.add_days(1), time)
+    }
+}
+
+impl private::Boundary for unit::Hour {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        (
+            date,
+            Time {
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+                ..time
+            },
+        )
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_secs(3600));
+        (date.add_days(days), time)
+    }
+}
+
+impl private::Boundary for unit::Minute {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        (
+            date,
+            Time {
+                second: 0,
+                nanosecond: 0,
+                ..time
+            },
+        )
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_secs(60));
+        (date.add_days(days), time)
+    }
+}
+
+impl private::Boundary for unit::Second {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        (date, Time { nanosecond: 0, ..time })
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_secs(1));
+        (date.add_days(days), time)
+    }
+}
+
+impl private::Boundary for unit::Millisecond {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        let nanosecond = time.nanosecond - time.nanosecond % 1_000_000;
+        (date, Time { nanosecond, ..time })
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_millis(1));
+        (date.add_days(days), time)
+    }
+}
+
+impl private::Boundary for unit::Microsecond {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        let nanosecond = time.nanosecond - time.nanosecond % 1_000;
+        (date, Time { nanosecond, ..time })
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_micros(1));
+        (date.add_days(days), time)
+    }
+}
+
+impl private::Boundary for unit::Nanosecond {
+    fn floor_local(date: Date, time: Time) -> (Date, Time) {
+        (date, time)
+    }
+
+    fn next_local(date: Date, time: Time) -> (Date, Time) {
+        let (days, time) = time.add_with_duration(Duration::from_nanos(1));
+        (date.add_days(days), time)
+    }
+}
+
+impl<Tz: TimeZone, U: private::Boundary + private::Sealed> Round<DateTime<Tz>> for U {
+    fn floor_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        let floor = U::floor_local(dt.date, dt.time);
+        round_local(dt, floor)
+    }
+
+    fn ceil_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        let (date, time) = U::floor_local(dt.date, dt.time);
+        if date == dt.date && time == dt.time {
+            dt
+        } else {
+            let next = U::next_local(date, time);
+            round_local(dt, next)
+        }
+    }
+
+    fn round_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        // The distance is measured in wall clock time so that e.g. 12:00 always
+        // rounds to the next day regardless of any offset changes in between.
+        let (date, time) = U::floor_local(dt.date, dt.time);
+        let next = U::next_local(date, time);
+        let current = wall_nanos(&dt.date, &dt.time);
+        if current - wall_nanos(&date, &time) >= wall_nanos(&next.0, &next.1) - current {
+            round_local(dt, next)
+        } else {
+            round_local(dt, (date, time))
+        }
+    }
+}
+
+/// Calls the given expression with the marker type of a [`unit::Unit`].
+macro_rules! with_marker {
+    ($unit:expr, |$marker:ident| $body:expr) => {
+        match $unit {
+            unit::Unit::Nanosecond => {
+                let $marker = unit::Nanosecond;
+                $body
+            }
+            unit::Unit::Microsecond => {
+                let $marker = unit::Microsecond;
+                $body
+            }
+            unit::Unit::Millisecond => {
+                let $marker = unit::Millisecond;
+                $body
+            }
+            unit::Unit::Second => {
+                let $marker = unit::Second;
+                $body
+            }
+            unit::Unit::Minute => {
+                let $marker = unit::Minute;
+                $body
+            }
+            unit::Unit::Hour => {
+                let $marker = unit::Hour;
+                $body
+            }
+            unit::Unit::Day => {
+                let $marker = unit::Day;
+                $body
+            }
+            unit::Unit::Week => {
+                let $marker = unit::Week;
+                $body
+            }
+            unit::Unit::Month => {
+                let $marker = unit::Month;
+                $body
+            }
+            unit::Unit::Quarter => {
+                let $marker = unit::Quarter;
+                $body
+            }
+            unit::Unit::Year => {
+                let $marker = unit::Year;
+                $body
+            }
+        }
+    };
+}
+
+impl<Tz: TimeZone> Advance<DateTime<Tz>> for unit::Unit {
+    fn next_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        with_marker!(self, |marker| marker.next_from(dt))
+    }
+
+    fn prev_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        with_marker!(self, |marker| marker.prev_from(dt))
+    }
+}
+
+impl<Tz: TimeZone> Round<DateTime<Tz>> for unit::Unit {
+    fn floor_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        with_marker!(self, |marker| marker.floor_from(dt))
+    }
+
+    fn ceil_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        with_marker!(self, |marker| marker.ceil_from(dt))
+    }
+
+    fn round_from(self, dt: DateTime<Tz>) -> DateTime<Tz> {
+        with_marker!(self, |marker| marker.round_from(dt))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{datetime, time, unit};
+    use super::*;
+    use crate::{datetime, time};
 
     #[test]
     fn test_advance_time() {
@@ -443,6 +826,8 @@ mod tests {
     fn test_advance_units() {
         let dt = datetime!(2022-02-08 03:00);
         assert_eq!(dt.next(unit::Year), datetime!(2023-02-08 03:00));
+        assert_eq!(dt.next(unit::Quarter), datetime!(2022-05-08 03:00));
+        assert_eq!(dt.prev(unit::Quarter), datetime!(2021-11-08 03:00));
         assert_eq!(dt.next(unit::Month), datetime!(2022-03-08 03:00));
         assert_eq!(dt.next(unit::Week), datetime!(2022-02-15 03:00));
         assert_eq!(dt.next(unit::Day), datetime!(2022-02-09 03:00));
@@ -450,4 +835,178 @@ mod tests {
         assert_eq!(dt.next(unit::Minute), datetime!(2022-02-08 03:01));
         assert_eq!(dt.next(unit::Second), datetime!(2022-02-08 03:00:01));
     }
+
+    #[test]
+    fn test_round_units() {
+        // February 9th 2022 was a Wednesday
+        let dt = datetime!(2022-02-09 15:31:45);
+        assert_eq!(dt.floor(unit::Year), datetime!(2022-01-01 00:00));
+        assert_eq!(dt.floor(unit::Quarter), datetime!(2022-01-01 00:00));
+        assert_eq!(dt.floor(unit::Month), datetime!(2022-02-01 00:00));
+        assert_eq!(dt.floor(unit::Week), datetime!(2022-02-07 00:00));
+        assert_eq!(dt.floor(unit::Day), datetime!(2022-02-09 00:00));
+        assert_eq!(dt.floor(unit::Hour), datetime!(2022-02-09 15:00));
+        assert_eq!(dt.floor(unit::Minute), datetime!(2022-02-09 15:31));
+        assert_eq!(dt.with_nanosecond(5).unwrap().floor(unit::Second), dt);
+
+        assert_eq!(dt.ceil(unit::Year), datetime!(2023-01-01 00:00));
+        assert_eq!(dt.ceil(unit::Quarter), datetime!(2022-04-01 00:00));
+        assert_eq!(dt.ceil(unit::Month), datetime!(2022-03-01 00:00));
+        assert_eq!(dt.ceil(unit::Week), datetime!(2022-02-14 00:00));
+        assert_eq!(dt.ceil(unit::Day), datetime!(2022-02-10 00:00));
+        assert_eq!(dt.ceil(unit::Hour), datetime!(2022-02-09 16:00));
+        assert_eq!(dt.ceil(unit::Minute), datetime!(2022-02-09 15:32));
+        assert_eq!(dt.ceil(unit::Second), dt);
+        assert_eq!(
+            dt.with_nanosecond(5).unwrap().ceil(unit::Second),
+            datetime!(2022-02-09 15:31:46)
+        );
+
+        assert_eq!(dt.round(unit::Year), datetime!(2022-01-01 00:00));
+        assert_eq!(dt.round(unit::Quarter), datetime!(2022-01-01 00:00));
+        assert_eq!(
+            datetime!(2022-02-16 00:00).round(unit::Quarter),
+            datetime!(2022-04-01 00:00)
+        );
+        assert_eq!(dt.round(unit::Month), datetime!(2022-02-01 00:00));
+        assert_eq!(dt.round(unit::Week), datetime!(2022-02-07 00:00));
+        assert_eq!(dt.round(unit::Day), datetime!(2022-02-10 00:00));
+        assert_eq!(dt.round(unit::Hour), datetime!(2022-02-09 16:00));
+        assert_eq!(dt.round(unit::Minute), datetime!(2022-02-09 15:32));
+        assert_eq!(dt.round(unit::Second), dt);
+
+        let dt = dt.with_nanosecond(123_456_789).unwrap();
+        assert_eq!(dt.floor(unit::Millisecond).nanosecond(), 123_000_000);
+        assert_eq!(dt.ceil(unit::Microsecond).nanosecond(), 123_457_000);
+        assert_eq!(dt.round(unit::Millisecond).nanosecond(), 123_000_000);
+        assert_eq!(dt.floor(unit::Nanosecond), dt);
+    }
+
+    #[test]
+    fn test_runtime_units() {
+        use unit::Unit;
+
+        let dt = datetime!(2022-02-09 15:31:45);
+        assert_eq!(dt.floor(Unit::Quarter), dt.floor(unit::Quarter));
+        assert_eq!(dt.ceil(Unit::Week), dt.ceil(unit::Week));
+        assert_eq!(dt.round(Unit::Hour), dt.round(unit::Hour));
+        assert_eq!(dt.next(Unit::Month), dt.next(unit::Month));
+        assert_eq!(dt.prev(Unit::Minute), dt.prev(unit::Minute));
+        assert!(Unit::Day > Unit::Hour);
+        assert!(Unit::Year > Unit::Quarter);
+        assert!(Unit::Nanosecond < Unit::Microsecond);
+    }
+
+    #[test]
+    fn test_round_midpoint() {
+        assert_eq!(
+            datetime!(2022-02-09 12:00).round(unit::Day),
+            datetime!(2022-02-10 00:00)
+        );
+        assert_eq!(
+            datetime!(2022-02-09 11:59:59).round(unit::Day),
+            datetime!(2022-02-09 00:00)
+        );
+        assert_eq!(
+            datetime!(2022-02-09 15:30).round(unit::Hour),
+            datetime!(2022-02-09 16:00)
+        );
+        assert_eq!(
+            datetime!(2022-02-09 15:29:59).round(unit::Hour),
+            datetime!(2022-02-09 15:00)
+        );
+        // February 2022 has 28 days so the midpoint is the 15th at midnight
+        assert_eq!(
+            datetime!(2022-02-15 00:00).round(unit::Month),
+            datetime!(2022-03-01 00:00)
+        );
+        assert_eq!(
+            datetime!(2022-02-14 23:59).round(unit::Month),
+            datetime!(2022-02-01 00:00)
+        );
+        // Thursday at noon is the midpoint of the week
+        assert_eq!(
+            datetime!(2022-02-10 12:00).round(unit::Week),
+            datetime!(2022-02-14 00:00)
+        );
+        assert_eq!(
+            datetime!(2022-02-10 11:59).round(unit::Week),
+            datetime!(2022-02-07 00:00)
+        );
+    }
+
+    #[test]
+    fn test_round_aligned() {
+        let dt = datetime!(2022-01-01 00:00 +09:00);
+        assert_eq!(dt.floor(unit::Year), dt);
+        assert_eq!(dt.ceil(unit::Year), dt);
+        assert_eq!(dt.round(unit::Year), dt);
+        assert_eq!(dt.ceil(unit::Day).offset(), dt.offset());
+        assert_eq!(datetime!(2021-12-31 23:59:59 +09:00).ceil(unit::Minute), dt);
+    }
+
+    #[test]
+    fn test_round_to_duration() {
+        let quarter = Duration::from_secs(15 * 60);
+        let dt = datetime!(2022-02-09 23:52:30);
+        assert_eq!(dt.round_to(quarter), Ok(datetime!(2022-02-10 00:00)));
+        assert_eq!(dt.ceil_to(quarter), Ok(datetime!(2022-02-10 00:00)));
+        assert_eq!(dt.floor_to(quarter), Ok(datetime!(2022-02-09 23:45)));
+        let before = datetime!(2022-02-09 23:52:29);
+        assert_eq!(before.round_to(quarter), Ok(datetime!(2022-02-09 23:45)));
+
+        let aligned = datetime!(2022-02-09 10:15);
+        assert_eq!(aligned.floor_to(quarter), Ok(aligned));
+        assert_eq!(aligned.ceil_to(quarter), Ok(aligned));
+        assert_eq!(aligned.round_to(quarter), Ok(aligned));
+
+        // The grid follows the local date rather than UTC
+        let dt = datetime!(2022-02-09 23:52:30 +05:45);
+        assert_eq!(dt.round_to(quarter), Ok(datetime!(2022-02-10 00:00 +05:45)));
+        let day = Duration::from_secs(86_400);
+        assert_eq!(dt.floor_to(day), Ok(datetime!(2022-02-09 00:00 +05:45)));
+        assert_eq!(dt.round_to(day), Ok(datetime!(2022-02-10 00:00 +05:45)));
+
+        let nanos = datetime!(2022-02-09 10:15).with_nanosecond(1_500).unwrap();
+        assert_eq!(nanos.floor_to(Duration::from_micros(1)).unwrap().nanosecond(), 1_000);
+        assert_eq!(nanos.round_to(Duration::from_micros(1)).unwrap().nanosecond(), 2_000);
+    }
+
+    #[test]
+    fn test_round_to_epoch_anchor() {
+        // 7 hours does not evenly divide a day so it's anchored at the epoch
+        let seven = Duration::from_secs(7 * 3600);
+        assert_eq!(
+            datetime!(1970-01-01 08:00).floor_to(seven),
+            Ok(datetime!(1970-01-01 07:00))
+        );
+        assert_eq!(
+            datetime!(1970-01-02 08:00).floor_to(seven),
+            Ok(datetime!(1970-01-02 04:00))
+        );
+        assert_eq!(
+            datetime!(1970-01-02 08:00).ceil_to(seven),
+            Ok(datetime!(1970-01-02 11:00))
+        );
+        assert_eq!(
+            datetime!(1969-12-31 20:00).floor_to(seven),
+            Ok(datetime!(1969-12-31 17:00))
+        );
+        assert_eq!(
+            datetime!(1969-12-31 20:31).round_to(seven),
+            Ok(datetime!(1970-01-01 00:00))
+        );
+    }
+
+    #[test]
+    fn test_round_to_invalid() {
+        let dt = datetime!(2022-02-09 10:15);
+        assert_eq!(dt.round_to(Duration::ZERO), Err(Error::OutOfRange));
+        assert_eq!(dt.floor_to(Duration::from_secs(86_401)), Err(Error::OutOfRange));
+        assert_eq!(dt.ceil_to(Duration::MAX), Err(Error::OutOfRange));
+        assert_eq!(
+            crate::DateTime::MAX.ceil_to(Duration::from_secs(60)),
+            Err(Error::OutOfRange)
+        );
+    }
 }
@@ -1,2 +1,22 @@
 #[cfg(feature = "system")]
 pub(crate) mod systemtime;
+
+#[cfg(feature = "std")]
+use crate::{DateTime, Utc};
+
+/// Returns the current date and time in UTC.
+///
+/// On `wasm32-unknown-unknown` with the `wasm-bindgen` feature this uses JavaScript's
+/// `Date.now()`, since [`std::time::SystemTime::now`] panics on that target.
+#[cfg(feature = "std")]
+pub(crate) fn utc_now() -> DateTime<Utc> {
+    #[cfg(all(feature = "wasm-bindgen", target_arch = "wasm32", target_os = "unknown"))]
+    {
+        crate::timestamp::Timestamp::from_milliseconds(js_sys::Date::now() as i64).to_utc()
+    }
+
+    #[cfg(not(all(feature = "wasm-bindgen", target_arch = "wasm32", target_os = "unknown")))]
+    {
+        std::time::SystemTime::now().into()
+    }
+}
@@ -0,0 +1,111 @@
+//! A process-wide cache of the local timezone information.
+//!
+//! The OS APIs only answer what the local timezone looks like at a single instant,
+//! so the cache stores windows of time during which the timezone information does
+//! not change. These windows are found by probing the OS APIs around the requested
+//! instant and narrowing down where the information changes.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    PoisonError, RwLock,
+};
+
+use super::SystemTime;
+use crate::Error;
+
+/// The number of seconds in an hour, which is the first probe distance.
+const HOUR: i64 = 3600;
+
+/// The largest probe distance. Transitions closer together than this could be missed.
+const WEEK: i64 = 7 * 86400;
+
+/// How far to probe in each direction before giving up on finding a transition.
+const HORIZON: i64 = 9 * WEEK;
+
+/// A half-open range of UNIX timestamps `[start, end)` sharing the same timezone information.
+struct Window {
+    start: i64,
+    end: i64,
+    tz: SystemTime,
+}
+
+static WINDOWS: RwLock<[Option<Window>; 4]> = RwLock::new([None, None, None, None]);
+static NEXT_SLOT: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the furthest timestamp from `ts` in the given direction that shares its
+/// timezone information, up to the horizon.
+fn find_boundary(ts: i64, direction: i64, tz: &SystemTime) -> i64 {
+    let same = |seconds: i64| match SystemTime::at(seconds) {
+        Ok(other) => other.offset() == tz.offset() && other.is_dst() == tz.is_dst(),
+        Err(_) => false,
+    };
+
+    // Probe with exponentially growing steps until the information changes
+    let mut step = HOUR;
+    let mut last_same = 0;
+    let mut first_different = None;
+    while last_same < HORIZON {
+        let distance = (last_same + step).min(HORIZON);
+        if same(ts + distance * direction) {
+            last_same = distance;
+            step = (step * 2).min(WEEK);
+        } else {
+            first_different = Some(distance);
+            break;
+        }
+    }
+
+    // Then narrow down the exact second that it changed
+    let mut hi = match first_different {
+        Some(hi) => hi,
+        None => return ts + last_same * direction,
+    };
+    let mut lo = last_same;
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if same(ts + mid * direction) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    ts + lo * direction
+}
+
+impl Window {
+    fn new(ts: i64) -> Result<Self, Error> {
+        let tz = SystemTime::at(ts)?;
+        let start = find_boundary(ts, -1, &tz);
+        let end = find_boundary(ts, 1, &tz) + 1;
+        Ok(Self { start, end, tz })
+    }
+
+    fn contains(&self, ts: i64) -> bool {
+        self.start <= ts && ts < self.end
+    }
+}
+
+/// Returns the local timezone information at the given UNIX timestamp in seconds.
+pub(crate) fn get(ts: i64) -> Result<SystemTime, Error> {
+    {
+        let windows = WINDOWS.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(window) = windows.iter().flatten().find(|w| w.contains(ts)) {
+            return Ok(window.tz.clone());
+        }
+    }
+
+    let window = Window::new(ts)?;
+    let tz = window.tz.clone();
+    let mut windows = WINDOWS.write().unwrap_or_else(PoisonError::into_inner);
+    let slot = NEXT_SLOT.fetch_add(1, Ordering::Relaxed) % windows.len();
+    windows[slot] = Some(window);
+    Ok(tz)
+}
+
+/// Clears the cache so the next query re-reads the timezone information from the OS.
+pub(crate) fn clear() {
+    let mut windows = WINDOWS.write().unwrap_or_else(PoisonError::into_inner);
+    for window in windows.iter_mut() {
+        *window = None;
+    }
+}
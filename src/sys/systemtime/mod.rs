@@ -1,9 +1,25 @@
 #[cfg_attr(target_family = "windows", path = "windows.rs")]
 #[cfg_attr(target_family = "unix", path = "unix.rs")]
+#[cfg_attr(
+    all(feature = "wasm-bindgen", target_arch = "wasm32", target_os = "unknown"),
+    path = "wasm.rs"
+)]
 mod imp;
 
-#[cfg(all(not(target_family = "windows"), not(target_family = "unix")))]
-compile_error!("The platform you're compiling for is unfortunately unsupported");
+#[cfg(feature = "std")]
+pub(crate) mod cache;
+
+#[cfg(not(any(
+    target_family = "windows",
+    target_family = "unix",
+    all(feature = "wasm-bindgen", target_arch = "wasm32", target_os = "unknown")
+)))]
+compile_error!(
+    "The platform you're compiling for is unfortunately unsupported. \
+     On wasm32-unknown-unknown, enable the `wasm-bindgen` feature."
+);
 
 pub(crate) use imp::get_system_time_components;
+#[cfg(feature = "std")]
+pub(crate) use imp::zone_id;
 pub(crate) use imp::SystemTime;
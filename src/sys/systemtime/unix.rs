@@ -9,6 +9,7 @@ use crate::{DateTime, Error, Utc, UtcOffset};
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub(crate) struct SystemTime {
     offset: UtcOffset,
+    is_dst: bool,
     #[cfg(feature = "alloc")]
     name: Option<String>,
 }
@@ -26,6 +27,13 @@ impl SystemTime {
         Self::new_from_time(ts)
     }
 
+    /// Returns the local timezone information at the given UNIX timestamp in seconds.
+    pub(crate) fn at(timestamp: i64) -> Result<Self, Error> {
+        // time_t could be 32-bits on some platforms
+        let timestamp = libc::time_t::try_from(timestamp).map_err(|_| Error::NoSystemTime)?;
+        Self::new_from_time(timestamp)
+    }
+
     fn new_from_time(timestamp: libc::time_t) -> Result<Self, Error> {
         let mut tm = MaybeUninit::uninit();
 
@@ -63,6 +71,8 @@ impl SystemTime {
         // a completely bogus value.
         let seconds = tm.tm_gmtoff as i32;
         let offset = UtcOffset::from_seconds_unchecked(seconds);
+        // A negative value means the information is not available
+        let is_dst = tm.tm_isdst > 0;
 
         #[cfg(feature = "alloc")]
         {
@@ -73,12 +83,12 @@ impl SystemTime {
             // the same aliasing and size
             let slice = unsafe { core::slice::from_raw_parts(tm.tm_zone as *const u8, length) };
             let name = core::str::from_utf8(slice).ok().map(String::from);
-            Ok(Self { offset, name })
+            Ok(Self { offset, is_dst, name })
         }
 
         #[cfg(not(feature = "alloc"))]
         {
-            Ok(Self { offset })
+            Ok(Self { offset, is_dst })
         }
     }
 
@@ -86,6 +96,11 @@ impl SystemTime {
         self.offset
     }
 
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
     #[cfg(feature = "alloc")]
     pub(crate) fn name(&self) -> Option<&str> {
         self.name.as_deref()
@@ -97,6 +112,42 @@ impl SystemTime {
     }
 }
 
+/// Returns the IANA identifier of the local timezone, e.g. `Europe/Berlin`.
+///
+/// This looks at the `TZ` environment variable first, then where `/etc/localtime`
+/// points to, and finally the contents of `/etc/timezone`.
+#[cfg(feature = "std")]
+pub(crate) fn zone_id() -> Option<String> {
+    fn from_path(path: &std::path::Path) -> Option<String> {
+        let path = path.to_str()?;
+        path.rfind("zoneinfo/").map(|idx| String::from(&path[idx + 9..]))
+    }
+
+    if let Some(tz) = std::env::var_os("TZ") {
+        let tz = tz.to_str()?;
+        let tz = tz.strip_prefix(':').unwrap_or(tz);
+        if tz.starts_with('/') {
+            return from_path(std::path::Path::new(tz));
+        } else if !tz.is_empty() {
+            return Some(String::from(tz));
+        }
+    }
+
+    if let Ok(path) = std::fs::read_link("/etc/localtime") {
+        if let Some(id) = from_path(&path) {
+            return Some(id);
+        }
+    }
+
+    let contents = std::fs::read_to_string("/etc/timezone").ok()?;
+    let id = contents.trim();
+    if id.is_empty() {
+        None
+    } else {
+        Some(String::from(id))
+    }
+}
+
 #[cfg(target_os = "macos")]
 fn get_current_duration_from_epoch() -> Result<(Duration, libc::time_t), Error> {
     let mut timeval = MaybeUninit::uninit();
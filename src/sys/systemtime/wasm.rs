@@ -0,0 +1,94 @@
+#[cfg(feature = "std")]
+use alloc::string::String;
+
+use crate::{timestamp::Timestamp, DateTime, Error, Utc, UtcOffset};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct SystemTime {
+    offset: UtcOffset,
+    is_dst: bool,
+}
+
+/// Returns the UTC offset in seconds of the browser's timezone at the given
+/// number of milliseconds since the UNIX epoch.
+fn offset_at(milliseconds: f64) -> Option<i32> {
+    let date = js_sys::Date::new_0();
+    if date.set_time(milliseconds).is_nan() {
+        return None;
+    }
+    // getTimezoneOffset returns the minutes to add to local time to get UTC,
+    // which is the negation of a UTC offset. Some engines return fractional
+    // minutes for historical local mean time.
+    let minutes = date.get_timezone_offset();
+    if minutes.is_nan() {
+        None
+    } else {
+        Some((-minutes * 60.0).round() as i32)
+    }
+}
+
+impl SystemTime {
+    pub(crate) fn new() -> Result<Self, Error> {
+        Self::from_milliseconds(js_sys::Date::now())
+    }
+
+    /// Returns the local timezone information at the given UNIX timestamp in seconds.
+    pub(crate) fn at(timestamp: i64) -> Result<Self, Error> {
+        Self::from_milliseconds(timestamp as f64 * 1000.0)
+    }
+
+    fn from_milliseconds(milliseconds: f64) -> Result<Self, Error> {
+        let seconds = offset_at(milliseconds).ok_or(Error::NoSystemTime)?;
+
+        // JavaScript has no notion of DST, so it's inferred by comparing against
+        // the offsets in the middle of winter and summer of the same year.
+        // Whichever of those is smaller is the standard offset, regardless of hemisphere.
+        let date = js_sys::Date::new_0();
+        date.set_time(milliseconds);
+        let year = date.get_utc_full_year() as f64;
+        let january = offset_at(js_sys::Date::utc(year, 0.0));
+        let july = offset_at(js_sys::Date::utc(year, 6.0));
+        let is_dst = match (january, july) {
+            (Some(january), Some(july)) => seconds > january.min(july),
+            _ => false,
+        };
+
+        Ok(Self {
+            offset: UtcOffset::from_seconds_unchecked(seconds),
+            is_dst,
+        })
+    }
+
+    pub(crate) fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// The browser does not expose timezone abbreviations in a portable way.
+    pub(crate) fn name(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Returns the IANA identifier of the browser's timezone, e.g. `Europe/Berlin`.
+///
+/// This comes from `Intl.DateTimeFormat().resolvedOptions().timeZone`.
+#[cfg(feature = "std")]
+pub(crate) fn zone_id() -> Option<String> {
+    let format = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new());
+    let options = format.resolved_options();
+    let key = js_sys::JsString::from("timeZone");
+    js_sys::Reflect::get(&options, &key).ok()?.as_string()
+}
+
+pub(crate) fn get_system_time_components() -> Result<(DateTime<Utc>, SystemTime), Error> {
+    let utc = Timestamp::from_milliseconds(js_sys::Date::now() as i64).to_utc();
+    let tz = SystemTime::at(utc.timestamp().as_seconds())?;
+    let mut local = utc;
+    local.shift(tz.offset());
+    Ok((local, tz))
+}
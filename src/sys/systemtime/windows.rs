@@ -3,7 +3,10 @@ use core::mem::MaybeUninit;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 
-use crate::{Date, DateTime, Time, Utc, UtcOffset};
+use crate::{
+    gregorian::{date_to_epoch_days, days_in_month, weekday_difference, weekday_from_days},
+    Date, DateTime, Time, Timestamp, Utc, UtcOffset,
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub(crate) struct SystemTime {
@@ -45,6 +48,21 @@ struct TIME_ZONE_INFORMATION {
     DaylightBias: i32,
 }
 
+#[repr(C)]
+#[allow(non_camel_case_types)]
+#[allow(non_snake_case)]
+struct DYNAMIC_TIME_ZONE_INFORMATION {
+    Bias: i32,
+    StandardName: [u16; 32],
+    StandardDate: SYSTEMTIME,
+    StandardBias: i32,
+    DaylightName: [u16; 32],
+    DaylightDate: SYSTEMTIME,
+    DaylightBias: i32,
+    TimeZoneKeyName: [u16; 128],
+    DynamicDaylightTimeDisabled: u8,
+}
+
 #[cfg(feature = "alloc")]
 fn windows_utf16_to_utf8(s: &[u16]) -> Option<String> {
     // Find the first "null terminator byte"
@@ -60,6 +78,37 @@ fn windows_utf16_to_utf8(s: &[u16]) -> Option<String> {
 extern "system" {
     fn GetTimeZoneInformation(lpTimeZoneInformation: *mut TIME_ZONE_INFORMATION) -> u32;
     fn GetSystemTime(lpSystemTime: *mut SYSTEMTIME);
+    #[cfg(feature = "std")]
+    fn GetDynamicTimeZoneInformation(pTimeZoneInformation: *mut DYNAMIC_TIME_ZONE_INFORMATION) -> u32;
+    fn GetTimeZoneInformationForYear(
+        wYear: u16,
+        pdtzi: *const DYNAMIC_TIME_ZONE_INFORMATION,
+        ptzi: *mut TIME_ZONE_INFORMATION,
+    ) -> i32;
+}
+
+/// Returns the local transition time of a `SYSTEMTIME` rule in the given year,
+/// in seconds since the UNIX epoch.
+///
+/// Rules with a zero year are in "day-in-month" format where `wDay` is the
+/// occurrence of `wDayOfWeek` within the month, with 5 meaning the last one.
+/// Rules with an absolute year only apply to that year.
+fn transition_in_year(rule: &SYSTEMTIME, year: i16) -> Option<i64> {
+    let month = rule.wMonth as u8;
+    let day = if rule.wYear == 0 {
+        let first_weekday = weekday_from_days(date_to_epoch_days(year, month, 1));
+        let mut day = weekday_difference(rule.wDayOfWeek as u8, first_weekday) + 1 + (rule.wDay as u8 - 1) * 7;
+        if day > days_in_month(year, month) {
+            day -= 7;
+        }
+        day
+    } else if rule.wYear as i16 == year {
+        rule.wDay as u8
+    } else {
+        return None;
+    };
+    let days = date_to_epoch_days(year, month, day) as i64;
+    Some(days * 86400 + rule.wHour as i64 * 3600 + rule.wMinute as i64 * 60 + rule.wSecond as i64)
 }
 
 impl SystemTime {
@@ -94,6 +143,51 @@ impl SystemTime {
         }
     }
 
+    /// Returns the local timezone information at the given UNIX timestamp in seconds.
+    ///
+    /// Windows only exposes the two yearly DST transitions for a given year. If the
+    /// rules for that year cannot be retrieved then the current rules are used.
+    pub(crate) fn at(timestamp: i64) -> Result<Self, crate::Error> {
+        let mut tz = Self::new()?;
+        let year = Timestamp::from_seconds(timestamp).to_utc().year();
+        // The SYSTEMTIME struct only supports years from 1601 onwards
+        if year >= 1601 {
+            let mut tzinfo = MaybeUninit::uninit();
+            // SAFETY: a NULL dynamic timezone means the current timezone is used,
+            // and this returns zero on failure
+            let code = unsafe { GetTimeZoneInformationForYear(year as u16, core::ptr::null(), tzinfo.as_mut_ptr()) };
+            if code != 0 {
+                // SAFETY: at this point, the WinAPI returned without errors
+                tz.info = unsafe { tzinfo.assume_init() };
+                #[cfg(feature = "alloc")]
+                {
+                    tz.std_name = windows_utf16_to_utf8(&tz.info.StandardName);
+                    tz.dst_name = windows_utf16_to_utf8(&tz.info.DaylightName);
+                }
+            }
+        }
+        tz.is_dst = tz.is_dst_at(timestamp);
+        Ok(tz)
+    }
+
+    fn is_dst_at(&self, timestamp: i64) -> bool {
+        // A zero month means that the timezone does not observe DST
+        if self.info.DaylightDate.wMonth == 0 || self.info.StandardDate.wMonth == 0 {
+            return false;
+        }
+        let std_offset = -(self.info.Bias + self.info.StandardBias) as i64 * 60;
+        let dst_offset = -(self.info.Bias + self.info.DaylightBias) as i64 * 60;
+        let year = crate::Timestamp::from_seconds(timestamp).to_utc().year();
+        // DST starts in local standard time and ends in local daylight time
+        let start = transition_in_year(&self.info.DaylightDate, year).map(|s| s - std_offset);
+        let end = transition_in_year(&self.info.StandardDate, year).map(|s| s - dst_offset);
+        match (start, end) {
+            (Some(start), Some(end)) if start < end => start <= timestamp && timestamp < end,
+            (Some(start), Some(end)) => !(end <= timestamp && timestamp < start),
+            _ => false,
+        }
+    }
+
     pub(crate) fn offset(&self) -> UtcOffset {
         let seconds = if self.is_dst {
             (self.info.Bias + self.info.DaylightBias) * 60
@@ -106,6 +200,11 @@ impl SystemTime {
         UtcOffset::from_seconds_unchecked(-seconds)
     }
 
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
     #[cfg(feature = "alloc")]
     pub(crate) fn name(&self) -> Option<&str> {
         if self.is_dst {
@@ -121,6 +220,21 @@ impl SystemTime {
     }
 }
 
+/// Returns the Windows identifier of the local timezone, e.g. `W. Europe Standard Time`.
+#[cfg(feature = "std")]
+pub(crate) fn zone_id() -> Option<String> {
+    let mut tzinfo = MaybeUninit::uninit();
+    // SAFETY: this returns TIME_ZONE_ID_INVALID (u32::MAX) on failure
+    let code = unsafe { GetDynamicTimeZoneInformation(tzinfo.as_mut_ptr()) };
+    if code == u32::MAX {
+        return None;
+    }
+
+    // SAFETY: at this point, the WinAPI returned without errors
+    let tzinfo = unsafe { tzinfo.assume_init() };
+    windows_utf16_to_utf8(&tzinfo.TimeZoneKeyName).or_else(|| windows_utf16_to_utf8(&tzinfo.StandardName))
+}
+
 pub(crate) fn get_system_time_components() -> Result<(DateTime<Utc>, SystemTime), crate::Error> {
     // SAFETY: this function does not fail according to the windows API docs
     // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlocaltime
@@ -143,5 +257,9 @@ pub(crate) fn get_system_time_components() -> Result<(DateTime<Utc>, SystemTime)
         second: dt.wSecond as u8,
         nanosecond: dt.wMilliseconds as u32 * 1_000_000,
     };
-    Ok((DateTime::__new_utc_unchecked_from_macro(date, time), SystemTime::new()?))
+    let utc = date.at(time);
+    let tz = SystemTime::at(utc.timestamp().as_seconds())?;
+    let mut local = utc;
+    local.shift(tz.offset());
+    Ok((local, tz))
 }
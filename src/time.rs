@@ -2,11 +2,11 @@ use crate::{
     interval::{MICROS_PER_HOUR, MICROS_PER_MIN, MICROS_PER_SEC, NANOS_PER_HOUR, NANOS_PER_MIN, NANOS_PER_SEC},
     step::Advance,
     utils::{divmod, ensure_in_range},
-    Date, DateTime, Interval, Utc,
+    Date, DateTime, Error, Interval, Utc,
 };
 
 use core::{
-    ops::{Add, Sub},
+    ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration,
 };
 
@@ -23,7 +23,37 @@ use crate::fmt::{FromIsoFormat, ParseError, Parser};
 ///
 /// This type is not aware of any particular calendar, date, or time zone. The maximum
 /// supported precision is nanoseconds. Comparisons assume they're on the same calendar date.
+///
+/// # Leap seconds
+///
+/// A leap second such as `23:59:60` is stored as the previous second with a nanosecond
+/// value past `999_999_999`, see [`Time::with_nanosecond`]. This keeps the second that
+/// was written, so leap seconds round trip through formatting. They are accepted without
+/// any extra options when parsing ISO-8601, RFC 3339, [`Profile`]s, and format specifiers.
+///
+/// A [`Timestamp`] keeps the leap second the same way so that it round trips, but
+/// arithmetic carries the leap second into the following minute since UNIX time has
+/// no leap seconds. [`Time::MAX`] is the largest time that isn't a leap second.
+///
+/// ```
+/// use eos::{ext::IntervalLiteral, DateTime};
+///
+/// let leap = DateTime::from_rfc3339("2016-12-31T23:59:60.5Z")?;
+/// assert_eq!(leap.to_rfc3339().to_string(), "2016-12-31 23:59:60.500000+00:00");
+/// assert_eq!(leap.timestamp().to_utc(), leap);
+/// assert_eq!((leap + 0.seconds()).to_rfc3339().to_string(), "2017-01-01 00:00:00.500000+00:00");
+/// # Ok::<_, eos::fmt::ParseError>(())
+/// ```
+///
+/// [`Timestamp`]: crate::Timestamp
+///
+/// [`Profile`]: crate::fmt::Profile
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug), bytecheck(verify))
+)]
 pub struct Time {
     pub(crate) hour: u8,
     pub(crate) minute: u8,
@@ -31,7 +61,36 @@ pub struct Time {
     pub(crate) nanosecond: u32,
 }
 
-const MAXIMUM_SECONDS_FROM_DURATION: u64 = i32::MAX as u64 * 24 * 60 * 60;
+/// The precision of the sub-second part of a [`Time`].
+///
+/// This is used by [`Time::truncate_subsec`] and [`Time::with_precision_rounded`] along with
+/// their [`DateTime`] counterparts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubsecondPrecision {
+    /// Whole seconds.
+    Second,
+    /// Milliseconds.
+    Millisecond,
+    /// Microseconds.
+    Microsecond,
+    /// Nanoseconds, which is the full precision of a [`Time`].
+    Nanosecond,
+}
+
+impl SubsecondPrecision {
+    /// Returns the number of nanoseconds in one step of this precision.
+    const fn step(self) -> u32 {
+        match self {
+            Self::Second => 1_000_000_000,
+            Self::Millisecond => 1_000_000,
+            Self::Microsecond => 1_000,
+            Self::Nanosecond => 1,
+        }
+    }
+}
+
+/// The number of nanoseconds in a day, as an `i128` since that's what durations are added in.
+const NANOS_PER_DAY: i128 = NANOS_PER_HOUR as i128 * 24;
 
 impl Time {
     /// The minimum valid time.
@@ -58,12 +117,12 @@ impl Time {
     #[doc(hidden)]
     #[cfg(feature = "macros")]
     #[inline]
-    pub const fn __new_unchecked_from_macro(hour: u8, minute: u8, second: u8) -> Self {
+    pub const fn __new_unchecked_from_macro(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Self {
         Self {
             hour,
             minute,
             second,
-            nanosecond: 0,
+            nanosecond,
         }
     }
 
@@ -92,9 +151,9 @@ impl Time {
     /// ```
     #[inline]
     pub const fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
-        ensure_in_range!(hour, 23);
-        ensure_in_range!(minute, 59);
-        ensure_in_range!(second, 59);
+        if !Self::is_valid_hms(hour, minute, second) {
+            return None;
+        }
         Some(Self {
             nanosecond: 0,
             hour,
@@ -103,6 +162,72 @@ impl Time {
         })
     }
 
+    /// Creates a new [`Time`] from the specified hour, minute, and second, returning which
+    /// field was invalid on failure.
+    ///
+    /// This is the same as [`Time::new`] except it returns [`Error::FieldOutOfRange`]
+    /// naming the hour, minute, or second if the time is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use eos::{time, Error, Time};
+    ///
+    /// assert_eq!(Time::try_new(23, 10, 0), Ok(time!(23:10)));
+    /// assert_eq!(
+    ///     Time::try_new(24, 0, 0),
+    ///     Err(Error::FieldOutOfRange { field: "hour", value: 24, min: 0, max: 23 })
+    /// );
+    /// ```
+    pub fn try_new(hour: u8, minute: u8, second: u8) -> Result<Self, Error> {
+        Error::check_field("hour", hour as i64, 0, 23)?;
+        Error::check_field("minute", minute as i64, 0, 59)?;
+        Error::check_field("second", second as i64, 0, 59)?;
+        Ok(Self {
+            nanosecond: 0,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Returns `true` if the given hour, minute, and second form a valid time.
+    ///
+    /// This is the exact check that [`Time::new`] performs, without creating the time.
+    /// Leap seconds are not accepted here, see [`Time::with_nanosecond`] for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use eos::Time;
+    /// assert!(Time::is_valid_hms(23, 59, 59));
+    /// assert!(!Time::is_valid_hms(24, 0, 0));
+    /// assert!(!Time::is_valid_hms(12, 60, 0));
+    /// assert!(!Time::is_valid_hms(12, 0, 60));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_valid_hms(hour: u8, minute: u8, second: u8) -> bool {
+        hour < 24 && minute < 60 && second < 60
+    }
+
+    /// Combines this [`Time`] with a [`Date`] to create a [`DateTime`] in [`Utc`].
+    ///
+    /// This is the mirror of [`Date::at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{date, datetime, time};
+    ///
+    /// assert_eq!(time!(09:30).on(date!(2021-03-17)), datetime!(2021-03-17 09:30));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn on(self, date: Date) -> DateTime<Utc> {
+        self.at(date)
+    }
+
     /// Combines this [`Time`] with a [`Date`] to create a [`DateTime`] in [`Utc`].
     #[inline]
     #[must_use]
@@ -191,17 +316,11 @@ impl Time {
     }
 
     /// Converts nanoseconds into a date representation and returns the left-over days.
+    ///
+    /// Every `i64` is accepted, which is around 106,000 days in either direction. Callers
+    /// with a wider range, such as anything involving a [`Duration`], have to go through
+    /// [`Self::split_days_from_nanos`] instead.
     pub(crate) fn adjust_from_nanos(nanos: i64) -> (i32, Self) {
-        // Arithmetic can be done entirely using nanoseconds
-        // Duration is comprised of a u64 seconds + u32 nanosecond.
-        // The u32 nanosecond doesn't go over NANOS_PER_SEC which means that
-        // Duration can only represent 2^64 seconds which is around 213.5 trillion
-        // days, a bit over the limit of an i32. There's a constant that allows us
-        // to set the maximum number of seconds we can represent in a duration.
-        //
-        // With that maximum in place, the highest a nanosecond value will be
-        // boils down to 3.6 trillion, which fits perfectly fine in a 64-bit number.
-        // When it goes through the reduction steps it'll cap at around 1 billion.
         let (hour, nanos) = divmod!(nanos, NANOS_PER_HOUR as i64);
         let (minute, nanos) = divmod!(nanos, NANOS_PER_MIN as i64);
         let (second, nanos) = divmod!(nanos, NANOS_PER_SEC as i64);
@@ -218,26 +337,127 @@ impl Time {
         )
     }
 
+    /// Splits nanoseconds into the number of whole days and the time within the final day.
+    ///
+    /// A [`Duration`] holds up to 2<sup>64</sup> seconds, or around 1.8 × 10<sup>28</sup>
+    /// nanoseconds, which overflows an `i64` but fits comfortably in an `i128`.
+    fn split_days_from_nanos(nanos: i128) -> (i128, Self) {
+        let (days, nanos) = divmod!(nanos, NANOS_PER_DAY);
+        let (_, time) = Self::adjust_from_nanos(nanos as i64);
+        (days, time)
+    }
+
     /// Adds the time with the given duration and returns the number of days that have passed.
+    ///
+    /// The time wraps around correctly for every duration, but the number of days
+    /// saturates if it doesn't fit in an `i32`.
     pub(crate) fn add_with_duration(self, duration: Duration) -> (i32, Self) {
-        if duration.as_secs() > MAXIMUM_SECONDS_FROM_DURATION {
-            (i32::MAX, self)
-        } else {
-            let diff = self.total_nanos() as i64 + duration.as_nanos() as i64;
-            Self::adjust_from_nanos(diff)
-        }
+        let (days, time) = Self::split_days_from_nanos(self.total_nanos() as i128 + duration.as_nanos() as i128);
+        (i32::try_from(days).unwrap_or(i32::MAX), time)
     }
 
     /// Subtracts the time with the given duration and returns the number of days that have passed.
+    ///
+    /// The time wraps around correctly for every duration, but the number of days
+    /// saturates if it doesn't fit in an `i32`.
     pub(crate) fn sub_with_duration(self, duration: Duration) -> (i32, Self) {
-        if duration.as_secs() > MAXIMUM_SECONDS_FROM_DURATION {
-            (i32::MIN + 1, self)
+        let (days, time) = Self::split_days_from_nanos(self.total_nanos() as i128 - duration.as_nanos() as i128);
+        (i32::try_from(days).unwrap_or(i32::MIN + 1), time)
+    }
+
+    pub(crate) fn checked_add_with_duration(self, duration: Duration) -> Option<(i32, Self)> {
+        Self::checked_adjust_from_nanos(self.total_nanos() as i128 + duration.as_nanos() as i128)
+    }
+
+    pub(crate) fn checked_sub_with_duration(self, duration: Duration) -> Option<(i32, Self)> {
+        Self::checked_adjust_from_nanos(self.total_nanos() as i128 - duration.as_nanos() as i128)
+    }
+
+    fn checked_adjust_from_nanos(nanos: i128) -> Option<(i32, Self)> {
+        let (days, time) = Self::split_days_from_nanos(nanos);
+        Some((i32::try_from(days).ok()?, time))
+    }
+
+    /// Adds the time components of an [`Interval`] to the time and returns the
+    /// number of days that have wrapped around alongside the new time.
+    ///
+    /// Similar to the `+` operator, the year, month, and day components of the
+    /// interval are ignored. Since a [`Time`] wraps around midnight this only
+    /// returns `None` if the number of days cannot fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{time, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(time!(23:00).checked_add(2.hours()), Some((1, time!(01:00))));
+    /// assert_eq!(time!(01:00).checked_add(30.minutes()), Some((0, time!(01:30))));
+    /// ```
+    pub fn checked_add(self, interval: Interval) -> Option<(i32, Self)> {
+        let (sub, duration) = interval.get_time_duration();
+        if sub {
+            self.checked_sub_with_duration(duration)
         } else {
-            let diff = self.total_nanos() as i64 - duration.as_nanos() as i64;
-            Self::adjust_from_nanos(diff)
+            self.checked_add_with_duration(duration)
         }
     }
 
+    /// Subtracts the time components of an [`Interval`] from the time and returns
+    /// the number of days that have wrapped around alongside the new time.
+    ///
+    /// See [`Self::checked_add`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{time, ext::IntervalLiteral};
+    ///
+    /// assert_eq!(time!(01:00).checked_sub(2.hours()), Some((-1, time!(23:00))));
+    /// ```
+    pub fn checked_sub(self, interval: Interval) -> Option<(i32, Self)> {
+        let (sub, duration) = interval.get_time_duration();
+        if sub {
+            self.checked_add_with_duration(duration)
+        } else {
+            self.checked_sub_with_duration(duration)
+        }
+    }
+
+    /// Adds a [`Duration`] to the time and returns the number of days that have
+    /// wrapped around alongside the new time.
+    ///
+    /// This only returns `None` if the number of days cannot fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::time;
+    /// use core::time::Duration;
+    ///
+    /// assert_eq!(time!(23:00).checked_add_duration(Duration::from_secs(7200)), Some((1, time!(01:00))));
+    /// assert_eq!(time!(00:00).checked_add_duration(Duration::MAX), None);
+    /// ```
+    pub fn checked_add_duration(self, duration: Duration) -> Option<(i32, Self)> {
+        self.checked_add_with_duration(duration)
+    }
+
+    /// Subtracts a [`Duration`] from the time and returns the number of days that
+    /// have wrapped around alongside the new time.
+    ///
+    /// This only returns `None` if the number of days cannot fit in an `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::time;
+    /// use core::time::Duration;
+    ///
+    /// assert_eq!(time!(01:00).checked_sub_duration(Duration::from_secs(7200)), Some((-1, time!(23:00))));
+    /// ```
+    pub fn checked_sub_duration(self, duration: Duration) -> Option<(i32, Self)> {
+        self.checked_sub_with_duration(duration)
+    }
+
     // The "common" functions begin here.
     // I want to "unroll" the trait and make them inherent methods since their discoverability
     // is better in the documentation, and the trait usability is mostly subpar.
@@ -297,6 +517,75 @@ impl Time {
         self.nanosecond
     }
 
+    /// Returns a new [`Time`] with everything below the given precision set to zero.
+    ///
+    /// This never rounds up, so the hour, minute, and second are left unchanged. A
+    /// leap second stays a leap second.
+    ///
+    /// ```rust
+    /// use eos::{time, SubsecondPrecision};
+    ///
+    /// let t = time!(12:30:59).with_nanosecond(123_456_789).unwrap();
+    /// assert_eq!(t.truncate_subsec(SubsecondPrecision::Millisecond).nanosecond(), 123_000_000);
+    /// assert_eq!(t.truncate_subsec(SubsecondPrecision::Microsecond).nanosecond(), 123_456_000);
+    /// assert_eq!(t.truncate_subsec(SubsecondPrecision::Second), time!(12:30:59));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn truncate_subsec(mut self, precision: SubsecondPrecision) -> Self {
+        self.nanosecond -= self.nanosecond % precision.step();
+        self
+    }
+
+    /// Returns a new [`Time`] rounded to the given precision, with ties rounding up.
+    ///
+    /// Rounding up can carry into the next second, and from there into the next minute,
+    /// hour, and day. Like [`Time::checked_add_duration`], the number of days carried over
+    /// is returned alongside the time.
+    ///
+    /// ```rust
+    /// use eos::{time, SubsecondPrecision};
+    ///
+    /// let t = time!(12:30:59).with_nanosecond(123_500_000).unwrap();
+    /// assert_eq!(t.with_precision_rounded(SubsecondPrecision::Millisecond).1.nanosecond(), 124_000_000);
+    ///
+    /// let t = time!(23:59:59).with_nanosecond(999_600_000).unwrap();
+    /// assert_eq!(t.with_precision_rounded(SubsecondPrecision::Millisecond), (1, time!(00:00)));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn with_precision_rounded(self, precision: SubsecondPrecision) -> (i32, Self) {
+        let step = precision.step();
+        let remainder = self.nanosecond % step;
+        let mut nanosecond = self.nanosecond - remainder;
+        if remainder >= step - remainder {
+            nanosecond += step;
+        }
+
+        // A leap second can hold up to two seconds worth of nanoseconds
+        let limit = if self.nanosecond >= 1_000_000_000 {
+            2_000_000_000
+        } else {
+            1_000_000_000
+        };
+        if nanosecond >= limit {
+            Self { nanosecond: 0, ..self }.add_with_duration(Duration::from_secs(1))
+        } else {
+            (0, Self { nanosecond, ..self })
+        }
+    }
+
+    /// Returns the `(second, nanosecond)` pair used when displaying this time.
+    ///
+    /// A leap second is stored as a nanosecond value past `999_999_999`, so it's
+    /// shown as the following second instead, e.g. `23:59:60`.
+    pub(crate) const fn display_second(&self) -> (u8, u32) {
+        if self.nanosecond >= 1_000_000_000 {
+            (self.second + 1, self.nanosecond - 1_000_000_000)
+        } else {
+            (self.second, self.nanosecond)
+        }
+    }
+
     /// Returns a new [`Time`] that points to the given hour.
     /// If the hour is out of bounds (`0..24`) then [`None`] is returned.
     #[inline]
@@ -386,6 +675,20 @@ impl Sub<Interval> for Time {
     }
 }
 
+// Like the binary operators these wrap around midnight.
+// The number of days that wrapped around can be retrieved with `Time::checked_add`.
+impl AddAssign<Interval> for Time {
+    fn add_assign(&mut self, rhs: Interval) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Interval> for Time {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
 impl Add<Duration> for Time {
     type Output = Self;
 
@@ -402,6 +705,18 @@ impl Sub<Duration> for Time {
     }
 }
 
+impl AddAssign<Duration> for Time {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
 impl Sub for Time {
     type Output = Interval;
 
@@ -415,34 +730,32 @@ pub(crate) fn fmt_iso_time<W>(f: &mut W, t: &Time, precision: IsoFormatPrecision
 where
     W: core::fmt::Write,
 {
+    let (second, nanosecond) = t.display_second();
     match precision {
         IsoFormatPrecision::Hour => write!(f, "{:02}:00", t.hour),
         IsoFormatPrecision::Minute => write!(f, "{:02}:{:02}", t.hour, t.minute),
-        IsoFormatPrecision::Second => write!(f, "{:02}:{:02}:{:02}", t.hour, t.minute, t.second),
+        IsoFormatPrecision::Second => write!(f, "{:02}:{:02}:{:02}", t.hour, t.minute, second),
         IsoFormatPrecision::Millisecond => {
-            let ms = t.millisecond();
-            write!(f, "{:02}:{:02}:{:02}.{:03}", t.hour, t.minute, t.second, ms)
+            let ms = nanosecond / 1_000_000;
+            write!(f, "{:02}:{:02}:{:02}.{:03}", t.hour, t.minute, second, ms)
         }
         IsoFormatPrecision::Microsecond => {
-            let ms = t.microsecond();
-            write!(f, "{:02}:{:02}:{:02}.{:06}", t.hour, t.minute, t.second, ms)
+            let us = nanosecond / 1_000;
+            write!(f, "{:02}:{:02}:{:02}.{:06}", t.hour, t.minute, second, us)
         }
         IsoFormatPrecision::Nanosecond => {
-            write!(f, "{:02}:{:02}:{:02}.{:07}", t.hour, t.minute, t.second, t.nanosecond)
+            write!(f, "{:02}:{:02}:{:02}.{:09}", t.hour, t.minute, second, nanosecond)
         }
     }
 }
 
 impl core::fmt::Display for Time {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.nanosecond != 0 {
-            write!(
-                f,
-                "{:02}:{:02}:{:02}.{:07}",
-                self.hour, self.minute, self.second, self.nanosecond
-            )
+        let (second, nanosecond) = self.display_second();
+        if nanosecond != 0 {
+            write!(f, "{:02}:{:02}:{:02}.{:09}", self.hour, self.minute, second, nanosecond)
         } else {
-            write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+            write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, second)
         }
     }
 }
@@ -1,3 +1,9 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+
 use crate::{
     date::Date,
     datetime::DateTime,
@@ -5,6 +11,7 @@ use crate::{
     time::Time,
     timezone::{Utc, UtcOffset},
     utils::{divmod, divrem},
+    Error, TimeZone,
 };
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
@@ -13,6 +20,15 @@ const NANOS_PER_MICRO: u32 = 1_000;
 const MILLIS_PER_SEC: u64 = 1_000;
 const MICROS_PER_SEC: u64 = 1_000_000;
 
+/// The number of seconds between January 1st 1601 and the Unix epoch.
+const FILETIME_EPOCH_OFFSET: i64 = 11_644_473_600;
+const FILETIME_TICKS_PER_SEC: u64 = 10_000_000;
+/// The number of seconds between January 1st 1900 and the Unix epoch.
+const NTP_EPOCH_OFFSET: i64 = 2_208_988_800;
+/// The number of seconds between January 6th 1980 and the Unix epoch.
+const GPS_EPOCH_OFFSET: i64 = 315_964_800;
+const SECONDS_PER_WEEK: u32 = 604_800;
+
 /// A UNIX timestamp.
 ///
 /// This is defined by the number of seconds since the Unix epoch,
@@ -87,13 +103,62 @@ impl Timestamp {
         self.seconds
     }
 
+    /// Returns the current time as a `Timestamp`.
+    #[inline]
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn now() -> Self {
+        DateTime::utc_now().timestamp()
+    }
+
+    /// Returns the fractional part of this timestamp in nanoseconds.
+    ///
+    /// This always counts forward from [`Timestamp::as_seconds`], even for timestamps
+    /// before the UNIX epoch. For example, half a second before the epoch is -1 seconds
+    /// and 500,000,000 nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Timestamp;
+    ///
+    /// let ts = Timestamp::from_milliseconds(-500);
+    /// assert_eq!(ts.as_seconds(), -1);
+    /// assert_eq!(ts.nanoseconds(), 500_000_000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
     /// Returns the total number of milliseconds in this timestamp.
+    ///
+    /// This is an `i128` so that it can't overflow for any timestamp.
     #[inline]
     #[must_use]
     pub const fn as_milliseconds(&self) -> i128 {
         self.seconds as i128 * MILLIS_PER_SEC as i128 + (self.nanoseconds / NANOS_PER_MILLI) as i128
     }
 
+    /// Returns the total number of microseconds in this timestamp.
+    ///
+    /// This is an `i128` so that it can't overflow for any timestamp.
+    #[inline]
+    #[must_use]
+    pub const fn as_microseconds(&self) -> i128 {
+        self.seconds as i128 * MICROS_PER_SEC as i128 + (self.nanoseconds / NANOS_PER_MICRO) as i128
+    }
+
+    /// Returns the total number of nanoseconds in this timestamp.
+    ///
+    /// This is an `i128` so that it can't overflow for any timestamp.
+    #[inline]
+    #[must_use]
+    pub const fn as_nanoseconds(&self) -> i128 {
+        self.seconds as i128 * NANOS_PER_SEC as i128 + self.nanoseconds as i128
+    }
+
     /// Returns the number of seconds as an `f64`.
     ///
     /// This contains the fractional seconds.
@@ -112,22 +177,204 @@ impl Timestamp {
         (self.seconds as f32) + (self.nanoseconds as f32) / (NANOS_PER_SEC as f32)
     }
 
+    /// Creates a new `Timestamp` from a Windows `FILETIME` value.
+    ///
+    /// A `FILETIME` is the number of 100 nanosecond intervals since January 1st 1601 UTC.
+    ///
+    /// Returns [`Error::OutOfRange`] if the timestamp is outside of the range
+    /// that a [`DateTime`] can represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::from_filetime(116_444_736_000_000_000), Ok(Timestamp::from_seconds(0)));
+    /// assert!(Timestamp::from_filetime(u64::MAX).is_err());
+    /// ```
+    pub fn from_filetime(ticks: u64) -> Result<Self, Error> {
+        let seconds = (ticks / FILETIME_TICKS_PER_SEC) as i64 - FILETIME_EPOCH_OFFSET;
+        let nanoseconds = (ticks % FILETIME_TICKS_PER_SEC) as u32 * 100;
+        Self::new(seconds, nanoseconds).checked_range()
+    }
+
+    /// Returns the Windows `FILETIME` value for this timestamp.
+    ///
+    /// A `FILETIME` is the number of 100 nanosecond intervals since January 1st 1601 UTC.
+    /// Any precision finer than 100 nanoseconds is truncated.
+    ///
+    /// Returns [`Error::OutOfRange`] if the timestamp is before January 1st 1601 or
+    /// too far in the future to fit.
+    pub fn to_filetime(&self) -> Result<u64, Error> {
+        let ticks = (self.seconds as i128 + FILETIME_EPOCH_OFFSET as i128) * FILETIME_TICKS_PER_SEC as i128
+            + (self.nanoseconds / 100) as i128;
+        u64::try_from(ticks).map_err(|_| Error::OutOfRange)
+    }
+
+    /// Creates a new `Timestamp` from an NTP timestamp.
+    ///
+    /// An NTP timestamp is the number of seconds since January 1st 1900 UTC along with
+    /// a fraction of a second in units of 2<sup>-32</sup> seconds. Only the first NTP era,
+    /// which ends in February 2036, is supported. The fraction is truncated to nanosecond
+    /// precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::Timestamp;
+    ///
+    /// assert_eq!(Timestamp::from_ntp(2_208_988_800, 0), Timestamp::from_seconds(0));
+    /// assert_eq!(Timestamp::from_ntp(2_208_988_800, 1 << 31), Timestamp::from_milliseconds(500));
+    /// ```
+    #[must_use]
+    pub const fn from_ntp(seconds: u32, fraction: u32) -> Self {
+        Self {
+            seconds: seconds as i64 - NTP_EPOCH_OFFSET,
+            nanoseconds: ((fraction as u64 * NANOS_PER_SEC as u64) >> 32) as u32,
+        }
+    }
+
+    /// Creates a new `Timestamp` from a GPS week number and the seconds into that week.
+    ///
+    /// GPS time counts from January 6th 1980 and, unlike UTC, does not have leap seconds.
+    /// Since this library ignores leap seconds, the result is on the GPS time scale and is
+    /// ahead of UTC by however many leap seconds have been inserted since 1980 (18 seconds
+    /// as of 2017). Converting to UTC requires subtracting that offset, which has to come
+    /// from an external source.
+    ///
+    /// The week number is the full week number rather than the 10-bit broadcast value
+    /// that rolls over every 1024 weeks.
+    ///
+    /// Returns [`Error::OutOfRange`] if the seconds are negative, not finite, or
+    /// not less than the number of seconds in a week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, Timestamp};
+    ///
+    /// let ts = Timestamp::from_gps_weeks_and_seconds(1024, 0.0)?;
+    /// assert_eq!(ts.to_utc(), datetime!(1999-08-22 00:00));
+    /// assert!(Timestamp::from_gps_weeks_and_seconds(0, 604_800.0).is_err());
+    /// # Ok::<_, eos::Error>(())
+    /// ```
+    pub fn from_gps_weeks_and_seconds(week: u16, seconds: f64) -> Result<Self, Error> {
+        // This also rejects NaN
+        if !(0.0..(SECONDS_PER_WEEK as f64)).contains(&seconds) {
+            return Err(Error::OutOfRange);
+        }
+        let nanos = (seconds * NANOS_PER_SEC as f64 + 0.5) as u64;
+        let (whole, nanos) = divrem!(nanos, NANOS_PER_SEC as u64);
+        Ok(Self {
+            seconds: GPS_EPOCH_OFFSET + week as i64 * SECONDS_PER_WEEK as i64 + whole as i64,
+            nanoseconds: nanos as u32,
+        })
+    }
+
+    /// Creates a `Timestamp` from a total number of nanoseconds, if it fits.
+    fn from_total_nanoseconds(nanoseconds: i128) -> Option<Self> {
+        let (seconds, nanoseconds) = divmod!(nanoseconds, NANOS_PER_SEC as i128);
+        match i64::try_from(seconds) {
+            Ok(seconds) => Some(Self {
+                seconds,
+                nanoseconds: nanoseconds as u32,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Adds a [`Duration`] to this timestamp.
+    ///
+    /// If the result overflows then [`None`] is returned.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        Self::from_total_nanoseconds(self.as_nanoseconds() + duration.as_nanos() as i128)
+    }
+
+    /// Subtracts a [`Duration`] from this timestamp.
+    ///
+    /// If the result overflows then [`None`] is returned.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        Self::from_total_nanoseconds(self.as_nanoseconds() - duration.as_nanos() as i128)
+    }
+
+    /// Returns the amount of time elapsed from an earlier timestamp to this one.
+    ///
+    /// # Errors
+    ///
+    /// If `earlier` is later than `self` then the amount of time between the two is
+    /// returned as the error instead. This mirrors [`std::time::SystemTime::duration_since`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use eos::Timestamp;
+    ///
+    /// let earlier = Timestamp::from_milliseconds(-500);
+    /// let later = Timestamp::from_seconds(2);
+    /// assert_eq!(later.duration_since(earlier), Ok(Duration::from_millis(2500)));
+    /// assert_eq!(earlier.duration_since(later), Err(Duration::from_millis(2500)));
+    /// ```
+    pub fn duration_since(&self, earlier: Self) -> Result<Duration, Duration> {
+        fn to_duration(nanos: u128) -> Duration {
+            let seconds = (nanos / NANOS_PER_SEC as u128) as u64;
+            Duration::new(seconds, (nanos % NANOS_PER_SEC as u128) as u32)
+        }
+
+        let nanos = self.as_nanoseconds() - earlier.as_nanoseconds();
+        if nanos >= 0 {
+            Ok(to_duration(nanos as u128))
+        } else {
+            Err(to_duration(nanos.unsigned_abs()))
+        }
+    }
+
+    /// Converts the `Timestamp` into a [`DateTime`] in the given timezone.
+    ///
+    /// This is equivalent to [`DateTime::from_timestamp`], so it saturates if the
+    /// timestamp is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use eos::{datetime, utc_offset, Timestamp};
+    ///
+    /// let ts = Timestamp::from_seconds(1641173925);
+    /// assert_eq!(ts.to_datetime(utc_offset!(-05:00)), datetime!(2022-01-02 20:38:45 -05:00));
+    /// assert_eq!(ts, datetime!(2022-01-02 20:38:45 -05:00));
+    /// ```
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn to_datetime<Tz: TimeZone>(self, timezone: Tz) -> DateTime<Tz> {
+        DateTime::from_timestamp(self, timezone)
+    }
+
+    pub(crate) fn checked_range(self) -> Result<Self, Error> {
+        if self.seconds < Self::MIN_VALID || self.seconds > Self::MAX_VALID {
+            Err(Error::OutOfRange)
+        } else {
+            Ok(self)
+        }
+    }
+
     /// Converts the `Timestamp` into a [`DateTime`] in UTC.
     ///
     /// If the timestamp is out of range whether in the negative or positive
-    /// direction then it saturates towards the overflowing side.
+    /// direction then it saturates towards the overflowing side. Every second from
+    /// [`DateTime::MIN`] to [`DateTime::MAX`] is in range, including their nanoseconds.
     #[must_use = "this returns the result of the operation, without modifying the original"]
     pub const fn to_utc(self) -> DateTime<Utc> {
         // This is a manual implementation due to the `const fn` requirement.
 
-        if self.seconds >= Self::MAX_VALID {
+        if self.seconds > Self::MAX_VALID {
             return DateTime {
                 date: Date::MAX,
                 time: Time::MAX,
                 offset: UtcOffset::UTC,
                 timezone: Utc,
             };
-        } else if self.seconds <= Self::MIN_VALID {
+        } else if self.seconds < Self::MIN_VALID {
             return DateTime {
                 date: Date::MIN,
                 time: Time::MIN,
@@ -156,6 +403,84 @@ impl Timestamp {
     }
 }
 
+impl Add<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the timestamp overflows.
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the timestamp overflows.
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// The signed difference between two timestamps.
+///
+/// This is the same as [`Timestamp::duration_since`], where an `Err` means that
+/// the right hand side is later than the left hand side.
+impl Sub for Timestamp {
+    type Output = Result<Duration, Duration>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.duration_since(rhs)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+    fn from(dt: DateTime<Tz>) -> Self {
+        dt.timestamp()
+    }
+}
+
+impl<Tz: TimeZone> PartialEq<DateTime<Tz>> for Timestamp {
+    fn eq(&self, other: &DateTime<Tz>) -> bool {
+        *self == other.timestamp()
+    }
+}
+
+impl<Tz: TimeZone> PartialEq<Timestamp> for DateTime<Tz> {
+    fn eq(&self, other: &Timestamp) -> bool {
+        self.timestamp() == *other
+    }
+}
+
+impl<Tz: TimeZone> PartialOrd<DateTime<Tz>> for Timestamp {
+    fn partial_cmp(&self, other: &DateTime<Tz>) -> Option<Ordering> {
+        Some(self.cmp(&other.timestamp()))
+    }
+}
+
+impl<Tz: TimeZone> PartialOrd<Timestamp> for DateTime<Tz> {
+    fn partial_cmp(&self, other: &Timestamp) -> Option<Ordering> {
+        Some(self.timestamp().cmp(other))
+    }
+}
+
 impl core::fmt::Debug for Timestamp {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.nanoseconds == 0 {
@@ -184,4 +509,132 @@ mod tests {
             datetime!(2022-01-02 20:38:45 -5:00)
         );
     }
+
+    #[test]
+    fn test_arithmetic() {
+        let ts = Timestamp::from_milliseconds(-1500);
+        assert_eq!((ts.as_seconds(), ts.nanoseconds()), (-2, 500_000_000));
+        assert_eq!(ts.as_milliseconds(), -1500);
+        assert_eq!(ts.as_microseconds(), -1_500_000);
+        assert_eq!(ts.as_nanoseconds(), -1_500_000_000);
+
+        assert_eq!(ts + Duration::from_millis(1500), Timestamp::from_seconds(0));
+        assert_eq!(ts + Duration::from_millis(1), Timestamp::new(-2, 501_000_000));
+        assert_eq!(ts - Duration::from_millis(600), Timestamp::new(-3, 900_000_000));
+        let mut copy = ts;
+        copy += Duration::from_secs(2);
+        assert_eq!(copy, Timestamp::from_milliseconds(500));
+        copy -= Duration::from_secs(2);
+        assert_eq!(copy, ts);
+
+        assert_eq!(Timestamp::from_seconds(1) - ts, Ok(Duration::from_millis(2500)));
+        assert_eq!(ts - Timestamp::from_seconds(1), Err(Duration::from_millis(2500)));
+        assert_eq!(ts - ts, Ok(Duration::ZERO));
+
+        let max = Timestamp::new(i64::MAX, 999_999_999);
+        assert_eq!(max.checked_add(Duration::from_nanos(1)), None);
+        assert_eq!(
+            Timestamp::from_seconds(i64::MIN).checked_sub(Duration::from_nanos(1)),
+            None
+        );
+        assert_eq!(Timestamp::from_seconds(i64::MIN).checked_add(Duration::MAX), Some(max));
+        let span = max - Timestamp::from_seconds(i64::MIN);
+        assert_eq!(span, Ok(Duration::new(u64::MAX, 999_999_999)));
+
+        // Leap second nanoseconds are normalised when doing arithmetic
+        let leap = Timestamp::new(0, 1_500_000_000);
+        assert_eq!(leap + Duration::ZERO, Timestamp::new(1, 500_000_000));
+    }
+
+    #[test]
+    fn test_datetime_interop() {
+        let dt = datetime!(2022-01-02 20:38:45 -5:00).with_nanosecond(1).unwrap();
+        let ts = Timestamp::new(1641173925, 1);
+        assert_eq!(ts, dt);
+        assert_eq!(dt, ts);
+        assert_eq!(Timestamp::from(dt), ts);
+        assert_eq!(ts.to_datetime(UtcOffset::from_hms(-5, 0, 0).unwrap()), dt);
+        assert!(ts < dt.with_nanosecond(2).unwrap());
+        assert!(dt.with_nanosecond(2).unwrap() > ts);
+        assert!(dt.with_nanosecond(0).unwrap() < ts);
+    }
+
+    #[test]
+    fn test_filetime() {
+        let epoch = 116_444_736_000_000_000;
+        assert_eq!(Timestamp::from_filetime(epoch), Ok(Timestamp::from_seconds(0)));
+        assert_eq!(Timestamp::from_seconds(0).to_filetime(), Ok(epoch));
+        assert_eq!(
+            Timestamp::from_filetime(0).map(Timestamp::to_utc),
+            Ok(datetime!(1601-01-01 00:00))
+        );
+        assert_eq!(
+            Timestamp::from_filetime(1).unwrap(),
+            Timestamp::new(-11_644_473_600, 100)
+        );
+
+        let ts = datetime!(2022-01-02 20:38:45).timestamp();
+        assert_eq!(ts.to_filetime(), Ok(132_856_295_250_000_000));
+        assert_eq!(Timestamp::from_filetime(132_856_295_250_000_000), Ok(ts));
+
+        // Sub-tick precision is truncated
+        let ts = Timestamp::new(0, 199);
+        assert_eq!(ts.to_filetime(), Ok(epoch + 1));
+
+        assert_eq!(
+            Timestamp::from_seconds(-11_644_473_601).to_filetime(),
+            Err(Error::OutOfRange)
+        );
+        assert_eq!(Timestamp::from_seconds(i64::MAX).to_filetime(), Err(Error::OutOfRange));
+        assert_eq!(Timestamp::from_filetime(u64::MAX), Err(Error::OutOfRange));
+        let max = DateTime::MAX.timestamp();
+        assert_eq!(
+            max.to_filetime().and_then(Timestamp::from_filetime),
+            Ok(Timestamp::new(max.as_seconds(), 999_999_900))
+        );
+        assert!(Timestamp::from_filetime(max.to_filetime().unwrap() + 1).is_err());
+    }
+
+    #[test]
+    fn test_ntp() {
+        assert_eq!(Timestamp::from_ntp(0, 0).to_utc(), datetime!(1900-01-01 00:00));
+        assert_eq!(Timestamp::from_ntp(2_208_988_800, 0), Timestamp::from_seconds(0));
+        assert_eq!(
+            Timestamp::from_ntp(2_208_988_800, 1 << 31),
+            Timestamp::new(0, 500_000_000)
+        );
+        assert_eq!(
+            Timestamp::from_ntp(2_208_988_800, 1 << 30),
+            Timestamp::new(0, 250_000_000)
+        );
+        assert_eq!(
+            Timestamp::from_ntp(2_208_988_800, u32::MAX),
+            Timestamp::new(0, 999_999_999)
+        );
+        assert_eq!(
+            Timestamp::from_ntp(u32::MAX, 0).to_utc(),
+            datetime!(2036-02-07 06:28:15)
+        );
+    }
+
+    #[test]
+    fn test_gps() {
+        let gps = |week, seconds| Timestamp::from_gps_weeks_and_seconds(week, seconds).map(Timestamp::to_utc);
+        assert_eq!(gps(0, 0.0), Ok(datetime!(1980-01-06 00:00)));
+        assert_eq!(gps(1024, 0.0), Ok(datetime!(1999-08-22 00:00)));
+        assert_eq!(gps(2000, 0.0), Ok(datetime!(2018-05-06 00:00)));
+        assert_eq!(gps(2000, 86_400.0 + 3_723.0), Ok(datetime!(2018-05-07 01:02:03)));
+        assert_eq!(
+            Timestamp::from_gps_weeks_and_seconds(0, 0.1),
+            Ok(Timestamp::new(315_964_800, 100_000_000))
+        );
+        assert_eq!(
+            Timestamp::from_gps_weeks_and_seconds(0, 604_799.5),
+            Ok(Timestamp::new(315_964_800 + 604_799, 500_000_000))
+        );
+        assert_eq!(gps(0, -0.5), Err(Error::OutOfRange));
+        assert_eq!(gps(0, 604_800.0), Err(Error::OutOfRange));
+        assert_eq!(gps(0, f64::NAN), Err(Error::OutOfRange));
+        assert_eq!(gps(0, f64::INFINITY), Err(Error::OutOfRange));
+    }
 }
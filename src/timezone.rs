@@ -3,10 +3,23 @@ use crate::sys::systemtime;
 
 use crate::{utils::ensure_in_range, Date, DateTime, Error, Time, Timestamp};
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, borrow::ToOwned};
+
 /// An offset from UTC.
 ///
 /// This struct can only store values up to ±24:00:00.
+///
+/// The hours, minutes, and seconds components always share the same sign, so an offset
+/// of -00:30:15 has `0` hours, `-30` minutes, and `-15` seconds. Offsets are created from
+/// a total number of seconds with [`UtcOffset::from_seconds`] or from their components
+/// with [`UtcOffset::from_hms`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug), bytecheck(verify))
+)]
 pub struct UtcOffset {
     pub(crate) hours: i8,
     pub(crate) minutes: i8,
@@ -65,6 +78,7 @@ impl UtcOffset {
     /// assert_eq!(UtcOffset::from_hms(23, 56, 59)?.into_hms(), (23, 56, 59));
     /// assert_eq!(UtcOffset::from_hms(0, 30, 0)?.into_hms(), (0, 30, 0));
     /// assert_eq!(UtcOffset::from_hms(0, -30, 30)?.into_hms(), (0, -30, -30));
+    /// assert_eq!(UtcOffset::from_hms(0, 0, 30)?.into_hms(), (0, 0, 30));
     /// # Some(())
     /// # }
     /// # test();
@@ -93,7 +107,8 @@ impl UtcOffset {
             // Special case for 0 hours, it takes the sign of minutes
             // -30:30 => -30:-30
             // 30:-30 => 30:30
-            if seconds.is_positive() != minutes.is_positive() {
+            // If the minutes are also 0 then the seconds keep their own sign
+            if minutes != 0 && seconds.is_positive() != minutes.is_positive() {
                 seconds = -seconds;
             }
         }
@@ -191,7 +206,61 @@ impl UtcOffset {
     #[inline]
     #[must_use]
     pub const fn is_negative(&self) -> bool {
-        self.hours < 0 && self.minutes < 0 && self.seconds < 0
+        self.total_seconds() < 0
+    }
+
+    /// Returns `true` if this offset has no seconds component.
+    ///
+    /// Some formats, such as RFC 3339, can only represent offsets in whole minutes.
+    ///
+    /// ```rust
+    /// # use eos::utc_offset;
+    /// assert!(utc_offset!(-05:30).is_whole_minutes());
+    /// assert!(!utc_offset!(-00:16:08).is_whole_minutes());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_whole_minutes(&self) -> bool {
+        self.seconds == 0
+    }
+
+    /// Returns this offset with the seconds component removed.
+    ///
+    /// ```rust
+    /// # use eos::utc_offset;
+    /// assert_eq!(utc_offset!(-00:16:08).truncate_to_minutes(), utc_offset!(-00:16));
+    /// assert_eq!(utc_offset!(+05:30:59).truncate_to_minutes(), utc_offset!(+05:30));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn truncate_to_minutes(self) -> Self {
+        Self { seconds: 0, ..self }
+    }
+
+    /// Returns this offset rounded to the nearest minute.
+    ///
+    /// Offsets exactly halfway between two minutes are rounded away from zero.
+    ///
+    /// ```rust
+    /// # use eos::utc_offset;
+    /// assert_eq!(utc_offset!(-00:16:08).round_to_minutes(), utc_offset!(-00:16));
+    /// assert_eq!(utc_offset!(+00:19:32).round_to_minutes(), utc_offset!(+00:20));
+    /// assert_eq!(utc_offset!(-00:00:30).round_to_minutes(), utc_offset!(-00:01));
+    /// assert_eq!(utc_offset!(+23:59:59).round_to_minutes(), utc_offset!(+24:00));
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn round_to_minutes(self) -> Self {
+        let minutes = self.hours as i32 * 60 + self.minutes as i32;
+        let minutes = if self.seconds >= 30 {
+            minutes + 1
+        } else if self.seconds <= -30 {
+            minutes - 1
+        } else {
+            minutes
+        };
+        // This is at most ±24 hours so it's always in bounds
+        Self::from_seconds_unchecked(minutes * 60)
     }
 
     /// Subtracts two offsets, returning [`None`] if the result would be out of bounds.
@@ -287,11 +356,17 @@ impl UtcOffset {
 
 impl core::fmt::Display for UtcOffset {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (m, s) = (self.minutes.abs(), self.seconds.abs());
+        // The sign has to be written separately since the hours could be zero, e.g. -00:30
+        let sign = if self.is_negative() { '-' } else { '+' };
+        let (h, m, s) = (
+            self.hours.unsigned_abs(),
+            self.minutes.unsigned_abs(),
+            self.seconds.unsigned_abs(),
+        );
         if s > 0 {
-            write!(f, "{:+03}:{:02}:{:02}", self.hours, m, s)
+            write!(f, "{}{:02}:{:02}:{:02}", sign, h, m, s)
         } else {
-            write!(f, "{:+03}:{:02}", self.hours, m)
+            write!(f, "{}{:02}:{:02}", sign, h, m)
         }
     }
 }
@@ -603,6 +678,88 @@ impl<Tz: TimeZone> DateTimeResolution<Tz> {
         }
     }
 
+    /// Returns the date time if it was resolved unambiguously.
+    ///
+    /// This is similar to [`DateTimeResolution::exact`] except the reason
+    /// for the failure is discarded.
+    ///
+    /// ```
+    /// use eos::{date, time, utc_offset, TimeZone};
+    ///
+    /// let resolution = utc_offset!(-05:00).resolve(date!(2022-01-01), time!(12:00));
+    /// assert_eq!(resolution.single().map(|dt| dt.hour()), Some(12));
+    /// ```
+    #[must_use]
+    pub fn single(self) -> Option<DateTime<Tz>> {
+        self.exact().ok()
+    }
+
+    /// Returns the date time if it was resolved unambiguously, otherwise returns an
+    /// [`AmbiguityError`] holding both candidate date times.
+    ///
+    /// This is useful for strict applications that want to reject ambiguous or missing
+    /// times while still being able to report the possible interpretations.
+    ///
+    /// ```
+    /// use eos::{date, time, utc_offset, DateTimeResolution, DateTimeResolutionKind};
+    ///
+    /// let (date, time) = (date!(2021-11-07), time!(01:30));
+    /// let resolution = DateTimeResolution::ambiguous(date, time, utc_offset!(-04:00), utc_offset!(-05:00), eos::Utc);
+    /// let error = resolution.into_result().unwrap_err();
+    /// assert_eq!(error.kind(), DateTimeResolutionKind::Ambiguous);
+    /// assert_eq!(error.earlier().offset(), &utc_offset!(-04:00));
+    /// assert_eq!(error.later().offset(), &utc_offset!(-05:00));
+    /// ```
+    pub fn into_result(self) -> Result<DateTime<Tz>, AmbiguityError<Tz>> {
+        match self.kind {
+            DateTimeResolutionKind::Unambiguous => Ok(DateTime {
+                date: self.date,
+                time: self.time,
+                offset: self.earlier,
+                timezone: self.timezone,
+            }),
+            kind => {
+                let (earlier, later) = self.into_pair();
+                Err(AmbiguityError { kind, earlier, later })
+            }
+        }
+    }
+
+    /// Maps both candidate date times of this resolution while preserving its kind.
+    ///
+    /// The candidates are the local date time paired with the earlier and later
+    /// offsets respectively, similar to [`DateTimeResolution::earlier`] and
+    /// [`DateTimeResolution::later`] except that missing times are mapped as well.
+    ///
+    /// The function should change the local date and time of both candidates in the
+    /// same way. The new local date and time are taken from the earlier candidate.
+    ///
+    /// ```
+    /// use eos::{date, time, utc_offset, TimeZone};
+    ///
+    /// let resolution = utc_offset!(-05:00).resolve(date!(2022-01-01), time!(12:30:45));
+    /// let truncated = resolution.map(|dt| dt.with_second(0).unwrap());
+    /// assert!(truncated.is_unambiguous());
+    /// assert_eq!(truncated.time(), &time!(12:30));
+    /// ```
+    pub fn map<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(DateTime<Tz>) -> DateTime<Tz>,
+    {
+        let kind = self.kind;
+        let (earlier, later) = self.into_pair();
+        let earlier = f(earlier);
+        let later = f(later);
+        Self {
+            date: earlier.date,
+            time: earlier.time,
+            timezone: later.timezone,
+            earlier: earlier.offset,
+            later: later.offset,
+            kind,
+        }
+    }
+
     pub(crate) fn backwards(self) -> DateTime<Tz> {
         match self.kind {
             DateTimeResolutionKind::Missing => {
@@ -628,6 +785,106 @@ impl<Tz: TimeZone> DateTimeResolution<Tz> {
     }
 }
 
+/// The error returned by [`DateTimeResolution::into_result`] when a local date time
+/// is either missing or ambiguous.
+///
+/// Unlike [`Error`], this holds onto both candidate date times so they can be
+/// inspected or presented to the user. It can be converted into an [`Error`]
+/// using the [`From`] trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguityError<Tz: TimeZone> {
+    kind: DateTimeResolutionKind,
+    earlier: DateTime<Tz>,
+    later: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> AmbiguityError<Tz> {
+    /// Returns the kind of resolution that failed.
+    ///
+    /// This is either [`DateTimeResolutionKind::Missing`] or [`DateTimeResolutionKind::Ambiguous`].
+    #[must_use]
+    pub fn kind(&self) -> DateTimeResolutionKind {
+        self.kind
+    }
+
+    /// Returns the local date time using the offset before the transition.
+    #[must_use]
+    pub fn earlier(&self) -> &DateTime<Tz> {
+        &self.earlier
+    }
+
+    /// Returns the local date time using the offset after the transition.
+    #[must_use]
+    pub fn later(&self) -> &DateTime<Tz> {
+        &self.later
+    }
+
+    /// Converts into a pair of the earlier and later date times.
+    pub fn into_pair(self) -> (DateTime<Tz>, DateTime<Tz>) {
+        (self.earlier, self.later)
+    }
+}
+
+impl<Tz: TimeZone> AmbiguityError<Tz> {
+    fn to_error(&self) -> Error {
+        match self.kind {
+            DateTimeResolutionKind::Ambiguous => Error::AmbiguousDateTime(self.earlier.date, self.earlier.time),
+            _ => Error::SkippedDateTime(self.earlier.date, self.earlier.time),
+        }
+    }
+}
+
+impl<Tz: TimeZone> From<AmbiguityError<Tz>> for Error {
+    fn from(error: AmbiguityError<Tz>) -> Self {
+        error.to_error()
+    }
+}
+
+impl<Tz: TimeZone> core::fmt::Display for AmbiguityError<Tz> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_error().fmt(f)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "nightly"))]
+impl<Tz: TimeZone + core::fmt::Debug> crate::utils::ErrorTrait for AmbiguityError<Tz> {}
+
+/// A snapshot of the state of a [`TimeZone`] at a given point in time.
+///
+/// This bundles the UTC offset, the abbreviated name, and whether daylight saving
+/// time is in effect so that they can be retrieved together and remain consistent
+/// with one another. See [`TimeZone::info_at`] and [`DateTime::zone_info`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneInfo {
+    offset: UtcOffset,
+    name: Option<Cow<'static, str>>,
+    is_dst: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl ZoneInfo {
+    /// Creates a new [`ZoneInfo`] from the given offset, name, and DST status.
+    pub fn new(offset: UtcOffset, name: Option<Cow<'static, str>>, is_dst: bool) -> Self {
+        Self { offset, name, is_dst }
+    }
+
+    /// Returns the UTC offset, including any DST adjustment.
+    pub fn offset(&self) -> &UtcOffset {
+        &self.offset
+    }
+
+    /// Returns the abbreviated name of the timezone, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns `true` if daylight saving time is in effect.
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+}
+
 /// A trait that defines timezone behaviour.
 pub trait TimeZone: Clone {
     /// Returns the name of the timezone at a given UNIX timestamp.
@@ -698,13 +955,107 @@ pub trait TimeZone: Clone {
     fn is_fixed(&self) -> bool {
         false
     }
+
+    /// Returns the next transition strictly after the given instant, if known.
+    ///
+    /// A transition is returned as the instant it happens along with the UTC offsets
+    /// before and after it. Transitions that only change the name or DST status of the
+    /// timezone without changing its UTC offset are skipped.
+    ///
+    /// The default implementation returns `None`, which is correct for fixed offset
+    /// timezones. Timezones backed by transition data, such as a tz database, should
+    /// override this. See also [`DateTime::next_dst_transition`] and [`crate::iter::transitions`].
+    fn next_transition(&self, _after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)> {
+        None
+    }
+
+    /// Returns the UTC offset, name, and DST status of the timezone at a given UNIX timestamp.
+    ///
+    /// The default implementation calls [`TimeZone::offset`] and [`TimeZone::name`] and
+    /// assumes that DST is not in effect. Timezones that observe DST, or that could change
+    /// state in between those calls, should override this to compute everything at once.
+    #[cfg(feature = "alloc")]
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        ZoneInfo::new(
+            self.offset(ts),
+            self.name(ts).map(|name| Cow::Owned(name.to_owned())),
+            false,
+        )
+    }
+}
+
+// Forwards the implementation through a pointer so the timezone is shared rather than
+// cloned into every datetime. Resolving a local time still has to clone the inner
+// timezone since `TimeZone::resolve` takes it by value.
+macro_rules! forward_timezone_impl {
+    ($(#[$attr:meta])* impl<$($lt:lifetime,)? $t:ident> for $ty:ty) => {
+        $(#[$attr])*
+        impl<$($lt,)? $t: TimeZone> TimeZone for $ty {
+            fn name(&self, ts: Timestamp) -> Option<&str> {
+                T::name(self, ts)
+            }
+
+            fn offset(&self, ts: Timestamp) -> UtcOffset {
+                T::offset(self, ts)
+            }
+
+            fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+            where
+                Self: Sized,
+            {
+                T::clone(&self).resolve(date, time).with_timezone(self)
+            }
+
+            fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+            where
+                Self: Sized,
+            {
+                let offset = T::offset(&self, utc.timestamp());
+                utc.shift(offset);
+                DateTime {
+                    date: utc.date,
+                    time: utc.time,
+                    offset,
+                    timezone: self,
+                }
+            }
+
+            fn is_fixed(&self) -> bool {
+                T::is_fixed(self)
+            }
+
+            fn next_transition(&self, after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)> {
+                T::next_transition(self, after)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+                T::info_at(self, ts)
+            }
+        }
+    };
 }
 
+forward_timezone_impl!(impl<'a, T> for &'a T);
+forward_timezone_impl!(
+    #[cfg(feature = "alloc")]
+    impl<T> for alloc::sync::Arc<T>
+);
+forward_timezone_impl!(
+    #[cfg(feature = "alloc")]
+    impl<T> for alloc::rc::Rc<T>
+);
+
 impl TimeZone for UtcOffset {
     fn offset(&self, _ts: Timestamp) -> UtcOffset {
         *self
     }
 
+    #[cfg(feature = "alloc")]
+    fn info_at(&self, _ts: Timestamp) -> ZoneInfo {
+        ZoneInfo::new(*self, None, false)
+    }
+
     fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
@@ -726,8 +1077,93 @@ impl TimeZone for UtcOffset {
     }
 }
 
+/// A fixed [`UtcOffset`] paired with a static abbreviation such as `"CET"` or `"PST"`.
+///
+/// This behaves exactly like the inner offset except that it reports its name,
+/// so the `%Z` formatting directive prints the abbreviation instead of the offset.
+///
+/// ```rust
+/// use eos::{datetime, utc_offset, NamedOffset};
+///
+/// const CET: NamedOffset = NamedOffset::new(utc_offset!(+01:00), "CET");
+///
+/// let dt = datetime!(2022-01-23 18:20:30).in_timezone(CET);
+/// assert_eq!(eos::format_dt!("%H:%M %Z", dt).to_string(), "19:20 CET");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NamedOffset {
+    offset: UtcOffset,
+    name: &'static str,
+}
+
+impl NamedOffset {
+    /// Creates a new [`NamedOffset`] from an offset and its abbreviation.
+    #[inline]
+    pub const fn new(offset: UtcOffset, name: &'static str) -> Self {
+        Self { offset, name }
+    }
+
+    /// Returns the underlying [`UtcOffset`].
+    #[inline]
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Returns the abbreviation of this offset.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl From<NamedOffset> for UtcOffset {
+    fn from(named: NamedOffset) -> Self {
+        named.offset
+    }
+}
+
+impl TimeZone for NamedOffset {
+    fn name(&self, _ts: Timestamp) -> Option<&str> {
+        Some(self.name)
+    }
+
+    fn offset(&self, _ts: Timestamp) -> UtcOffset {
+        self.offset
+    }
+
+    #[cfg(feature = "alloc")]
+    fn info_at(&self, _ts: Timestamp) -> ZoneInfo {
+        ZoneInfo::new(self.offset, Some(Cow::Borrowed(self.name)), false)
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        // This is always unambiguous
+        DateTimeResolution::unambiguous(date, time, self.offset, self)
+    }
+
+    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        utc.shift(self.offset);
+        utc.with_timezone(self)
+    }
+
+    fn is_fixed(&self) -> bool {
+        true
+    }
+}
+
 /// The UTC timezone.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
 pub struct Utc;
 
 impl TimeZone for Utc {
@@ -740,6 +1176,11 @@ impl TimeZone for Utc {
         UtcOffset::UTC
     }
 
+    #[cfg(feature = "alloc")]
+    fn info_at(&self, _ts: Timestamp) -> ZoneInfo {
+        ZoneInfo::new(UtcOffset::UTC, Some(Cow::Borrowed("UTC")), false)
+    }
+
     fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
@@ -780,16 +1221,27 @@ impl Utc {
 ///
 /// Currently, the following OS APIs are being used to get the local timezone:
 ///
-/// | Platform |                  Function Call                  |
-/// |----------|-------------------------------------------------|
-/// | POSIX    | [`localtime_r`]                                 |
-/// | Windows  | [`GetTimeZoneInformation`] and [`GetLocalTime`] |
+/// | Platform |                                      Function Call                                       |
+/// |----------|------------------------------------------------------------------------------------------|
+/// | POSIX    | [`localtime_r`]                                                                          |
+/// | Windows  | [`GetTimeZoneInformation`], [`GetTimeZoneInformationForYear`], and [`GetSystemTime`]     |
 ///
 /// **Disclaimer**: These OS APIs might change over time.
 ///
+/// The offset is queried for every instant, so conversions for dates other than today
+/// take DST into account. Times that fall in a gap or a fold are detected by checking
+/// the offsets on either side of the local time.
+///
+/// On Windows, the operating system only exposes the two yearly DST transition dates
+/// for a given year. These are honoured for every year the system has rules for, but
+/// historical rule changes that Windows does not record are not.
+///
 /// [`localtime_r`]: https://linux.die.net/man/3/localtime_r
-/// [`GetTimeZoneInformation`]: https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-gettimezoneinformation
-/// [`GetLocalTime`]: https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlocaltime
+/// [`GetTimeZoneInformation`]:
+///     https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-gettimezoneinformation
+/// [`GetTimeZoneInformationForYear`]:
+///     https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-gettimezoneinformationforyear
+/// [`GetSystemTime`]: https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemtime
 ///
 /// ## Why `localtime_r`?
 ///
@@ -838,38 +1290,465 @@ impl System {
     pub fn now() -> Result<DateTime<Self>, Error> {
         DateTime::now()
     }
+
+    /// Returns the current UTC offset of the system's local timezone.
+    pub fn current_offset() -> Result<UtcOffset, Error> {
+        systemtime::SystemTime::new().map(|tz| tz.offset())
+    }
+
+    /// Returns the UTC offset the system's local timezone has at the given instant.
+    ///
+    /// Unlike [`System::current_offset`] this allows querying offsets in the past or
+    /// the future, which takes DST into account. See the [`System`] documentation for
+    /// the limitations on Windows.
+    ///
+    /// If the operating system cannot provide the offset then UTC is returned.
+    pub fn offset_at(dt: &DateTime<Utc>) -> UtcOffset {
+        systemtime::SystemTime::at(dt.timestamp().as_seconds())
+            .map(|tz| tz.offset())
+            .unwrap_or(UtcOffset::UTC)
+    }
+
+    /// Returns the identifier of the system's local timezone.
+    ///
+    /// On POSIX systems this is the IANA identifier, such as `Europe/Berlin`, when it can
+    /// be found through the `TZ` environment variable, `/etc/localtime`, or `/etc/timezone`.
+    /// On Windows this is the Windows timezone identifier, such as `W. Europe Standard Time`.
+    ///
+    /// Note that this is different from the abbreviation returned by [`TimeZone::name`].
+    ///
+    /// This requires the `std` feature to be enabled.
+    #[cfg(feature = "std")]
+    pub fn name() -> Option<alloc::string::String> {
+        systemtime::zone_id()
+    }
+}
+
+/// Resolves a local date and time for timezones that can only map UTC to local time.
+///
+/// This checks which of the offsets in effect a day before and a day after the local
+/// time map back to it. The `info_at` function returns the timezone information at
+/// the given UNIX timestamp in seconds.
+#[cfg(feature = "system")]
+fn resolve_system<Tz: TimeZone>(
+    tz: Tz,
+    date: Date,
+    time: Time,
+    info_at: impl Fn(i64) -> systemtime::SystemTime,
+) -> DateTimeResolution<Tz> {
+    let offset_at = |seconds: i64| info_at(seconds).offset();
+    let local = date.days_since_epoch() as i64 * 86400 + time.total_seconds() as i64;
+    let before = offset_at(local - 86400);
+    let after = offset_at(local + 86400);
+    let is_valid = |offset: UtcOffset| offset_at(local - offset.total_seconds() as i64) == offset;
+    match (is_valid(before), is_valid(after)) {
+        (true, true) if before != after => {
+            // The larger offset maps to the earlier instant
+            let (earlier, later) = if before > after {
+                (before, after)
+            } else {
+                (after, before)
+            };
+            DateTimeResolution::ambiguous(date, time, earlier, later, tz)
+        }
+        (true, _) => DateTimeResolution::unambiguous(date, time, before, tz),
+        (false, true) => DateTimeResolution::unambiguous(date, time, after, tz),
+        (false, false) if before != after => DateTimeResolution::missing(date, time, before, after, tz),
+        (false, false) => {
+            // More than one transition happened within two days, there isn't much that can be done here
+            let offset = offset_at(local - before.total_seconds() as i64);
+            DateTimeResolution::unambiguous(date, time, offset, tz)
+        }
+    }
+}
+
+/// Converts a UTC datetime for timezones that can only map UTC to local time.
+#[cfg(feature = "system")]
+fn convert_utc_system<Tz: TimeZone>(tz: Tz, mut utc: DateTime<Utc>, offset: UtcOffset) -> DateTime<Tz> {
+    utc.shift(offset);
+    // Resolving the local time again could pick the wrong side of a fold
+    DateTimeResolution::unambiguous(*utc.date(), *utc.time(), offset, tz).lenient()
+}
+
+#[cfg(feature = "system")]
+impl System {
+    /// Returns the local timezone information at the given UNIX timestamp in seconds,
+    /// falling back to the information this was created with.
+    fn info_at_seconds(&self, seconds: i64) -> systemtime::SystemTime {
+        systemtime::SystemTime::at(seconds).unwrap_or_else(|_| self.0.clone())
+    }
 }
 
 #[cfg(feature = "system")]
 impl TimeZone for System {
+    // This is the name at the time of creation since the return value has to be borrowed.
+    // `info_at` returns the name at the given time instead.
     fn name(&self, _ts: Timestamp) -> Option<&str> {
         self.0.name()
     }
 
-    fn offset(&self, _ts: Timestamp) -> UtcOffset {
-        self.0.offset()
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.info_at_seconds(ts.as_seconds()).offset()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        let info = self.info_at_seconds(ts.as_seconds());
+        let name = info.name().map(|name| Cow::Owned(name.to_owned()));
+        ZoneInfo::new(info.offset(), name, info.is_dst())
     }
 
     fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
     {
-        // This is, unfortunately, always unambiguous
-        // This could probably be improved if the APIs allow for it
-        DateTimeResolution::unambiguous(date, time, self.0.offset(), self)
+        let this = self.clone();
+        resolve_system(self, date, time, |seconds| this.info_at_seconds(seconds))
     }
 
-    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
     where
         Self: Sized,
     {
-        // TODO: proper algorithm
-        utc.shift(self.0.offset());
-        utc.with_timezone(self)
+        let offset = self.offset(utc.timestamp());
+        convert_utc_system(self, utc, offset)
     }
 
     fn is_fixed(&self) -> bool {
-        true // temporary?
+        false
+    }
+}
+
+/// The system's local timezone backed by a process-wide cache.
+///
+/// Querying the operating system for the local timezone, as [`System`] does, can be
+/// expensive when done frequently such as when timestamping log lines. This type
+/// instead caches the periods of time during which the local offset stays the same,
+/// so most queries do not call into the operating system at all. [`System::now`] uses
+/// the same cache.
+///
+/// Every [`CachedSystem`] shares the same cache. If the local timezone changes while the
+/// process is running, e.g. because the `TZ` environment variable or `/etc/localtime`
+/// changed, the cached information is stale until [`CachedSystem::refresh`] is called.
+///
+/// This is created with [`System::cached`] and requires the `system` and `std` features
+/// to be enabled.
+#[cfg(all(feature = "system", feature = "std"))]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CachedSystem(System);
+
+#[cfg(all(feature = "system", feature = "std"))]
+impl core::fmt::Debug for CachedSystem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CachedSystem").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(feature = "system", feature = "std"))]
+impl System {
+    /// Returns a [`CachedSystem`] that shares a process-wide cache of the local timezone.
+    pub fn cached() -> Result<CachedSystem, Error> {
+        let now = DateTime::utc_now().timestamp().as_seconds();
+        Ok(CachedSystem(Self(systemtime::cache::get(now)?)))
+    }
+}
+
+#[cfg(all(feature = "system", feature = "std"))]
+impl CachedSystem {
+    /// Clears the process-wide cache so the local timezone is read from the operating system again.
+    ///
+    /// This affects every [`CachedSystem`] along with [`System::now`].
+    pub fn refresh(&self) -> Result<(), Error> {
+        systemtime::cache::clear();
+        let now = DateTime::utc_now().timestamp().as_seconds();
+        systemtime::cache::get(now).map(|_| ())
+    }
+
+    /// Returns the current [`DateTime`] in the cached local time.
+    pub fn now(&self) -> DateTime<Self> {
+        DateTime::utc_now().in_timezone(self.clone())
+    }
+
+    fn info_at_seconds(&self, seconds: i64) -> systemtime::SystemTime {
+        systemtime::cache::get(seconds).unwrap_or_else(|_| (self.0).0.clone())
+    }
+}
+
+#[cfg(all(feature = "system", feature = "std"))]
+impl TimeZone for CachedSystem {
+    // See the implementation for System
+    fn name(&self, ts: Timestamp) -> Option<&str> {
+        self.0.name(ts)
+    }
+
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.info_at_seconds(ts.as_seconds()).offset()
+    }
+
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        let info = self.info_at_seconds(ts.as_seconds());
+        let name = info.name().map(|name| Cow::Owned(name.to_owned()));
+        ZoneInfo::new(info.offset(), name, info.is_dst())
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        let this = self.clone();
+        resolve_system(self, date, time, |seconds| this.info_at_seconds(seconds))
+    }
+
+    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        let offset = self.offset(utc.timestamp());
+        convert_utc_system(self, utc, offset)
+    }
+
+    fn is_fixed(&self) -> bool {
+        false
+    }
+}
+
+/// An object safe version of [`TimeZone`] used to erase custom timezones.
+#[cfg(feature = "alloc")]
+trait ErasedTimeZone: core::fmt::Debug + Send + Sync {
+    fn erased_name(&self, ts: Timestamp) -> Option<&str>;
+    fn erased_offset(&self, ts: Timestamp) -> UtcOffset;
+    fn erased_info_at(&self, ts: Timestamp) -> ZoneInfo;
+    fn resolve_offsets(&self, date: Date, time: Time) -> (DateTimeResolutionKind, UtcOffset, UtcOffset);
+    fn convert_offset(&self, utc: &DateTime<Utc>) -> UtcOffset;
+    fn erased_is_fixed(&self) -> bool;
+    fn erased_next_transition(&self, after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)>;
+}
+
+#[cfg(feature = "alloc")]
+impl<Tz> ErasedTimeZone for Tz
+where
+    Tz: TimeZone + core::fmt::Debug + Send + Sync,
+{
+    fn erased_name(&self, ts: Timestamp) -> Option<&str> {
+        TimeZone::name(self, ts)
+    }
+
+    fn erased_offset(&self, ts: Timestamp) -> UtcOffset {
+        TimeZone::offset(self, ts)
+    }
+
+    fn erased_info_at(&self, ts: Timestamp) -> ZoneInfo {
+        TimeZone::info_at(self, ts)
+    }
+
+    fn resolve_offsets(&self, date: Date, time: Time) -> (DateTimeResolutionKind, UtcOffset, UtcOffset) {
+        let resolution = self.clone().resolve(date, time);
+        (resolution.kind, resolution.earlier, resolution.later)
+    }
+
+    fn convert_offset(&self, utc: &DateTime<Utc>) -> UtcOffset {
+        self.clone().convert_utc(*utc).offset
+    }
+
+    fn erased_is_fixed(&self) -> bool {
+        TimeZone::is_fixed(self)
+    }
+
+    fn erased_next_transition(&self, after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)> {
+        TimeZone::next_transition(self, after)
+    }
+}
+
+/// A user provided [`TimeZone`] with its type erased.
+///
+/// This is created with [`AnyTimeZone::custom`] and is cheap to clone since the
+/// timezone is reference counted.
+///
+/// This requires the `alloc` feature to be enabled.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CustomTimeZone(alloc::sync::Arc<dyn ErasedTimeZone>);
+
+/// A timezone that is chosen at runtime.
+///
+/// Since [`DateTime`] is generic over its timezone, datetimes in different kinds of
+/// timezones cannot be stored together or picked from configuration. This enum
+/// allows that by covering the timezones provided by this library along with any
+/// other [`TimeZone`] implementation through [`AnyTimeZone::custom`].
+///
+/// Conversion, comparison, and formatting all work the same as with the wrapped
+/// timezone.
+///
+/// This requires the `alloc` feature to be enabled.
+///
+/// ```
+/// use eos::{datetime, utc_offset, AnyTimeZone, NamedOffset, Utc};
+///
+/// let zones = [
+///     AnyTimeZone::from(Utc),
+///     AnyTimeZone::from(utc_offset!(+09:00)),
+///     AnyTimeZone::custom(NamedOffset::new(utc_offset!(-05:00), "EST")),
+/// ];
+/// let now = datetime!(2022-01-01 12:00);
+/// let times: Vec<_> = zones.iter().map(|tz| now.in_timezone(tz.clone())).collect();
+/// assert!(times.iter().all(|dt| dt == &now));
+/// assert_eq!(times[1].hour(), 21);
+/// assert_eq!(times[2].tzname(), Some("EST"));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub enum AnyTimeZone {
+    /// The [`Utc`] timezone.
+    #[default]
+    Utc,
+    /// A fixed [`UtcOffset`].
+    Fixed(UtcOffset),
+    /// The [`System`] timezone.
+    #[cfg(feature = "system")]
+    System(System),
+    /// Any other timezone, see [`AnyTimeZone::custom`].
+    Custom(CustomTimeZone),
+}
+
+#[cfg(feature = "alloc")]
+impl AnyTimeZone {
+    /// Erases the type of the given timezone.
+    ///
+    /// This is mainly for timezones that do not have their own variant, such as
+    /// the ones provided by other crates.
+    pub fn custom<Tz>(timezone: Tz) -> Self
+    where
+        Tz: TimeZone + core::fmt::Debug + Send + Sync + 'static,
+    {
+        Self::Custom(CustomTimeZone(alloc::sync::Arc::new(timezone)))
+    }
+
+    /// Swaps the timezone of a datetime without changing its offset.
+    fn wrap<Tz: TimeZone>(self, dt: DateTime<Tz>) -> DateTime<Self> {
+        DateTime {
+            date: dt.date,
+            time: dt.time,
+            offset: dt.offset,
+            timezone: self,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Utc> for AnyTimeZone {
+    fn from(_: Utc) -> Self {
+        Self::Utc
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<UtcOffset> for AnyTimeZone {
+    fn from(offset: UtcOffset) -> Self {
+        Self::Fixed(offset)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "system"))]
+impl From<System> for AnyTimeZone {
+    fn from(system: System) -> Self {
+        Self::System(system)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TimeZone for AnyTimeZone {
+    fn name(&self, ts: Timestamp) -> Option<&str> {
+        match self {
+            Self::Utc => Utc.name(ts),
+            Self::Fixed(offset) => offset.name(ts),
+            #[cfg(feature = "system")]
+            Self::System(system) => system.name(ts),
+            Self::Custom(custom) => custom.0.erased_name(ts),
+        }
+    }
+
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        match self {
+            Self::Utc => UtcOffset::UTC,
+            Self::Fixed(offset) => *offset,
+            #[cfg(feature = "system")]
+            Self::System(system) => system.offset(ts),
+            Self::Custom(custom) => custom.0.erased_offset(ts),
+        }
+    }
+
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        match self {
+            Self::Utc => Utc.info_at(ts),
+            Self::Fixed(offset) => offset.info_at(ts),
+            #[cfg(feature = "system")]
+            Self::System(system) => system.info_at(ts),
+            Self::Custom(custom) => custom.0.erased_info_at(ts),
+        }
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        match &self {
+            Self::Utc => DateTimeResolution::unambiguous(date, time, UtcOffset::UTC, self),
+            Self::Fixed(offset) => DateTimeResolution::unambiguous(date, time, *offset, self),
+            #[cfg(feature = "system")]
+            Self::System(system) => system.clone().resolve(date, time).with_timezone(self),
+            Self::Custom(custom) => {
+                let (kind, earlier, later) = custom.0.resolve_offsets(date, time);
+                match kind {
+                    DateTimeResolutionKind::Missing => DateTimeResolution::missing(date, time, earlier, later, self),
+                    DateTimeResolutionKind::Unambiguous => DateTimeResolution::unambiguous(date, time, earlier, self),
+                    DateTimeResolutionKind::Ambiguous => {
+                        DateTimeResolution::ambiguous(date, time, earlier, later, self)
+                    }
+                }
+            }
+        }
+    }
+
+    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        match &self {
+            Self::Utc => self.wrap(utc),
+            Self::Fixed(offset) => {
+                let dt = (*offset).convert_utc(utc);
+                self.wrap(dt)
+            }
+            #[cfg(feature = "system")]
+            Self::System(system) => {
+                let dt = system.clone().convert_utc(utc);
+                self.wrap(dt)
+            }
+            Self::Custom(custom) => {
+                let offset = custom.0.convert_offset(&utc);
+                let dt = offset.convert_utc(utc);
+                self.wrap(dt)
+            }
+        }
+    }
+
+    fn is_fixed(&self) -> bool {
+        match self {
+            Self::Utc | Self::Fixed(_) => true,
+            #[cfg(feature = "system")]
+            Self::System(system) => system.is_fixed(),
+            Self::Custom(custom) => custom.0.erased_is_fixed(),
+        }
+    }
+
+    fn next_transition(&self, after: &DateTime<Utc>) -> Option<(DateTime<Utc>, UtcOffset, UtcOffset)> {
+        match self {
+            Self::Utc | Self::Fixed(_) => None,
+            #[cfg(feature = "system")]
+            Self::System(system) => system.next_transition(after),
+            Self::Custom(custom) => custom.0.erased_next_transition(after),
+        }
     }
 }
 
@@ -892,5 +1771,34 @@ mod tests {
         assert!(UtcOffset::from_seconds(86400).is_some());
         assert!(UtcOffset::from_seconds(3600).is_some());
         assert!(UtcOffset::from_seconds(-3600).is_some());
+        assert!(UtcOffset::from_seconds(-86401).is_none());
+        assert!(UtcOffset::from_seconds(86401).is_none());
+        assert!(UtcOffset::from_seconds(i32::MIN).is_none());
+    }
+
+    #[test]
+    fn test_component_signs() {
+        for seconds in -86400..=86400 {
+            let offset = UtcOffset::from_seconds(seconds).unwrap();
+            let (h, m, s) = offset.into_hms();
+            assert_eq!(offset.total_seconds(), seconds);
+            assert_eq!((offset.hours(), offset.minutes(), offset.seconds()), (h, m, s));
+            assert!(h.signum() * m.signum() >= 0 && h.signum() * s.signum() >= 0 && m.signum() * s.signum() >= 0);
+            assert!(m.abs() < 60 && s.abs() < 60);
+            assert_eq!(offset.is_negative(), seconds < 0);
+            assert_eq!(UtcOffset::from_hms(h, m, s), Some(offset));
+        }
+
+        // Mismatched signs are normalised to the sign of the largest non-zero component
+        assert_eq!(UtcOffset::from_hms(-5, 30, 15).unwrap().into_hms(), (-5, -30, -15));
+        assert_eq!(UtcOffset::from_hms(5, -30, -15).unwrap().into_hms(), (5, 30, 15));
+        assert_eq!(UtcOffset::from_hms(0, -30, 15).unwrap().into_hms(), (0, -30, -15));
+        assert_eq!(UtcOffset::from_hms(0, 0, -15).unwrap().total_seconds(), -15);
+
+        const OFFSET: Option<UtcOffset> = UtcOffset::from_seconds(-3600 * 5 - 1800);
+        assert_eq!(OFFSET.map(UtcOffset::into_hms), Some((-5, -30, 0)));
+        assert_eq!(UtcOffset::MIN.total_seconds(), -86400);
+        assert_eq!(UtcOffset::MAX.total_seconds(), 86400);
+        assert!(UtcOffset::UTC.is_utc());
     }
 }
@@ -1,20 +1,33 @@
 //! Date and time units
 //!
 //! This is meant to be used with date and time modification functions
-//! such as [`DateTime::next`].
+//! such as [`DateTime::next`] and [`DateTime::floor`].
 //!
 //! These are implemented as [Zero-Sized Types] (ZSTs) since implementing them
 //! as an enum would make it possible to use variants that don't make sense for the
 //! target type. For example, passing in [`Year`] for [`Time`] should not be possible.
 //!
+//! When the unit is only known at runtime, such as when it comes from user input, the
+//! [`Unit`] enum can be used with [`DateTime`] instead.
+//!
+//! [`DateTime`]: crate::DateTime
 //! [Zero-Sized Types]: https://doc.rust-lang.org/nomicon/exotic-sizes.html#zero-sized-types-zsts
 //! [`Time`]: crate::Time
 //! [`DateTime::next`]: crate::DateTime::next
+//! [`DateTime::floor`]: crate::DateTime::floor
+
+use core::time::Duration;
 
 /// A year.
 #[derive(Debug, Copy, Clone)]
 pub struct Year;
 
+/// A quarter of a year. Equal to 3 months.
+///
+/// Quarters start on January 1st, April 1st, July 1st, and October 1st.
+#[derive(Debug, Copy, Clone)]
+pub struct Quarter;
+
 /// A month.
 #[derive(Debug, Copy, Clone)]
 pub struct Month;
@@ -52,3 +65,187 @@ pub struct Microsecond;
 /// A nanosecond.
 #[derive(Debug, Copy, Clone)]
 pub struct Nanosecond;
+
+/// A date or time unit chosen at runtime.
+///
+/// Unlike the unit marker types, this can be used when the unit isn't known until runtime.
+/// It can be passed to [`DateTime::next`], [`DateTime::prev`], [`DateTime::floor`],
+/// [`DateTime::ceil`], and [`DateTime::round`] just like the marker types, and to
+/// [`DateTime::units_until`] to count the whole units between two date times.
+///
+/// Units are ordered from smallest to largest.
+///
+/// ```rust
+/// use eos::{datetime, unit::Unit};
+///
+/// let unit: Unit = "days".parse()?;
+/// assert!(unit > Unit::Hour);
+/// assert_eq!(datetime!(2021-3-17 02:30).floor(unit), datetime!(2021-3-17 00:00));
+/// # Ok::<_, eos::fmt::ParseError>(())
+/// ```
+///
+/// [`DateTime::next`]: crate::DateTime::next
+/// [`DateTime::prev`]: crate::DateTime::prev
+/// [`DateTime::floor`]: crate::DateTime::floor
+/// [`DateTime::ceil`]: crate::DateTime::ceil
+/// [`DateTime::round`]: crate::DateTime::round
+/// [`DateTime::units_until`]: crate::DateTime::units_until
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Unit {
+    /// A nanosecond.
+    Nanosecond,
+    /// A microsecond.
+    Microsecond,
+    /// A millisecond.
+    Millisecond,
+    /// A second.
+    Second,
+    /// A minute.
+    Minute,
+    /// An hour.
+    Hour,
+    /// A day.
+    Day,
+    /// A week, starting on Monday.
+    Week,
+    /// A month.
+    Month,
+    /// A quarter of a year, starting on January 1st, April 1st, July 1st, or October 1st.
+    Quarter,
+    /// A year.
+    Year,
+}
+
+impl Unit {
+    /// Returns the fixed length of the unit, if it has one.
+    ///
+    /// Days are estimated as 24 hours and weeks as 7 of those days, even though a day
+    /// in a timezone can be shorter or longer due to DST transitions. Months, quarters,
+    /// and years vary too much in length and return [`None`].
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use eos::unit::Unit;
+    ///
+    /// assert_eq!(Unit::Minute.duration_estimate(), Some(Duration::from_secs(60)));
+    /// assert_eq!(Unit::Day.duration_estimate(), Some(Duration::from_secs(86400)));
+    /// assert_eq!(Unit::Month.duration_estimate(), None);
+    /// ```
+    #[must_use]
+    pub const fn duration_estimate(self) -> Option<Duration> {
+        match self {
+            Self::Nanosecond => Some(Duration::from_nanos(1)),
+            Self::Microsecond => Some(Duration::from_micros(1)),
+            Self::Millisecond => Some(Duration::from_millis(1)),
+            Self::Second => Some(Duration::from_secs(1)),
+            Self::Minute => Some(Duration::from_secs(60)),
+            Self::Hour => Some(Duration::from_secs(3600)),
+            Self::Day => Some(Duration::from_secs(86400)),
+            Self::Week => Some(Duration::from_secs(7 * 86400)),
+            Self::Month | Self::Quarter | Self::Year => None,
+        }
+    }
+
+    /// Returns the singular English name of the unit, e.g. `"day"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Nanosecond => "nanosecond",
+            Self::Microsecond => "microsecond",
+            Self::Millisecond => "millisecond",
+            Self::Second => "second",
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::Year => "year",
+        }
+    }
+}
+
+impl core::fmt::Display for Unit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a unit from its singular or plural English name, or a common abbreviation.
+///
+/// The comparison is case insensitive, which means that `"m"` and `"M"` are both minutes.
+/// Months can be abbreviated as `"mo"` instead.
+///
+/// ```rust
+/// use eos::unit::Unit;
+///
+/// assert_eq!("day".parse(), Ok(Unit::Day));
+/// assert_eq!("Days".parse(), Ok(Unit::Day));
+/// assert_eq!("d".parse(), Ok(Unit::Day));
+/// assert_eq!("mo".parse(), Ok(Unit::Month));
+/// assert_eq!("ms".parse(), Ok(Unit::Millisecond));
+/// assert!("fortnight".parse::<Unit>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for Unit {
+    type Err = crate::fmt::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: [(&str, Unit); 45] = [
+            ("nanosecond", Unit::Nanosecond),
+            ("nanoseconds", Unit::Nanosecond),
+            ("nanos", Unit::Nanosecond),
+            ("ns", Unit::Nanosecond),
+            ("microsecond", Unit::Microsecond),
+            ("microseconds", Unit::Microsecond),
+            ("micros", Unit::Microsecond),
+            ("us", Unit::Microsecond),
+            ("\u{b5}s", Unit::Microsecond),
+            ("millisecond", Unit::Millisecond),
+            ("milliseconds", Unit::Millisecond),
+            ("millis", Unit::Millisecond),
+            ("ms", Unit::Millisecond),
+            ("second", Unit::Second),
+            ("seconds", Unit::Second),
+            ("sec", Unit::Second),
+            ("secs", Unit::Second),
+            ("s", Unit::Second),
+            ("minute", Unit::Minute),
+            ("minutes", Unit::Minute),
+            ("min", Unit::Minute),
+            ("mins", Unit::Minute),
+            ("m", Unit::Minute),
+            ("hour", Unit::Hour),
+            ("hours", Unit::Hour),
+            ("hr", Unit::Hour),
+            ("hrs", Unit::Hour),
+            ("h", Unit::Hour),
+            ("day", Unit::Day),
+            ("days", Unit::Day),
+            ("d", Unit::Day),
+            ("week", Unit::Week),
+            ("weeks", Unit::Week),
+            ("wk", Unit::Week),
+            ("w", Unit::Week),
+            ("month", Unit::Month),
+            ("months", Unit::Month),
+            ("mo", Unit::Month),
+            ("quarter", Unit::Quarter),
+            ("quarters", Unit::Quarter),
+            ("q", Unit::Quarter),
+            ("year", Unit::Year),
+            ("years", Unit::Year),
+            ("yr", Unit::Year),
+            ("y", Unit::Year),
+        ];
+
+        match NAMES.iter().find(|(name, _)| s.eq_ignore_ascii_case(name)) {
+            Some(&(_, unit)) => Ok(unit),
+            None => match s.chars().next() {
+                Some(c) => Err(crate::fmt::ParseError::UnexpectedChar(c)),
+                None => Err(crate::fmt::ParseError::UnexpectedEnd),
+            },
+        }
+    }
+}
@@ -15,6 +15,16 @@ macro_rules! ensure_in_range {
 
 pub(crate) use ensure_in_range;
 
+/// The standard error trait.
+///
+/// Without `std` this is only available from `core` on recent toolchains,
+/// so it's gated behind the `nightly` feature.
+#[cfg(feature = "std")]
+pub(crate) use std::error::Error as ErrorTrait;
+
+#[cfg(all(feature = "nightly", not(feature = "std")))]
+pub(crate) use core::error::Error as ErrorTrait;
+
 /// Computes the quotient and remainder using truncating division.
 ///
 /// Equivalent to `(lhs / rhs, lhs % rhs)`.
@@ -41,8 +51,6 @@ pub(crate) use divrem;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_divrem() {
         assert_eq!(divrem!(-23, 12), (-1, -11));
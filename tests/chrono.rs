@@ -0,0 +1,99 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime};
+use eos::{date, datetime, time, utc_offset, Date, DateTime, Error, Time, Utc, UtcOffset};
+
+#[test]
+fn date_round_trip() -> Result<(), Error> {
+    for date in [
+        date!(2022 - 03 - 17),
+        date!(1970 - 01 - 01),
+        date!(-0001 - 12 - 31),
+        Date::MIN,
+        Date::MAX,
+    ] {
+        let naive = NaiveDate::from(date);
+        assert_eq!(Date::try_from(naive)?, date);
+    }
+    assert_eq!(
+        NaiveDate::from(date!(2020 - 02 - 29)),
+        NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+    );
+    Ok(())
+}
+
+#[test]
+fn date_year_boundary() {
+    let max = NaiveDate::from_ymd_opt(i16::MAX as i32, 12, 31).unwrap();
+    let min = NaiveDate::from_ymd_opt(i16::MIN as i32, 1, 1).unwrap();
+    assert_eq!(Date::try_from(max), Ok(Date::MAX));
+    assert_eq!(Date::try_from(min), Ok(Date::MIN));
+
+    let out_of_range = |value: i32| Error::FieldOutOfRange {
+        field: "year",
+        value: value as i64,
+        min: i16::MIN as i64,
+        max: i16::MAX as i64,
+    };
+    let after = NaiveDate::from_ymd_opt(i16::MAX as i32 + 1, 1, 1).unwrap();
+    let before = NaiveDate::from_ymd_opt(i16::MIN as i32 - 1, 12, 31).unwrap();
+    assert_eq!(Date::try_from(after), Err(out_of_range(i16::MAX as i32 + 1)));
+    assert_eq!(Date::try_from(before), Err(out_of_range(i16::MIN as i32 - 1)));
+
+    let dt = chrono::TimeZone::from_utc_datetime(&chrono::Utc, &after.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(DateTime::try_from(dt), Err(out_of_range(i16::MAX as i32 + 1)));
+}
+
+#[test]
+fn time_round_trip() {
+    let t = time!(23:59:59).with_nanosecond(123_456_789).unwrap();
+    assert_eq!(
+        NaiveTime::from(t),
+        NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap()
+    );
+    assert_eq!(Time::from(NaiveTime::from(t)), t);
+    assert_eq!(Time::from(NaiveTime::from(Time::MIDNIGHT)), Time::MIDNIGHT);
+}
+
+#[test]
+fn leap_seconds() {
+    let leap = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    let naive = NaiveTime::from(leap);
+    assert_eq!(naive, NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap());
+    assert_eq!(Time::from(naive), leap);
+
+    // chrono only has leap seconds at second 59
+    let odd = time!(12:00:30).with_nanosecond(1_500_000_000).unwrap();
+    assert_eq!(
+        NaiveTime::from(odd),
+        NaiveTime::from_hms_nano_opt(12, 0, 30, 999_999_999).unwrap()
+    );
+}
+
+#[test]
+fn datetime_round_trip() -> Result<(), Error> {
+    let dt = datetime!(2022-03-17 14:25:30).with_nanosecond(5).unwrap();
+    let converted = chrono::DateTime::<chrono::Utc>::from(dt);
+    assert_eq!(converted.timestamp(), dt.timestamp().as_seconds());
+    assert_eq!(converted.timestamp_subsec_nanos(), 5);
+    assert_eq!(DateTime::<Utc>::try_from(converted)?, dt);
+    Ok(())
+}
+
+#[test]
+fn offset_round_trip() -> Result<(), Error> {
+    let offset = utc_offset!(-05:30);
+    let fixed = FixedOffset::try_from(offset)?;
+    assert_eq!(fixed.local_minus_utc(), -19_800);
+    assert_eq!(UtcOffset::from(fixed), offset);
+    assert_eq!(
+        UtcOffset::from(FixedOffset::east_opt(86_399).unwrap()).total_seconds(),
+        86_399
+    );
+    assert!(matches!(
+        FixedOffset::try_from(UtcOffset::MAX),
+        Err(Error::FieldOutOfRange { field: "offset", .. })
+    ));
+    assert!(FixedOffset::try_from(UtcOffset::MIN).is_err());
+    Ok(())
+}
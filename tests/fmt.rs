@@ -1,7 +1,7 @@
 use eos::{
     date, datetime,
     fmt::{format_spec, FormatSpec, FormatSpecKind},
-    format_dt, time, utc_offset, DateTime, Utc,
+    format_dt, time, utc_offset, DateTime, NamedOffset, Utc,
 };
 
 #[test]
@@ -14,6 +14,30 @@ fn parse_invalid_format_spec() {
     assert!(eos::fmt::parse_spec("this is invalid %#L").is_err());
 }
 
+#[test]
+fn errors_convert_into_crate_error() -> Result<(), eos::Error> {
+    use std::error::Error;
+
+    fn parse(s: &str) -> Result<DateTime<eos::UtcOffset>, eos::Error> {
+        let spec = eos::fmt::parse_spec("%Y-%m-%d %H:%M")?;
+        Ok(DateTime::parse_from_spec(s, &spec)?)
+    }
+
+    assert_eq!(parse("2022-01-01 10:30")?, datetime!(2022-01-01 10:30));
+    let error = parse("2022-01-01").unwrap_err();
+    assert!(matches!(error, eos::Error::Parse(_)));
+    assert!(error.source().is_some());
+
+    let error = eos::Error::from(eos::fmt::parse_spec("%L").unwrap_err());
+    assert!(matches!(error, eos::Error::FormatSpec(_)));
+    assert_eq!(
+        error.source().map(|e| e.to_string()),
+        Some("unknown specifier `L`".to_owned())
+    );
+    assert!(eos::Error::OutOfRange.source().is_none());
+    Ok(())
+}
+
 #[test]
 fn parse_valid_format_spec() {
     assert_eq!(
@@ -66,7 +90,7 @@ fn test_datetime_format() {
     assert_eq!(format_dt!("%Y-%m-%d", dt).to_string(), "2022-01-23");
     assert_eq!(
         format_dt!("%Y-%m-%d %H:%M:%S %Z", dt).to_string(),
-        "2022-01-23 18:20:30 "
+        "2022-01-23 18:20:30 -05:00"
     );
     assert_eq!(
         format_dt!("%Y-%m-%d %H:%M:%S %Z", utc).to_string(),
@@ -80,6 +104,23 @@ fn test_datetime_format() {
     assert_eq!(format_dt!("%G-W%V-%u", dt).to_string(), "2022-W03-7");
 }
 
+#[test]
+fn test_named_offset_format() {
+    const PST: NamedOffset = NamedOffset::new(utc_offset!(-08:00), "PST");
+    let dt = datetime!(2022-01-23 18:20:30).in_timezone(PST);
+
+    assert_eq!(dt.timezone().name(), "PST");
+    assert_eq!(dt.offset(), &utc_offset!(-08:00));
+    assert_eq!(
+        format_dt!("%Y-%m-%d %H:%M:%S %Z %o", dt).to_string(),
+        "2022-01-23 10:20:30 PST -08:00"
+    );
+    assert_eq!(dt, datetime!(2022-01-23 10:20:30 -08:00));
+
+    let odd = datetime!(2022-01-23 18:20:30).in_timezone(utc_offset!(+10:23:40));
+    assert_eq!(format_dt!("%Z", odd).to_string(), "+10:23:40");
+}
+
 #[test]
 fn test_datetime_to_rfc3339() {
     let dt = datetime!(2001-02-03 04:05:01);
@@ -164,6 +205,73 @@ fn test_datetime_from_spec() -> Result<(), eos::fmt::ParseError> {
     Ok(())
 }
 
+#[test]
+fn test_iso_week_with_calendar_year_roundtrip() -> Result<(), eos::fmt::ParseError> {
+    // Dates whose ISO year differs from the calendar year
+    let cases = [
+        (datetime!(2014-12-29 00:00), "2014 2015-W01-1"),
+        (datetime!(2015-12-31 00:00), "2015 2015-W53-4"),
+        (datetime!(2016-01-01 00:00), "2016 2015-W53-5"),
+        (datetime!(2016-01-03 00:00), "2016 2015-W53-7"),
+        (datetime!(2021-01-01 00:00), "2021 2020-W53-5"),
+        (datetime!(2019-12-30 00:00), "2019 2020-W01-1"),
+    ];
+    let spec = format_spec!("%Y %G-W%V-%u");
+    for (dt, expected) in cases {
+        let formatted = dt.format(spec).to_string();
+        assert_eq!(formatted, expected);
+        assert_eq!(DateTime::parse_from_spec(&formatted, spec)?, dt);
+    }
+
+    // The calendar year still has to agree with the ISO week date
+    assert!(DateTime::parse_from_spec("2015 2015-W01-1", spec).is_err());
+    assert!(DateTime::parse_from_spec("2015 2015-W53-5", spec).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_parse_many() {
+    let rows = [
+        "2022-01-28 01:15 +0000",
+        "2022-01-28 01:15",
+        "2022-13-01 00:00 +0000",
+        "2022-02-28 23:59 -0500",
+        "",
+        "2022-03-01 12:00 +0900",
+        "2022-03-01T12:00 +0900",
+    ];
+    let (parsed, errors) = eos::fmt::parse_many(rows, format_spec!("%Y-%m-%d %H:%M %z"));
+    assert_eq!(
+        parsed,
+        [
+            datetime!(2022-01-28 01:15),
+            datetime!(2022-03-01 04:59),
+            datetime!(2022-03-01 03:00),
+        ]
+    );
+    let indices: Vec<_> = errors.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, [1, 2, 4, 6]);
+    for (index, error) in errors {
+        assert_eq!(
+            Err(error),
+            DateTime::parse_from_spec(rows[index], format_spec!("%Y-%m-%d %H:%M %z"))
+        );
+    }
+
+    // A runtime spec is only parsed once and shared between every row
+    let spec = eos::fmt::parse_spec("%Y-%m-%d %H:%M %z").unwrap();
+    let iter = eos::fmt::parse_iter(rows.iter().copied(), &spec);
+    assert_eq!(iter.len(), rows.len());
+    let results: Vec<_> = iter.collect();
+    assert_eq!(results[0], Ok(datetime!(2022-01-28 01:15 +00:00)));
+    assert_eq!(results[3], Ok(datetime!(2022-02-28 23:59 -05:00)));
+    assert_eq!(results.iter().filter(|r| r.is_err()).count(), 4);
+
+    let (parsed, errors) = eos::fmt::parse_many(core::iter::empty(), &spec);
+    assert!(parsed.is_empty());
+    assert!(errors.is_empty());
+}
+
 #[test]
 fn test_datetime_format_parse_roundtrip() {
     let dates = [
@@ -212,3 +320,162 @@ fn test_datetime_format_parse_roundtrip() {
         }
     }
 }
+
+#[test]
+fn test_format_edge_values() {
+    use eos::{
+        fmt::{FromIsoFormat, IsoFormatPrecision, ToIsoFormat},
+        Date, IsoWeekDate, Time, UtcOffset,
+    };
+
+    // Nanoseconds are always 9 digits
+    let time = time!(01:02:03).with_nanosecond(500).unwrap();
+    assert_eq!(time.to_string(), "01:02:03.000000500");
+    assert_eq!(format_dt!("%S.%f", time).to_string(), "03.000000500");
+    assert_eq!(
+        time.to_iso_format_with_precision(IsoFormatPrecision::Nanosecond),
+        "01:02:03.000000500"
+    );
+    assert_eq!(Time::from_iso_format(&time.to_string()), Ok(time));
+
+    // Leap seconds are shown as the 60th second
+    let leap = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    assert_eq!(leap.to_string(), "23:59:60.500000000");
+    assert_eq!(format_dt!("%H:%M:%S.%f", leap).to_string(), "23:59:60.500000000");
+    assert_eq!(
+        leap.to_iso_format_with_precision(IsoFormatPrecision::Millisecond),
+        "23:59:60.500"
+    );
+    assert_eq!(Time::from_iso_format(&leap.to_string()), Ok(leap));
+
+    // Midnight and noon in the 12-hour clock
+    assert_eq!(format_dt!("%I:%M %p", time!(00:30:00)).to_string(), "12:30 AM");
+    assert_eq!(format_dt!("%I:%M %p", time!(12:30:00)).to_string(), "12:30 PM");
+
+    // Negative offsets with a zero hour component
+    let offset = utc_offset!(-00:30);
+    assert_eq!(offset.to_string(), "-00:30");
+    assert_eq!(UtcOffset::from_seconds(-30).unwrap().to_string(), "-00:00:30");
+    let dt = datetime!(2022-01-23 18:20:30).with_timezone(offset);
+    assert_eq!(format_dt!("%z %o", dt).to_string(), "-0030 -00:30");
+    assert_eq!(dt.to_rfc3339().to_string(), "2022-01-23 18:20:30-00:30");
+    assert_eq!(DateTime::from_iso_format(&dt.to_string()), Ok(dt));
+
+    // Negative and large years
+    let date = Date::new(-1, 12, 31).unwrap();
+    assert_eq!(format_dt!("%Y %G", date).to_string(), "-0001 -0001");
+    assert_eq!(format_dt!("%_Y", date).to_string(), "   -1");
+    assert_eq!(date.iso_week().to_string(), "-0001-W52-5");
+    assert_eq!(
+        IsoWeekDate::from_iso_format(&date.iso_week().to_string()),
+        Ok(date.iso_week())
+    );
+    assert_eq!(format_dt!("%Y %y", Date::MIN).to_string(), "-32768 -32768");
+    assert_eq!(Date::MIN.iso_week().to_string(), "-32768-W01-4");
+    assert_eq!(Date::MAX.iso_week().to_string(), "+32767-W52-7");
+    assert_eq!(format_dt!("%G-W%V-%u", Date::MAX).to_string(), "32767-W52-7");
+}
+
+#[test]
+fn test_parse_edge_values() {
+    use eos::{
+        fmt::{FromIsoFormat, ParseError, Profile},
+        Date, UtcOffset,
+    };
+
+    let spec = format_spec!("%B %b %Y");
+    let dt = DateTime::parse_from_spec("April Apr 2022", spec).unwrap();
+    assert_eq!(dt.date(), &date!(2022 - 04 - 01));
+    assert_eq!(
+        DateTime::parse_from_spec("01 99999", format_spec!("%m %Y")),
+        Err(ParseError::OutOfBounds)
+    );
+
+    // The smallest year has no positive counterpart
+    assert_eq!(Date::from_iso_format("-32768-01-01"), Ok(Date::MIN));
+    let spec = format_spec!("%Y-%m-%d");
+    assert_eq!(
+        DateTime::parse_from_spec("-32768-01-01", spec).map(|dt| *dt.date()),
+        Ok(Date::MIN)
+    );
+    let dt = Date::MIN.at(time!(00:00:00)).with_timezone(UtcOffset::UTC);
+    let s = dt.format_with_profile(Profile::Iso8601Extended).unwrap().to_string();
+    assert_eq!(DateTime::parse_with_profile(&s, Profile::Iso8601Extended), Ok(dt));
+
+    // Offsets with only a seconds component keep their sign
+    let offset = UtcOffset::from_seconds(50).unwrap();
+    let dt = date!(2022 - 01 - 01).at(time!(00:00:00)).with_timezone(offset);
+    for spec in [format_spec!("%Y-%m-%d %z"), format_spec!("%Y-%m-%d %o")] {
+        let s = dt.format(&spec).to_string();
+        assert_eq!(DateTime::parse_from_spec(&s, spec), Ok(dt));
+    }
+
+    // Seconds are rounded away and the local time is adjusted to keep the same instant
+    let dt = dt.with_timezone(-offset);
+    assert_eq!(
+        dt.format_with_profile(Profile::Rfc3339).unwrap().to_string(),
+        "2021-12-31T23:59:50-00:01"
+    );
+
+    // Rounding doesn't turn into a negative zero offset
+    let dt = dt.with_timezone(UtcOffset::from_seconds(-20).unwrap());
+    assert_eq!(
+        dt.format_with_profile(Profile::Rfc3339).unwrap().to_string(),
+        "2022-01-01T00:00:20+00:00"
+    );
+    assert_eq!(dt.to_rfc3339().to_string(), "2022-01-01 00:00:20+00:00");
+
+    // The largest offsets round trip through ISO-8601 but can't be represented by profiles
+    for offset in [UtcOffset::MIN, UtcOffset::MAX] {
+        let dt = dt.with_timezone(offset);
+        assert_eq!(DateTime::from_iso_format(&dt.to_string()), Ok(dt));
+        assert!(dt.format_with_profile(Profile::Rfc3339).is_err());
+    }
+    assert!(DateTime::from_iso_format("2022-01-01T00:00:00+24:30").is_err());
+}
+
+#[test]
+fn test_filename_preset_sorts_chronologically() {
+    use eos::{fmt::presets::FILENAME, Timestamp};
+
+    // 0000-01-01T00:00:00Z and 9999-12-31T23:59:59Z
+    const MIN_SECONDS: i64 = -62_167_219_200;
+    const MAX_SECONDS: i64 = 253_402_300_799;
+
+    // A small xorshift generator so the sample is random but reproducible
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut sample = Vec::new();
+    for _ in 0..2000 {
+        let seconds = MIN_SECONDS + (next() % (MAX_SECONDS - MIN_SECONDS + 1) as u64) as i64;
+        let nanoseconds = (next() % 1_000_000_000) as u32;
+        let dt = Timestamp::new(seconds, nanoseconds).to_utc();
+        sample.push(dt);
+        // Values that only differ in the sub-second component
+        sample.push(dt.with_nanosecond((nanoseconds + 1) % 1_000_000_000).unwrap());
+    }
+    sample.push(Timestamp::new(MIN_SECONDS, 0).to_utc());
+    sample.push(Timestamp::new(MAX_SECONDS, 999_999_999).to_utc());
+
+    let mut strings: Vec<_> = sample.iter().map(|dt| dt.to_filename_string().unwrap()).collect();
+    strings.sort();
+    sample.sort();
+
+    for (s, dt) in strings.iter().zip(sample.iter()) {
+        assert_eq!(s.len(), 30);
+        assert!(!s.contains(':'));
+        assert_eq!(DateTime::parse_from_spec(s, FILENAME).unwrap(), *dt);
+    }
+
+    // Other timezones are converted to UTC first
+    let dt = datetime!(2024-03-01 04:30:00 -05:00).with_millisecond(500).unwrap();
+    assert_eq!(dt.to_filename_string().unwrap(), "2024-03-01_09-30-00.500000000Z");
+    assert_eq!(datetime!(0000-01-01 00:00 +00:01).to_filename_string(), None);
+    assert_eq!(datetime!(9999-12-31 23:59 -00:01).to_filename_string(), None);
+}
@@ -1,5 +1,5 @@
 use core::time::Duration;
-use eos::{date, datetime, ext::IntervalLiteral, time, Interval};
+use eos::{date, datetime, ext::IntervalLiteral, interval, time, Interval, IntervalParts, Timestamp};
 
 #[test]
 fn zero() {
@@ -32,6 +32,69 @@ fn from_unit() {
     assert_eq!(Interval::from_microseconds(1234).microseconds(), 1234);
 }
 
+#[test]
+fn components_and_totals() {
+    let interval = 90.minutes();
+    assert_eq!((interval.hours(), interval.minutes()), (1, 30));
+    assert_eq!(interval.total_minutes(), 90);
+    assert_eq!(interval.total_hours(), 1);
+    assert_eq!(interval.total_seconds(), 5400);
+    assert_eq!(interval.total_nanoseconds(), 5_400_000_000_000);
+
+    // The totals only count the time components, the exact accessors count days as 24 hours
+    let interval = 2.days() + 3.hours() + 4.minutes() + 5.seconds() + 6.microseconds();
+    assert_eq!(interval.total_hours(), 3);
+    assert_eq!(interval.total_minutes(), 3 * 60 + 4);
+    assert_eq!(interval.total_seconds(), 11_045);
+    assert_eq!(interval.total_milliseconds(), 11_045_000);
+    assert_eq!(interval.total_microseconds(), 11_045_000_006);
+    assert_eq!(interval.total_nanoseconds(), 11_045_000_006_000);
+    assert_eq!(interval.as_seconds(), 183_845);
+    assert_eq!(interval.as_nanoseconds(), 183_845_000_006_000);
+    assert_eq!((interval + 5.years()).as_seconds(), 183_845);
+    assert_eq!((1.days() - 1.hours()).as_seconds(), 82_800);
+    assert_eq!((1.days() - 1.hours()).total_seconds(), -3_600);
+    assert_eq!((-1).days().as_seconds(), -86_400);
+
+    let huge = Interval::from_days(i32::MAX) + Interval::from_microseconds(i64::MAX);
+    assert_eq!(huge.total_microseconds(), i64::MAX);
+    assert_eq!(
+        huge.as_nanoseconds(),
+        (i32::MAX as i128 * 86_400_000_000 + i64::MAX as i128) * 1_000
+    );
+
+    let interval = -(1234.months() + 10.days() + 25.hours() + 61.seconds() + 1_234_567.microseconds());
+    let parts = interval.into_parts();
+    assert_eq!(
+        parts,
+        IntervalParts {
+            years: -102,
+            months: -10,
+            days: -10,
+            hours: -25,
+            minutes: -1,
+            seconds: -2,
+            microseconds: -234_567,
+        }
+    );
+    assert_eq!(parts.years, interval.years() as i32);
+    assert_eq!(parts.months, interval.months());
+    assert_eq!(parts.hours, interval.hours());
+    assert_eq!(parts.seconds, interval.seconds());
+    assert_eq!(interval.milliseconds(), -234);
+    assert_eq!(interval.nanoseconds(), -234_567_000);
+    assert_eq!(
+        interval.total_nanoseconds(),
+        interval.total_microseconds() as i128 * 1000
+    );
+
+    // The years are not clamped to an i16
+    let interval = Interval::from_months(i32::MAX);
+    assert_eq!(interval.years(), i16::MAX);
+    assert_eq!(interval.into_parts().years, i32::MAX / 12);
+    assert_eq!(Interval::ZERO.into_parts(), IntervalParts::default());
+}
+
 #[test]
 fn from_literal() {
     assert_eq!(2.years().years(), 2);
@@ -43,6 +106,14 @@ fn from_literal() {
     assert_eq!(1234.seconds().seconds(), 34);
     assert_eq!(1234.milliseconds().milliseconds(), 234);
     assert_eq!(1234.microseconds().microseconds(), 1234);
+    assert_eq!(1_234_567.nanoseconds().microseconds(), 1234);
+
+    // Other integer types and saturation at the bounds
+    assert_eq!(2_500_000_000_i64.hours().total_hours(), 2_500_000_000);
+    assert_eq!(90_u32.minutes(), Interval::from_minutes(90));
+    assert_eq!(i64::MAX.days(), Interval::from_days(i32::MAX));
+    assert_eq!(i64::MIN.weeks(), Interval::from_days(i32::MIN));
+    assert_eq!(u32::MAX.years(), Interval::from_months(i32::MAX));
 }
 
 #[test]
@@ -487,6 +558,37 @@ fn diff_between_dates() {
     );
 }
 
+#[test]
+fn diff_between_dates_in_ymd() {
+    let birthday = date!(2000 - 2 - 29);
+    assert_eq!(
+        Interval::between_dates_in_ymd(&birthday, &date!(2001 - 2 - 28)),
+        (1, 0, 0)
+    );
+    assert_eq!(
+        Interval::between_dates_in_ymd(&birthday, &date!(2001 - 3 - 1)),
+        (1, 0, 1)
+    );
+    assert_eq!(
+        Interval::between_dates_in_ymd(&birthday, &date!(2001 - 2 - 27)),
+        (0, 11, 29)
+    );
+    assert_eq!(Interval::between_dates_in_ymd(&birthday, &birthday), (0, 0, 0));
+    assert_eq!(
+        Interval::between_dates_in_ymd(&date!(2001 - 2 - 28), &birthday),
+        (0, -11, -28)
+    );
+
+    assert_eq!(
+        Interval::between_dates_in_ymd(&date!(325 - 2 - 28), &date!(1064 - 7 - 31)),
+        (739, 5, 3)
+    );
+    assert_eq!(
+        Interval::between_dates_in_ymd(&date!(550 - 2 - 28), &date!(113 - 6 - 30)),
+        (-436, -7, -28)
+    );
+}
+
 #[test]
 fn diff_between_times() {
     assert_eq!(
@@ -979,3 +1081,320 @@ fn diff_between_small_times_with_offsets() {
         3.hours() + 59.minutes()
     );
 }
+
+#[test]
+fn normalization() {
+    assert_eq!(90.minutes().normalized(), (1.hours() + 30.minutes()).normalized());
+    assert_eq!(24.hours().normalized(), 1.days());
+    assert_ne!(24.hours(), 1.days());
+    assert_eq!(25.hours().normalized(), 1.days() + 1.hours());
+    assert_eq!((-25).hours().normalized(), (-1).days() - 1.hours());
+    assert_eq!((1.days() - 1.hours()).normalized(), 23.hours());
+    assert_eq!((2.hours() - 3.days()).normalized(), (-2).days() - 22.hours());
+    assert_eq!(
+        (5.months() + 49.hours()).normalized(),
+        5.months() + 2.days() + 1.hours()
+    );
+    assert_eq!(Interval::ZERO.normalized(), Interval::ZERO);
+
+    let overflowing = Interval::from_days(i32::MAX) + 48.hours();
+    assert_eq!(overflowing.normalized(), overflowing);
+
+    let interval = 40.days() + 25.hours();
+    assert_eq!(
+        interval.normalize_in(&datetime!(2021-01-01 00:00)),
+        1.months() + 10.days() + 1.hours()
+    );
+    assert_eq!(
+        interval.normalize_in(&datetime!(2021-02-01 00:00)),
+        1.months() + 13.days() + 1.hours()
+    );
+    assert_eq!(
+        14.months().normalize_in(&datetime!(2021-02-01 00:00)),
+        1.years() + 2.months()
+    );
+
+    for anchor in [
+        datetime!(2020-02-29 12:00),
+        datetime!(2021-12-31 23:59),
+        datetime!(1999-06-15 00:00),
+    ] {
+        let interval = 400.days() + 90.hours() + 61.minutes();
+        let normalized = interval.normalize_in(&anchor);
+        assert_eq!(anchor + normalized, anchor + interval);
+    }
+}
+
+#[test]
+fn scalar_arithmetic() {
+    let interval = 1.years() + 2.months() + 3.days() + 4.hours() + 5.milliseconds();
+    assert_eq!(
+        interval * 3,
+        3.years() + 6.months() + 9.days() + 12.hours() + 15.milliseconds()
+    );
+    assert_eq!(interval * -1, -interval);
+    assert_eq!((interval * 4) / 4, interval);
+
+    // Division truncates each component independently toward zero
+    assert_eq!(
+        (3.months() + 3.days() + 3.hours()) / 2,
+        1.months() + 1.days() + 90.minutes()
+    );
+    assert_eq!((-3).days() / 2, (-1).days());
+    assert_eq!(1.microseconds() / 2, Interval::ZERO);
+
+    let mut value = 10.minutes();
+    value *= 6;
+    assert_eq!(value, 1.hours());
+    value /= 4;
+    assert_eq!(value, 15.minutes());
+
+    assert_eq!(Interval::from_months(i32::MAX).checked_mul(2), None);
+    assert_eq!(Interval::from_days(i32::MIN).checked_mul(-1), None);
+    assert_eq!(Interval::from_microseconds(i64::MAX).checked_mul(2), None);
+    assert_eq!(1.days().checked_div(0), None);
+    assert_eq!(Interval::from_days(i32::MIN).checked_div(-1), None);
+    assert_eq!(
+        Interval::from_days(i32::MIN).checked_div(1),
+        Some(Interval::from_days(i32::MIN))
+    );
+}
+
+#[test]
+#[should_panic]
+fn scalar_mul_overflow_panics() {
+    let _ = Interval::from_days(i32::MAX) * 2;
+}
+
+#[test]
+#[should_panic]
+fn scalar_div_by_zero_panics() {
+    let _ = 1.days() / 0;
+}
+
+#[test]
+fn float_scaling() {
+    assert_eq!(1.seconds().mul_f64(1.5), 1500.milliseconds());
+    assert_eq!((1.hours() + 30.minutes()).mul_f64(2.0), 3.hours());
+    assert_eq!(10.minutes().mul_f64(-0.25), (-150).seconds());
+    assert_eq!(1.hours().mul_f64(0.0), Interval::ZERO);
+    assert_eq!(Interval::ZERO.mul_f64(1e300), Interval::ZERO);
+
+    // Rounded to the nearest microsecond with ties away from zero
+    assert_eq!(1.microseconds().mul_f64(0.5), 1.microseconds());
+    assert_eq!(1.microseconds().mul_f64(0.49), Interval::ZERO);
+    assert_eq!((-1).microseconds().mul_f64(0.5), (-1).microseconds());
+    assert_eq!(3.microseconds().mul_f64(1.0 / 3.0), 1.microseconds());
+
+    // Calendar components have no fixed length to scale
+    assert_eq!(1.days().checked_mul_f64(1.0), None);
+    assert_eq!(1.months().checked_mul_f64(2.0), None);
+    assert_eq!((1.days() + 1.hours()).checked_mul_f64(0.5), None);
+    assert_eq!(
+        (1.days() - 1.days() + 1.hours()).checked_mul_f64(0.5),
+        Some(30.minutes())
+    );
+
+    assert_eq!(1.seconds().checked_mul_f64(f64::NAN), None);
+    assert_eq!(1.seconds().checked_mul_f64(f64::INFINITY), None);
+    assert_eq!(1.seconds().checked_mul_f64(f64::NEG_INFINITY), None);
+    assert_eq!(Interval::from_microseconds(i64::MAX).checked_mul_f64(1.0), None);
+    assert_eq!(
+        Interval::from_microseconds(i64::MIN).checked_mul_f64(1.0),
+        Some(Interval::from_microseconds(i64::MIN))
+    );
+    assert_eq!(Interval::from_microseconds(i64::MIN).checked_mul_f64(-1.0), None);
+}
+
+#[test]
+#[should_panic]
+fn float_scaling_calendar_panics() {
+    let _ = 1.days().mul_f64(1.5);
+}
+
+#[test]
+fn duration_conversions() {
+    let duration = Duration::new(2 * 86_400 + 3_723, 456_789_000);
+    let interval = Interval::try_from(duration).unwrap();
+    assert_eq!(interval.days(), 2);
+    assert_eq!(interval.try_into_duration(), Ok(duration));
+    assert_eq!(Duration::try_from(interval), Ok(duration));
+
+    assert_eq!(Duration::try_from(Interval::ZERO), Ok(Duration::ZERO));
+    assert_eq!(
+        (2.days() - 30.minutes()).try_into_duration(),
+        Ok(Duration::from_secs(2 * 86_400 - 1_800))
+    );
+    assert_eq!(Duration::try_from(1.months()), Err(eos::Error::OutOfRange));
+    assert_eq!(
+        (1.months() - 1.months() + 1.years()).try_into_duration(),
+        Err(eos::Error::OutOfRange)
+    );
+    assert_eq!(
+        (30.minutes() - 1.days()).try_into_duration(),
+        Err(eos::Error::OutOfRange)
+    );
+    assert_eq!((-1).microseconds().try_into_duration(), Err(eos::Error::OutOfRange));
+
+    let max = Interval::from_days(i32::MAX) + Interval::from_microseconds(i64::MAX);
+    assert!(max.try_into_duration().is_ok());
+
+    assert_eq!(Interval::ZERO.as_seconds_f64(), 0.0);
+    assert_eq!((2.hours() + 250.milliseconds()).as_seconds_f64(), 7200.25);
+    assert_eq!((1.years() + 1.days()).as_seconds_f64(), 86400.0);
+    assert_eq!((-1).days().as_seconds_f64(), -86400.0);
+}
+
+#[test]
+fn const_constructors() {
+    const RETRY_BACKOFF: Interval = Interval::from_seconds(30);
+    const COMPOSITE: Interval = Interval::from_years(1)
+        .and_months(2)
+        .and_weeks(1)
+        .and_days(3)
+        .and_hours(4)
+        .and_minutes(5)
+        .and_seconds(6)
+        .and_milliseconds(7)
+        .and_microseconds(8)
+        .and_nanoseconds(9_999);
+
+    assert_eq!(RETRY_BACKOFF, 30.seconds());
+    assert_eq!(
+        COMPOSITE,
+        1.years()
+            + 2.months()
+            + 10.days()
+            + 4.hours()
+            + 5.minutes()
+            + 6.seconds()
+            + 7.milliseconds()
+            + 17.microseconds()
+    );
+    assert_eq!(Interval::from_nanoseconds(1_999), 1.microseconds());
+    assert_eq!(Interval::from_nanoseconds(-1_999), (-1).microseconds());
+    assert_eq!(Interval::from_days(1).and_hours(-25), 1.days() - 25.hours());
+}
+
+#[test]
+#[should_panic]
+fn const_constructor_overflow_panics() {
+    let _ = Interval::from_days(i32::MAX).and_days(1);
+}
+
+#[test]
+fn totals_match_timestamps() {
+    let epoch = datetime!(1970-01-01 00:00);
+    for dt in [
+        datetime!(2022-01-03 01:38:45).with_nanosecond(123_456_000).unwrap(),
+        datetime!(1969-12-31 23:59:58).with_nanosecond(250_000_000).unwrap(),
+        datetime!(1900-02-28 12:00:01).with_nanosecond(999_999_000).unwrap(),
+    ] {
+        let interval = Interval::days_between(&epoch, &dt);
+        assert_eq!(interval.as_nanoseconds(), dt.timestamp_nanos());
+        assert_eq!(interval.as_nanoseconds() / 1_000, dt.timestamp_micros() as i128);
+        assert_eq!(
+            interval.as_nanoseconds().div_euclid(1_000_000_000),
+            dt.timestamp().as_seconds() as i128
+        );
+        assert_eq!(
+            interval.as_nanoseconds().div_euclid(1_000_000),
+            dt.timestamp_millis() as i128
+        );
+    }
+
+    // Sub-microsecond nanoseconds are kept by the timestamp and truncated toward the past
+    let dt = datetime!(1969-12-31 23:59:59 -01:00)
+        .with_nanosecond(999_999_999)
+        .unwrap();
+    assert_eq!(dt.timestamp(), Timestamp::new(3599, 999_999_999));
+    assert_eq!(dt.timestamp_millis(), 3_599_999);
+    let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(1_500_000_001).unwrap();
+    assert_eq!(dt.timestamp(), Timestamp::new(-1, 1_500_000_001));
+    assert_eq!(dt.timestamp_millis(), 500);
+    assert_eq!(dt.timestamp_nanos(), 500_000_001);
+    let dt = datetime!(1969-12-31 23:59:59).with_nanosecond(1).unwrap();
+    assert_eq!(dt.timestamp(), Timestamp::new(-1, 1));
+    assert_eq!(dt.timestamp_millis(), -1_000);
+}
+
+#[test]
+fn interval_macro() {
+    const SCHEDULE: [Interval; 3] = [interval!(1 day), interval!(1 week 2 days), interval!("P1M")];
+    assert_eq!(SCHEDULE, [1.days(), 9.days(), 1.months()]);
+
+    assert_eq!(interval!(2 years 3 months 4 days 5 hours), interval!("P2Y3M4DT5H"));
+    assert_eq!(
+        interval!(1 year 1 month 1 week 1 day 1 hour 1 minute 1 second 1 millisecond 1 microsecond),
+        Interval::from_years(1)
+            .and_months(1)
+            .and_weeks(1)
+            .and_days(1)
+            .and_hours(1)
+            .and_minutes(1)
+            .and_seconds(1)
+            .and_milliseconds(1)
+            .and_microseconds(1)
+    );
+    assert_eq!(interval!(30 minutes 30 minutes), 1.hours());
+    assert_eq!(interval!(-1 day 25 hours), (-1).days() + 25.hours());
+    assert_eq!(interval!(2147483647 months), Interval::from_months(i32::MAX));
+    assert_eq!(interval!(-2147483648 days), Interval::from_days(i32::MIN));
+
+    assert_eq!(interval!("PT0S"), Interval::ZERO);
+    assert_eq!(interval!("PT-0.5S"), (-500).milliseconds());
+    assert_eq!(interval!("+P1Y"), 1.years());
+    assert_eq!(interval!("-P1YT-1H"), (-1).years() + 1.hours());
+
+    // Both forms accept the same range of years
+    assert_eq!(interval!(32767 years), interval!("P32767Y"));
+    assert_eq!(interval!(-32768 years), interval!("P-32768Y"));
+}
+
+#[test]
+#[should_panic]
+fn interval_macro_years_out_of_range_panics() {
+    let _ = Interval::ZERO.__and_unit_from_macro(32768, "years");
+}
+
+#[test]
+#[should_panic]
+fn interval_macro_iso_years_out_of_range_panics() {
+    let _ = Interval::__from_iso_from_macro("P32768Y");
+}
+
+#[test]
+#[should_panic]
+fn interval_macro_empty_time_panics() {
+    let _ = Interval::__from_iso_from_macro("P1DT");
+}
+
+#[test]
+#[cfg(feature = "parsing")]
+fn interval_macro_matches_iso_parsing() {
+    use eos::fmt::FromIsoFormat;
+
+    macro_rules! check {
+        ($($iso:literal),+ $(,)?) => {
+            $(assert_eq!(interval!($iso), Interval::from_iso_format($iso).unwrap(), "{}", $iso);)+
+        };
+    }
+
+    check!(
+        "PT15M",
+        "PT20.5S",
+        "P10Y2M3DT10S",
+        "-P30D",
+        "P-30D",
+        "-P-30DT30S",
+        "P2Y1M2DT10M",
+        "PT9H75M-94S",
+        "PT9H75M-93.976S",
+        "PT-88H69M-53.018S",
+        "PT1.000001S",
+        "P32767Y",
+        "P-32768Y",
+        "P999999999D",
+        "PT999999999H",
+    );
+}
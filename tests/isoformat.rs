@@ -3,8 +3,8 @@ use std::time::Duration;
 use eos::{
     date, datetime,
     ext::IntervalLiteral,
-    fmt::{FromIsoFormat, ToIsoFormat},
-    time, utc_offset, Date, DateTime, Interval, IsoWeekDate, Time, Weekday,
+    fmt::{FromIsoFormat, ParseError, Profile, ProfileOptions, ToIsoFormat},
+    time, utc_offset, Date, DateTime, Error, Interval, IsoWeekDate, Time, UtcOffset, Weekday,
 };
 
 #[test]
@@ -219,6 +219,12 @@ fn test_interval_isoformat() {
     assert_eq!(eos::Interval::ZERO.to_iso_format(), "PT0S");
     assert_eq!((1.days() + 3.months() + 1.years()).to_iso_format(), "P1Y3M1D");
     assert_eq!((3.seconds() + 500.milliseconds()).to_iso_format(), "PT3.5S");
+    assert_eq!(Interval::from_months(i32::MAX).to_iso_format(), "P178956970Y7M");
+    assert_eq!(Interval::from_months(i32::MIN).to_iso_format(), "P-178956970Y-8M");
+    assert_eq!(
+        Interval::from_microseconds(i64::MAX).to_iso_format(),
+        "PT2562047788H54.775807S"
+    );
 }
 
 #[test]
@@ -709,3 +715,210 @@ fn test_valid_duration() -> Result<(), eos::fmt::ParseError> {
     );
     Ok(())
 }
+
+#[test]
+fn test_profile_conformance_matrix() {
+    let millis = Profile::Custom(ProfileOptions::new().fraction_digits(3, 3));
+    let profiles = [
+        Profile::Rfc3339,
+        Profile::Iso8601Extended,
+        Profile::Iso8601Basic,
+        millis,
+    ];
+
+    // input => accepted by RFC 3339, ISO extended, ISO basic, exactly 3 fractional digits
+    let matrix = [
+        ("2021-02-03T04:05:06Z", [true, true, false, false]),
+        ("2021-02-03T04:05:06+01:00", [true, true, false, false]),
+        ("2021-02-03T04:05:06.123Z", [true, true, false, true]),
+        ("2021-02-03T04:05:06.1Z", [true, true, false, false]),
+        ("2021-02-03T04:05:06.123456789Z", [true, true, false, false]),
+        ("2021-02-03T04:05:06.1234567890Z", [false, false, false, false]),
+        ("2021-02-03T04:05:06,123Z", [false, true, false, false]),
+        ("2021-02-03 04:05:06Z", [true, false, false, false]),
+        ("2021-02-03T04:05:06-00:00", [true, false, false, false]),
+        ("2021-02-03T04:05:06+00:00", [true, true, false, false]),
+        ("2021-W05-3T04:05:06Z", [false, true, false, false]),
+        ("2021-034T04:05:06Z", [false, true, false, false]),
+        ("+12021-02-03T04:05:06Z", [false, true, false, false]),
+        ("-0001-02-03T04:05:06Z", [false, true, false, false]),
+        ("20210203T040506Z", [false, false, true, false]),
+        ("20210203T040506.123+0100", [false, false, true, false]),
+        ("2021W053T040506Z", [false, false, true, false]),
+        ("2021034T040506Z", [false, false, true, false]),
+        ("+120210203T040506Z", [false, false, true, false]),
+        ("20210203T040506-0000", [false, false, false, false]),
+        // Never accepted
+        ("2021-02-03T04:05:06", [false, false, false, false]),
+        ("2021-02-03T04:05Z", [false, false, false, false]),
+        ("2021-02-03T24:00:00Z", [false, false, false, false]),
+        ("2021-02-03T04:05:06+01", [false, false, false, false]),
+        ("2021-02-03T04:05:06+01:00:00", [false, false, false, false]),
+        ("2021-02-30T04:05:06Z", [false, false, false, false]),
+        ("2021-W05T04:05:06Z", [false, false, false, false]),
+        ("2021-02-03T04:05:06Zjunk", [false, false, false, false]),
+        ("2021-0203T04:05:06Z", [false, false, false, false]),
+        ("202102-03T04:05:06Z", [false, false, false, false]),
+    ];
+
+    for (input, expected) in matrix {
+        for (profile, accepted) in profiles.iter().zip(expected) {
+            let result = DateTime::parse_with_profile(input, *profile);
+            assert_eq!(result.is_ok(), accepted, "{} with {:?}: {:?}", input, profile, result);
+        }
+    }
+}
+
+#[test]
+fn test_profile_parse_values() {
+    let expected = datetime!(2021-02-03 04:05:06 +01:00).with_millisecond(123).unwrap();
+    let inputs = [
+        ("2021-02-03T04:05:06.123+01:00", Profile::Rfc3339),
+        ("2021-02-03 04:05:06.123+01:00", Profile::Rfc3339),
+        ("2021-02-03T04:05:06,123+01:00", Profile::Iso8601Extended),
+        ("2021-W05-3T04:05:06.123+01:00", Profile::Iso8601Extended),
+        ("2021-034T04:05:06.123+01:00", Profile::Iso8601Extended),
+        ("20210203T040506.123+0100", Profile::Iso8601Basic),
+        ("2021W053T040506.123+0100", Profile::Iso8601Basic),
+        ("2021034T040506.123+0100", Profile::Iso8601Basic),
+    ];
+    for (input, profile) in inputs {
+        assert_eq!(DateTime::parse_with_profile(input, profile), Ok(expected), "{}", input);
+    }
+
+    assert_eq!(
+        DateTime::parse_with_profile("-0001-02-03T04:05:06Z", Profile::Iso8601Extended).map(|dt| dt.year()),
+        Ok(-1)
+    );
+    assert_eq!(
+        DateTime::parse_with_profile("-00001-02-03T04:05:06Z", Profile::Iso8601Extended).map(|dt| dt.year()),
+        Ok(-1)
+    );
+    assert_eq!(
+        DateTime::parse_with_profile("-000010203T040506Z", Profile::Iso8601Basic).map(|dt| dt.year()),
+        Ok(-1)
+    );
+    assert_eq!(
+        DateTime::parse_with_profile("2016-12-31T23:59:60Z", Profile::Rfc3339).map(|dt| dt.nanosecond()),
+        Ok(1_000_000_000)
+    );
+
+    let utc = Profile::Custom(ProfileOptions::new().optional_offset(true));
+    assert_eq!(
+        DateTime::parse_with_profile("2021-02-03T04:05:06", utc),
+        Ok(datetime!(2021-02-03 04:05:06 +00:00))
+    );
+
+    assert_eq!(
+        DateTime::parse_with_profile("2021-W05-3T04:05:06Z", Profile::Rfc3339),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_with_profile("2021-02-03T04:05:06-00:00", Profile::Iso8601Extended),
+        Err(ParseError::UnsupportedSpecifier)
+    );
+    assert_eq!(
+        DateTime::parse_with_profile("2021-02-03 04:05:06Z", Profile::Iso8601Extended),
+        Err(ParseError::UnexpectedChar(' '))
+    );
+}
+
+#[test]
+fn test_profile_format() {
+    let dt = datetime!(2021-02-03 04:05:06 -05:30);
+    let millis = Profile::Custom(ProfileOptions::new().fraction_digits(3, 3));
+    let micros = Profile::Custom(ProfileOptions::new().fraction_digits(6, 9));
+    let seconds = Profile::Custom(ProfileOptions::new().fraction_digits(0, 0));
+    let cases = [
+        (dt, Profile::Rfc3339, "2021-02-03T04:05:06-05:30"),
+        (dt, Profile::Iso8601Extended, "2021-02-03T04:05:06-05:30"),
+        (dt, Profile::Iso8601Basic, "20210203T040506-0530"),
+        (dt, millis, "2021-02-03T04:05:06.000-05:30"),
+        (
+            dt.with_nanosecond(123_456_789).unwrap(),
+            millis,
+            "2021-02-03T04:05:06.123-05:30",
+        ),
+        (
+            dt.with_nanosecond(120_000_000).unwrap(),
+            micros,
+            "2021-02-03T04:05:06.120000-05:30",
+        ),
+        (
+            dt.with_nanosecond(123_456_789).unwrap(),
+            micros,
+            "2021-02-03T04:05:06.123456789-05:30",
+        ),
+        (
+            dt.with_nanosecond(5).unwrap(),
+            Profile::Rfc3339,
+            "2021-02-03T04:05:06.000000005-05:30",
+        ),
+        (
+            dt.with_nanosecond(999_999_999).unwrap(),
+            seconds,
+            "2021-02-03T04:05:06-05:30",
+        ),
+        (
+            datetime!(2016-12-31 23:59:59 +00:00)
+                .with_nanosecond(1_500_000_000)
+                .unwrap(),
+            Profile::Rfc3339,
+            "2016-12-31T23:59:60.5+00:00",
+        ),
+    ];
+    for (dt, profile, expected) in cases {
+        let formatted = dt.format_with_profile(profile).unwrap().to_string();
+        assert_eq!(formatted, expected);
+        let parsed = DateTime::parse_with_profile(&formatted, profile).unwrap();
+        assert_eq!(parsed.format_with_profile(profile).unwrap().to_string(), formatted);
+    }
+
+    let offset = datetime!(2021-02-03 04:05:06 -00:30);
+    assert_eq!(
+        offset.format_with_profile(Profile::Rfc3339).unwrap().to_string(),
+        "2021-02-03T04:05:06-00:30"
+    );
+
+    // Years outside of 0000 to 9999 need a profile with expanded years
+    let base = datetime!(2021-02-03 04:05:06);
+    let years = [
+        (10000, "+10000-02-03T04:05:06+00:00", "+100000203T040506+0000"),
+        (12021, "+12021-02-03T04:05:06+00:00", "+120210203T040506+0000"),
+        (32767, "+32767-02-03T04:05:06+00:00", "+327670203T040506+0000"),
+        (-1, "-0001-02-03T04:05:06+00:00", "-000010203T040506+0000"),
+        (-32768, "-32768-02-03T04:05:06+00:00", "-327680203T040506+0000"),
+    ];
+    for (year, extended, basic) in years {
+        let dt = base.with_year(year).unwrap();
+        for (profile, expected) in [(Profile::Iso8601Extended, extended), (Profile::Iso8601Basic, basic)] {
+            let formatted = dt.format_with_profile(profile).unwrap().to_string();
+            assert_eq!(formatted, expected);
+            assert_eq!(
+                DateTime::parse_with_profile(&formatted, profile),
+                Ok(dt.with_timezone(UtcOffset::UTC))
+            );
+        }
+        assert_eq!(
+            dt.format_with_profile(Profile::Rfc3339).err(),
+            Some(Error::FieldOutOfRange {
+                field: "year",
+                value: year as i64,
+                min: 0,
+                max: 9999,
+            })
+        );
+    }
+
+    // The year is checked after the offset is rounded to whole minutes
+    let dt = datetime!(9999-12-31 23:59:50 +00:00).with_timezone(UtcOffset::from_seconds(20).unwrap());
+    assert_eq!(
+        dt.format_with_profile(Profile::Rfc3339).unwrap().to_string(),
+        "9999-12-31T23:59:30+00:00"
+    );
+    let dt = datetime!(9999-12-31 23:59:50 +00:00).with_timezone(UtcOffset::from_seconds(40).unwrap());
+    assert!(matches!(
+        dt.format_with_profile(Profile::Rfc3339),
+        Err(Error::FieldOutOfRange { field: "year", .. })
+    ));
+}
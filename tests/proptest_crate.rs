@@ -0,0 +1,83 @@
+#![cfg(feature = "proptest")]
+
+use eos::{date, datetime, time, Date, DateTime, Interval, Time, Utc};
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestError, TestRunner},
+};
+
+/// Runs a failing property and returns the value it shrank to.
+fn minimal_failure<T: Arbitrary>(fails: impl Fn(&T) -> bool) -> T {
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&any::<T>(), |value| {
+        if fails(&value) {
+            Err(TestCaseError::fail("failed"))
+        } else {
+            Ok(())
+        }
+    });
+    match result {
+        Err(TestError::Fail(_, value)) => value,
+        _ => panic!("the property should fail"),
+    }
+}
+
+proptest! {
+    #[test]
+    fn dates_are_valid(date in any::<Date>()) {
+        prop_assert_eq!(Date::new(date.year(), date.month(), date.day()), Some(date));
+    }
+
+    #[test]
+    fn times_are_valid(time in any::<Time>()) {
+        prop_assert!(time.nanosecond() < 1_000_000_000);
+        prop_assert_eq!(time.with_nanosecond(0), Time::new(time.hour(), time.minute(), time.second()));
+    }
+
+    #[test]
+    fn datetimes_are_valid(dt in any::<DateTime<Utc>>()) {
+        prop_assert!(DateTime::MIN <= dt && dt <= DateTime::MAX);
+    }
+
+    #[test]
+    fn intervals_are_bounded(interval in any::<Interval>()) {
+        let (months, days, micros) = eos::extra::proptest::INTERVAL_RANGE;
+        prop_assert!(interval.months().abs() <= months);
+        prop_assert!(interval.days().abs() <= days);
+        prop_assert!(interval.microseconds().abs() <= micros);
+    }
+}
+
+#[test]
+fn edge_cases_are_produced() {
+    let mut runner = TestRunner::deterministic();
+    let (mut leap_day, mut new_years_eve) = (false, false);
+    for _ in 0..1000 {
+        let date = any::<Date>().new_tree(&mut runner).unwrap().current();
+        leap_day |= date.month() == 2 && date.day() == 29;
+        new_years_eve |= date.month() == 12 && date.day() == 31;
+    }
+    assert!(leap_day && new_years_eve);
+
+    let last = Time::MAX;
+    let produced = (0..1000).any(|_| any::<Time>().new_tree(&mut runner).unwrap().current() == last);
+    assert!(produced);
+}
+
+#[test]
+fn shrinks_toward_epoch_and_midnight() {
+    assert_eq!(
+        minimal_failure(|date: &Date| date.year() >= 2000),
+        date!(2000 - 01 - 01)
+    );
+    assert_eq!(minimal_failure(|date: &Date| date.year() < 1900), date!(1899 - 12 - 31));
+    assert_eq!(minimal_failure(|time: &Time| time.hour() >= 12), time!(12:00));
+    assert_eq!(
+        minimal_failure(|dt: &DateTime<Utc>| dt.year() >= 2000 && dt.hour() >= 12),
+        datetime!(2000-01-01 12:00)
+    );
+    assert_eq!(
+        minimal_failure(|interval: &Interval| interval.days() > 0),
+        Interval::from_days(1)
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "rand")]
+
+use eos::{date, datetime, time, Date, DateTime, Time, Utc};
+use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x0e05)
+}
+
+#[test]
+fn date_ranges() {
+    let mut rng = rng();
+    let (start, end) = (date!(2000 - 01 - 01), date!(2030 - 01 - 01));
+    for _ in 0..1000 {
+        let date = rng.gen_range(start..end);
+        assert!(start <= date && date < end);
+    }
+
+    // Both ends of an inclusive range are reachable
+    let (start, end) = (date!(2021 - 12 - 31), date!(2022 - 01 - 01));
+    let mut seen = [false; 2];
+    for _ in 0..100 {
+        seen[rng.gen_range(start..=end).days_since(&start) as usize] = true;
+    }
+    assert_eq!(seen, [true, true]);
+
+    assert_eq!(rng.gen_range(start..=start), start);
+}
+
+#[test]
+fn time_ranges() {
+    let mut rng = rng();
+    let (start, end) = (time!(09:00), time!(17:30));
+    for _ in 0..1000 {
+        let time = rng.gen_range(start..end);
+        assert!(start <= time && time < end);
+    }
+
+    let end = time!(09:00).with_nanosecond(1).unwrap();
+    assert_eq!(rng.gen_range(start..end), start);
+}
+
+#[test]
+fn datetime_ranges() {
+    let mut rng = rng();
+    let (start, end) = (datetime!(2022-03-13 00:00), datetime!(2022-03-14 00:00));
+    let uniform = Uniform::new(start, end);
+    for _ in 0..1000 {
+        let dt = rng.sample(uniform);
+        assert!(start <= dt && dt < end);
+    }
+
+    let full = rng.gen_range(DateTime::MIN..=DateTime::MAX);
+    assert!(DateTime::MIN <= full && full <= DateTime::MAX);
+}
+
+#[test]
+fn standard_produces_valid_values() {
+    let mut rng = rng();
+    for _ in 0..1000 {
+        let date: Date = rng.gen();
+        assert_eq!(Date::from_epoch_days(date.days_since_epoch()), Ok(date));
+
+        let time: Time = rng.gen();
+        assert!(time.nanosecond() < 1_000_000_000);
+
+        let dt: DateTime<Utc> = rng.gen();
+        assert!(DateTime::MIN <= dt && dt <= DateTime::MAX);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot sample empty range")]
+fn empty_range_panics() {
+    let date = date!(2022 - 01 - 01);
+    rng().gen_range(date..date);
+}
+
+#[test]
+#[should_panic(expected = "Uniform::new called with `low >= high`")]
+fn reversed_uniform_panics() {
+    Uniform::new(time!(12:00), time!(11:00));
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "rkyv")]
+
+use eos::{
+    date, datetime,
+    extra::rkyv::{ArchivedDate, ArchivedDateTime, ArchivedInterval, ArchivedTime, ArchivedUtc, ArchivedUtcOffset},
+    time, utc_offset, Date, DateTime, Interval, Utc, UtcOffset,
+};
+use rkyv::{
+    api::high::{HighDeserializer, HighSerializer, HighValidator},
+    bytecheck::CheckBytes,
+    rancor::Error,
+    ser::allocator::ArenaHandle,
+    util::AlignedVec,
+    Archive, Deserialize, Serialize,
+};
+
+type Serializer<'a> = HighSerializer<AlignedVec, ArenaHandle<'a>, Error>;
+
+fn round_trip<T>(value: &T) -> T
+where
+    T: Archive + for<'a> Serialize<Serializer<'a>>,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>> + Deserialize<T, HighDeserializer<Error>>,
+{
+    let bytes = rkyv::to_bytes::<Error>(value).unwrap();
+    rkyv::from_bytes::<T, Error>(&bytes).unwrap()
+}
+
+#[test]
+fn values_round_trip() {
+    let date = date!(2024 - 02 - 29);
+    assert_eq!(round_trip(&date), date);
+    assert_eq!(round_trip(&Date::MIN), Date::MIN);
+    assert_eq!(round_trip(&Date::MAX), Date::MAX);
+
+    let time = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    assert_eq!(round_trip(&time), time);
+
+    let offset = utc_offset!(-05:30);
+    assert_eq!(round_trip(&offset), offset);
+    assert_eq!(round_trip(&UtcOffset::MAX), UtcOffset::MAX);
+
+    let interval = Interval::from_months(-14) + Interval::from_days(3) + Interval::from_microseconds(12345);
+    assert_eq!(round_trip(&interval), interval);
+
+    let utc = datetime!(2022-03-17 14:25:30).with_nanosecond(5).unwrap();
+    assert_eq!(round_trip(&utc), utc);
+
+    let local = datetime!(2022-03-17 14:25:30 -05:00);
+    let back = round_trip(&local);
+    assert_eq!(back, local);
+    assert_eq!(back.offset(), &utc_offset!(-05:00));
+    assert_eq!(back.timezone(), &utc_offset!(-05:00));
+}
+
+#[test]
+fn archived_layout() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<ArchivedDate>(), 4);
+    assert_eq!(size_of::<ArchivedTime>(), 8);
+    assert_eq!(size_of::<ArchivedUtcOffset>(), 3);
+    assert_eq!(size_of::<ArchivedUtc>(), 0);
+    assert_eq!(size_of::<ArchivedInterval>(), 16);
+    assert_eq!(size_of::<ArchivedDateTime<Utc>>(), 16);
+    assert_eq!(size_of::<ArchivedDateTime<UtcOffset>>(), 20);
+
+    let bytes = rkyv::to_bytes::<Error>(&date!(2022 - 03 - 17)).unwrap();
+    assert_eq!(&bytes[..], &[0xE6, 0x07, 3, 17]);
+
+    let bytes = rkyv::to_bytes::<Error>(&utc_offset!(-05:30)).unwrap();
+    assert_eq!(&bytes[..], &[-5i8 as u8, -30i8 as u8, 0]);
+
+    let bytes = rkyv::to_bytes::<Error>(&datetime!(2022-03-17 14:25:30 +01:00)).unwrap();
+    assert_eq!(
+        &bytes[..],
+        &[0xE6, 0x07, 3, 17, 14, 25, 30, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 0]
+    );
+}
+
+/// Serializes the value, lets `tamper` modify the bytes, and returns the result of
+/// deserializing them again.
+fn tampered<T>(value: &T, tamper: impl FnOnce(&mut [u8])) -> Result<T, Error>
+where
+    T: Archive + for<'a> Serialize<Serializer<'a>>,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>> + Deserialize<T, HighDeserializer<Error>>,
+{
+    let mut bytes = rkyv::to_bytes::<Error>(value).unwrap();
+    tamper(&mut bytes);
+    rkyv::from_bytes::<T, Error>(&bytes)
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let date = date!(2023 - 02 - 28);
+    assert!(tampered(&date, |b| b[2] = 13).is_err());
+    assert!(tampered(&date, |b| b[2] = 0).is_err());
+    assert!(tampered(&date, |b| b[3] = 29).is_err());
+    assert_eq!(tampered(&date, |b| b[3] = 27).ok(), Some(date!(2023 - 02 - 27)));
+
+    let error = tampered(&date, |b| b[2] = 13).unwrap_err();
+    assert!(error.to_string().contains("month"), "{}", error);
+
+    let time = time!(12:00);
+    assert!(tampered(&time, |b| b[0] = 24).is_err());
+    assert!(tampered(&time, |b| b[1] = 60).is_err());
+    assert!(tampered(&time, |b| b[2] = 60).is_err());
+    assert!(tampered(&time, |b| b[4..8].copy_from_slice(&2_000_000_000u32.to_le_bytes())).is_err());
+    assert!(tampered(&time, |b| b[4..8].copy_from_slice(&1_999_999_999u32.to_le_bytes())).is_ok());
+
+    let offset = utc_offset!(+05:30);
+    assert!(tampered(&offset, |b| b[0] = 25).is_err());
+    assert!(tampered(&offset, |b| b[1] = 60).is_err());
+    assert!(tampered(&offset, |b| b[1] = -30i8 as u8).is_err());
+    assert!(tampered(&UtcOffset::MAX, |b| b[1] = 1).is_err());
+}
+
+#[test]
+fn datetime_offset_must_match_timezone() {
+    // The offset is the 3 bytes after the 4 byte date and 8 byte time
+    let utc = datetime!(2022-03-17 14:25:30);
+    assert!(tampered(&utc, |b| b[12] = 1).is_err());
+
+    let local = datetime!(2022-03-17 14:25:30 +01:00);
+    assert!(tampered(&local, |b| b[12] = 2).is_err());
+    assert!(tampered(&local, |b| b[15] = 2).is_err());
+    let both = tampered(&local, |b| {
+        b[12] = 2;
+        b[15] = 2;
+    });
+    assert_eq!(both.ok().map(|dt| dt.offset().hours()), Some(2));
+
+    // Field checks also apply to the nested date
+    assert!(tampered(&local, |b| b[2] = 13).is_err());
+}
+
+#[test]
+fn access_without_deserializing() {
+    let dates = vec![date!(2022 - 01 - 01), date!(2022 - 12 - 31)];
+    let bytes = rkyv::to_bytes::<Error>(&dates).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<Date>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.iter().map(Date::from).collect::<Vec<_>>(), dates);
+
+    let local = datetime!(2022-03-17 14:25:30 -05:00);
+    let bytes = rkyv::to_bytes::<Error>(&local).unwrap();
+    let archived = rkyv::access::<ArchivedDateTime<UtcOffset>, Error>(&bytes).unwrap();
+    let converted = DateTime::<UtcOffset>::from(archived);
+    assert_eq!(converted, local);
+    assert_eq!(converted.timezone(), local.timezone());
+
+    let interval = Interval::from_days(-3);
+    let bytes = rkyv::to_bytes::<Error>(&interval).unwrap();
+    let archived = rkyv::access::<ArchivedInterval, Error>(&bytes).unwrap();
+    assert_eq!(Interval::from(archived), interval);
+}
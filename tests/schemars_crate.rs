@@ -0,0 +1,104 @@
+#![cfg(feature = "schemars")]
+
+use eos::{date, datetime, time, utc_offset, Date, DateTime, Interval, Time, Utc, UtcOffset};
+use regex::Regex;
+use schemars::{schema_for, JsonSchema};
+use serde::Serialize;
+use serde_json::json;
+
+/// Checks that every value serializes to a string matching the schema's pattern.
+fn assert_matches<T: JsonSchema + Serialize>(values: &[T]) {
+    let schema = schema_for!(T);
+    let pattern = schema
+        .get("pattern")
+        .and_then(|p| p.as_str())
+        .expect("schema has a pattern");
+    let regex = Regex::new(pattern).unwrap();
+    for value in values {
+        let json = serde_json::to_value(value).unwrap();
+        let string = json.as_str().expect("value serializes to a string");
+        assert!(regex.is_match(string), "{} does not match {}", string, pattern);
+    }
+}
+
+#[test]
+fn schemas_have_formats() {
+    let formats = [
+        (schema_for!(Date), "date"),
+        (schema_for!(Time), "time"),
+        (schema_for!(DateTime<Utc>), "date-time"),
+        (schema_for!(DateTime<UtcOffset>), "date-time"),
+        (schema_for!(Interval), "duration"),
+    ];
+    for (schema, format) in formats {
+        assert_eq!(schema.get("type"), Some(&json!("string")));
+        assert_eq!(schema.get("format"), Some(&json!(format)));
+    }
+}
+
+#[test]
+fn patterns_match_serialized_values() {
+    assert_matches(&[
+        date!(2022 - 01 - 01),
+        date!(0000 - 01 - 01),
+        date!(9999 - 12 - 31),
+        Date::new(-1, 1, 1).unwrap(),
+        Date::new(10000, 1, 1).unwrap(),
+        Date::MIN,
+        Date::MAX,
+    ]);
+
+    assert_matches(&[
+        time!(00:00),
+        time!(10:00).with_nanosecond(5).unwrap(),
+        time!(23:59:59).with_nanosecond(1_500_000_000).unwrap(),
+        Time::MAX,
+    ]);
+
+    assert_matches(&[DateTime::MIN, DateTime::MAX, datetime!(2022-01-01 10:00)]);
+    assert_matches(&[
+        datetime!(2022-01-01 10:00 -05:00),
+        datetime!(2022-01-01 10:00).in_timezone(UtcOffset::from_seconds(-1815).unwrap()),
+        datetime!(2022-01-01 10:00).in_timezone(utc_offset!(+14:00)),
+    ]);
+
+    assert_matches(&[
+        Interval::ZERO,
+        Interval::from_months(-14),
+        Interval::from_days(3) + Interval::from_microseconds(-1500),
+        Interval::from_years(1)
+            + Interval::from_months(2)
+            + Interval::from_days(3)
+            + Interval::from_hours(4)
+            + Interval::from_minutes(5)
+            + Interval::from_milliseconds(6500),
+        Interval::from_seconds(-59),
+    ]);
+}
+
+#[test]
+fn patterns_reject_other_strings() {
+    let date = Regex::new(schema_for!(Date).get("pattern").unwrap().as_str().unwrap()).unwrap();
+    assert!(!date.is_match("2022-01-01T00:00:00+00:00"));
+    assert!(!date.is_match("22-01-01"));
+
+    let dt = Regex::new(schema_for!(DateTime).get("pattern").unwrap().as_str().unwrap()).unwrap();
+    assert!(!dt.is_match("2022-01-01T10:00:00"));
+    assert!(!dt.is_match("2022-01-01T10:00:00Z"));
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Event {
+    date: Date,
+    at: DateTime,
+    duration: Interval,
+}
+
+#[test]
+fn derived_schemas_inline_the_types() {
+    let schema = serde_json::to_value(schema_for!(Event)).unwrap();
+    assert_eq!(schema["properties"]["date"]["format"], json!("date"));
+    assert_eq!(schema["properties"]["at"]["format"], json!("date-time"));
+    assert_eq!(schema["properties"]["duration"]["format"], json!("duration"));
+}
@@ -0,0 +1,247 @@
+#![cfg(all(feature = "serde", feature = "parsing"))]
+
+use eos::{
+    date, datetime,
+    extra::{Expiry, WeekdaySet},
+    time, utc_offset, Date, DateTime, Time, Timestamp, Utc, UtcOffset, Weekday,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + core::fmt::Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value);
+
+    let binary = bincode::serialize(value).unwrap();
+    assert_eq!(&bincode::deserialize::<T>(&binary).unwrap(), value);
+}
+
+#[test]
+fn test_round_trip() {
+    round_trip(&date!(2022 - 01 - 03));
+    round_trip(&date!(-200 - 12 - 31));
+    round_trip(&date!(-9999 - 01 - 01));
+    round_trip(&date!(9999 - 12 - 31));
+
+    round_trip(&time!(01:38:45));
+    round_trip(&Time::MIN);
+    round_trip(&Time::MAX);
+
+    round_trip(&datetime!(2022-01-03 01:38:45));
+    round_trip(&datetime!(1969-12-31 23:59:59).with_nanosecond(123_456_789).unwrap());
+    round_trip(&datetime!(2022-01-03 01:38:45 -05:00));
+    round_trip(&datetime!(2022-01-03 01:38:45 +10:23:40));
+
+    round_trip(&Timestamp::new(1641173925, 123_000_000));
+    round_trip(&Timestamp::from_seconds(-1));
+}
+
+#[test]
+fn test_binary_layout() {
+    let dt = datetime!(2022-01-03 01:38:45);
+    let bytes = bincode::serialize(&dt).unwrap();
+    assert_eq!(bytes, bincode::serialize(&(1641173925i64, 0u32, 0i32)).unwrap());
+
+    let ts = Timestamp::new(1641173925, 500);
+    let bytes = bincode::serialize(&ts).unwrap();
+    assert_eq!(bytes, bincode::serialize(&(1641173925i64, 500u32)).unwrap());
+
+    let dt = datetime!(2022-01-03 01:38:45 -05:00);
+    let bytes = bincode::serialize(&dt).unwrap();
+    assert_eq!(bytes, bincode::serialize(&(1641191925i64, 0u32, -18000i32)).unwrap());
+    let utc: DateTime<Utc> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(utc, dt);
+    let offset: DateTime<UtcOffset> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(offset.offset(), &utc_offset!(-05:00));
+
+    let date = date!(1970 - 01 - 02);
+    assert_eq!(bincode::serialize(&date).unwrap(), bincode::serialize(&1i32).unwrap());
+
+    let time = time!(01:00:01);
+    assert_eq!(
+        bincode::serialize(&time).unwrap(),
+        bincode::serialize(&(3601u32, 0u32)).unwrap()
+    );
+
+    let leap = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    let bytes = bincode::serialize(&leap).unwrap();
+    assert_eq!(bytes, bincode::serialize(&(86399u32, 1_500_000_000u32)).unwrap());
+    assert_eq!(bincode::deserialize::<Time>(&bytes).unwrap(), leap);
+}
+
+#[test]
+fn test_human_readable() {
+    let dt = datetime!(2022-01-03 01:38:45);
+    assert_eq!(serde_json::to_string(&dt).unwrap(), r#""2022-01-03T01:38:45+00:00""#);
+    let ts = dt.timestamp();
+    assert_eq!(serde_json::to_string(&ts).unwrap(), r#""2022-01-03T01:38:45+00:00""#);
+}
+
+#[test]
+fn test_binary_out_of_range() {
+    let bytes = bincode::serialize(&(86400u32, 0u32)).unwrap();
+    assert!(bincode::deserialize::<Time>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&i32::MAX).unwrap();
+    assert!(bincode::deserialize::<Date>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&(0i64, 0u32, 86401i32)).unwrap();
+    assert!(bincode::deserialize::<DateTime<Utc>>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&(i64::MAX, 0u32, 0i32)).unwrap();
+    assert!(bincode::deserialize::<DateTime<Utc>>(&bytes).is_err());
+    assert!(bincode::deserialize::<DateTime<UtcOffset>>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&(i64::MIN, 0u32, 3600i32)).unwrap();
+    assert!(bincode::deserialize::<DateTime<UtcOffset>>(&bytes).is_err());
+
+    let bytes = bincode::serialize(&(0i64, 2_000_000_000u32, 0i32)).unwrap();
+    assert!(bincode::deserialize::<DateTime<Utc>>(&bytes).is_err());
+
+    let max = bincode::serialize(&DateTime::MAX).unwrap();
+    assert_eq!(bincode::deserialize::<DateTime<Utc>>(&max).unwrap(), DateTime::MAX);
+}
+
+#[test]
+fn test_weekday_set() {
+    let set = WeekdaySet::new().with(Weekday::Monday).with(Weekday::Sunday);
+    assert_eq!(serde_json::to_string(&set).unwrap(), "65");
+    assert_eq!(serde_json::from_str::<WeekdaySet>("65").unwrap(), set);
+    assert_eq!(bincode::serialize(&set).unwrap(), [65]);
+    round_trip(&set);
+    round_trip(&WeekdaySet::EMPTY);
+    round_trip(&WeekdaySet::ALL);
+
+    assert!(serde_json::from_str::<WeekdaySet>("128").is_err());
+    assert!(serde_json::from_str::<WeekdaySet>("256").is_err());
+}
+
+#[test]
+fn test_expiry() {
+    let expiry = Expiry::new(Timestamp::from_milliseconds(1_641_173_925_123));
+    assert_eq!(serde_json::to_string(&expiry).unwrap(), "1641173925123");
+    assert_eq!(serde_json::from_str::<Expiry>("1641173925123").unwrap(), expiry);
+    assert_eq!(
+        serde_json::from_str::<Expiry>("-1500").unwrap().timestamp(),
+        Timestamp::new(-2, 500_000_000)
+    );
+    round_trip(&expiry);
+
+    // Sub-millisecond precision is kept in binary formats
+    let expiry = Expiry::new(Timestamp::new(1_641_173_925, 123_456_789));
+    let bytes = bincode::serialize(&expiry).unwrap();
+    assert_eq!(bytes, bincode::serialize(&(1_641_173_925i64, 123_456_789u32)).unwrap());
+    assert_eq!(bincode::deserialize::<Expiry>(&bytes).unwrap(), expiry);
+}
+
+#[test]
+fn test_date_and_time_only() {
+    #[derive(serde_derive::Serialize, serde_derive::Deserialize, PartialEq, Debug)]
+    struct Row {
+        #[serde(with = "eos::serde::date_only")]
+        date: DateTime<Utc>,
+        #[serde(with = "eos::serde::date_only::strict")]
+        strict: DateTime<Utc>,
+        #[serde(with = "eos::serde::time_only")]
+        time: DateTime<Utc>,
+    }
+
+    let row = Row {
+        date: datetime!(2024-02-29 23:59:59),
+        strict: date!(-1 - 12 - 31).at(time!(00:00:01)),
+        time: datetime!(2024-02-29 13:45:10).with_nanosecond(500_000_000).unwrap(),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(
+        json,
+        r#"{"date":"2024-02-29","strict":"-0001-12-31","time":"13:45:10.500000000"}"#
+    );
+    let expected = Row {
+        date: datetime!(2024-02-29 00:00),
+        strict: date!(-1 - 12 - 31).at(Time::MIDNIGHT),
+        time: datetime!(1970-01-01 13:45:10).with_nanosecond(500_000_000).unwrap(),
+    };
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), expected);
+    round_trip(&expected);
+
+    let bytes = bincode::serialize(&row).unwrap();
+    assert_eq!(bincode::deserialize::<Row>(&bytes).unwrap(), expected);
+
+    // The lenient form accepts a time component and truncates it in UTC
+    let json = r#"{"date":"2024-02-29T22:00:00-05:00","strict":"2024-02-29","time":"00:00:00"}"#;
+    let row = serde_json::from_str::<Row>(json).unwrap();
+    assert_eq!(row.date, datetime!(2024-03-01 00:00));
+    assert_eq!(row.strict, datetime!(2024-02-29 00:00));
+    assert_eq!(row.time, DateTime::UNIX_EPOCH);
+
+    // The strict form rejects it
+    let json = r#"{"date":"2024-02-29","strict":"2024-02-29T00:00:00Z","time":"00:00:00"}"#;
+    let error = serde_json::from_str::<Row>(json).unwrap_err();
+    assert!(error.to_string().contains("without a time component"), "{}", error);
+
+    let json = r#"{"date":"2024-02-30","strict":"2024-02-29","time":"00:00:00"}"#;
+    assert!(serde_json::from_str::<Row>(json).is_err());
+    let json = r#"{"date":"2024-02-29","strict":"2024-02-29","time":"2024-02-29T00:00:00Z"}"#;
+    assert!(serde_json::from_str::<Row>(json).is_err());
+}
+
+#[test]
+fn test_timestamp_out_of_range() {
+    #[derive(serde_derive::Deserialize, PartialEq, Debug)]
+    struct Row {
+        #[serde(with = "eos::serde::timestamp")]
+        seconds: DateTime<Utc>,
+        #[serde(with = "eos::serde::rfc3339_or_timestamp")]
+        either: DateTime<Utc>,
+    }
+
+    let max = DateTime::MAX.timestamp().as_seconds();
+    let json = format!(r#"{{"seconds":{},"either":{}}}"#, max, max);
+    let row = serde_json::from_str::<Row>(&json).unwrap();
+    assert_eq!(row.seconds, DateTime::MAX.with_nanosecond(0).unwrap());
+    assert_eq!(row.either, row.seconds);
+
+    // Timestamps outside of the supported range are errors instead of being clamped
+    for value in [
+        (max + 1).to_string(),
+        i64::MAX.to_string(),
+        i64::MIN.to_string(),
+        u64::MAX.to_string(),
+    ] {
+        let json = format!(r#"{{"seconds":{},"either":0}}"#, value);
+        assert!(serde_json::from_str::<Row>(&json).is_err(), "{}", value);
+        let json = format!(r#"{{"seconds":0,"either":{}}}"#, value);
+        assert!(serde_json::from_str::<Row>(&json).is_err(), "{}", value);
+    }
+    let json = r#"{"seconds":0,"either":1e15}"#;
+    assert!(serde_json::from_str::<Row>(json).is_err());
+}
+
+#[test]
+fn test_binary_leap_second_round_trip() {
+    let leap = datetime!(2016-12-31 23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    let bytes = bincode::serialize(&leap).unwrap();
+    assert_eq!(
+        bytes,
+        bincode::serialize(&(1483228799i64, 1_500_000_000u32, 0i32)).unwrap()
+    );
+    let back: DateTime<Utc> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, leap);
+    assert_eq!(back.nanosecond(), 1_500_000_000);
+
+    let leap = datetime!(2016-12-31 18:59:59 -05:00)
+        .with_nanosecond(1_500_000_000)
+        .unwrap();
+    let back: DateTime<UtcOffset> = bincode::deserialize(&bincode::serialize(&leap).unwrap()).unwrap();
+    assert_eq!(back, leap);
+    assert_eq!(back.nanosecond(), 1_500_000_000);
+    assert_eq!(back.offset(), &utc_offset!(-05:00));
+
+    let ts = Timestamp::new(1483228799, 1_500_000_000);
+    assert_eq!(
+        bincode::deserialize::<Timestamp>(&bincode::serialize(&ts).unwrap()).unwrap(),
+        ts
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "time")]
+
+use eos::{date, datetime, time, utc_offset, Date, DateTime, Error, Time, UtcOffset};
+
+fn year_out_of_range(value: i64, min: i64, max: i64) -> Error {
+    Error::FieldOutOfRange {
+        field: "year",
+        value,
+        min,
+        max,
+    }
+}
+
+#[test]
+fn date_round_trip_across_shared_range() -> Result<(), Error> {
+    // Every day in the range supported by both libraries
+    let mut date = ::time::Date::MIN;
+    loop {
+        let converted = Date::try_from(date)?;
+        assert_eq!(converted.year() as i32, date.year());
+        assert_eq!(converted.ordinal(), date.ordinal());
+        assert_eq!(::time::Date::try_from(converted)?, date);
+        match date.next_day() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn date_year_boundary() {
+    let (min, max) = (::time::Date::MIN.year() as i64, ::time::Date::MAX.year() as i64);
+    assert_eq!(
+        ::time::Date::try_from(date!(10000 - 01 - 01)),
+        Err(year_out_of_range(10_000, min, max))
+    );
+    assert_eq!(
+        ::time::Date::try_from(Date::MIN),
+        Err(year_out_of_range(i16::MIN as i64, min, max))
+    );
+    assert_eq!(
+        ::time::Date::try_from(date!(9999 - 12 - 31)).map(|d| d.year()),
+        Ok(9999)
+    );
+    assert!(::time::OffsetDateTime::try_from(datetime!(10000-01-01 00:00)).is_err());
+}
+
+#[test]
+fn time_round_trip_across_shared_range() {
+    // Every second of the day with a few different nanoseconds
+    for seconds in 0..86_400u32 {
+        let (hour, minute, second) = ((seconds / 3600) as u8, (seconds / 60 % 60) as u8, (seconds % 60) as u8);
+        for nanosecond in [0, 1, 500_000_000, 999_999_999] {
+            let t = Time::new(hour, minute, second)
+                .unwrap()
+                .with_nanosecond(nanosecond)
+                .unwrap();
+            let converted = ::time::Time::from(t);
+            assert_eq!(converted.as_hms_nano(), (hour, minute, second, nanosecond));
+            assert_eq!(Time::from(converted), t);
+        }
+    }
+}
+
+#[test]
+fn leap_seconds_are_clamped() {
+    let leap = time!(23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    assert_eq!(::time::Time::from(leap).as_hms_nano(), (23, 59, 59, 999_999_999));
+}
+
+#[test]
+fn offset_round_trip_across_shared_range() -> Result<(), Error> {
+    for seconds in (-86_400..=86_400).step_by(15) {
+        let offset = UtcOffset::from_seconds(seconds).unwrap();
+        let converted = ::time::UtcOffset::from(offset);
+        assert_eq!(converted.whole_seconds(), seconds);
+        assert_eq!(UtcOffset::try_from(converted)?, offset);
+    }
+
+    let large = ::time::UtcOffset::from_hms(25, 0, 0).unwrap();
+    assert!(matches!(
+        UtcOffset::try_from(large),
+        Err(Error::FieldOutOfRange { field: "offset", .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn datetime_round_trip() -> Result<(), Error> {
+    let dt = datetime!(2022-03-17 14:25:30 -05:00).with_nanosecond(5).unwrap();
+    let converted = ::time::OffsetDateTime::try_from(dt)?;
+    assert_eq!(converted.unix_timestamp(), dt.timestamp().as_seconds());
+    assert_eq!(converted.nanosecond(), 5);
+    assert_eq!(converted.offset().whole_seconds(), -5 * 3600);
+
+    let back = DateTime::<UtcOffset>::try_from(converted)?;
+    assert_eq!(back, dt);
+    assert_eq!(back.offset(), &utc_offset!(-05:00));
+    Ok(())
+}
@@ -1,9 +1,12 @@
 // These tests are adapted from Python's datetime library
 // https://github.com/python/cpython/blob/3.10/Lib/test/datetimetester.py
 
+// The adapted tests keep Python's `x = x + y` spelling
+#![allow(clippy::assign_op_pattern)]
+
 use eos::{
-    datetime, ext::IntervalLiteral, time, utc_offset, Date, DateTime, DateTimeResolution, Interval, Time, TimeZone,
-    Timestamp, Utc, UtcOffset, Weekday,
+    date, datetime, ext::IntervalLiteral, time, unit, utc_offset, AnyTimeZone, Date, DateTime, DateTimeResolution,
+    DateTimeResolutionKind, Interval, Time, TimeZone, Timestamp, Utc, UtcOffset, Weekday, ZoneInfo,
 };
 
 fn this_or_next_sunday(date: Date) -> Date {
@@ -50,9 +53,9 @@ impl AmericanTimeZone {
 impl TimeZone for AmericanTimeZone {
     fn name(&self, ts: Timestamp) -> Option<&str> {
         if self.is_dst(&ts.to_utc()) {
-            Some(self.name)
-        } else {
             Some(self.dst_name)
+        } else {
+            Some(self.name)
         }
     }
 
@@ -77,6 +80,18 @@ impl TimeZone for AmericanTimeZone {
         utc.with_timezone(self)
     }
 
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        if self.is_dst(&ts.to_utc()) {
+            ZoneInfo::new(
+                self.offset.saturating_add(utc_offset!(+01:00)),
+                Some(self.dst_name.into()),
+                true,
+            )
+        } else {
+            ZoneInfo::new(self.offset, Some(self.name.into()), false)
+        }
+    }
+
     fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
     where
         Self: Sized,
@@ -129,6 +144,43 @@ const PACIFIC: AmericanTimeZone = AmericanTimeZone {
     dst_name: "PDT",
 };
 
+// AmericanTimeZone::convert_utc resolves the local time again, which loses the second
+// occurrence of an ambiguous hour. This keeps the offset that was computed from UTC.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct FoldAware(AmericanTimeZone);
+
+impl TimeZone for FoldAware {
+    fn name(&self, ts: Timestamp) -> Option<&str> {
+        self.0.name(ts)
+    }
+
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.0.offset(ts)
+    }
+
+    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        let offset = self.0.offset(utc.timestamp());
+        utc.shift(offset);
+        DateTimeResolution::unambiguous(*utc.date(), *utc.time(), offset, self).lenient()
+    }
+
+    fn info_at(&self, ts: Timestamp) -> ZoneInfo {
+        self.0.info_at(ts)
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        self.0.resolve(date, time).with_timezone(self)
+    }
+}
+
+const FOLD_EAST: FoldAware = FoldAware(EAST);
+
 const DT: DateTime = datetime!(2021-12-31 00:00);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -241,6 +293,159 @@ fn test_datetime_resolve() -> Result<(), eos::Error> {
     Ok(())
 }
 
+#[test]
+fn test_resolution_combinators() {
+    let ambiguous = EAST.resolve(date!(2021 - 11 - 07), time!(01:30));
+    assert_eq!(ambiguous.single(), None);
+    let error = ambiguous.into_result().unwrap_err();
+    assert_eq!(error.kind(), DateTimeResolutionKind::Ambiguous);
+    assert_eq!(error.earlier(), &datetime!(2021-11-07 1:30 am -04:00));
+    assert_eq!(error.later(), &datetime!(2021-11-07 1:30 am -05:00));
+    assert_eq!(
+        eos::Error::from(error),
+        eos::Error::AmbiguousDateTime(date!(2021 - 11 - 07), time!(01:30))
+    );
+
+    let missing = EAST.resolve(date!(2021 - 03 - 14), time!(02:30));
+    assert_eq!(missing.single(), None);
+    let error = missing.into_result().unwrap_err();
+    assert_eq!(error.kind(), DateTimeResolutionKind::Missing);
+    assert_eq!(error.to_string(), "2021-03-14T02:30:00 was skipped");
+    let (earlier, later) = error.into_pair();
+    assert_eq!(earlier.offset(), &utc_offset!(-05:00));
+    assert_eq!(later.offset(), &utc_offset!(-04:00));
+
+    let unambiguous = EAST.resolve(date!(2021 - 11 - 07), time!(03:30));
+    assert_eq!(
+        unambiguous.single(),
+        Some(datetime!(2021-11-07 3:30 am -05:00).with_timezone(EAST))
+    );
+    assert_eq!(unambiguous.into_result().map(|dt| dt.hour()), Ok(3));
+
+    // Mapping keeps the kind and offsets
+    let mapped = missing.map(|dt| dt.with_minute(0).unwrap());
+    assert!(mapped.is_missing());
+    assert_eq!(mapped.time(), &time!(02:00));
+    assert_eq!(mapped.lenient(), datetime!(2021-03-14 03:00 am -04:00));
+    let mapped = ambiguous.map(|dt| dt.with_minute(15).unwrap());
+    assert!(mapped.is_ambiguous());
+    assert_eq!(mapped.later().map(|dt| *dt.offset()), Ok(utc_offset!(-05:00)));
+    assert_eq!(mapped.earlier().map(|dt| *dt.time()), Ok(time!(01:15)));
+}
+
+#[test]
+fn test_builder_in_timezone() -> Result<(), eos::Error> {
+    let mut builder = eos::Builder::new();
+    builder.year(2021).month(11).day(7).hour(1).minute(30);
+    let resolution = builder.timezone(EAST).build_in()?;
+    assert!(resolution.is_ambiguous());
+    assert_eq!(resolution.later()?, datetime!(2021-11-07 1:30 am -05:00));
+    assert_eq!(
+        builder.timezone(EAST).build(),
+        Err(eos::Error::AmbiguousDateTime(date!(2021 - 11 - 07), time!(01:30)))
+    );
+    assert_eq!(
+        builder.timezone(EAST).build_lenient_in()?,
+        datetime!(2021-11-07 1:30 am -04:00)
+    );
+
+    builder.month(3).day(14).hour(2);
+    assert!(builder.timezone(EAST).build_in()?.is_missing());
+    assert_eq!(
+        builder.timezone(EAST).build_lenient_in()?,
+        datetime!(2021-03-14 3:30 am -04:00)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_any_timezone() {
+    let zones = [
+        AnyTimeZone::Utc,
+        AnyTimeZone::from(utc_offset!(+09:00)),
+        AnyTimeZone::custom(FOLD_EAST),
+    ];
+    let utc = datetime!(2021-11-07 05:30);
+    let times: Vec<_> = zones.iter().map(|tz| utc.in_timezone(tz.clone())).collect();
+    assert!(times.iter().all(|dt| dt == &utc));
+    assert_eq!(times[1].to_string(), "2021-11-07T14:30:00+09:00");
+    assert_eq!(times[2].to_string(), "2021-11-07T01:30:00-04:00");
+    assert_eq!(times[2].tzname(), Some("EDT"));
+    // The fold is preserved through the erased type
+    let later = (utc + 1.hours()).in_timezone(zones[2].clone());
+    assert_eq!(later.to_string(), "2021-11-07T01:30:00-05:00");
+    assert_eq!(later.tzname(), Some("EST"));
+    assert!(times[2] < later);
+
+    let resolution = zones[2].clone().resolve(date!(2021 - 11 - 07), time!(01:30));
+    assert!(resolution.is_ambiguous());
+    let resolution = zones[2].clone().resolve(date!(2021 - 03 - 14), time!(02:30));
+    assert!(resolution.is_missing());
+    assert_eq!(resolution.lenient(), datetime!(2021-03-14 03:30 -04:00));
+    assert!(!zones[2].is_fixed());
+    assert!(zones[1].is_fixed());
+}
+
+static CLONES: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+// A timezone that keeps track of how many times it was cloned
+#[derive(Debug)]
+struct CountedZone(AmericanTimeZone);
+
+impl Clone for CountedZone {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Self(self.0)
+    }
+}
+
+impl TimeZone for CountedZone {
+    fn name(&self, ts: Timestamp) -> Option<&str> {
+        self.0.name(ts)
+    }
+
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.0.offset(ts)
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self> {
+        let inner = self.0;
+        inner.resolve(date, time).with_timezone(self)
+    }
+
+    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self> {
+        let dt = utc.in_timezone(self.0);
+        DateTimeResolution::unambiguous(*dt.date(), *dt.time(), *dt.offset(), self).lenient()
+    }
+}
+
+#[test]
+fn test_timezone_references() {
+    let zone = CountedZone(EAST);
+    let start = datetime!(2021-11-07 04:00);
+    let times: Vec<DateTime<&CountedZone>> = (0..1_000_000)
+        .map(|i| (start + i.seconds()).in_timezone(&zone))
+        .collect();
+    assert_eq!(CLONES.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+    // The fold is kept when converting through a reference
+    assert_eq!(times[5400].to_string(), "2021-11-07T01:30:00-04:00");
+    assert_eq!(times[5400 + 3600].to_string(), "2021-11-07T01:30:00-05:00");
+    assert_eq!(times[5400 + 3600].tzname(), Some("EST"));
+    assert!(times.windows(2).all(|w| w[0] < w[1]));
+
+    let shared = std::sync::Arc::new(EAST);
+    let dt = start.in_timezone(shared.clone());
+    assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+    assert_eq!(dt.to_string(), "2021-11-07T00:00:00-04:00");
+    let resolution = std::rc::Rc::new(EAST).resolve(date!(2021 - 03 - 14), time!(02:30));
+    assert!(resolution.is_missing());
+    assert_eq!(
+        (&EAST).at(date!(2021 - 11 - 07), time!(01:30)).offset(),
+        &utc_offset!(-04:00)
+    );
+}
+
 #[test]
 fn test_datetime_missing_interval() {
     let local = datetime!(2021-03-14 01:30).with_timezone(EAST);
@@ -255,3 +460,906 @@ fn test_datetime_missing_interval() {
     assert_eq!(local - 1.minutes(), datetime!(2021-03-14 01:59 -05:00));
     assert_eq!(local + 30.minutes(), datetime!(2021-03-14 03:30 -04:00));
 }
+
+#[test]
+fn test_daily_schedule() {
+    use eos::extra::DailySchedule;
+
+    // 02:30 doesn't exist on the day DST starts so it's shifted forward
+    let schedule = DailySchedule::new(time!(02:30), EAST);
+    let last = datetime!(2021-03-13 07:30);
+    let next = schedule.next_after(&last).unwrap();
+    assert_eq!(next, datetime!(2021-03-14 03:30 -04:00));
+    assert_eq!(schedule.next_after(&next).unwrap(), datetime!(2021-03-15 02:30 -04:00));
+
+    // 01:30 happens twice on the day DST ends but should only fire once
+    let schedule = DailySchedule::new(time!(01:30), EAST);
+    let last = datetime!(2021-11-06 01:30 -04:00).in_timezone(Utc);
+    let next = schedule.next_after(&last).unwrap();
+    assert_eq!(next, datetime!(2021-11-07 01:30 -04:00));
+    assert_eq!(schedule.next_after(&next).unwrap(), datetime!(2021-11-08 01:30 -05:00));
+    // In between the two 01:30s
+    let between = datetime!(2021-11-07 01:00 -05:00).in_timezone(Utc);
+    assert_eq!(
+        schedule.next_after(&between).unwrap(),
+        datetime!(2021-11-08 01:30 -05:00)
+    );
+
+    // Catching up after a multi-day outage that spans a transition
+    let schedule = DailySchedule::new(time!(09:00), EAST);
+    let last = datetime!(2021-03-12 09:00 -05:00).in_timezone(Utc);
+    let now = datetime!(2021-03-16 12:00);
+    assert_eq!(
+        schedule.missed_between(&last, &now),
+        vec![
+            datetime!(2021-03-13 14:00),
+            datetime!(2021-03-14 13:00),
+            datetime!(2021-03-15 13:00),
+        ]
+    );
+    assert_eq!(schedule.next_after(&now), Some(datetime!(2021-03-16 13:00)));
+    assert!(schedule.missed_between(&now, &last).is_empty());
+}
+
+#[test]
+fn test_days_between_across_dst() {
+    // Only 23 hours have elapsed since DST started in between
+    let start = datetime!(2021-03-13 12:00).with_timezone(EAST);
+    let end = datetime!(2021-03-14 12:00).with_timezone(EAST);
+    let end = EAST.resolve(*end.date(), *end.time()).lenient();
+    assert_eq!(end.offset(), &utc_offset!(-04:00));
+
+    let elapsed = Interval::days_between(&start, &end);
+    assert_eq!(elapsed.days(), 0);
+    assert_eq!(elapsed.hours(), 23);
+    assert_eq!(Interval::calendar_days_between(&start, &end, EAST), 1);
+
+    assert_eq!(Interval::calendar_days_between(&start, &end, Utc), 1);
+    assert_eq!(Interval::calendar_days_between(&end, &start, EAST), -1);
+}
+
+#[test]
+fn test_instant_range_in() {
+    let date = date!(2022 - 01 - 15);
+    assert_eq!(
+        date.instant_range_in(Utc),
+        (datetime!(2022-01-15 00:00), datetime!(2022-01-16 00:00))
+    );
+    assert_eq!(
+        date.instant_range_in(utc_offset!(+09:30)),
+        (datetime!(2022-01-14 14:30), datetime!(2022-01-15 14:30))
+    );
+    assert_eq!(
+        date.instant_range_in(utc_offset!(-08:00)),
+        (datetime!(2022-01-15 08:00), datetime!(2022-01-16 08:00))
+    );
+
+    // The day DST starts is only 23 hours long
+    let (start, end) = date!(2021 - 03 - 14).instant_range_in(EAST);
+    assert_eq!(start, datetime!(2021-03-14 05:00));
+    assert_eq!(end, datetime!(2021-03-15 04:00));
+
+    // The day DST ends is 25 hours long
+    let (start, end) = date!(2021 - 11 - 07).instant_range_in(EAST);
+    assert_eq!(start, datetime!(2021-11-07 04:00));
+    assert_eq!(end, datetime!(2021-11-08 05:00));
+}
+
+#[test]
+fn test_add_duration_across_dst() {
+    use core::time::Duration;
+
+    let hour = Duration::from_secs(3600);
+
+    // 02:00 EST skips to 03:00 EDT
+    let before = FOLD_EAST.resolve(date!(2021 - 03 - 14), time!(01:30)).lenient();
+    assert_eq!(before.offset(), &utc_offset!(-05:00));
+    let after = before + hour;
+    assert_eq!(after.time(), &time!(03:30));
+    assert_eq!(after.offset(), &utc_offset!(-04:00));
+    assert_eq!(after - hour, before);
+    assert_eq!((after - hour).offset(), &utc_offset!(-05:00));
+    assert_eq!(after.duration_since(&before), Ok(hour));
+
+    // 02:00 EDT falls back to 01:00 EST, so 01:30 happens twice
+    let before = FOLD_EAST.resolve(date!(2021 - 11 - 07), time!(00:30)).lenient();
+    assert_eq!(before.offset(), &utc_offset!(-04:00));
+    let first = before + hour;
+    let second = before + hour * 2;
+    assert_eq!(first.time(), &time!(01:30));
+    assert_eq!(first.offset(), &utc_offset!(-04:00));
+    assert_eq!(second.time(), &time!(01:30));
+    assert_eq!(second.offset(), &utc_offset!(-05:00));
+    assert_eq!(second - hour, first);
+    assert_eq!(second - hour * 2, before);
+
+    assert_eq!(before.checked_add_duration(hour * 2), Some(second));
+    assert_eq!(second.checked_sub_duration(hour * 2), Some(before));
+    assert_eq!((before + hour * 2).timezone(), &FOLD_EAST);
+}
+
+#[test]
+fn test_date_at_in() {
+    let dt = date!(2021 - 06 - 01).at_in(time!(09:30), EAST);
+    assert_eq!(dt.time(), &time!(09:30));
+    assert_eq!(dt.offset(), &utc_offset!(-04:00));
+    assert_eq!(dt.timezone(), &EAST);
+
+    // 02:00 EST skips to 03:00 EDT
+    let skipped = date!(2021 - 03 - 14).at_in(time!(02:30), EAST);
+    assert_eq!(skipped.time(), &time!(03:30));
+    assert_eq!(skipped.offset(), &utc_offset!(-04:00));
+
+    // 02:00 EDT falls back to 01:00 EST, the earlier one is chosen
+    let ambiguous = date!(2021 - 11 - 07).at_in(time!(01:30), EAST);
+    assert_eq!(ambiguous.time(), &time!(01:30));
+    assert_eq!(ambiguous.offset(), &utc_offset!(-04:00));
+
+    assert_eq!(
+        date!(2021 - 11 - 07).at_in(time!(01:30), Utc),
+        time!(01:30).on(date!(2021 - 11 - 07))
+    );
+    assert_eq!(
+        date!(2021 - 11 - 07).midnight(),
+        date!(2021 - 11 - 07).at(Time::MIDNIGHT)
+    );
+}
+
+#[test]
+fn test_round_across_dst() {
+    // 02:00 EST skips to 03:00 EDT
+    let dt = EAST.resolve(date!(2021 - 03 - 14), time!(03:40)).lenient();
+    assert_eq!(dt.offset(), &utc_offset!(-04:00));
+    let midnight = dt.floor(unit::Day);
+    assert_eq!(midnight.time(), &time!(00:00));
+    assert_eq!(midnight.offset(), &utc_offset!(-05:00));
+    assert_eq!(dt.floor(unit::Hour).time(), &time!(03:00));
+    assert_eq!(dt.ceil(unit::Hour).time(), &time!(04:00));
+
+    // Rounding into the gap gets resolved leniently
+    let before = EAST.resolve(date!(2021 - 03 - 14), time!(01:45)).lenient();
+    let rounded = before.round(unit::Hour);
+    assert_eq!(rounded.time(), &time!(03:00));
+    assert_eq!(rounded.offset(), &utc_offset!(-04:00));
+    assert_eq!(before.ceil(unit::Hour), rounded);
+
+    // The day DST starts is 23 hours long but rounding uses the wall clock
+    let noon = EAST.resolve(date!(2021 - 03 - 14), time!(12:00)).lenient();
+    assert_eq!(noon.round(unit::Day).date(), &date!(2021 - 03 - 15));
+    let morning = EAST.resolve(date!(2021 - 03 - 14), time!(11:59)).lenient();
+    assert_eq!(morning.round(unit::Day).time(), &time!(00:00));
+    assert_eq!(morning.round(unit::Day).offset(), &utc_offset!(-05:00));
+
+    // 02:00 EDT falls back to 01:00 EST, so 01:00 happens twice
+    let dt = EAST.resolve(date!(2021 - 11 - 07), time!(01:30)).later().unwrap();
+    assert_eq!(dt.offset(), &utc_offset!(-05:00));
+    let floor = dt.floor(unit::Hour);
+    assert_eq!(floor.time(), &time!(01:00));
+    assert_eq!(floor.offset(), &utc_offset!(-05:00));
+    assert!(floor < dt);
+    let ceil = dt.ceil(unit::Hour);
+    assert_eq!(ceil.time(), &time!(02:00));
+    assert_eq!(ceil.offset(), &utc_offset!(-05:00));
+    assert_eq!(dt.floor(unit::Day).offset(), &utc_offset!(-04:00));
+
+    // Rounding within the second occurrence stays in it
+    let dt = EAST.resolve(date!(2021 - 11 - 07), time!(01:30:30)).later().unwrap();
+    let ceil = dt.ceil(unit::Minute);
+    assert_eq!(ceil.time(), &time!(01:31));
+    assert_eq!(ceil.offset(), &utc_offset!(-05:00));
+    assert!(ceil > dt);
+    assert_eq!(dt.round(unit::Minute), ceil);
+    assert_eq!(dt.ceil_to(core::time::Duration::from_secs(60)), Ok(ceil));
+    let carried = dt.with_nanosecond(999_600_000).unwrap().with_second(59).unwrap();
+    let carried = carried.with_precision_rounded(eos::SubsecondPrecision::Millisecond);
+    assert_eq!(carried, ceil);
+    assert_eq!(carried.offset(), &utc_offset!(-05:00));
+    assert_eq!(dt.floor(unit::Minute).offset(), &utc_offset!(-05:00));
+    assert!(dt.floor(unit::Minute) < dt);
+
+    // The first occurrence stays in daylight saving time
+    let dt = EAST.resolve(date!(2021 - 11 - 07), time!(01:30:30)).earlier().unwrap();
+    assert_eq!(dt.offset(), &utc_offset!(-04:00));
+    assert_eq!(dt.floor(unit::Minute).offset(), &utc_offset!(-04:00));
+    assert_eq!(dt.ceil(unit::Minute).offset(), &utc_offset!(-04:00));
+    let ceil = dt.ceil(unit::Hour);
+    assert_eq!(ceil.time(), &time!(02:00));
+    assert_eq!(ceil.offset(), &utc_offset!(-05:00));
+
+    // Rounding up into the repeated hour picks the first occurrence
+    let before = EAST.resolve(date!(2021 - 11 - 07), time!(00:45)).lenient();
+    let ceil = before.ceil(unit::Hour);
+    assert_eq!(ceil.time(), &time!(01:00));
+    assert_eq!(ceil.offset(), &utc_offset!(-04:00));
+    assert_eq!(before.round(unit::Hour), ceil);
+}
+
+#[test]
+fn test_start_and_end_of_across_dst() {
+    // 01:00 happens twice on this day, first in EDT then in EST
+    let dt = EAST.resolve(date!(2021 - 11 - 07), time!(01:30)).later().unwrap();
+    let start = dt.start_of_day();
+    assert_eq!(start.time(), &time!(00:00));
+    assert_eq!(start.offset(), &utc_offset!(-04:00));
+    let end = dt.end_of_day();
+    assert_eq!(end.time(), &Time::MAX);
+    assert_eq!(end.offset(), &utc_offset!(-05:00));
+    assert_eq!(
+        end.duration_since(&start).unwrap(),
+        std::time::Duration::new(25 * 3600 - 1, 999_999_999)
+    );
+
+    let start = dt.start_of_month();
+    assert_eq!(start.date(), &date!(2021 - 11 - 01));
+    assert_eq!(start.offset(), &utc_offset!(-04:00));
+    let end = dt.end_of_month();
+    assert_eq!(end.date(), &date!(2021 - 11 - 30));
+    assert_eq!(end.offset(), &utc_offset!(-05:00));
+
+    // 02:00 EST skips to 03:00 EDT
+    let dt = EAST.resolve(date!(2021 - 03 - 14), time!(12:00)).lenient();
+    let start = dt.start_of_day();
+    let end = dt.end_of_day();
+    assert_eq!(start.offset(), &utc_offset!(-05:00));
+    assert_eq!(end.offset(), &utc_offset!(-04:00));
+    assert_eq!(
+        end.duration_since(&start).unwrap(),
+        std::time::Duration::new(23 * 3600 - 1, 999_999_999)
+    );
+    assert_eq!(dt.start_of_week(Weekday::Monday).offset(), &utc_offset!(-05:00));
+    assert_eq!(dt.start_of_week(Weekday::Monday).date(), &date!(2021 - 03 - 08));
+    assert_eq!(dt.end_of_week(Weekday::Monday), end);
+    assert_eq!(dt.end_of_quarter().offset(), &utc_offset!(-04:00));
+    assert_eq!(dt.end_of_year().offset(), &utc_offset!(-05:00));
+}
+
+#[test]
+fn test_next_occurrence_across_dst() {
+    // 02:00 EDT falls back to 01:00 EST, so 01:30 happens twice
+    let before = EAST.resolve(date!(2021 - 11 - 07), time!(00:50)).lenient();
+    let next = before.next_occurrence(time!(01:30));
+    assert_eq!(next.time(), &time!(01:30));
+    assert_eq!(next.offset(), &utc_offset!(-04:00));
+
+    // Between the two 01:30s the earlier one already happened
+    let between = EAST.resolve(date!(2021 - 11 - 07), time!(01:15)).later().unwrap();
+    assert!(between > next);
+    let next = between.next_occurrence(time!(01:30));
+    assert_eq!(next.date(), &date!(2021 - 11 - 08));
+    assert_eq!(next.offset(), &utc_offset!(-05:00));
+    assert!(between.next(time!(01:30)) < between);
+
+    let prev = next.prev_occurrence(time!(01:30));
+    assert_eq!(prev.date(), &date!(2021 - 11 - 07));
+    assert_eq!(prev.offset(), &utc_offset!(-04:00));
+    assert_eq!(between.prev_occurrence(time!(01:30)), prev);
+
+    // 02:00 EST skips to 03:00 EDT so 02:30 is shifted to 03:30
+    let before = EAST.resolve(date!(2021 - 03 - 14), time!(01:00)).lenient();
+    let next = before.next_occurrence(time!(02:30));
+    assert_eq!(next.time(), &time!(03:30));
+    assert_eq!(next.offset(), &utc_offset!(-04:00));
+    let after = EAST.resolve(date!(2021 - 03 - 14), time!(03:20)).lenient();
+    assert_eq!(after.next_occurrence(time!(02:30)), next);
+    let after = EAST.resolve(date!(2021 - 03 - 14), time!(03:45)).lenient();
+    assert_eq!(after.prev_occurrence(time!(02:30)), next);
+    assert_eq!(next.next_occurrence(time!(02:30)).date(), &date!(2021 - 03 - 15));
+    assert_eq!(next.prev_occurrence(time!(02:30)).date(), &date!(2021 - 03 - 13));
+
+    // Every day lands on the same local time
+    let mut current = EAST.resolve(date!(2021 - 03 - 10), time!(12:00)).lenient();
+    for _ in 0..10 {
+        let next = current.next_occurrence(time!(09:30));
+        assert_eq!(next.time(), &time!(09:30));
+        assert!(next > current);
+        current = next;
+    }
+    assert_eq!(current.date(), &date!(2021 - 03 - 20));
+
+    // The edges return the last candidate rather than overflowing
+    assert_eq!(
+        DateTime::MAX.next_occurrence(Time::MIDNIGHT),
+        Date::MAX.at(Time::MIDNIGHT)
+    );
+    assert_eq!(DateTime::MIN.prev_occurrence(Time::MAX), Date::MIN.at(Time::MAX));
+}
+
+#[test]
+fn test_tomorrow_and_yesterday_across_dst() {
+    let dt = EAST.resolve(date!(2021 - 03 - 13), time!(09:00)).lenient();
+    assert_eq!(dt.offset(), &utc_offset!(-05:00));
+    let next = dt.tomorrow();
+    assert_eq!(next.date(), &date!(2021 - 03 - 14));
+    assert_eq!(next.time(), &time!(09:00));
+    assert_eq!(next.offset(), &utc_offset!(-04:00));
+    assert_eq!(next.yesterday(), dt);
+    assert_eq!(dt.tomorrow(), dt + 1.days());
+    assert_eq!(next.checked_yesterday(), Some(dt));
+
+    // 02:30 does not exist on the day DST starts, so it's shifted forward
+    let dt = EAST.resolve(date!(2021 - 03 - 13), time!(02:30)).lenient();
+    let next = dt.tomorrow();
+    assert_eq!(next.time(), &time!(03:30));
+    assert_eq!(next.offset(), &utc_offset!(-04:00));
+    assert_eq!(dt.tomorrow(), dt + 1.days());
+    let dt = EAST.resolve(date!(2021 - 03 - 15), time!(02:30)).lenient();
+    assert_eq!(dt.yesterday(), next);
+}
+
+#[test]
+fn test_resolve_with_across_dst() -> Result<(), eos::Error> {
+    let dt = EAST.resolve(date!(2021 - 03 - 13), time!(09:00)).lenient();
+
+    // 02:30 does not exist on the day DST starts
+    let resolution = dt.resolve_with(date!(2021 - 03 - 14), time!(02:30));
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Missing);
+    assert!(resolution.exact().is_err());
+    let moved = resolution.lenient();
+    assert_eq!(moved.time(), &time!(03:30));
+    assert_eq!(moved.offset(), &utc_offset!(-04:00));
+    // with_time keeps the old offset and produces a local time that never happened
+    assert_eq!(
+        dt.with_date(date!(2021 - 03 - 14)).with_time(time!(02:30)).offset(),
+        &utc_offset!(-05:00)
+    );
+
+    // 01:30 happens twice on the day DST ends
+    let resolution = dt.resolve_with(date!(2021 - 11 - 07), time!(01:30));
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Ambiguous);
+    assert_eq!(resolution.earlier()?.offset(), &utc_offset!(-04:00));
+    assert_eq!(resolution.later()?.offset(), &utc_offset!(-05:00));
+
+    let resolution = dt.resolve_with(date!(2021 - 07 - 01), time!(12:00));
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Unambiguous);
+    assert_eq!(resolution.exact()?.offset(), &utc_offset!(-04:00));
+    Ok(())
+}
+
+#[test]
+fn test_new_resolved() -> Result<(), eos::Error> {
+    let resolution = DateTime::new_resolved(2021, 3, 14, time!(02:30), EAST)?;
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Missing);
+    assert_eq!(
+        resolution.exact(),
+        Err(eos::Error::SkippedDateTime(date!(2021 - 03 - 14), time!(02:30)))
+    );
+    assert_eq!(resolution.lenient().time(), &time!(03:30));
+
+    let resolution = DateTime::new_resolved(2021, 11, 7, time!(01:30), EAST)?;
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Ambiguous);
+    assert_eq!(
+        resolution.exact(),
+        Err(eos::Error::AmbiguousDateTime(date!(2021 - 11 - 07), time!(01:30)))
+    );
+    assert_eq!(resolution.earlier()?.offset(), &utc_offset!(-04:00));
+    assert_eq!(resolution.later()?.offset(), &utc_offset!(-05:00));
+
+    let resolution = DateTime::new_resolved(2021, 11, 8, time!(01:30), EAST)?;
+    assert_eq!(resolution.kind(), DateTimeResolutionKind::Unambiguous);
+    assert_eq!(
+        resolution.exact()?,
+        EAST.resolve(date!(2021 - 11 - 08), time!(01:30)).exact()?
+    );
+
+    assert_eq!(
+        DateTime::new_resolved(2021, 2, 29, time!(01:30), EAST),
+        Err(eos::Error::FieldOutOfRange {
+            field: "day",
+            value: 29,
+            min: 1,
+            max: 28
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_hash_across_timezones() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut set = HashSet::new();
+    set.insert(datetime!(2000-01-02 03:04:05 +03:00));
+    set.insert(datetime!(2000-01-02 00:04:05 +00:00));
+    set.insert(datetime!(2000-01-01 19:04:05 -05:00));
+    assert_eq!(set.len(), 1);
+
+    let utc = datetime!(2021-11-07 06:30);
+    let east = utc.in_timezone(FOLD_EAST);
+    assert_eq!(east, utc);
+    assert_eq!(hash(&east), hash(&utc));
+    assert_eq!(hash(&east), hash(&utc.in_timezone(FoldAware(CENTRAL))));
+    assert_ne!(hash(&east), hash(&(utc + 1.hours()).in_timezone(FOLD_EAST)));
+
+    // A leap second is the same instant in every timezone
+    let leap = datetime!(2016-12-31 23:59:59).with_nanosecond(1_500_000_000).unwrap();
+    let tokyo = datetime!(2017-01-01 08:59:59 +09:00)
+        .with_nanosecond(1_500_000_000)
+        .unwrap();
+    assert_eq!(leap, tokyo);
+    assert_eq!(hash(&leap), hash(&tokyo));
+}
+
+#[test]
+fn test_nth_weekday_across_dst() {
+    // DST starts on the second Sunday of March and ends on the first Sunday of November
+    let dt = EAST.resolve(date!(2021 - 03 - 01), time!(02:30)).lenient();
+    let start = dt.nth_weekday_of_month(Weekday::Sunday, 2).unwrap();
+    assert_eq!(start.date(), &date!(2021 - 03 - 14));
+    assert_eq!(start.time(), &time!(03:30));
+    assert_eq!(start.offset(), &utc_offset!(-04:00));
+    assert!(dt.nth_weekday_of_month(Weekday::Sunday, 5).is_err());
+
+    let last = dt.last_weekday_of_month(Weekday::Wednesday);
+    assert_eq!(last.date(), &date!(2021 - 03 - 31));
+    assert_eq!(last.time(), &time!(02:30));
+    assert_eq!(last.offset(), &utc_offset!(-04:00));
+
+    let dt = EAST.resolve(date!(2021 - 11 - 20), time!(01:30)).lenient();
+    let end = dt.nth_weekday_of_month(Weekday::Sunday, 1).unwrap();
+    assert_eq!(end.date(), &date!(2021 - 11 - 07));
+    assert_eq!(end.offset(), &utc_offset!(-04:00));
+}
+
+#[test]
+fn test_interval_assign_across_dst() {
+    let starts = [
+        EAST.resolve(date!(2021 - 03 - 13), time!(02:30)).lenient(),
+        EAST.resolve(date!(2021 - 03 - 14), time!(01:30)).lenient(),
+        EAST.resolve(date!(2021 - 11 - 06), time!(01:30)).lenient(),
+        EAST.resolve(date!(2021 - 11 - 07), time!(00:30)).lenient(),
+        EAST.resolve(date!(2021 - 10 - 31), time!(12:00)).lenient(),
+    ];
+    let intervals = [1.hours(), 1.days(), 1.weeks(), 1.months(), 30.minutes() + 1.hours()];
+
+    for start in starts {
+        for interval in intervals {
+            let mut cursor = start;
+            cursor += interval;
+            let expected = start + interval;
+            assert_eq!(cursor, expected);
+            assert_eq!(cursor.offset(), expected.offset());
+
+            let mut cursor = start;
+            cursor -= interval;
+            let expected = start - interval;
+            assert_eq!(cursor, expected);
+            assert_eq!(cursor.offset(), expected.offset());
+        }
+    }
+}
+
+/// A timezone whose state changes every time it is queried, similar to the system timezone
+/// changing in between calls.
+#[derive(Debug, Clone, Default)]
+struct FlipFlopZone {
+    calls: std::rc::Rc<std::cell::Cell<u32>>,
+}
+
+impl FlipFlopZone {
+    fn flip(&self) -> bool {
+        self.calls.set(self.calls.get() + 1);
+        self.calls.get() % 2 == 1
+    }
+}
+
+impl TimeZone for FlipFlopZone {
+    fn name(&self, _ts: Timestamp) -> Option<&str> {
+        Some(if self.flip() { "ONE" } else { "TWO" })
+    }
+
+    fn offset(&self, _ts: Timestamp) -> UtcOffset {
+        if self.flip() {
+            utc_offset!(+01:00)
+        } else {
+            utc_offset!(+02:00)
+        }
+    }
+
+    fn info_at(&self, _ts: Timestamp) -> ZoneInfo {
+        if self.flip() {
+            ZoneInfo::new(utc_offset!(+01:00), Some("ONE".into()), false)
+        } else {
+            ZoneInfo::new(utc_offset!(+02:00), Some("TWO".into()), true)
+        }
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        DateTimeResolution::unambiguous(date, time, utc_offset!(+01:00), self)
+    }
+
+    fn convert_utc(self, mut utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        utc.shift(utc_offset!(+01:00));
+        DateTimeResolution::unambiguous(*utc.date(), *utc.time(), utc_offset!(+01:00), self).lenient()
+    }
+}
+
+/// A timezone that can only be cloned, not copied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SharedZone(std::rc::Rc<AmericanTimeZone>);
+
+impl TimeZone for SharedZone {
+    fn name(&self, ts: Timestamp) -> Option<&str> {
+        self.0.name(ts)
+    }
+
+    fn offset(&self, ts: Timestamp) -> UtcOffset {
+        self.0.offset(ts)
+    }
+
+    fn resolve(self, date: Date, time: Time) -> DateTimeResolution<Self>
+    where
+        Self: Sized,
+    {
+        let resolved = (*self.0).resolve(date, time);
+        let (earlier, later) = resolved.into_pair();
+        if resolved.is_missing() {
+            DateTimeResolution::missing(date, time, *earlier.offset(), *later.offset(), self)
+        } else if resolved.is_ambiguous() {
+            DateTimeResolution::ambiguous(date, time, *earlier.offset(), *later.offset(), self)
+        } else {
+            DateTimeResolution::unambiguous(date, time, *earlier.offset(), self)
+        }
+    }
+
+    fn convert_utc(self, utc: DateTime<Utc>) -> DateTime<Self>
+    where
+        Self: Sized,
+    {
+        let local = (*self.0).convert_utc(utc);
+        DateTimeResolution::unambiguous(*local.date(), *local.time(), *local.offset(), self).lenient()
+    }
+}
+
+#[test]
+fn test_timezone_of() {
+    let zone = SharedZone(std::rc::Rc::new(EAST));
+    let summer = datetime!(2021-07-01 12:00).in_timezone(zone.clone());
+    let winter = datetime!(2021-12-01 12:00 +01:00);
+
+    let converted = winter.in_timezone_of(&summer);
+    assert_eq!(converted, winter);
+    assert_eq!(converted.timezone(), &zone);
+    assert_eq!(converted.offset(), &utc_offset!(-05:00));
+    assert_eq!(converted.time(), &time!(06:00));
+    assert_eq!(std::rc::Rc::strong_count(&zone.0), 3);
+
+    let kept = winter.with_timezone_of(&summer);
+    assert_eq!(kept.date(), winter.date());
+    assert_eq!(kept.time(), winter.time());
+    assert_eq!(kept.offset(), &utc_offset!(-05:00));
+    assert_eq!(kept.timezone(), &zone);
+
+    // Local times in a gap are shifted forward
+    let gap = datetime!(2021-03-14 02:30).with_timezone_of(&summer);
+    assert_eq!(gap.time(), &time!(03:30));
+    assert_eq!(gap.offset(), &utc_offset!(-04:00));
+
+    // Going back the other way works the same for copyable timezones
+    assert_eq!(
+        summer.clone().in_timezone_of(&winter),
+        datetime!(2021-07-01 13:00 +01:00)
+    );
+    assert_eq!(summer.with_timezone_of(&winter), datetime!(2021-07-01 08:00 +01:00));
+}
+
+#[test]
+fn test_zone_info() {
+    let summer = datetime!(2021-07-01 12:00).in_timezone(EAST).zone_info();
+    assert_eq!(summer.offset(), &utc_offset!(-04:00));
+    assert_eq!(summer.name(), Some("EDT"));
+    assert!(summer.is_dst());
+
+    let winter = datetime!(2021-01-01 12:00).in_timezone(EAST);
+    let info = winter.zone_info();
+    assert_eq!(info.offset(), winter.offset());
+    assert_eq!(info.name(), winter.tzname());
+    assert!(!info.is_dst());
+
+    // The default implementation forwards to offset and name
+    let info = AlwaysEasternStandard.info_at(winter.timestamp());
+    assert_eq!(info.offset(), &utc_offset!(-05:00));
+    assert_eq!(info.name(), None);
+    assert!(!info.is_dst());
+
+    let info = utc_offset!(+09:00).info_at(winter.timestamp());
+    assert_eq!(
+        (info.offset(), info.name(), info.is_dst()),
+        (&utc_offset!(+09:00), None, false)
+    );
+    let info = Utc.info_at(winter.timestamp());
+    assert_eq!(
+        (info.offset(), info.name(), info.is_dst()),
+        (&UtcOffset::UTC, Some("UTC"), false)
+    );
+}
+
+#[test]
+fn test_zone_info_single_query() {
+    use eos::fmt::format_spec;
+
+    let zone = FlipFlopZone::default();
+    let dt = zone.clone().at(date!(2022 - 01 - 03), time!(12:00));
+    zone.calls.set(0);
+
+    let info = dt.zone_info();
+    assert_eq!(zone.calls.get(), 1);
+    assert_eq!(
+        (info.offset(), info.name(), info.is_dst()),
+        (&utc_offset!(+01:00), Some("ONE"), false)
+    );
+
+    // The offset directives use the offset stored in the datetime, only %Z queries the zone
+    zone.calls.set(1);
+    let formatted = dt.format(format_spec!("%z %Z %o %Z")).to_string();
+    assert_eq!(zone.calls.get(), 3);
+    assert_eq!(formatted, "+0100 TWO +01:00 ONE");
+
+    // Directives that do not need the zone do not query it
+    let formatted = dt.format(format_spec!("%Y-%m-%d")).to_string();
+    assert_eq!(zone.calls.get(), 3);
+    assert_eq!(formatted, "2022-01-03");
+}
+
+#[test]
+fn test_whole_units_until_across_dst() {
+    // The night DST starts is only 23 hours long
+    let before = EAST.resolve(date!(2021 - 03 - 13), time!(12:00)).lenient();
+    let after = EAST.resolve(date!(2021 - 03 - 14), time!(12:00)).lenient();
+    assert_eq!(before.hours_until(&after), 23);
+    assert_eq!(before.days_until(&after), 0);
+    assert_eq!(after.hours_until(&before), -23);
+    assert_eq!(before.minutes_until(&after), 23 * 60);
+    assert_eq!(before.seconds_until(&after), 23 * 3600);
+
+    // The night DST ends is 25 hours long
+    let before = EAST.resolve(date!(2021 - 11 - 06), time!(12:00)).lenient();
+    let after = EAST.resolve(date!(2021 - 11 - 07), time!(12:00)).lenient();
+    assert_eq!(before.hours_until(&after), 25);
+    assert_eq!(before.days_until(&after), 1);
+    assert_eq!(after.days_until(&before), -1);
+
+    // Comparing against a different timezone uses the same instant
+    let utc = after.in_timezone(Utc);
+    assert_eq!(before.hours_until(&utc), 25);
+    assert_eq!(utc.seconds_until(&after), 0);
+}
+
+#[test]
+fn test_iter_to_across_dst() {
+    // 02:30 is skipped on the day DST starts
+    let start = EAST.resolve(date!(2021 - 03 - 13), time!(02:30)).lenient();
+    let end = EAST.resolve(date!(2021 - 03 - 16), time!(00:00)).lenient();
+    let days: Vec<_> = start.iter_to(end).collect();
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[1].time(), &time!(03:30));
+    // The wall clock time doesn't drift after the skipped time
+    assert_eq!(days[2].time(), &time!(02:30));
+    assert_eq!(days[2].offset(), &utc_offset!(-04:00));
+
+    // Hourly steps through the night DST ends
+    let start = EAST.resolve(date!(2021 - 11 - 07), time!(00:00)).lenient();
+    let end = EAST.resolve(date!(2021 - 11 - 07), time!(04:00)).lenient();
+    let hours: Vec<_> = start.iter_to(end).step(1.hours()).collect();
+    assert_eq!(hours.len(), 4);
+    assert!(hours.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(
+        hours.iter().rev().cloned().collect::<Vec<_>>(),
+        start.iter_to(end).step(1.hours()).rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_recurrences() {
+    use eos::iter::{self, ShortMonthPolicy};
+
+    // The wall clock time is kept across DST transitions
+    let start = EAST.resolve(date!(2021 - 03 - 01), time!(02:30)).lenient();
+    let sundays: Vec<_> = iter::every_weekday(start, Weekday::Sunday).take(3).collect();
+    assert_eq!(sundays[0].date(), &date!(2021 - 03 - 07));
+    assert_eq!(sundays[1].date(), &date!(2021 - 03 - 14));
+    // 02:30 is skipped on this day so it moves forward
+    assert_eq!(sundays[1].time(), &time!(03:30));
+    assert_eq!(sundays[2].time(), &time!(02:30));
+    assert_eq!(sundays[2].offset(), &utc_offset!(-04:00));
+
+    // The start is included if it matches
+    let monday = datetime!(2022-03-14 09:00);
+    assert_eq!(iter::every_weekday(monday, Weekday::Monday).next(), Some(monday));
+    assert_eq!(
+        iter::monthly_on_day(monday, 14, ShortMonthPolicy::Skip).unwrap().next(),
+        Some(monday)
+    );
+    assert_eq!(
+        iter::monthly_on_day(monday, 13, ShortMonthPolicy::Skip).unwrap().next(),
+        Some(datetime!(2022-04-13 09:00))
+    );
+    assert_eq!(
+        iter::yearly_on(monday, 3, 13, ShortMonthPolicy::Skip).unwrap().next(),
+        Some(datetime!(2023-03-13 09:00))
+    );
+
+    // Clamping never drifts onto an earlier day
+    let clamped: Vec<_> = iter::monthly_on_day(datetime!(2024-01-01 00:00), 30, ShortMonthPolicy::Clamp)
+        .unwrap()
+        .take(4)
+        .map(|dt| *dt.date())
+        .collect();
+    assert_eq!(
+        clamped,
+        [
+            date!(2024 - 01 - 30),
+            date!(2024 - 02 - 29),
+            date!(2024 - 03 - 30),
+            date!(2024 - 04 - 30)
+        ]
+    );
+
+    // Leap days only occur in leap years when skipped
+    let leap_days = iter::yearly_on(datetime!(1896-01-01 00:00), 2, 29, ShortMonthPolicy::Skip)
+        .unwrap()
+        .take_while(|dt| dt.year() < 1910)
+        .map(|dt| dt.year())
+        .collect::<Vec<_>>();
+    assert_eq!(leap_days, [1896, 1904, 1908]);
+
+    // The iterators end at the maximum date and are fused
+    let end = Date::MAX.at(time!(00:00));
+    let mut weekly = iter::every_weekday(end, Weekday::Monday);
+    assert_eq!(weekly.next(), None);
+    assert_eq!(weekly.next(), None);
+    let mut monthly = iter::monthly_on_day(end, 31, ShortMonthPolicy::Clamp).unwrap();
+    assert_eq!(monthly.next(), Some(end));
+    assert_eq!(monthly.next(), None);
+    assert_eq!(iter::yearly_on(end, 12, 31, ShortMonthPolicy::Skip).unwrap().count(), 1);
+
+    assert!(iter::monthly_on_day(monday, 0, ShortMonthPolicy::Clamp).is_none());
+    assert!(iter::yearly_on(monday, 2, 30, ShortMonthPolicy::Clamp).is_none());
+    assert!(iter::yearly_on(monday, 13, 1, ShortMonthPolicy::Clamp).is_none());
+}
+
+#[cfg(feature = "cron")]
+#[test]
+fn test_cron_across_dst() {
+    use eos::extra::Cron;
+
+    // 02:30 is skipped on the day DST starts so it doesn't fire
+    let cron = Cron::parse("30 2 * * *").unwrap();
+    let start = EAST.resolve(date!(2021 - 03 - 13), time!(03:00)).lenient();
+    let fires: Vec<_> = cron.after(start).take(2).collect();
+    assert_eq!(fires[0].date(), &date!(2021 - 03 - 15));
+    assert_eq!(fires[0].time(), &time!(02:30));
+    assert_eq!(cron.prev_before(&fires[0]).unwrap().date(), &date!(2021 - 03 - 13));
+
+    // 01:30 happens twice on the day DST ends but only fires once
+    let cron = Cron::parse("30 1 * * *").unwrap();
+    let start = EAST.resolve(date!(2021 - 11 - 07), time!(00:00)).lenient();
+    let fires: Vec<_> = cron.after(start).take(2).collect();
+    assert_eq!(fires[0].date(), &date!(2021 - 11 - 07));
+    assert_eq!(fires[0].offset(), &utc_offset!(-04:00));
+    assert_eq!(fires[1].date(), &date!(2021 - 11 - 08));
+
+    // Starting during the repeated hour doesn't fire again
+    let repeated = EAST.resolve(date!(2021 - 11 - 07), time!(01:15)).later().unwrap();
+    assert_eq!(cron.next_after(&repeated).unwrap().date(), &date!(2021 - 11 - 08));
+    assert_eq!(cron.prev_before(&repeated), Some(fires[0]));
+
+    // Every 30 minutes through the repeated hour fires at each instant once
+    let cron = Cron::parse("*/30 * * * *").unwrap();
+    let fires: Vec<_> = cron.after(start).take(6).collect();
+    let times: Vec<_> = fires.iter().map(|dt| *dt.time()).collect();
+    assert_eq!(
+        times,
+        [
+            time!(00:30),
+            time!(01:00),
+            time!(01:30),
+            time!(02:00),
+            time!(02:30),
+            time!(03:00)
+        ]
+    );
+    assert!(fires.windows(2).all(|w| w[0] < w[1]));
+}
+
+// The system timezone depends on the machine running the tests, so these only check that
+// each platform's code path runs and is self-consistent. They bail out early on machines
+// that cannot report any timezone information.
+#[cfg(feature = "system")]
+#[test]
+fn test_system_queries() {
+    use eos::System;
+
+    let current = match System::current_offset() {
+        Ok(offset) => offset,
+        Err(_) => return,
+    };
+
+    let now = Utc::now();
+    let tz = System::new().unwrap();
+    assert_eq!(tz.offset(now.timestamp()), current);
+    assert_eq!(System::offset_at(&now), current);
+
+    for dt in [
+        datetime!(1970-01-01 00:00),
+        datetime!(2021-01-15 12:00),
+        datetime!(2021-07-15 12:00),
+        datetime!(2037-12-31 23:59),
+    ] {
+        let offset = System::offset_at(&dt);
+        assert!(offset >= UtcOffset::MIN && offset <= UtcOffset::MAX);
+    }
+
+    // Converting to local time and resolving it again always finds the same instant,
+    // including around the DST transitions of most timezones
+    for start in [
+        datetime!(2021-03-14 00:00),
+        datetime!(2021-03-28 00:00),
+        datetime!(2021-11-07 00:00),
+    ] {
+        for hours in 0..12 {
+            let utc = start + (hours * 30).minutes();
+            let local = utc.in_timezone(tz.clone());
+            assert_eq!(local, utc);
+            assert_eq!(local.offset(), &System::offset_at(&utc));
+
+            let resolved = tz.clone().resolve(*local.date(), *local.time());
+            assert!(!resolved.is_missing());
+            let candidates = [resolved.clone().earlier().unwrap(), resolved.later().unwrap()];
+            assert!(candidates.contains(&local));
+        }
+    }
+
+    if let Some(name) = System::name() {
+        assert!(!name.is_empty());
+        assert!(!name.starts_with('/') && !name.starts_with(':'));
+    }
+}
+
+#[cfg(all(feature = "system", feature = "std"))]
+#[test]
+fn test_cached_system() {
+    use eos::System;
+
+    let (tz, cached) = match (System::new(), System::cached()) {
+        (Ok(tz), Ok(cached)) => (tz, cached),
+        _ => return,
+    };
+
+    // The cache agrees with querying the system directly, including around DST transitions
+    for start in [
+        datetime!(2021-03-14 00:00),
+        datetime!(2021-03-28 00:00),
+        datetime!(2021-11-07 00:00),
+    ] {
+        for minutes in 0..48 {
+            let utc = start + (minutes * 15).minutes();
+            assert_eq!(cached.offset(utc.timestamp()), tz.offset(utc.timestamp()));
+            assert_eq!(cached.info_at(utc.timestamp()), tz.info_at(utc.timestamp()));
+
+            let local = utc.in_timezone(cached.clone());
+            assert_eq!(local, utc);
+            let resolved = cached.clone().resolve(*local.date(), *local.time());
+            assert_eq!(resolved.kind(), tz.clone().resolve(*local.date(), *local.time()).kind());
+        }
+    }
+
+    cached.refresh().unwrap();
+    assert_eq!(cached.now().offset(), System::now().unwrap().offset());
+
+    let local = eos::now_local().unwrap();
+    assert_eq!(*local.offset(), System::offset_at(&local.clone().in_timezone(Utc)));
+}